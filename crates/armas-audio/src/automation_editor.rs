@@ -0,0 +1,577 @@
+//! Automation Editor Component
+//!
+//! Breakpoint editor for a single automation lane.
+//!
+//! Features:
+//! - Drag on empty space to marquee-select points, click to select one
+//!   (Shift toggles), drag a selected point to move the whole selection
+//! - Double-click empty space to add a point
+//! - Copy, paste at the playhead and delete via the platform shortcuts
+//!   while the editor has focus
+//! - Scale selected values about a pivot or stretch their timing
+
+use crate::timeline_track::AutomationPoint;
+use armas_basic::ext::draw_focus_ring;
+use armas_basic::theme::Theme;
+use egui::{Color32, Event, Key, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2};
+
+/// Radius of a breakpoint handle in pixels
+const POINT_RADIUS: f32 = 4.0;
+
+/// An edit applied to the current selection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AutomationEdit {
+    /// Copy the selected points to the editor clipboard
+    Copy,
+    /// Paste the clipboard with its first point at the playhead, clamped to the lane
+    Paste,
+    /// Remove the selected points
+    Delete,
+    /// Scale selected values toward (`factor` < 1) or away from (`factor` > 1) `pivot`
+    ScaleValues {
+        /// Multiplier applied to each value's distance from `pivot`
+        factor: f32,
+        /// Value the selection is scaled about
+        pivot: f32,
+    },
+    /// Stretch selected times by `factor`, anchored at the earliest selected point
+    /// and clamped to the lane
+    StretchTime {
+        /// Multiplier applied to each point's distance from the anchor
+        factor: f32,
+    },
+}
+
+/// Scale point values toward or away from `pivot`
+///
+/// Results are clamped to 0.0..=1.0.
+#[must_use]
+pub fn scale_values(points: &[AutomationPoint], factor: f32, pivot: f32) -> Vec<AutomationPoint> {
+    points
+        .iter()
+        .map(|p| AutomationPoint {
+            time: p.time,
+            value: (p.value - pivot).mul_add(factor, pivot).clamp(0.0, 1.0),
+        })
+        .collect()
+}
+
+/// Stretch point times by `factor` about `anchor` (in beats)
+///
+/// Times never go below zero.
+#[must_use]
+pub fn stretch_time(points: &[AutomationPoint], factor: f32, anchor: f32) -> Vec<AutomationPoint> {
+    points
+        .iter()
+        .map(|p| AutomationPoint {
+            time: (p.time - anchor).mul_add(factor, anchor).max(0.0),
+            value: p.value,
+        })
+        .collect()
+}
+
+/// Selection, clipboard and drag state (stored in egui temp data)
+#[derive(Clone, Default)]
+struct AutomationEditorState {
+    /// Indices of selected points, in ascending order
+    selection: Vec<usize>,
+    /// Copied points with times relative to the earliest one
+    clipboard: Vec<AutomationPoint>,
+    /// Marquee corners while a marquee drag is active
+    marquee: Option<(Pos2, Pos2)>,
+    /// Whether the current drag moves the selection
+    moving: bool,
+}
+
+/// Response from the automation editor
+#[derive(Debug, Clone)]
+pub struct AutomationEditorResponse {
+    /// The UI response
+    pub response: Response,
+    /// Whether the point list was edited this frame
+    pub changed: bool,
+    /// Indices of the selected points after this frame
+    pub selection: Vec<usize>,
+}
+
+impl AutomationEditorResponse {
+    /// Check if the point list was edited this frame
+    #[must_use]
+    pub const fn changed(&self) -> bool {
+        self.changed
+    }
+}
+
+/// Automation lane editor
+///
+/// Edits a time-sorted list of [`AutomationPoint`]s. The selection and the
+/// clipboard live in the component; edits can come from shortcuts or from
+/// [`AutomationEditor::edit`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::Ui;
+/// # use armas_basic::Theme;
+/// # fn example(ui: &mut Ui, theme: &Theme) {
+/// use armas_audio::{AutomationEdit, AutomationEditor, AutomationPoint};
+///
+/// let mut points = vec![
+///     AutomationPoint::new(0.0, 0.2),
+///     AutomationPoint::new(4.0, 0.8),
+/// ];
+///
+/// let response = AutomationEditor::new(&mut points)
+///     .beats(8.0)
+///     .playhead(Some(2.0))
+///     .edit(AutomationEdit::ScaleValues { factor: 0.5, pivot: 0.5 })
+///     .show(ui, theme);
+///
+/// if response.changed() {
+///     println!("{} points", points.len());
+/// }
+/// # }
+/// ```
+pub struct AutomationEditor<'a> {
+    points: &'a mut Vec<AutomationPoint>,
+    id: Option<egui::Id>,
+    beats: f32,
+    beat_width: f32,
+    height: f32,
+    playhead: Option<f32>,
+    line_color: Option<Color32>,
+    edits: Vec<AutomationEdit>,
+}
+
+impl<'a> AutomationEditor<'a> {
+    /// Create a new automation editor
+    pub const fn new(points: &'a mut Vec<AutomationPoint>) -> Self {
+        Self {
+            points,
+            id: None,
+            beats: 16.0,
+            beat_width: 40.0,
+            height: 120.0,
+            playhead: None,
+            line_color: None,
+            edits: Vec::new(),
+        }
+    }
+
+    /// Set unique ID for state persistence
+    #[must_use]
+    pub fn id(mut self, id: impl Into<egui::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the visible length in beats
+    #[must_use]
+    pub const fn beats(mut self, beats: f32) -> Self {
+        self.beats = beats.max(1.0);
+        self
+    }
+
+    /// Set the width of one beat in pixels
+    #[must_use]
+    pub const fn beat_width(mut self, width: f32) -> Self {
+        self.beat_width = width.max(4.0);
+        self
+    }
+
+    /// Set the lane height
+    #[must_use]
+    pub const fn height(mut self, height: f32) -> Self {
+        self.height = height.max(40.0);
+        self
+    }
+
+    /// Set the playhead position in beats (paste target)
+    #[must_use]
+    pub const fn playhead(mut self, beat: Option<f32>) -> Self {
+        self.playhead = beat;
+        self
+    }
+
+    /// Set the curve and point color
+    #[must_use]
+    pub const fn line_color(mut self, color: Color32) -> Self {
+        self.line_color = Some(color);
+        self
+    }
+
+    /// Apply an edit to the current selection this frame
+    #[must_use]
+    pub fn edit(mut self, edit: AutomationEdit) -> Self {
+        self.edits.push(edit);
+        self
+    }
+
+    /// Show the automation editor
+    pub fn show(mut self, ui: &mut Ui, theme: &Theme) -> AutomationEditorResponse {
+        let id = self.id.unwrap_or_else(|| ui.id().with("automation_editor"));
+        let desired_size = Vec2::new(self.beats * self.beat_width, self.height);
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click_and_drag());
+
+        let mut state: AutomationEditorState =
+            ui.ctx().data_mut(|d| d.get_temp(id).unwrap_or_default());
+        let len = self.points.len();
+        state.selection.retain(|&i| i < len);
+
+        let mut changed = self.handle_pointer(ui, rect, &response, &mut state);
+        if response.clicked() || response.drag_started() {
+            response.request_focus();
+        }
+
+        if response.has_focus() {
+            let shortcuts: Vec<AutomationEdit> = ui.input(|i| {
+                i.events
+                    .iter()
+                    .filter_map(|event| match event {
+                        Event::Copy => Some(AutomationEdit::Copy),
+                        Event::Paste(_) => Some(AutomationEdit::Paste),
+                        Event::Key {
+                            key: Key::Delete | Key::Backspace,
+                            pressed: true,
+                            ..
+                        } => Some(AutomationEdit::Delete),
+                        _ => None,
+                    })
+                    .collect()
+            });
+            self.edits.extend(shortcuts);
+        }
+
+        for edit in std::mem::take(&mut self.edits) {
+            changed |= self.apply(edit, &mut state);
+        }
+
+        if ui.is_rect_visible(rect) {
+            self.draw(ui.painter(), theme, rect, &state);
+            if response.has_focus() {
+                draw_focus_ring(ui.painter(), rect, theme);
+            }
+        }
+
+        let selection = state.selection.clone();
+        ui.ctx().data_mut(|d| d.insert_temp(id, state));
+
+        if changed {
+            ui.ctx().request_repaint();
+        }
+
+        AutomationEditorResponse {
+            response,
+            changed,
+            selection,
+        }
+    }
+
+    /// Handle selection, marquee and point dragging; returns true if points moved
+    fn handle_pointer(
+        &mut self,
+        ui: &Ui,
+        rect: Rect,
+        response: &Response,
+        state: &mut AutomationEditorState,
+    ) -> bool {
+        let mut changed = false;
+
+        if response.double_clicked() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                if self.hit_point(rect, pos).is_none() {
+                    self.points.push(self.pos_to_point(rect, pos));
+                    state.selection = vec![self.points.len() - 1];
+                    sort_points(self.points, &mut state.selection);
+                    return true;
+                }
+            }
+        }
+
+        if response.clicked() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                let toggle = ui.input(|i| i.modifiers.shift);
+                match self.hit_point(rect, pos) {
+                    Some(index) if toggle => {
+                        if let Some(at) = state.selection.iter().position(|&i| i == index) {
+                            state.selection.remove(at);
+                        } else {
+                            state.selection.push(index);
+                            state.selection.sort_unstable();
+                        }
+                    }
+                    Some(index) => state.selection = vec![index],
+                    None => state.selection.clear(),
+                }
+            }
+        }
+
+        if response.drag_started() {
+            // Start where the button went down, not where the drag threshold was crossed
+            if let Some(pos) = ui.input(|i| i.pointer.press_origin()) {
+                match self.hit_point(rect, pos) {
+                    Some(index) => {
+                        if !state.selection.contains(&index) {
+                            state.selection = vec![index];
+                        }
+                        state.moving = true;
+                    }
+                    None => state.marquee = Some((pos, pos)),
+                }
+            }
+        }
+
+        if response.dragged() {
+            if state.moving {
+                let delta = response.drag_delta();
+                if delta != Vec2::ZERO {
+                    for &i in &state.selection {
+                        let point = &mut self.points[i];
+                        point.time =
+                            (point.time + delta.x / self.beat_width).clamp(0.0, self.beats);
+                        point.value = (point.value - delta.y / rect.height()).clamp(0.0, 1.0);
+                    }
+                    changed = true;
+                }
+            } else if let (Some((_, end)), Some(pos)) =
+                (state.marquee.as_mut(), response.interact_pointer_pos())
+            {
+                *end = pos;
+            }
+        }
+
+        if response.drag_stopped() {
+            if state.moving {
+                state.moving = false;
+                sort_points(self.points, &mut state.selection);
+            }
+            if let Some((start, end)) = state.marquee.take() {
+                let marquee = Rect::from_two_pos(start, end);
+                state.selection = (0..self.points.len())
+                    .filter(|&i| marquee.contains(self.point_to_pos(rect, self.points[i])))
+                    .collect();
+            }
+        }
+
+        changed
+    }
+
+    /// Apply one edit to the selection; returns true if points changed
+    fn apply(&mut self, edit: AutomationEdit, state: &mut AutomationEditorState) -> bool {
+        let selected: Vec<AutomationPoint> =
+            state.selection.iter().map(|&i| self.points[i]).collect();
+
+        match edit {
+            AutomationEdit::Copy => {
+                let origin = earliest_time(&selected);
+                state.clipboard = selected
+                    .iter()
+                    .map(|p| AutomationPoint::new(p.time - origin, p.value))
+                    .collect();
+                false
+            }
+            AutomationEdit::Paste => {
+                if state.clipboard.is_empty() {
+                    return false;
+                }
+                let at = self.playhead.unwrap_or(0.0);
+                let first = self.points.len();
+                self.points.extend(
+                    state.clipboard.iter().map(|p| {
+                        AutomationPoint::new((p.time + at).clamp(0.0, self.beats), p.value)
+                    }),
+                );
+                state.selection = (first..self.points.len()).collect();
+                sort_points(self.points, &mut state.selection);
+                true
+            }
+            AutomationEdit::Delete => {
+                if state.selection.is_empty() {
+                    return false;
+                }
+                for &i in state.selection.iter().rev() {
+                    self.points.remove(i);
+                }
+                state.selection.clear();
+                true
+            }
+            AutomationEdit::ScaleValues { factor, pivot } => {
+                let scaled = scale_values(&selected, factor, pivot);
+                self.write_selection(&state.selection, &scaled)
+            }
+            AutomationEdit::StretchTime { factor } => {
+                let stretched: Vec<AutomationPoint> =
+                    stretch_time(&selected, factor, earliest_time(&selected))
+                        .into_iter()
+                        .map(|p| AutomationPoint::new(p.time.min(self.beats), p.value))
+                        .collect();
+                let changed = self.write_selection(&state.selection, &stretched);
+                sort_points(self.points, &mut state.selection);
+                changed
+            }
+        }
+    }
+
+    /// Write transformed points back to their selected indices
+    fn write_selection(&mut self, selection: &[usize], points: &[AutomationPoint]) -> bool {
+        for (&i, &point) in selection.iter().zip(points) {
+            self.points[i] = point;
+        }
+        !selection.is_empty()
+    }
+
+    fn point_to_pos(&self, rect: Rect, point: AutomationPoint) -> Pos2 {
+        Pos2::new(
+            point.time.mul_add(self.beat_width, rect.min.x),
+            point.value.mul_add(-rect.height(), rect.max.y),
+        )
+    }
+
+    fn pos_to_point(&self, rect: Rect, pos: Pos2) -> AutomationPoint {
+        AutomationPoint::new(
+            ((pos.x - rect.min.x) / self.beat_width).clamp(0.0, self.beats),
+            ((rect.max.y - pos.y) / rect.height()).clamp(0.0, 1.0),
+        )
+    }
+
+    fn hit_point(&self, rect: Rect, pos: Pos2) -> Option<usize> {
+        let hit_radius = POINT_RADIUS * 2.0;
+        self.points
+            .iter()
+            .position(|&p| self.point_to_pos(rect, p).distance(pos) <= hit_radius)
+    }
+
+    fn draw(
+        &self,
+        painter: &egui::Painter,
+        theme: &Theme,
+        rect: Rect,
+        state: &AutomationEditorState,
+    ) {
+        let corner_radius = f32::from(theme.spacing.corner_radius_small);
+        painter.rect_filled(rect, corner_radius, theme.muted());
+
+        // Beat grid
+        let grid_stroke = Stroke::new(1.0, theme.border().gamma_multiply(0.5));
+        for beat in 1..self.beats.ceil() as usize {
+            let x = (beat as f32).mul_add(self.beat_width, rect.min.x);
+            painter.vline(x, rect.y_range(), grid_stroke);
+        }
+
+        let line_color = self.line_color.unwrap_or_else(|| theme.primary());
+        let positions: Vec<Pos2> = self
+            .points
+            .iter()
+            .map(|&p| self.point_to_pos(rect, p))
+            .collect();
+        if positions.len() > 1 {
+            painter.add(egui::Shape::line(
+                positions.clone(),
+                Stroke::new(2.0, line_color),
+            ));
+        }
+
+        for (i, &pos) in positions.iter().enumerate() {
+            if state.selection.contains(&i) {
+                painter.circle_filled(pos, POINT_RADIUS + 1.0, theme.foreground());
+            }
+            painter.circle_filled(pos, POINT_RADIUS, line_color);
+        }
+
+        if let Some(beat) = self.playhead {
+            let x = beat.mul_add(self.beat_width, rect.min.x);
+            if rect.x_range().contains(x) {
+                painter.vline(x, rect.y_range(), Stroke::new(1.5, theme.secondary()));
+            }
+        }
+
+        if let Some((start, end)) = state.marquee {
+            let marquee = Rect::from_two_pos(start, end);
+            painter.rect_filled(marquee, 0.0, line_color.gamma_multiply(0.15));
+            painter.rect_stroke(
+                marquee,
+                0.0,
+                Stroke::new(1.0, line_color),
+                egui::StrokeKind::Inside,
+            );
+        }
+
+        painter.rect_stroke(
+            rect,
+            corner_radius,
+            Stroke::new(1.0, theme.border()),
+            egui::StrokeKind::Inside,
+        );
+    }
+}
+
+/// Earliest time among `points`, or 0.0 when empty
+fn earliest_time(points: &[AutomationPoint]) -> f32 {
+    points
+        .iter()
+        .map(|p| p.time)
+        .reduce(f32::min)
+        .unwrap_or(0.0)
+}
+
+/// Sort points by time, remapping selected indices to their new positions
+fn sort_points(points: &mut Vec<AutomationPoint>, selection: &mut Vec<usize>) {
+    let mut tagged: Vec<(AutomationPoint, bool)> = points
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| (p, selection.contains(&i)))
+        .collect();
+    tagged.sort_by(|a, b| a.0.time.total_cmp(&b.0.time));
+
+    *points = tagged.iter().map(|&(p, _)| p).collect();
+    *selection = tagged
+        .iter()
+        .enumerate()
+        .filter(|(_, &(_, selected))| selected)
+        .map(|(i, _)| i)
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_values_about_pivot() {
+        let points = [
+            AutomationPoint::new(0.0, 0.1),
+            AutomationPoint::new(1.0, 0.9),
+        ];
+        let scaled = scale_values(&points, 0.5, 0.5);
+
+        assert!((scaled[0].value - 0.3).abs() < 1e-6);
+        assert!((scaled[1].value - 0.7).abs() < 1e-6);
+        assert_eq!(scaled[1].time, 1.0);
+    }
+
+    #[test]
+    fn test_stretch_time_from_anchor() {
+        let points = [
+            AutomationPoint::new(2.0, 0.5),
+            AutomationPoint::new(3.0, 0.5),
+        ];
+        let stretched = stretch_time(&points, 2.0, 2.0);
+
+        assert_eq!(stretched[0].time, 2.0);
+        assert_eq!(stretched[1].time, 4.0);
+    }
+
+    #[test]
+    fn test_sort_points_keeps_selection() {
+        let mut points = vec![
+            AutomationPoint::new(2.0, 0.0),
+            AutomationPoint::new(0.0, 1.0),
+            AutomationPoint::new(1.0, 0.5),
+        ];
+        let mut selection = vec![0];
+        sort_points(&mut points, &mut selection);
+
+        assert_eq!(points[2], AutomationPoint::new(2.0, 0.0));
+        assert_eq!(selection, vec![2]);
+    }
+}
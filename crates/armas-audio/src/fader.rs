@@ -56,6 +56,14 @@ pub struct FaderResponse {
     pub value: f32,
     /// Whether the value changed this frame
     pub changed: bool,
+    /// Whether the user grabbed the fader this frame (start of an automation touch)
+    pub drag_started: bool,
+    /// Whether the user released the fader this frame (end of an automation touch)
+    pub drag_ended: bool,
+    /// Value smoothed by the fader's ballistics, for meter-style readouts
+    ///
+    /// Equal to `value` unless [`Fader::ballistics`] is set.
+    pub display_value: f32,
 }
 
 // Fader (minimal) default dimensions - just the track
@@ -80,6 +88,9 @@ const CHANNEL_CORNER_RADIUS: f32 = 4.0;
 const THUMB_WIDTH: f32 = 20.0;
 const THUMB_HEIGHT: f32 = 54.0;
 
+/// Display value difference below which ballistics stop repainting
+const BALLISTICS_EPSILON: f32 = 0.0005;
+
 /// Minimal vertical fader component (track + channel + thumb only)
 ///
 /// This is the core interactive slider without any container/housing.
@@ -102,6 +113,10 @@ pub struct Fader {
     velocity_mode: bool,
     /// Sensitivity for velocity mode
     velocity_sensitivity: f64,
+    /// Minimum size of the interactive area around the fader
+    touch_target: f32,
+    /// Rise and fall times in milliseconds for the display value
+    ballistics: Option<(f32, f32)>,
 }
 
 impl Fader {
@@ -120,6 +135,8 @@ impl Fader {
             default_value: None,
             velocity_mode: true, // Enabled by default for faders
             velocity_sensitivity: 1.0,
+            touch_target: 0.0,
+            ballistics: None,
         }
     }
 
@@ -205,6 +222,28 @@ impl Fader {
         self
     }
 
+    /// Enlarge the interactive area to at least `size` points
+    ///
+    /// The whole fader rect grows symmetrically: it widens to `size`, and its
+    /// ends extend far enough that the thumb keeps a `size`-tall target at
+    /// the top and bottom of its travel. Thin faders become easy to grab on
+    /// touch screens. Only interaction is affected; the fader draws as before.
+    #[must_use]
+    pub const fn touch_target(mut self, size: f32) -> Self {
+        self.touch_target = size;
+        self
+    }
+
+    /// Smooth [`FaderResponse::display_value`] with rise and fall times
+    ///
+    /// Useful for meter-style readouts that should glide rather than jump.
+    /// The control value itself is never smoothed.
+    #[must_use]
+    pub const fn ballistics(mut self, rise_ms: f32, fall_ms: f32) -> Self {
+        self.ballistics = Some((rise_ms, fall_ms));
+        self
+    }
+
     /// Show the fader and return the response
     pub fn show(mut self, ui: &mut Ui, theme: &armas_basic::Theme) -> FaderResponse {
         let mut changed = false;
@@ -238,6 +277,17 @@ impl Fader {
         // Calculate fader rect (the actual fader area, always self.width wide)
         let fader_rect = self.calculate_fader_rect(rect, scale_width);
 
+        // Enlarged touch area, sharing the fader's interaction
+        let hit_rect = self.calculate_hit_rect(fader_rect);
+        if hit_rect != fader_rect {
+            let touch_response = ui.interact(
+                hit_rect,
+                response.id.with("touch_target"),
+                Sense::click_and_drag(),
+            );
+            response = response.union(touch_response).with_new_rect(rect);
+        }
+
         // Handle interactions
         if self.handle_double_click(&response, &mut changed) {
             response.mark_changed();
@@ -274,11 +324,46 @@ impl Fader {
             });
         }
 
+        let display_value = self.update_display_value(ui, fader_id.with("display_value"));
+
         FaderResponse {
+            drag_started: response.drag_started(),
+            drag_ended: response.drag_stopped(),
             response,
             value: self.value,
             changed,
+            display_value,
+        }
+    }
+
+    /// Interactive area: the fader rect widened to the touch target, with its
+    /// ends extended by the amount the thumb falls short of that size
+    fn calculate_hit_rect(&self, fader_rect: Rect) -> Rect {
+        let thumb_height = THUMB_HEIGHT * (self.height / FADER_DEFAULT_HEIGHT);
+        fader_rect.expand2(Vec2::new(
+            ((self.touch_target - fader_rect.width()) / 2.0).max(0.0),
+            ((self.touch_target - thumb_height) / 2.0).max(0.0),
+        ))
+    }
+
+    /// Advance the smoothed display value towards the control value
+    fn update_display_value(&self, ui: &Ui, display_id: egui::Id) -> f32 {
+        let Some((rise_ms, fall_ms)) = self.ballistics else {
+            return self.value;
+        };
+
+        let dt = ui.input(|i| i.stable_dt);
+        let current: f32 = ui
+            .ctx()
+            .data(|d| d.get_temp(display_id))
+            .unwrap_or(self.value);
+        let display = ballistics_step(current, self.value, dt, rise_ms, fall_ms);
+        ui.ctx().data_mut(|d| d.insert_temp(display_id, display));
+
+        if (display - self.value).abs() > BALLISTICS_EPSILON {
+            ui.ctx().request_repaint();
         }
+        display
     }
 
     /// Calculate fader rect based on scale position
@@ -735,7 +820,16 @@ impl FaderStrip {
     }
 
     /// Show the fader strip and return the new value
-    pub fn show(mut self, ui: &mut Ui, theme: &armas_basic::Theme) -> (Response, f32) {
+    ///
+    /// Kept for compatibility; use [`Self::show_response`] to also get
+    /// change and drag start/end events.
+    pub fn show(self, ui: &mut Ui, theme: &armas_basic::Theme) -> (Response, f32) {
+        let fader_response = self.show_response(ui, theme);
+        (fader_response.response, fader_response.value)
+    }
+
+    /// Show the fader strip and return the full fader response
+    pub fn show_response(self, ui: &mut Ui, theme: &armas_basic::Theme) -> FaderResponse {
         let desired_size = Vec2::new(self.width, self.height);
         let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
 
@@ -812,12 +906,48 @@ impl FaderStrip {
                 .size(fader_width, fader_height)
                 .show(&mut fader_ui, theme);
 
-            self.value = fader_response.value;
-
             // Return the fader's response (which handles interaction)
-            return (fader_response.response, self.value);
+            return fader_response;
         }
 
-        (response, self.value)
+        FaderResponse {
+            response,
+            value: self.value,
+            changed: false,
+            drag_started: false,
+            drag_ended: false,
+            display_value: self.value,
+        }
+    }
+}
+
+/// One step of exponential smoothing from `current` towards `target`
+///
+/// Rising values use `rise_ms` as the time constant, falling values `fall_ms`.
+/// A time constant of zero jumps straight to the target.
+fn ballistics_step(current: f32, target: f32, dt: f32, rise_ms: f32, fall_ms: f32) -> f32 {
+    let time_ms = if target > current { rise_ms } else { fall_ms };
+    if time_ms <= 0.0 {
+        return target;
+    }
+    let alpha = 1.0 - (-dt * 1000.0 / time_ms).exp();
+    (target - current).mul_add(alpha, current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ballistics_rise_and_fall_rates() {
+        // One time constant covers ~63% of the distance
+        let risen = ballistics_step(0.0, 1.0, 0.01, 10.0, 100.0);
+        assert!((risen - 0.632).abs() < 0.01);
+
+        // Falling with a 10x longer time constant moves much less
+        let fallen = ballistics_step(1.0, 0.0, 0.01, 10.0, 100.0);
+        assert!((1.0 - fallen - 0.095).abs() < 0.01);
+
+        assert!((ballistics_step(0.2, 0.8, 0.01, 0.0, 0.0) - 0.8).abs() < f32::EPSILON);
     }
 }
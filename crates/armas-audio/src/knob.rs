@@ -34,6 +34,10 @@ pub struct KnobResponse {
     pub value: f32,
     /// Whether the value changed this frame
     pub changed: bool,
+    /// Whether the user grabbed the knob this frame (start of an automation touch)
+    pub drag_started: bool,
+    /// Whether the user released the knob this frame (end of an automation touch)
+    pub drag_ended: bool,
 }
 
 impl KnobResponse {
@@ -235,6 +239,8 @@ impl Knob {
         }
 
         KnobResponse {
+            drag_started: response.drag_started(),
+            drag_ended: response.drag_stopped(),
             response,
             value: *value,
             changed,
@@ -28,6 +28,7 @@ pub mod xy_pad;
 pub mod icons;
 pub mod piano;
 pub(crate) mod playhead;
+pub(crate) mod serde_color;
 pub(crate) mod slot;
 pub(crate) mod snap_grid;
 pub(crate) mod time_ruler;
@@ -35,24 +36,24 @@ pub(crate) mod timeline_track;
 pub(crate) mod track_header;
 
 // Re-exports
-pub use automation_editor::{AutomationEditor, AutomationEditorResponse, AutomationPoint as AutoPoint};
+pub use automation_editor::{AutomationEdit, AutomationEditor, AutomationEditorResponse};
 pub use drum_sequencer::{
     DrumRow, DrumSequencer, DrumSequencerColorScheme, DrumSequencerResponse, DrumSequencerVariant,
     DrumStep,
 };
-pub use fader::{Fader, FaderScalePosition, FaderStrip};
-pub use knob::Knob;
-pub use meter::{AudioMeter, MeterStyle, ScalePosition};
+pub use fader::{Fader, FaderResponse, FaderScalePosition, FaderStrip};
+pub use knob::{Knob, KnobResponse};
+pub use meter::{AudioMeter, MeterOrientation, MeterScale, MeterStyle, ScalePosition};
 pub use midi_controller::{MidiController, MidiControllerResponse, MidiControllerState};
 pub use midi_pad::{MidiPad, MidiPadResponse, PadColorScheme, PadConfig, PadState, PadVariant};
 pub use mixer_strip::{Insert, MixerStrip, MixerStripMode, MixerStripResponse, Route, Send};
 pub use mod_wheel::{ModWheel, WheelSize, WheelType};
 pub use mpe_keyboard::{MPEKey, MPEKeyboard, MPEKeyboardResponse, MPENote, MPEOrientation};
-pub use piano_roll::{Note, PianoRoll, PianoRollResponse};
+pub use piano_roll::{ColorBy, Note, PianoRoll, PianoRollResponse};
 pub use step_sequencer::StepSequencer;
 pub use timeline::{
-    LoopRegionData, MarkerData, PunchRegionData, SelectionRangeData, Timeline, TimelineResponse,
-    Track,
+    Arrangement, LoopRegionData, MarkerData, PunchRegionData, SelectionRangeData, Timeline,
+    TimelineGeometry, TimelineResponse, Track,
 };
 pub use timeline_marker::{MarkerVariant, TimelineMarker, TimelineMarkerResponse};
 pub use timeline_region::{RegionVariant, TimelineRegion, TimelineRegionResponse};
@@ -60,9 +61,10 @@ pub use xy_pad::{XYPad, XYPadVariant};
 
 // Types from internal modules that are exposed through public API structs
 pub use piano_roll::GridDivision;
+pub use time_ruler::TimeDisplayMode;
 pub use timeline_track::{
     AutomationData, AutomationPoint, FadeCurve, FadeHandle, FadeSettings, MidiData, MidiNote,
-    PlaybackSettings, Region, RegionEdge, RegionType,
+    PlaybackSettings, Region, RegionEdge, RegionType, RenderQuality,
 };
 pub use track_header::TrackControls;
 
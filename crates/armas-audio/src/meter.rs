@@ -1,11 +1,25 @@
 //! Audio Meter Component
 //!
 //! DAW-style audio level meter with peak hold
-//! and customizable color gradients.
+//! and customizable color gradients. Meters can be vertical or horizontal,
+//! mono or a stereo pair sharing one scale.
 
 use armas_basic::color::{lerp_color, with_alpha, ColorStop, Gradient};
 use egui::{Color32, Pos2, Rect, Response, Sense, Ui, Vec2};
 
+/// Seconds the peak indicator holds before fading
+const PEAK_HOLD_SECS: f32 = 1.5;
+/// Seconds the peak indicator takes to fade out
+const PEAK_FADE_SECS: f32 = 1.0;
+/// Gap between the two bars of a stereo meter
+const CHANNEL_GAP: f32 = 2.0;
+/// Fill color between the ceiling and 0 dBFS (amber-500)
+const WARNING_COLOR: Color32 = Color32::from_rgb(245, 158, 11);
+/// Fill color above the ceiling once the signal reaches 0 dBFS (red-500)
+const OVER_COLOR: Color32 = Color32::from_rgb(239, 68, 68);
+/// Opacity of the warning zone drawn behind the fill
+const WARNING_ZONE_ALPHA: u8 = 30;
+
 /// Response from the audio meter
 #[derive(Debug, Clone)]
 pub struct MeterResponse {
@@ -15,6 +29,20 @@ pub struct MeterResponse {
     pub level: f32,
     /// Current peak hold value
     pub peak: f32,
+    /// Right channel level of a stereo meter (`level` is the left channel)
+    pub right_level: Option<f32>,
+    /// Right channel peak hold value of a stereo meter
+    pub right_peak: Option<f32>,
+}
+
+/// Direction the meter fills in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MeterOrientation {
+    /// Fills from bottom to top
+    #[default]
+    Vertical,
+    /// Fills from left to right
+    Horizontal,
 }
 
 /// Visual style for the meter display
@@ -29,18 +57,136 @@ pub enum MeterStyle {
 /// Scale position for dB markings
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScalePosition {
-    /// Scale on the left side
+    /// Scale on the left side (above a horizontal meter)
     Left,
-    /// Scale on the right side
+    /// Scale on the right side (below a horizontal meter)
     Right,
     /// No scale
     None,
 }
 
+/// Reference scale used for the meter's tick labels
+///
+/// K-system scales move the 0 mark below digital full scale so that
+/// program material sits around the reference with headroom above it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MeterScale {
+    /// Plain dBFS, 0 at digital full scale
+    #[default]
+    Dbfs,
+    /// K-12 (broadcast): 0 at -12 dBFS
+    K12,
+    /// K-14 (pop/rock mastering): 0 at -14 dBFS
+    K14,
+    /// K-20 (film/classical): 0 at -20 dBFS
+    K20,
+    /// Approximate loudness in LUFS, with the EBU R128 target (-23) marked
+    Lufs,
+}
+
+impl MeterScale {
+    /// Level in dBFS where this scale's 0 reference sits
+    #[must_use]
+    pub const fn reference_db(self) -> f32 {
+        match self {
+            Self::Dbfs | Self::Lufs => 0.0,
+            Self::K12 => -12.0,
+            Self::K14 => -14.0,
+            Self::K20 => -20.0,
+        }
+    }
+
+    /// Convert a dBFS (or LUFS) value to linear amplitude (0.0 to 1.0)
+    #[must_use]
+    pub fn db_to_level(db: f32) -> f32 {
+        10.0_f32.powf(db / 20.0).clamp(0.0, 1.0)
+    }
+
+    /// Tick marks as (dBFS, label) pairs, top to bottom
+    fn marks(self) -> Vec<(f32, String)> {
+        let mut marks: Vec<(f32, String)> = match self {
+            Self::Dbfs => [0.0, -3.0, -6.0, -12.0, -18.0, -24.0]
+                .iter()
+                .map(|&db| (db, format!("{db}")))
+                .collect(),
+            Self::K12 | Self::K14 | Self::K20 => {
+                let reference = self.reference_db();
+                [-reference, 4.0, 0.0, -6.0, -12.0, -20.0]
+                    .iter()
+                    .map(|&rel| {
+                        let label = if rel > 0.0 {
+                            format!("+{rel}")
+                        } else {
+                            format!("{rel}")
+                        };
+                        (reference + rel, label)
+                    })
+                    .collect()
+            }
+            Self::Lufs => [0.0, -9.0, -14.0, -18.0, -23.0, -36.0]
+                .iter()
+                .map(|&db| (db, format!("{db}")))
+                .collect(),
+        };
+        marks.push((f32::NEG_INFINITY, "-∞".to_string()));
+        marks
+    }
+}
+
+/// Peak hold state for one channel
+#[derive(Debug, Clone, Copy)]
+struct PeakHold {
+    /// Held peak level
+    value: f32,
+    /// Time since the peak was hit (for fade out)
+    time: f32,
+}
+
+impl PeakHold {
+    const fn new(level: f32) -> Self {
+        Self {
+            value: level,
+            time: 0.0,
+        }
+    }
+
+    fn update(&mut self, level: f32, dt: f32) {
+        if level > self.value {
+            self.value = level;
+            self.time = 0.0;
+        } else {
+            self.time += dt;
+            // Hold, then fade towards the current level
+            if self.time > PEAK_HOLD_SECS {
+                let fade_progress = ((self.time - PEAK_HOLD_SECS) / PEAK_FADE_SECS).min(1.0);
+                self.value = self
+                    .value
+                    .mul_add(1.0 - fade_progress, level * fade_progress);
+            }
+        }
+    }
+
+    /// Whether the indicator is still held or fading
+    fn is_active(self) -> bool {
+        self.time < PEAK_HOLD_SECS + PEAK_FADE_SECS
+    }
+
+    /// Indicator opacity, fading out after the hold period
+    fn fade_alpha(self) -> f32 {
+        if self.time > PEAK_HOLD_SECS {
+            1.0 - ((self.time - PEAK_HOLD_SECS) / PEAK_FADE_SECS).min(1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
 /// Audio level meter component
 ///
-/// A professional DAW-style vertical meter with instant response,
-/// peak hold indicator, and customizable color schemes.
+/// A professional DAW-style meter with instant response, peak hold
+/// indicator, and customizable color schemes. Vertical by default; use
+/// [`AudioMeter::orientation`] for a horizontal meter and
+/// [`AudioMeter::stereo`] for an L/R pair.
 ///
 /// # Example
 ///
@@ -72,15 +218,22 @@ pub enum ScalePosition {
 ///     .monochrome(theme.primary())
 ///     .show_scale()
 ///     .show(ui, theme);
+///
+/// // Compact horizontal stereo meter for a header
+/// AudioMeter::stereo(0.6, 0.5)
+///     .orientation(armas_audio::MeterOrientation::Horizontal)
+///     .show(ui, theme);
 /// # }
 /// ```
 pub struct AudioMeter {
     /// Target level (0.0 to 1.0)
     target_level: f32,
-    /// Peak hold value
-    peak_hold: f32,
-    /// Time since peak was hit (for fade out)
-    peak_hold_time: f32,
+    /// Peak hold of the (left) channel
+    peak_hold: PeakHold,
+    /// Right channel level and peak hold of a stereo meter
+    right: Option<(f32, PeakHold)>,
+    /// Fill direction
+    orientation: MeterOrientation,
     /// Custom gradient (takes precedence over color range)
     gradient: Option<Gradient>,
     /// Minimum level color (used when gradient is None)
@@ -97,12 +250,20 @@ pub struct AudioMeter {
     style: MeterStyle,
     /// Scale position
     scale_position: ScalePosition,
+    /// Scale reference for tick labels
+    scale: MeterScale,
     /// Corner radius for glassmorphic background
     corner_radius: f32,
     /// Background opacity (0.0 to 1.0)
     background_opacity: f32,
     /// Enable glassmorphic background
     glassmorphic: bool,
+    /// Ceiling as a linear level; the zone above it is the warning zone
+    ceiling: Option<f32>,
+    /// Fill color above the ceiling
+    warning_color: Color32,
+    /// Fill color above the ceiling while the signal is at 0 dBFS
+    over_color: Color32,
 }
 
 impl AudioMeter {
@@ -112,8 +273,9 @@ impl AudioMeter {
         let clamped_level = level.clamp(0.0, 1.0);
         Self {
             target_level: clamped_level,
-            peak_hold: clamped_level,
-            peak_hold_time: 0.0,
+            peak_hold: PeakHold::new(clamped_level),
+            right: None,
+            orientation: MeterOrientation::Vertical,
             gradient: None,
             min_color: Color32::from_rgb(0, 150, 0), // Dark green
             max_color: Color32::from_rgb(255, 0, 0), // Red
@@ -122,12 +284,32 @@ impl AudioMeter {
             height: 200.0,
             style: MeterStyle::Smooth,
             scale_position: ScalePosition::None,
+            scale: MeterScale::Dbfs,
             corner_radius: 16.0,
             background_opacity: 0.3,
             glassmorphic: true,
+            ceiling: None,
+            warning_color: WARNING_COLOR,
+            over_color: OVER_COLOR,
         }
     }
 
+    /// Create a stereo meter: left and right bars side by side sharing one scale
+    #[must_use]
+    pub const fn stereo(left: f32, right: f32) -> Self {
+        let right = right.clamp(0.0, 1.0);
+        let mut meter = Self::new(left);
+        meter.right = Some((right, PeakHold::new(right)));
+        meter.width = 30.0;
+        meter
+    }
+
+    /// Create a meter from a loudness value in LUFS, using the LUFS scale
+    #[must_use]
+    pub fn from_lufs(lufs: f32) -> Self {
+        Self::new(MeterScale::db_to_level(lufs)).scale(MeterScale::Lufs)
+    }
+
     /// Set meter width
     #[must_use]
     pub const fn width(mut self, width: f32) -> Self {
@@ -142,6 +324,23 @@ impl AudioMeter {
         self
     }
 
+    /// Set the fill direction
+    ///
+    /// Switching orientation swaps the width and height, so set the size
+    /// after choosing the orientation.
+    #[must_use]
+    pub const fn orientation(mut self, orientation: MeterOrientation) -> Self {
+        let horizontal = matches!(orientation, MeterOrientation::Horizontal);
+        let was_horizontal = matches!(self.orientation, MeterOrientation::Horizontal);
+        if horizontal != was_horizontal {
+            let width = self.width;
+            self.width = self.height;
+            self.height = width;
+        }
+        self.orientation = orientation;
+        self
+    }
+
     /// Set visual style
     #[must_use]
     pub const fn style(mut self, style: MeterStyle) -> Self {
@@ -199,6 +398,13 @@ impl AudioMeter {
         self
     }
 
+    /// Set the reference scale used for tick labels (dBFS, K-system, LUFS)
+    #[must_use]
+    pub const fn scale(mut self, scale: MeterScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
     /// Show scale on the right (convenience method)
     #[must_use]
     pub const fn show_scale(mut self) -> Self {
@@ -241,63 +447,97 @@ impl AudioMeter {
         self
     }
 
+    /// Set a ceiling in dBFS (e.g. -1.0 for streaming true-peak limits)
+    ///
+    /// The zone between the ceiling and 0 dBFS is marked as a warning zone
+    /// and fill above the ceiling uses the warning color, or the over color
+    /// while the signal sits at 0 dBFS.
+    #[must_use]
+    pub fn ceiling_db(mut self, db: f32) -> Self {
+        self.ceiling = Some(MeterScale::db_to_level(db.min(0.0)));
+        self
+    }
+
+    /// Set the fill colors above the ceiling (default: amber and red)
+    #[must_use]
+    pub const fn ceiling_colors(mut self, warning: Color32, over: Color32) -> Self {
+        self.warning_color = warning;
+        self.over_color = over;
+        self
+    }
+
     /// Update the target level (call this when audio level changes)
     pub const fn set_level(&mut self, level: f32) {
         self.target_level = level.clamp(0.0, 1.0);
     }
 
+    /// Update the right channel level of a stereo meter
+    pub const fn set_right_level(&mut self, level: f32) {
+        if let Some((right, _)) = &mut self.right {
+            *right = level.clamp(0.0, 1.0);
+        }
+    }
+
     /// Show the meter and return the response
     pub fn show(mut self, ui: &mut Ui, theme: &armas_basic::Theme) -> MeterResponse {
-        // Width only controls the meter tube, scale is additional space
-        let scale_width = if self.scale_position == ScalePosition::None {
+        let horizontal = self.orientation == MeterOrientation::Horizontal;
+
+        // Width/height only control the meter tube, scale is additional space
+        let scale_size = if self.scale_position == ScalePosition::None {
             0.0
-        } else {
+        } else if horizontal {
+            12.0 // One line of label text
+        } else if self.scale == MeterScale::Dbfs {
             14.0 // Minimal scale width - just enough for text
+        } else {
+            18.0 // Room for signed K-system / LUFS labels
         };
 
-        // Total allocation = meter width + scale width
-        let total_width = self.width + scale_width;
-        let desired_size = Vec2::new(total_width, self.height);
+        // Total allocation = meter size + scale size
+        let desired_size = if horizontal {
+            Vec2::new(self.width, self.height + scale_size)
+        } else {
+            Vec2::new(self.width + scale_size, self.height)
+        };
         let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
 
-        // Use target level directly — no animation lag for accurate metering
+        // Use target levels directly — no animation lag for accurate metering
         let dt = ui.input(|i| i.stable_dt);
         let current_level = self.target_level.clamp(0.0, 1.0);
-
-        // Update peak hold
-        if current_level > self.peak_hold {
-            self.peak_hold = current_level;
-            self.peak_hold_time = 0.0;
-        } else {
-            self.peak_hold_time += dt;
-            // Hold for 1.5 seconds, then fade over 1.0 seconds
-            if self.peak_hold_time > 1.5 {
-                let fade_progress = ((self.peak_hold_time - 1.5) / 1.0).min(1.0);
-                self.peak_hold = self
-                    .peak_hold
-                    .mul_add(1.0 - fade_progress, current_level * fade_progress);
-            }
+        self.peak_hold.update(current_level, dt);
+        if let Some((level, peak)) = &mut self.right {
+            peak.update(*level, dt);
         }
 
         // Request repaint if peak hold is still fading
-        if self.peak_hold_time < 2.5 {
+        let fading = self.peak_hold.is_active() || self.right.is_some_and(|(_, p)| p.is_active());
+        if fading {
             ui.ctx().request_repaint();
         }
 
         if ui.is_rect_visible(rect) {
-            // Calculate meter rect (the actual meter bar area, always self.width wide)
-            let meter_rect = if self.scale_position == ScalePosition::Left {
+            // Calculate meter rect (the actual meter bar area, without the scale)
+            let meter_rect = match (self.scale_position, horizontal) {
+                // No scale, meter uses full allocated space
+                (ScalePosition::None, _) => rect,
                 // Scale on left, meter on right
-                Rect::from_min_size(
-                    Pos2::new(rect.min.x + scale_width, rect.min.y),
+                (ScalePosition::Left, false) => Rect::from_min_size(
+                    Pos2::new(rect.min.x + scale_size, rect.min.y),
                     Vec2::new(self.width, rect.height()),
-                )
-            } else if self.scale_position == ScalePosition::Right {
+                ),
                 // Scale on right, meter on left
-                Rect::from_min_size(rect.min, Vec2::new(self.width, rect.height()))
-            } else {
-                // No scale, meter uses full allocated width
-                rect
+                (ScalePosition::Right, false) => {
+                    Rect::from_min_size(rect.min, Vec2::new(self.width, rect.height()))
+                }
+                // Scale above, meter below
+                (ScalePosition::Left, true) => Rect::from_min_size(
+                    Pos2::new(rect.min.x, rect.min.y + scale_size),
+                    Vec2::new(rect.width(), self.height),
+                ),
+                // Scale below, meter above
+                (ScalePosition::Right, true) => {
+                    Rect::from_min_size(rect.min, Vec2::new(rect.width(), self.height))
+                }
             };
 
             // Draw glassmorphic background
@@ -313,59 +553,50 @@ impl AudioMeter {
             }
 
             // Inner meter area (with padding only on scale side for compact layout)
-            let inner_meter_rect = if self.scale_position == ScalePosition::None {
-                meter_rect // No padding when no scale
-            } else if self.scale_position == ScalePosition::Left {
-                // Pad left side only
-                Rect::from_min_max(
-                    Pos2::new(meter_rect.min.x + 2.0, meter_rect.min.y),
-                    meter_rect.max,
-                )
-            } else {
-                // Pad right side only
-                Rect::from_min_max(
-                    meter_rect.min,
-                    Pos2::new(meter_rect.max.x - 2.0, meter_rect.max.y),
-                )
-            };
+            let mut inner_meter_rect = meter_rect;
+            match (self.scale_position, horizontal) {
+                (ScalePosition::None, _) => {}
+                (ScalePosition::Left, false) => inner_meter_rect.min.x += 2.0,
+                (ScalePosition::Right, false) => inner_meter_rect.max.x -= 2.0,
+                (ScalePosition::Left, true) => inner_meter_rect.min.y += 2.0,
+                (ScalePosition::Right, true) => inner_meter_rect.max.y -= 2.0,
+            }
 
-            // Draw meter fill based on style
-            match self.style {
-                MeterStyle::Smooth => {
-                    self.draw_smooth_meter(ui, inner_meter_rect, current_level);
-                }
-                MeterStyle::Segmented(segment_count) => {
-                    self.draw_segmented_meter(ui, inner_meter_rect, current_level, segment_count);
+            let peak_color = self.peak_color.unwrap_or_else(|| theme.primary());
+            let mut channels = vec![(current_level, self.peak_hold)];
+            channels.extend(self.right);
+            let channel_rects = self.channel_rects(inner_meter_rect, channels.len());
+
+            for (bar_rect, (level, peak)) in channel_rects.into_iter().zip(channels) {
+                // Tint the warning zone between the ceiling and 0 dBFS
+                if let Some(ceiling) = self.ceiling.filter(|&c| c < 1.0) {
+                    let zone = self.span_rect(bar_rect, Self::level_to_display(ceiling), 1.0);
+                    ui.painter().rect_filled(
+                        zone,
+                        0.0,
+                        with_alpha(self.warning_color, WARNING_ZONE_ALPHA),
+                    );
                 }
-            }
 
-            // Draw peak hold indicator
-            if self.peak_hold > 0.01 && self.peak_hold_time < 2.5 {
-                let peak_y = Self::level_to_display(self.peak_hold)
-                    .mul_add(-inner_meter_rect.height(), inner_meter_rect.max.y);
-                let peak_color = self.peak_color.unwrap_or_else(|| theme.primary());
-
-                // Fade out after hold period
-                let fade_alpha = if self.peak_hold_time > 1.5 {
-                    1.0 - ((self.peak_hold_time - 1.5) / 1.0).min(1.0)
-                } else {
-                    1.0
-                };
-
-                let peak_with_fade = Color32::from_rgba_unmultiplied(
-                    peak_color.r(),
-                    peak_color.g(),
-                    peak_color.b(),
-                    (f32::from(peak_color.a()) * fade_alpha) as u8,
-                );
+                // Draw meter fill based on style
+                match self.style {
+                    MeterStyle::Smooth => {
+                        self.draw_smooth_meter(ui, bar_rect, level);
+                    }
+                    MeterStyle::Segmented(segment_count) => {
+                        self.draw_segmented_meter(ui, bar_rect, level, segment_count);
+                    }
+                }
 
-                ui.painter().line_segment(
-                    [
-                        Pos2::new(inner_meter_rect.min.x, peak_y),
-                        Pos2::new(inner_meter_rect.max.x, peak_y),
-                    ],
-                    (2.0, peak_with_fade),
-                );
+                // Draw peak hold indicator
+                if peak.value > 0.01 && peak.is_active() {
+                    let position = Self::level_to_display(peak.value);
+                    let line = self.span_rect(bar_rect, position, position);
+                    ui.painter().line_segment(
+                        [line.min, line.max],
+                        (2.0, peak_color.gamma_multiply(peak.fade_alpha())),
+                    );
+                }
             }
 
             // Draw scale markings (pass full rect which includes scale area)
@@ -377,7 +608,9 @@ impl AudioMeter {
         MeterResponse {
             response,
             level: current_level,
-            peak: self.peak_hold,
+            peak: self.peak_hold.value,
+            right_level: self.right.map(|(level, _)| level),
+            right_peak: self.right.map(|(_, peak)| peak.value),
         }
     }
 
@@ -391,6 +624,60 @@ impl AudioMeter {
         level.clamp(0.0, 1.0).sqrt()
     }
 
+    /// Part of `rect` between display positions `from` and `to` (0.0 = silence)
+    ///
+    /// Vertical meters fill from the bottom up, horizontal meters from the
+    /// left to the right.
+    fn span_rect(&self, rect: Rect, from: f32, to: f32) -> Rect {
+        match self.orientation {
+            MeterOrientation::Vertical => Rect::from_min_max(
+                Pos2::new(rect.min.x, to.mul_add(-rect.height(), rect.max.y)),
+                Pos2::new(rect.max.x, from.mul_add(-rect.height(), rect.max.y)),
+            ),
+            MeterOrientation::Horizontal => Rect::from_min_max(
+                Pos2::new(from.mul_add(rect.width(), rect.min.x), rect.min.y),
+                Pos2::new(to.mul_add(rect.width(), rect.min.x), rect.max.y),
+            ),
+        }
+    }
+
+    /// Length of `rect` along the direction the meter fills
+    fn length(&self, rect: Rect) -> f32 {
+        match self.orientation {
+            MeterOrientation::Vertical => rect.height(),
+            MeterOrientation::Horizontal => rect.width(),
+        }
+    }
+
+    /// Thickness of `rect` across the direction the meter fills
+    fn thickness(&self, rect: Rect) -> f32 {
+        match self.orientation {
+            MeterOrientation::Vertical => rect.width(),
+            MeterOrientation::Horizontal => rect.height(),
+        }
+    }
+
+    /// Split the meter area into adjacent bars, one per channel
+    fn channel_rects(&self, rect: Rect, channels: usize) -> Vec<Rect> {
+        let count = channels.max(1) as f32;
+        let bar = (self.thickness(rect) - CHANNEL_GAP * (count - 1.0)) / count;
+        (0..channels)
+            .map(|i| {
+                let offset = i as f32 * (bar + CHANNEL_GAP);
+                match self.orientation {
+                    MeterOrientation::Vertical => Rect::from_min_size(
+                        Pos2::new(rect.min.x + offset, rect.min.y),
+                        Vec2::new(bar, rect.height()),
+                    ),
+                    MeterOrientation::Horizontal => Rect::from_min_size(
+                        Pos2::new(rect.min.x, rect.min.y + offset),
+                        Vec2::new(rect.width(), bar),
+                    ),
+                }
+            })
+            .collect()
+    }
+
     /// Draw smooth gradient meter fill
     fn draw_smooth_meter(&self, ui: &mut Ui, meter_rect: Rect, level: f32) {
         let painter = ui.painter();
@@ -399,10 +686,10 @@ impl AudioMeter {
             return;
         }
 
-        let corner_radius = (meter_rect.width() * 0.5).min(6.0);
+        let corner_radius = (self.thickness(meter_rect) * 0.5).min(6.0);
         let display_level = Self::level_to_display(level);
 
-        // Sample colors from bottom to top
+        // Sample colors from the silent end up
         let steps = 50;
         for i in 0..steps {
             let t = i as f32 / steps as f32;
@@ -416,7 +703,9 @@ impl AudioMeter {
 
             // Map display position back to linear level for color lookup
             let color_t = t * t; // inverse of sqrt
-            let base_color = self.get_color_at_level(color_t);
+            let base_color = self
+                .zone_color(color_t, level)
+                .unwrap_or_else(|| self.get_color_at_level(color_t));
 
             // Subtle brightness increase for the fill itself
             let brightness = level.powf(1.5).mul_add(0.3, 1.0);
@@ -428,15 +717,9 @@ impl AudioMeter {
                 base_color.a(),
             );
 
-            let segment_min_y = meter_rect.max.y - (capped_next * meter_rect.height());
-            let segment_max_y = meter_rect.max.y - (t * meter_rect.height());
+            let segment_rect = self.span_rect(meter_rect, t, capped_next);
 
-            let segment_rect = Rect::from_min_max(
-                Pos2::new(meter_rect.min.x, segment_min_y),
-                Pos2::new(meter_rect.max.x, segment_max_y),
-            );
-
-            // Round bottom corners only
+            // Round the silent end only
             let rounding = if t < 0.1 { corner_radius } else { 0.0 };
             painter.rect_filled(segment_rect, rounding, color);
         }
@@ -447,25 +730,23 @@ impl AudioMeter {
         let painter = ui.painter();
         let segment_count = segment_count.max(1) as usize;
         let gap = 2.0;
-        let segment_height =
-            (meter_rect.height() - (gap * (segment_count - 1) as f32)) / segment_count as f32;
+        let length = self.length(meter_rect).max(1.0);
+        let segment_length = (length - (gap * (segment_count - 1) as f32)) / segment_count as f32;
         let lit_segments = (Self::level_to_display(level) * segment_count as f32).ceil() as usize;
-        let corner_radius = (segment_height * 0.5).min(4.0);
+        let corner_radius = (segment_length * 0.5).min(4.0);
 
         // Glow intensity increases with level
         let glow_intensity = level.powf(1.5);
 
         for i in 0..segment_count {
             let t = (i as f32 + 0.5) / segment_count as f32;
-            let segment_y = ((i + 1) as f32).mul_add(-(segment_height + gap), meter_rect.max.y);
-
-            let segment_rect = Rect::from_min_size(
-                Pos2::new(meter_rect.min.x, segment_y),
-                Vec2::new(meter_rect.width(), segment_height),
-            );
+            let from = i as f32 * (segment_length + gap) / length;
+            let segment_rect = self.span_rect(meter_rect, from, from + segment_length / length);
 
             let is_lit = i < lit_segments;
-            let base_color = self.get_color_at_level(t);
+            let base_color = self
+                .zone_color(t * t, level)
+                .unwrap_or_else(|| self.get_color_at_level(t));
 
             if is_lit {
                 // Subtle outer glow for lit segments - consistent with fader and knob
@@ -510,6 +791,20 @@ impl AudioMeter {
         )
     }
 
+    /// Ceiling color for fill at linear level `at` while the signal is at `level`
+    ///
+    /// `None` below the ceiling (or without one), where the gradient applies.
+    fn zone_color(&self, at: f32, level: f32) -> Option<Color32> {
+        let ceiling = self.ceiling?;
+        if at <= ceiling {
+            None
+        } else if level >= 1.0 {
+            Some(self.over_color)
+        } else {
+            Some(self.warning_color)
+        }
+    }
+
     /// Draw dB scale markings
     /// `full_rect`: the entire allocated space including scale area
     /// `meter_rect`: just the meter bar area (for positioning scale relative to meter)
@@ -523,43 +818,50 @@ impl AudioMeter {
         let painter = ui.painter();
         let text_color = theme.muted_foreground();
 
-        // dB levels with proper logarithmic scaling
-        // Formula: linear = 10^(dB/20)
-        let db_marks = [
-            (1.0, "0"),     // 0 dB - digital maximum
-            (0.708, "-3"),  // -3 dB
-            (0.501, "-6"),  // -6 dB - safe headroom
-            (0.251, "-12"), // -12 dB - good average
-            (0.126, "-18"), // -18 dB - reference level
-            (0.063, "-24"), // -24 dB
-            (0.0, "-∞"),    // -inf dB - silence
-        ];
-
         let is_left = self.scale_position == ScalePosition::Left;
 
-        for (level, label) in db_marks {
-            let y = meter_rect.max.y - Self::level_to_display(level) * meter_rect.height();
+        // Marks are placed by their dBFS value, so the K-system 0 lands below full scale
+        for (db, label) in self.scale.marks() {
+            let position = Self::level_to_display(MeterScale::db_to_level(db));
+            let at = self.span_rect(meter_rect, position, position).min;
+            // Keep labels at the ends of a horizontal scale inside the meter's span
+            let end_align = if position < 0.05 {
+                egui::Align::Min
+            } else if position > 0.95 {
+                egui::Align::Max
+            } else {
+                egui::Align::Center
+            };
 
             // Position text in the scale area (outside the meter)
-            let (text_pos, text_align) = if is_left {
-                // Scale is on the left side of full_rect, outside the meter
-                (
-                    Pos2::new(full_rect.min.x + 1.0, y),
+            let (text_pos, text_align) = match (self.orientation, is_left) {
+                // Scale is on the left side of full_rect
+                (MeterOrientation::Vertical, true) => (
+                    Pos2::new(full_rect.min.x + 1.0, at.y),
                     egui::Align2::LEFT_CENTER,
-                )
-            } else {
-                // Scale is on the right side of full_rect, outside the meter
-                (
-                    Pos2::new(full_rect.max.x - 1.0, y),
+                ),
+                // Scale is on the right side of full_rect
+                (MeterOrientation::Vertical, false) => (
+                    Pos2::new(full_rect.max.x - 1.0, at.y),
                     egui::Align2::RIGHT_CENTER,
-                )
+                ),
+                // Scale is above the meter
+                (MeterOrientation::Horizontal, true) => (
+                    Pos2::new(at.x, full_rect.min.y),
+                    egui::Align2([end_align, egui::Align::Min]),
+                ),
+                // Scale is below the meter
+                (MeterOrientation::Horizontal, false) => (
+                    Pos2::new(at.x, full_rect.max.y),
+                    egui::Align2([end_align, egui::Align::Max]),
+                ),
             };
 
             // Draw text label only (no tick marks)
             painter.text(
                 text_pos,
                 text_align,
-                label,
+                &label,
                 egui::FontId::proportional(9.0),
                 text_color,
             );
@@ -622,4 +924,79 @@ mod tests {
         assert_eq!(meter.max_color, Color32::RED);
         assert!(meter.gradient.is_none());
     }
+
+    #[test]
+    fn test_ceiling_warning_zone() {
+        let meter = AudioMeter::new(0.5).ceiling_db(-1.0);
+        let ceiling = MeterScale::db_to_level(-1.0);
+        let between = MeterScale::db_to_level(-0.5);
+
+        // Below the ceiling the gradient applies
+        assert_eq!(meter.zone_color(0.5, 0.5), None);
+        // Between the ceiling and 0 dBFS the fill is in the warning color
+        assert!(between > ceiling);
+        assert_eq!(meter.zone_color(between, between), Some(WARNING_COLOR));
+        // At 0 dBFS the part above the ceiling switches to the over color
+        assert_eq!(meter.zone_color(between, 1.0), Some(OVER_COLOR));
+        // Without a ceiling there is no zone at all
+        assert_eq!(AudioMeter::new(1.0).zone_color(between, 1.0), None);
+    }
+
+    #[test]
+    fn test_k14_reference_below_full_scale() {
+        assert_eq!(MeterScale::K14.reference_db(), -14.0);
+
+        let marks = MeterScale::K14.marks();
+        let zero = marks.iter().find(|(_, label)| label == "0").unwrap();
+        assert_eq!(zero.0, -14.0);
+        assert!((MeterScale::db_to_level(zero.0) - 0.1995).abs() < 0.001);
+
+        // Digital full scale is labelled as the headroom above the reference
+        assert_eq!(marks[0], (0.0, "+14".to_string()));
+    }
+
+    #[test]
+    fn test_dbfs_scale_labels() {
+        let marks = MeterScale::Dbfs.marks();
+        assert_eq!(marks[0], (0.0, "0".to_string()));
+        assert_eq!(marks.last().unwrap().1, "-∞");
+        assert_eq!(MeterScale::db_to_level(f32::NEG_INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_horizontal_maps_level_to_x_length() {
+        let rect = Rect::from_min_size(Pos2::new(10.0, 20.0), Vec2::new(200.0, 20.0));
+
+        let vertical = AudioMeter::new(0.0).span_rect(rect, 0.0, 0.5);
+        assert_eq!(vertical.width(), rect.width());
+        assert_eq!(vertical.height(), 10.0);
+        assert_eq!(vertical.max.y, rect.max.y);
+
+        let horizontal = AudioMeter::new(0.0)
+            .orientation(MeterOrientation::Horizontal)
+            .span_rect(rect, 0.0, 0.5);
+        assert_eq!(horizontal.width(), 100.0);
+        assert_eq!(horizontal.height(), rect.height());
+        assert_eq!(horizontal.min.x, rect.min.x);
+    }
+
+    #[test]
+    fn test_orientation_swaps_size() {
+        let meter = AudioMeter::new(0.5).orientation(MeterOrientation::Horizontal);
+        assert_eq!(meter.width, 200.0);
+        assert_eq!(meter.height, 22.0);
+    }
+
+    #[test]
+    fn test_stereo_splits_bars() {
+        let meter = AudioMeter::stereo(0.8, 0.2);
+        assert_eq!(meter.target_level, 0.8);
+        assert_eq!(meter.right.map(|(level, _)| level), Some(0.2));
+
+        let rect = Rect::from_min_size(Pos2::ZERO, Vec2::new(30.0, 200.0));
+        let bars = meter.channel_rects(rect, 2);
+        assert_eq!(bars[0].width(), 14.0);
+        assert_eq!(bars[1].min.x, 16.0);
+        assert_eq!(bars[1].max.x, 30.0);
+    }
 }
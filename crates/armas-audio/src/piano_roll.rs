@@ -4,6 +4,7 @@
 //! Supports clicking to place notes, dragging to resize, and beautiful glassmorphic styling.
 
 use crate::{Piano, PianoOrientation};
+use armas_basic::color::{from_hsl, lerp_color};
 use armas_basic::theme::Theme;
 use egui::{Color32, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2};
 
@@ -36,6 +37,60 @@ impl GridDivision {
     }
 }
 
+/// How note blocks are colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorBy {
+    /// One color for every note (the note color, or the theme's primary)
+    #[default]
+    Fixed,
+    /// Gradient from dim (low velocity) to bright (high velocity)
+    Velocity,
+    /// A palette color per MIDI channel
+    Channel,
+}
+
+// Velocity gradient: base color scaled from dim to lightened
+const VELOCITY_DIM: f32 = 0.35;
+const VELOCITY_LIGHTEN: f32 = 0.3;
+// Channel palette: hues spread around the wheel, neighbouring channels far apart
+const CHANNEL_COUNT: u8 = 16;
+const CHANNEL_HUE_STEP: u8 = 7;
+const CHANNEL_SATURATION: f32 = 0.7;
+const CHANNEL_LIGHTNESS: f32 = 0.6;
+
+impl ColorBy {
+    /// Color of a note block before opacity is applied
+    ///
+    /// A note's own color always takes precedence.
+    #[must_use]
+    pub fn note_color(self, note: &Note, base: Color32) -> Color32 {
+        if let Some(color) = note.color {
+            return color;
+        }
+        match self {
+            Self::Fixed => base,
+            Self::Velocity => {
+                let low = scale_rgb(base, VELOCITY_DIM);
+                let high = lerp_color(base, Color32::WHITE, VELOCITY_LIGHTEN);
+                lerp_color(low, high, note.velocity.clamp(0.0, 1.0))
+            }
+            Self::Channel => channel_color(note.channel),
+        }
+    }
+}
+
+/// Palette color for a MIDI channel (0-15)
+fn channel_color(channel: u8) -> Color32 {
+    let slot = (channel % CHANNEL_COUNT) * CHANNEL_HUE_STEP % CHANNEL_COUNT;
+    let hue = f32::from(slot) * 360.0 / f32::from(CHANNEL_COUNT);
+    from_hsl(hue, CHANNEL_SATURATION, CHANNEL_LIGHTNESS)
+}
+
+fn scale_rgb(color: Color32, factor: f32) -> Color32 {
+    let scale = |c: u8| (f32::from(c) * factor) as u8;
+    Color32::from_rgb(scale(color.r()), scale(color.g()), scale(color.b()))
+}
+
 /// Momentum scroll state stored in egui temp data
 #[derive(Clone, Default)]
 struct PianoRollScrollState {
@@ -77,6 +132,10 @@ pub struct Note {
     pub duration: f32,
     /// Velocity (0.0-1.0)
     pub velocity: f32,
+    /// MIDI channel (0-15)
+    pub channel: u8,
+    /// Custom color, overriding the piano roll's coloring mode
+    pub color: Option<Color32>,
 }
 
 impl Note {
@@ -88,6 +147,8 @@ impl Note {
             start_beat,
             duration,
             velocity: 0.8,
+            channel: 0,
+            color: None,
         }
     }
 
@@ -99,8 +160,24 @@ impl Note {
             start_beat,
             duration,
             velocity: velocity.clamp(0.0, 1.0),
+            channel: 0,
+            color: None,
         }
     }
+
+    /// Set the MIDI channel (0-15)
+    #[must_use]
+    pub const fn channel(mut self, channel: u8) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// Set a custom color for this note
+    #[must_use]
+    pub const fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
 }
 
 /// Response from the piano roll
@@ -150,6 +227,8 @@ pub struct PianoRoll {
     note_color: Option<Color32>,
     /// Selected note color
     selected_note_color: Option<Color32>,
+    /// How note blocks are colored
+    color_by: ColorBy,
     /// Grid line style configuration
     grid_style: Option<GridLineStyle>,
     /// Snap to grid enabled
@@ -189,6 +268,7 @@ impl PianoRoll {
             black_key_color: None,
             note_color: None,
             selected_note_color: None,
+            color_by: ColorBy::Fixed,
             grid_style: None,
             snap_to_grid: false,
             scrollable: false,
@@ -312,6 +392,13 @@ impl PianoRoll {
         self
     }
 
+    /// Set how note blocks are colored (fixed, by velocity, or by channel)
+    #[must_use]
+    pub const fn color_by(mut self, color_by: ColorBy) -> Self {
+        self.color_by = color_by;
+        self
+    }
+
     /// Set grid line style for customizing line appearance
     #[must_use]
     pub const fn grid_style(mut self, style: GridLineStyle) -> Self {
@@ -726,21 +813,28 @@ impl PianoRoll {
     ) {
         for note in &self.notes {
             if let Some(note_rect) = self.get_note_rect_in_content(note, content_rect) {
-                let primary = theme.primary();
-                let intensity = (note.velocity * 255.0) as u8;
+                let base = self
+                    .color_by
+                    .note_color(note, self.note_color.unwrap_or_else(|| theme.primary()));
+                // Velocity drives the fill opacity unless it already drives the color
+                let intensity = if self.color_by == ColorBy::Velocity {
+                    255
+                } else {
+                    (note.velocity * 255.0) as u8
+                };
                 let note_color = Color32::from_rgba_unmultiplied(
-                    primary.r(),
-                    primary.g(),
-                    primary.b(),
+                    base.r(),
+                    base.g(),
+                    base.b(),
                     (f32::from(intensity) * self.note_opacity) as u8,
                 );
 
                 painter.rect_filled(note_rect, 4.0, note_color);
 
                 let border_color = Color32::from_rgba_unmultiplied(
-                    primary.r(),
-                    primary.g(),
-                    primary.b(),
+                    base.r(),
+                    base.g(),
+                    base.b(),
                     ((f32::from(intensity) * 1.3).min(255.0)) as u8,
                 );
                 painter.rect_stroke(
@@ -982,3 +1076,41 @@ struct NoteInteractions {
     added_note: Option<Note>,
     removed_indices: Vec<usize>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brightness(color: Color32) -> u32 {
+        u32::from(color.r()) + u32::from(color.g()) + u32::from(color.b())
+    }
+
+    #[test]
+    fn test_velocity_coloring_brightens_with_velocity() {
+        let base = Color32::from_rgb(60, 140, 230);
+        let soft = Note::with_velocity(60, 0.0, 1.0, 0.1);
+        let loud = Note::with_velocity(60, 1.0, 1.0, 1.0);
+
+        let soft_color = ColorBy::Velocity.note_color(&soft, base);
+        let loud_color = ColorBy::Velocity.note_color(&loud, base);
+        assert!(brightness(loud_color) > brightness(soft_color));
+        assert!(brightness(loud_color) > brightness(base));
+    }
+
+    #[test]
+    fn test_channel_coloring_and_note_override() {
+        let base = Color32::from_rgb(60, 140, 230);
+        let first = Note::new(60, 0.0, 1.0).channel(0);
+        let second = Note::new(60, 0.0, 1.0).channel(1);
+        assert_ne!(
+            ColorBy::Channel.note_color(&first, base),
+            ColorBy::Channel.note_color(&second, base)
+        );
+        assert_eq!(ColorBy::Fixed.note_color(&first, base), base);
+
+        let custom = second.color(Color32::GOLD);
+        for mode in [ColorBy::Fixed, ColorBy::Velocity, ColorBy::Channel] {
+            assert_eq!(mode.note_color(&custom, base), Color32::GOLD);
+        }
+    }
+}
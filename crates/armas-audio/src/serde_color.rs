@@ -0,0 +1,39 @@
+//! Serde representation of colors
+//!
+//! egui is built without its `serde` feature, so timeline data stores colors
+//! as the `[r, g, b, a]` array kept by [`Color32`] (premultiplied alpha).
+//! Use with `#[serde(with = "crate::serde_color")]`.
+
+use egui::Color32;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// serde's `with` requires taking the field by reference
+#[allow(clippy::trivially_copy_pass_by_ref)]
+pub fn serialize<S: Serializer>(color: &Color32, serializer: S) -> Result<S::Ok, S::Error> {
+    color.to_array().serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color32, D::Error> {
+    let [r, g, b, a] = <[u8; 4]>::deserialize(deserializer)?;
+    Ok(Color32::from_rgba_premultiplied(r, g, b, a))
+}
+
+/// Same representation for optional colors (`null` when unset)
+pub mod option {
+    use super::{Color32, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[allow(clippy::ref_option, clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S: Serializer>(
+        color: &Option<Color32>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        color.map(|c| c.to_array()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Color32>, D::Error> {
+        let color = Option::<[u8; 4]>::deserialize(deserializer)?;
+        Ok(color.map(|[r, g, b, a]| Color32::from_rgba_premultiplied(r, g, b, a)))
+    }
+}
@@ -8,6 +8,56 @@ use egui::{Pos2, Rect, Response, Sense, Stroke, Ui};
 /// Re-export `GridDivision` from `piano_roll` for time subdivisions
 pub use super::piano_roll::GridDivision;
 
+/// How beat positions are displayed as text
+///
+/// Shared by the ruler, markers, and regions so every readout agrees.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TimeDisplayMode {
+    /// Bars and beats, 1-based (`5:2`, or `5:2.50` between beats)
+    #[default]
+    BarsBeats,
+    /// SMPTE timecode (`HH:MM:SS:FF`) at a fixed tempo
+    Smpte {
+        /// Tempo used to convert beats to seconds
+        bpm: f32,
+        /// Frames per second
+        fps: u32,
+    },
+}
+
+impl TimeDisplayMode {
+    /// Format a beat position for display
+    #[must_use]
+    pub fn format(self, beat: f32, beats_per_measure: u32) -> String {
+        let beat = beat.max(0.0);
+        match self {
+            Self::BarsBeats => {
+                let beats_per_measure = beats_per_measure.max(1) as f32;
+                let bar = (beat / beats_per_measure).floor();
+                let beat_in_bar = bar.mul_add(-beats_per_measure, beat) + 1.0;
+                if beat_in_bar.fract().abs() < 0.001 {
+                    format!("{}:{}", bar as u32 + 1, beat_in_bar.round() as u32)
+                } else {
+                    format!("{}:{beat_in_bar:.2}", bar as u32 + 1)
+                }
+            }
+            Self::Smpte { bpm, fps } => {
+                let fps = fps.max(1);
+                let seconds = beat * 60.0 / bpm.max(1.0);
+                let total_frames = (seconds * fps as f32).floor() as u32;
+                let frames = total_frames % fps;
+                let total_seconds = total_frames / fps;
+                format!(
+                    "{:02}:{:02}:{:02}:{frames:02}",
+                    total_seconds / 3600,
+                    (total_seconds / 60) % 60,
+                    total_seconds % 60,
+                )
+            }
+        }
+    }
+}
+
 /// Horizontal time ruler for DAW timeline
 ///
 /// Shows measures, beats, and subdivisions with precise alignment.
@@ -41,6 +91,8 @@ pub struct TimeRuler {
     show_subdivisions: bool,
     /// Optional ID for `ScrollArea` (to avoid conflicts when multiple rulers exist)
     id: Option<egui::Id>,
+    /// How measure labels are displayed
+    display_mode: TimeDisplayMode,
 }
 
 impl Default for TimeRuler {
@@ -62,6 +114,7 @@ impl TimeRuler {
             show_beat_numbers: true,
             show_subdivisions: true,
             id: None,
+            display_mode: TimeDisplayMode::BarsBeats,
         }
     }
 
@@ -100,6 +153,13 @@ impl TimeRuler {
         self
     }
 
+    /// Set how measure labels are displayed (bars:beats or SMPTE)
+    #[must_use]
+    pub const fn display_mode(mut self, mode: TimeDisplayMode) -> Self {
+        self.display_mode = mode;
+        self
+    }
+
     /// Show the time ruler within a pre-allocated clipped area
     ///
     /// Use this when the ruler is part of a scrollable timeline.
@@ -200,7 +260,13 @@ impl TimeRuler {
                 .mul_add(self.beat_width, rect.min.x);
             let label_pos = Pos2::new(x + theme.spacing.xs, rect.min.y + theme.spacing.xs);
 
-            let label = format!("{}", measure + 1);
+            let label = match self.display_mode {
+                TimeDisplayMode::BarsBeats => format!("{}", measure + 1),
+                TimeDisplayMode::Smpte { .. } => self.display_mode.format(
+                    (measure * self.beats_per_measure) as f32,
+                    self.beats_per_measure,
+                ),
+            };
 
             painter.text(
                 label_pos,
@@ -240,3 +306,29 @@ impl TimeRuler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bars_beats_format() {
+        let mode = TimeDisplayMode::BarsBeats;
+        assert_eq!(mode.format(0.0, 4), "1:1");
+        assert_eq!(mode.format(17.0, 4), "5:2");
+        assert_eq!(mode.format(17.5, 4), "5:2.50");
+        assert_eq!(mode.format(6.0, 3), "3:1");
+    }
+
+    #[test]
+    fn test_smpte_format() {
+        let mode = TimeDisplayMode::Smpte {
+            bpm: 120.0,
+            fps: 30,
+        };
+        // 120 BPM: one beat is half a second
+        assert_eq!(mode.format(0.0, 4), "00:00:00:00");
+        assert_eq!(mode.format(1.0, 4), "00:00:00:15");
+        assert_eq!(mode.format(130.0, 4), "00:01:05:00");
+    }
+}
@@ -3,23 +3,32 @@
 //! Complete scrollable timeline view combining ruler, playhead, track headers, and tracks.
 
 use crate::{
-    MarkerVariant, Playhead, Region, RegionVariant, SnapGrid, TimeRuler, TimelineMarker,
-    TimelineRegion, TimelineTrack, TrackControls, TrackHeader,
+    MarkerVariant, Playhead, Region, RegionVariant, RenderQuality, SnapGrid, TimeDisplayMode,
+    TimeRuler, TimelineMarker, TimelineRegion, TimelineTrack, TrackControls, TrackHeader,
 };
+use armas_basic::ext::{draw_focus_ring, DragPayload};
 use armas_basic::theme::Theme;
 use egui::{pos2, vec2, Color32, Rect, Response, Sense, Ui, Vec2};
+use serde::{Deserialize, Serialize};
 
 // Track ID calculation constants
 const TRACK_ID_MULTIPLIER: usize = 1000; // Space between parent and child track IDs
 
+// Loop-active glow: one pulse per period, alpha oscillating between the bounds
+const LOOP_PULSE_PERIOD: f64 = 1.2;
+const LOOP_GLOW_MIN_ALPHA: f32 = 0.3;
+const LOOP_GLOW_MAX_ALPHA: f32 = 1.0;
+const LOOP_GLOW_WIDTH: f32 = 4.0;
+
 /// Data for a timeline marker
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MarkerData {
     /// Position in beats
     pub position: f32,
     /// Marker variant (cue, tempo, time signature)
     pub variant: MarkerVariant,
     /// Optional custom color
+    #[serde(with = "crate::serde_color::option")]
     pub color: Option<Color32>,
 }
 
@@ -119,13 +128,14 @@ impl PunchRegionData {
 }
 
 /// Track data for DAW timeline
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Track {
     /// Track name
     pub name: String,
     /// Track controls (mute, solo, arm)
     pub controls: TrackControls,
     /// Track color
+    #[serde(with = "crate::serde_color")]
     pub color: Color32,
     /// Regions on this track
     pub regions: Vec<Region>,
@@ -211,6 +221,27 @@ impl Track {
     }
 }
 
+/// Saved arrangement: the tracks, markers and playhead of a timeline
+///
+/// Produced by [`Timeline::from_json`]; see [`Timeline::to_json`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Arrangement {
+    /// Tracks, including folder children and their regions
+    pub tracks: Vec<Track>,
+    /// Timeline markers
+    pub markers: Vec<MarkerData>,
+    /// Playhead position in beats
+    pub playhead: f32,
+}
+
+/// Borrowed form of [`Arrangement`], so exporting doesn't clone the tracks
+#[derive(Serialize)]
+struct ArrangementRef<'a> {
+    tracks: &'a [Track],
+    markers: &'a [MarkerData],
+    playhead: f32,
+}
+
 /// Response from timeline interaction
 #[derive(Debug, Clone)]
 pub struct TimelineResponse {
@@ -230,6 +261,11 @@ pub struct TimelineResponse {
     pub region_clicked: Option<(usize, usize)>,
     /// Empty area clicked (`track_idx`, `beat_position`)
     pub empty_clicked: Option<(usize, f32)>,
+    /// Payload dropped on a track (`track_idx`, `beat_position`, payload)
+    ///
+    /// Payloads come from `armas_basic::ext::begin_drag`, e.g. items of a
+    /// draggable `TreeView`.
+    pub dropped_payload: Option<(usize, f32, DragPayload)>,
     /// Playhead was moved
     pub playhead_moved: bool,
     /// Playhead was clicked (not just moved)
@@ -238,6 +274,85 @@ pub struct TimelineResponse {
     pub playhead_position: f32,
     /// Which marker was moved (if any)
     pub marker_moved: Option<usize>,
+    /// Selected regions (`track_idx`, `region_idx`) after a marquee drag finished this frame
+    pub marquee_selected: Option<Vec<(usize, usize)>>,
+    /// Regions (`track_idx`, `region_idx`) under the playhead
+    ///
+    /// Only filled when [`Timeline::highlight_playing`] is enabled.
+    pub playing_regions: Vec<(usize, usize)>,
+    /// Space was pressed while the timeline had focus
+    pub play_toggle_requested: bool,
+    /// Delete or Backspace was pressed while the timeline had focus
+    pub delete_requested: bool,
+    /// Ctrl/Cmd+Z was pressed while the timeline had focus
+    pub undo_requested: bool,
+    /// Ctrl/Cmd+Y or Ctrl/Cmd+Shift+Z was pressed while the timeline had focus
+    pub redo_requested: bool,
+    /// Layout of this frame, for drawing custom overlays aligned to the timeline
+    pub geometry: TimelineGeometry,
+}
+
+/// Screen-space layout of a timeline for a single frame
+///
+/// Accounts for the track header column, the ruler and the current scroll
+/// offset, so custom overlays can be aligned to beats and tracks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimelineGeometry {
+    /// Visible track content area (excluding headers and ruler)
+    pub viewport_rect: Rect,
+    /// Full scrollable content area, shifted by the scroll offset
+    pub content_rect: Rect,
+    /// Ruler area above the track content
+    pub ruler_rect: Rect,
+    /// Track header column
+    pub header_rect: Rect,
+    /// Current scroll offset in pixels
+    pub scroll_offset: Vec2,
+    /// Width per beat in pixels
+    pub beat_width: f32,
+    /// Height of each track row
+    pub track_height: f32,
+    /// Number of visible track rows (children of collapsed folders excluded)
+    pub track_count: usize,
+}
+
+impl TimelineGeometry {
+    /// Screen x coordinate of a beat position
+    #[must_use]
+    pub const fn beat_to_x(&self, beat: f32) -> f32 {
+        beat.mul_add(self.beat_width, self.content_rect.min.x)
+    }
+
+    /// Beat position at a screen x coordinate
+    #[must_use]
+    pub fn x_to_beat(&self, x: f32) -> f32 {
+        (x - self.content_rect.min.x) / self.beat_width
+    }
+
+    /// Row index of the track at a screen y coordinate
+    ///
+    /// Rows follow display order (folders expanded in place). Returns `None`
+    /// outside the visible track area or below the last track.
+    #[must_use]
+    pub fn track_index_at_y(&self, y: f32) -> Option<usize> {
+        if !self.viewport_rect.y_range().contains(y) || self.track_height <= 0.0 {
+            return None;
+        }
+        let row = ((y - self.content_rect.min.y) / self.track_height).floor() as usize;
+        (row < self.track_count).then_some(row)
+    }
+
+    /// Screen rect of a track row (may lie outside the viewport when scrolled away)
+    #[must_use]
+    pub fn track_rect(&self, index: usize) -> Rect {
+        Rect::from_min_size(
+            pos2(
+                self.content_rect.min.x,
+                (index as f32).mul_add(self.track_height, self.content_rect.min.y),
+            ),
+            vec2(self.content_rect.width(), self.track_height),
+        )
+    }
 }
 
 /// Timeline component
@@ -301,6 +416,8 @@ pub struct Timeline<'a> {
     show_snap_grid: bool,
     /// Snap grid subdivision
     snap_grid_subdivision: u32,
+    /// Snap ruler seeks to the snap grid
+    snap_to_grid: bool,
     /// Minimum zoom level (`beat_width` multiplier)
     min_zoom: f32,
     /// Maximum zoom level (`beat_width` multiplier)
@@ -319,6 +436,20 @@ pub struct Timeline<'a> {
     momentum_damping: f64,
     /// Region height as a ratio of track height (0.0-1.0)
     region_height_ratio: f32,
+    /// Enable rubber-band selection of regions by dragging over track content
+    marquee_selection: bool,
+    /// How positions are displayed in the ruler and marker/region tooltips
+    time_display_mode: TimeDisplayMode,
+    /// Alternate a subtle background tint between even and odd track rows
+    track_striping: bool,
+    /// Grid line color override (None = theme border color)
+    grid_color: Option<Color32>,
+    /// Pulse the loop region border to show looping is engaged
+    loop_active: bool,
+    /// Brighten and report regions under the playhead
+    highlight_playing: bool,
+    /// Detail of waveform/MIDI previews in regions
+    render_quality: RenderQuality,
 }
 
 /// Info about a track in the flattened hierarchy
@@ -362,7 +493,14 @@ struct TimelineInteractions {
     track_collapse_clicked: Option<usize>,
     region_clicked: Option<(usize, usize)>,
     empty_clicked: Option<(usize, f32)>,
+    dropped_payload: Option<(usize, f32, DragPayload)>,
     playhead_moved: bool,
+    marquee_selected: Option<Vec<(usize, usize)>>,
+    playing_regions: Vec<(usize, usize)>,
+    play_toggle_requested: bool,
+    delete_requested: bool,
+    undo_requested: bool,
+    redo_requested: bool,
 }
 
 /// Momentum scroll state stored in egui temp data
@@ -378,6 +516,35 @@ struct ScrollMomentumState {
 }
 
 impl<'a> Timeline<'a> {
+    /// Export an arrangement to JSON
+    ///
+    /// Colors are stored as `[r, g, b, a]` arrays and enums by variant name,
+    /// so the output stays stable across releases.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(
+        tracks: &[Track],
+        markers: &[MarkerData],
+        playhead: f32,
+    ) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&ArrangementRef {
+            tracks,
+            markers,
+            playhead,
+        })
+    }
+
+    /// Import an arrangement exported with [`Timeline::to_json`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed or doesn't describe an arrangement.
+    pub fn from_json(json: &str) -> serde_json::Result<Arrangement> {
+        serde_json::from_str(json)
+    }
+
     /// Create a new timeline
     #[must_use]
     pub const fn new() -> Self {
@@ -398,6 +565,7 @@ impl<'a> Timeline<'a> {
             punch_region: None,
             show_snap_grid: false,
             snap_grid_subdivision: 4,
+            snap_to_grid: false,
             min_zoom: 0.5,
             max_zoom: 2.0,
             auto_follow_playhead: false,
@@ -407,6 +575,13 @@ impl<'a> Timeline<'a> {
             momentum_scrolling: true,
             momentum_damping: 5.0,
             region_height_ratio: 0.9,
+            marquee_selection: false,
+            time_display_mode: TimeDisplayMode::BarsBeats,
+            track_striping: false,
+            grid_color: None,
+            loop_active: false,
+            highlight_playing: false,
+            render_quality: RenderQuality::High,
         }
     }
 
@@ -461,6 +636,16 @@ impl<'a> Timeline<'a> {
         Some(current)
     }
 
+    /// Clear region selection on all tracks, including collapsed children
+    fn deselect_all_regions(tracks: &mut [Track]) {
+        for track in tracks {
+            for region in &mut track.regions {
+                region.selected = false;
+            }
+            Self::deselect_all_regions(&mut track.children);
+        }
+    }
+
     fn get_track_by_path_mut<'b>(tracks: &'b mut [Track], path: &[usize]) -> Option<&'b mut Track> {
         if path.is_empty() {
             return None;
@@ -590,6 +775,75 @@ impl<'a> Timeline<'a> {
         self
     }
 
+    /// Snap playhead seeks from the ruler to the snap grid subdivision
+    #[must_use]
+    pub const fn snap_to_grid(mut self, snap: bool) -> Self {
+        self.snap_to_grid = snap;
+        self
+    }
+
+    /// Enable or disable marquee (rubber-band) region selection
+    ///
+    /// When enabled, dragging over track content draws a selection box and
+    /// selects every region it touches across tracks. Hold Shift to add to the
+    /// existing selection. Default is false.
+    #[must_use]
+    pub const fn marquee_selection(mut self, enabled: bool) -> Self {
+        self.marquee_selection = enabled;
+        self
+    }
+
+    /// Alternate the background tint of even and odd track rows
+    ///
+    /// Makes dense timelines easier to follow horizontally. Default is false.
+    #[must_use]
+    pub const fn track_striping(mut self, striping: bool) -> Self {
+        self.track_striping = striping;
+        self
+    }
+
+    /// Override the color of measure lines (beat lines use a fainter tint)
+    #[must_use]
+    pub const fn grid_color(mut self, color: Color32) -> Self {
+        self.grid_color = Some(color);
+        self
+    }
+
+    /// Pulse a glow around the loop region while looping is engaged
+    ///
+    /// Has no effect without a loop region. Default is false.
+    #[must_use]
+    pub const fn loop_active(mut self, active: bool) -> Self {
+        self.loop_active = active;
+        self
+    }
+
+    /// Brighten regions under the playhead and report them in
+    /// [`TimelineResponse::playing_regions`]. Default is false.
+    #[must_use]
+    pub const fn highlight_playing(mut self, highlight: bool) -> Self {
+        self.highlight_playing = highlight;
+        self
+    }
+
+    /// Set the detail of waveform/MIDI previews in regions
+    ///
+    /// Use [`RenderQuality::Low`] on slow hardware. Default is High.
+    #[must_use]
+    pub const fn render_quality(mut self, quality: RenderQuality) -> Self {
+        self.render_quality = quality;
+        self
+    }
+
+    /// Set how positions are displayed (bars:beats or SMPTE)
+    ///
+    /// Applies to the ruler labels and the hover tooltips of markers and regions.
+    #[must_use]
+    pub const fn time_display_mode(mut self, mode: TimeDisplayMode) -> Self {
+        self.time_display_mode = mode;
+        self
+    }
+
     /// Scroll to show a specific beat position
     ///
     /// This is useful for:
@@ -747,6 +1001,40 @@ impl<'a> Timeline<'a> {
         }
     }
 
+    /// Screen-space geometry for the final scroll offset of this frame
+    fn geometry(
+        &self,
+        layout: &TimelineLayout,
+        scroll_offset: Vec2,
+        track_count: usize,
+    ) -> TimelineGeometry {
+        let origin = layout.available_rect.min;
+        let viewport_rect = Rect::from_min_size(
+            origin + vec2(self.track_header_width, self.ruler_height),
+            vec2(layout.timeline_width, layout.timeline_height),
+        );
+
+        TimelineGeometry {
+            viewport_rect,
+            content_rect: Rect::from_min_size(
+                viewport_rect.min - scroll_offset,
+                vec2(layout.content_width, layout.content_height),
+            ),
+            ruler_rect: Rect::from_min_size(
+                origin + vec2(self.track_header_width, 0.0),
+                vec2(layout.timeline_width, self.ruler_height),
+            ),
+            header_rect: Rect::from_min_size(
+                origin + vec2(0.0, self.ruler_height),
+                vec2(self.track_header_width, layout.timeline_height),
+            ),
+            scroll_offset,
+            beat_width: self.beat_width,
+            track_height: self.track_height,
+            track_count,
+        }
+    }
+
     /// Apply `scroll_to_beat` and `auto_follow_playhead` adjustments
     fn apply_scroll_adjustments(
         &self,
@@ -774,13 +1062,18 @@ impl<'a> Timeline<'a> {
     }
 
     /// Render the time ruler at the top
+    ///
+    /// Clicking the ruler moves the playhead to that beat and dragging along
+    /// it scrubs. Returns whether the playhead moved.
     fn render_ruler(
         &self,
         ui: &mut Ui,
         layout: &TimelineLayout,
         scroll_offset: Vec2,
+        playhead_position: &mut f32,
         theme: &Theme,
-    ) {
+    ) -> bool {
+        let mut moved = false;
         ui.horizontal(|ui| {
             ui.spacing_mut().item_spacing.x = 0.0;
 
@@ -791,12 +1084,20 @@ impl<'a> Timeline<'a> {
             );
 
             // Ruler
-            let ruler_rect = ui
-                .allocate_exact_size(
-                    Vec2::new(layout.timeline_width, self.ruler_height),
-                    Sense::hover(),
-                )
-                .0;
+            let (ruler_rect, ruler_response) = ui.allocate_exact_size(
+                Vec2::new(layout.timeline_width, self.ruler_height),
+                Sense::click_and_drag(),
+            );
+
+            if ruler_response.is_pointer_button_down_on() {
+                if let Some(pos) = ruler_response.interact_pointer_pos() {
+                    let beat = self.ruler_x_to_beat(pos.x - ruler_rect.min.x, scroll_offset.x);
+                    if beat != *playhead_position {
+                        *playhead_position = beat;
+                        moved = true;
+                    }
+                }
+            }
 
             // Draw ruler background
             ui.painter()
@@ -816,8 +1117,22 @@ impl<'a> Timeline<'a> {
                 .beat_width(self.beat_width)
                 .measures(self.measures)
                 .beats_per_measure(self.beats_per_measure)
+                .display_mode(self.time_display_mode)
                 .show_clipped(&mut ruler_ui, theme);
         });
+        moved
+    }
+
+    /// Beat under an x offset into the visible ruler, snapped if enabled
+    fn ruler_x_to_beat(&self, x: f32, scroll_x: f32) -> f32 {
+        let total_beats = (self.measures * self.beats_per_measure) as f32;
+        let beat = ((x + scroll_x) / self.beat_width).clamp(0.0, total_beats);
+        if self.snap_to_grid {
+            let grid_step = 1.0 / self.snap_grid_subdivision.max(1) as f32;
+            (beat / grid_step).round() * grid_step
+        } else {
+            beat
+        }
     }
 
     /// Render track headers with vertical scrolling
@@ -953,12 +1268,15 @@ impl<'a> Timeline<'a> {
         interactions: &mut TimelineInteractions,
         theme: &Theme,
     ) -> Rect {
-        let tracks_rect = ui
-            .allocate_exact_size(
-                Vec2::new(layout.timeline_width, layout.timeline_height),
-                Sense::hover(),
-            )
-            .0;
+        let sense = if self.marquee_selection {
+            Sense::drag()
+        } else {
+            Sense::hover()
+        };
+        let (tracks_rect, tracks_response) = ui.allocate_exact_size(
+            Vec2::new(layout.timeline_width, layout.timeline_height),
+            sense,
+        );
 
         ui.painter().with_clip_rect(tracks_rect);
 
@@ -990,8 +1308,8 @@ impl<'a> Timeline<'a> {
                             &mut track_ui,
                             track,
                             info.track_idx,
-                            &mut interactions.region_clicked,
-                            &mut interactions.empty_clicked,
+                            idx,
+                            interactions,
                             theme,
                         );
                     }
@@ -999,9 +1317,143 @@ impl<'a> Timeline<'a> {
             }
         }
 
+        if self.marquee_selection {
+            self.handle_marquee(
+                ui,
+                &tracks_response,
+                tracks,
+                flat_list,
+                tracks_rect,
+                scroll_offset,
+                interactions,
+                theme,
+            );
+        }
+
         tracks_rect
     }
 
+    /// Track a marquee drag over the track content and apply the selection on release
+    #[allow(clippy::too_many_arguments)]
+    fn handle_marquee(
+        &self,
+        ui: &Ui,
+        response: &Response,
+        tracks: &mut [Track],
+        flat_list: &[TrackInfo],
+        tracks_rect: Rect,
+        scroll_offset: Vec2,
+        interactions: &mut TimelineInteractions,
+        theme: &Theme,
+    ) {
+        // Anchor is stored in content space so the box stays put while scrolling
+        let anchor_id = response.id.with("marquee_anchor");
+        let content_origin = tracks_rect.min - scroll_offset;
+
+        if response.drag_started() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                ui.ctx()
+                    .data_mut(|d| d.insert_temp(anchor_id, pos - content_origin));
+            }
+        }
+
+        let Some(anchor) = ui.ctx().data(|d| d.get_temp::<Vec2>(anchor_id)) else {
+            return;
+        };
+        let Some(pointer_pos) = ui.input(|i| i.pointer.interact_pos()) else {
+            return;
+        };
+        let marquee =
+            Rect::from_two_pos(anchor.to_pos2(), (pointer_pos - content_origin).to_pos2());
+
+        if response.dragged() {
+            let screen_rect = marquee.translate(content_origin.to_vec2());
+            let painter = ui.painter().with_clip_rect(tracks_rect);
+            painter.rect_filled(screen_rect, 0.0, theme.primary().gamma_multiply(0.15));
+            painter.rect_stroke(
+                screen_rect,
+                0.0,
+                egui::Stroke::new(1.0, theme.primary()),
+                egui::StrokeKind::Inside,
+            );
+        }
+
+        if response.drag_stopped() {
+            ui.ctx().data_mut(|d| d.remove::<Vec2>(anchor_id));
+
+            let hits = self.regions_in_marquee(tracks, flat_list, marquee);
+            if !ui.input(|i| i.modifiers.shift) {
+                Self::deselect_all_regions(tracks);
+            }
+
+            let mut selected = Vec::new();
+            for info in flat_list {
+                if let Some(track) = Self::get_track_by_path_mut(tracks, &info.path) {
+                    for (region_idx, region) in track.regions.iter_mut().enumerate() {
+                        if hits.contains(&(info.track_idx, region_idx)) {
+                            region.selected = true;
+                        }
+                        if region.selected {
+                            selected.push((info.track_idx, region_idx));
+                        }
+                    }
+                }
+            }
+            interactions.marquee_selected = Some(selected);
+        }
+    }
+
+    /// Find regions intersecting a marquee given in content space
+    /// (pixels from the start of the first beat of the first track)
+    fn regions_in_marquee(
+        &self,
+        tracks: &[Track],
+        flat_list: &[TrackInfo],
+        marquee: Rect,
+    ) -> Vec<(usize, usize)> {
+        // Must match the region layout in `TimelineTrack`
+        let region_h = (self.track_height * self.region_height_ratio).max(20.0);
+        let region_y_offset = (self.track_height - region_h) / 2.0;
+
+        let mut hits = Vec::new();
+        for (idx, info) in flat_list.iter().enumerate() {
+            let Some(track) = Self::get_track_by_path(tracks, &info.path) else {
+                continue;
+            };
+            let region_top = (idx as f32).mul_add(self.track_height, region_y_offset);
+            for (region_idx, region) in track.regions.iter().enumerate() {
+                let region_rect = Rect::from_min_size(
+                    pos2(region.start * self.beat_width, region_top),
+                    vec2(region.duration * self.beat_width, region_h),
+                );
+                if region_rect.intersects(marquee) {
+                    hits.push((info.track_idx, region_idx));
+                }
+            }
+        }
+        hits
+    }
+
+    /// Find regions whose `[start, start + duration)` contains `beat`
+    fn regions_at_beat(
+        tracks: &[Track],
+        flat_list: &[TrackInfo],
+        beat: f32,
+    ) -> Vec<(usize, usize)> {
+        let mut hits = Vec::new();
+        for info in flat_list {
+            let Some(track) = Self::get_track_by_path(tracks, &info.path) else {
+                continue;
+            };
+            for (region_idx, region) in track.regions.iter().enumerate() {
+                if region.contains_beat(beat) {
+                    hits.push((info.track_idx, region_idx));
+                }
+            }
+        }
+        hits
+    }
+
     /// Render empty state message when no tracks exist
     fn render_empty_state(
         &self,
@@ -1041,10 +1493,11 @@ impl<'a> Timeline<'a> {
                     .mul_add(self.beat_width, tracks_rect.min.x - scroll_offset.x);
                 if x >= tracks_rect.min.x && x <= tracks_rect.max.x {
                     let is_measure = beat == 0;
-                    let color = if is_measure {
-                        theme.border().linear_multiply(0.3)
-                    } else {
-                        theme.input().linear_multiply(0.2)
+                    let color = match (self.grid_color, is_measure) {
+                        (Some(grid_color), true) => grid_color,
+                        (Some(grid_color), false) => grid_color.gamma_multiply(0.5),
+                        (None, true) => theme.border().linear_multiply(0.3),
+                        (None, false) => theme.input().linear_multiply(0.2),
                     };
                     painter.line_segment(
                         [pos2(x, tracks_rect.min.y), pos2(x, tracks_rect.max.y)],
@@ -1198,6 +1651,7 @@ impl<'a> Timeline<'a> {
                         .measures(self.measures)
                         .beats_per_measure(self.beats_per_measure)
                         .height(self.ruler_height)
+                        .display_mode(self.time_display_mode)
                         .vertical_range(vertical_range.0, vertical_range.1)
                         .id(self.id.unwrap_or_else(|| ui.id()).with("marker").with(i));
 
@@ -1301,6 +1755,7 @@ impl<'a> Timeline<'a> {
                                 .measures(self.measures)
                                 .beats_per_measure(self.beats_per_measure)
                                 .height(layout.content_height)
+                                .display_mode(self.time_display_mode)
                                 .vertical_range(0.0, 0.5)
                                 .id(self.id.unwrap_or_else(|| ui.id()).with("loop_region"))
                                 .show(&mut loop_ui, theme);
@@ -1311,6 +1766,38 @@ impl<'a> Timeline<'a> {
                         {
                             top_marker = 0;
                         }
+
+                        let time = ui.input(|i| i.time);
+                        if let Some(alpha) = self.loop_glow_alpha(time) {
+                            let region_rect = Rect::from_min_max(
+                                pos2(
+                                    markers_rect.min.x + loop_data.start * self.beat_width,
+                                    markers_rect.min.y,
+                                ),
+                                pos2(
+                                    markers_rect.min.x + loop_data.end * self.beat_width,
+                                    markers_rect.min.y + layout.content_height * 0.5,
+                                ),
+                            );
+                            let color = RegionVariant::Loop.default_color(theme);
+                            let painter = loop_ui.painter();
+                            painter.rect_stroke(
+                                region_rect,
+                                0.0,
+                                egui::Stroke::new(
+                                    LOOP_GLOW_WIDTH,
+                                    color.gamma_multiply(alpha * 0.35),
+                                ),
+                                egui::StrokeKind::Outside,
+                            );
+                            painter.rect_stroke(
+                                region_rect,
+                                0.0,
+                                egui::Stroke::new(1.5, color.gamma_multiply(alpha)),
+                                egui::StrokeKind::Inside,
+                            );
+                            ui.ctx().request_repaint();
+                        }
                     }
                 }
                 1 => {
@@ -1329,6 +1816,7 @@ impl<'a> Timeline<'a> {
                                 .measures(self.measures)
                                 .beats_per_measure(self.beats_per_measure)
                                 .height(layout.content_height)
+                                .display_mode(self.time_display_mode)
                                 .vertical_range(0.33, 0.67)
                                 .id(self.id.unwrap_or_else(|| ui.id()).with("selection_range"))
                                 .show(&mut selection_ui, theme);
@@ -1359,6 +1847,7 @@ impl<'a> Timeline<'a> {
                         .measures(self.measures)
                         .beats_per_measure(self.beats_per_measure)
                         .height(layout.content_height)
+                        .display_mode(self.time_display_mode)
                         .vertical_range(0.5, 1.0)
                         .id(self.id.unwrap_or_else(|| ui.id()).with("punch_region"))
                         .show(&mut punch_ui, theme);
@@ -1430,10 +1919,11 @@ impl<'a> Timeline<'a> {
 
     /// Build the final `TimelineResponse`
     #[allow(clippy::needless_pass_by_value)]
-    const fn build_response(
+    fn build_response(
         response: Response,
         interactions: TimelineInteractions,
         playhead_position: f32,
+        geometry: TimelineGeometry,
     ) -> TimelineResponse {
         TimelineResponse {
             response,
@@ -1444,10 +1934,92 @@ impl<'a> Timeline<'a> {
             track_collapse_clicked: interactions.track_collapse_clicked,
             region_clicked: interactions.region_clicked,
             empty_clicked: interactions.empty_clicked,
+            dropped_payload: interactions.dropped_payload,
             playhead_moved: interactions.playhead_moved,
             playhead_clicked: false,
             playhead_position,
             marker_moved: None,
+            marquee_selected: interactions.marquee_selected,
+            playing_regions: interactions.playing_regions,
+            play_toggle_requested: interactions.play_toggle_requested,
+            delete_requested: interactions.delete_requested,
+            undo_requested: interactions.undo_requested,
+            redo_requested: interactions.redo_requested,
+            geometry,
+        }
+    }
+
+    /// Handle keyboard shortcuts, only while the timeline has focus
+    ///
+    /// Keys are consumed so they don't also trigger global shortcuts.
+    fn handle_shortcuts(
+        &self,
+        ui: &Ui,
+        focus: &Response,
+        playhead_position: &mut f32,
+        interactions: &mut TimelineInteractions,
+    ) {
+        use egui::{Key, Modifiers};
+
+        if !focus.has_focus() {
+            return;
+        }
+
+        let grid_step = 1.0 / self.snap_grid_subdivision.max(1) as f32;
+        let total_beats = (self.measures * self.beats_per_measure) as f32;
+        ui.input_mut(|i| {
+            interactions.play_toggle_requested = i.consume_key(Modifiers::NONE, Key::Space);
+            interactions.delete_requested = i.consume_key(Modifiers::NONE, Key::Delete)
+                | i.consume_key(Modifiers::NONE, Key::Backspace);
+            // Check the shifted redo first: plain Cmd+Z also matches Cmd+Shift+Z
+            interactions.redo_requested = i
+                .consume_key(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z)
+                | i.consume_key(Modifiers::COMMAND, Key::Y);
+            interactions.undo_requested = i.consume_key(Modifiers::COMMAND, Key::Z);
+
+            let mut nudge = 0.0;
+            if i.consume_key(Modifiers::NONE, Key::ArrowLeft) {
+                nudge -= grid_step;
+            }
+            if i.consume_key(Modifiers::NONE, Key::ArrowRight) {
+                nudge += grid_step;
+            }
+            if nudge != 0.0 {
+                *playhead_position = (*playhead_position + nudge).clamp(0.0, total_beats);
+                interactions.playhead_moved = true;
+            }
+        });
+    }
+
+    /// Take focus when the timeline is clicked and draw a focus ring while focused
+    ///
+    /// egui gives focus up on clicks elsewhere. Clicks land on the tracks and
+    /// headers on top of the focus widget, so focus is requested here instead,
+    /// unless a child such as a track name field took focus this frame.
+    fn update_focus(ui: &Ui, focus: &Response, theme: &Theme) {
+        let click = ui.input(|i| {
+            i.pointer
+                .any_click()
+                .then(|| i.pointer.interact_pos())
+                .flatten()
+        });
+        let unclaimed = ui.memory(|m| m.focused().is_none());
+        if unclaimed && click.is_some_and(|pos| focus.rect.contains(pos)) {
+            focus.request_focus();
+        }
+
+        if focus.has_focus() {
+            // Keep the arrow keys for nudging instead of moving focus
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    focus.id,
+                    egui::EventFilter {
+                        horizontal_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+            draw_focus_ring(ui.painter(), focus.rect, theme);
         }
     }
 
@@ -1516,14 +2088,31 @@ impl<'a> Timeline<'a> {
         header_response.response.rect
     }
 
+    /// Background fill for a track row, or None when the row is not tinted
+    fn track_background(&self, row: usize, theme: &Theme) -> Option<Color32> {
+        (self.track_striping && row % 2 == 1).then(|| theme.muted().gamma_multiply(0.4))
+    }
+
+    /// Alpha of the loop region glow at `time` seconds, or None when looping is idle
+    fn loop_glow_alpha(&self, time: f64) -> Option<f32> {
+        self.loop_active.then(|| {
+            let phase = (time / LOOP_PULSE_PERIOD * std::f64::consts::TAU).sin() as f32;
+            egui::lerp(
+                LOOP_GLOW_MIN_ALPHA..=LOOP_GLOW_MAX_ALPHA,
+                phase.mul_add(0.5, 0.5),
+            )
+        })
+    }
+
     /// Render a single track timeline (non-recursive, flat rendering)
+    #[allow(clippy::too_many_arguments)]
     fn render_track_timeline_flat(
         &self,
         ui: &mut Ui,
         track: &mut Track,
         track_idx: usize,
-        region_clicked: &mut Option<(usize, usize)>,
-        empty_clicked: &mut Option<(usize, f32)>,
+        row: usize,
+        interactions: &mut TimelineInteractions,
         theme: &Theme,
     ) -> egui::Rect {
         let track_id = self
@@ -1532,7 +2121,7 @@ impl<'a> Timeline<'a> {
             .with("timeline_track")
             .with(track_idx);
 
-        let track_response = TimelineTrack::new()
+        let mut timeline_track = TimelineTrack::new()
             .id(track_id)
             .height(self.track_height)
             .region_height_ratio(self.region_height_ratio)
@@ -1540,14 +2129,34 @@ impl<'a> Timeline<'a> {
             .measures(self.measures)
             .beats_per_measure(self.beats_per_measure)
             .track_color(track.color)
-            .show(ui, &mut track.regions, theme);
+            .render_quality(self.render_quality);
+        if let Some(background) = self.track_background(row, theme) {
+            timeline_track = timeline_track.background_color(background);
+        }
+        if let Some(grid_color) = self.grid_color {
+            timeline_track = timeline_track.grid_color(grid_color);
+        }
+        let playing: Vec<usize> = interactions
+            .playing_regions
+            .iter()
+            .filter(|(playing_track, _)| *playing_track == track_idx)
+            .map(|&(_, region_idx)| region_idx)
+            .collect();
+        if !playing.is_empty() {
+            timeline_track = timeline_track.playing_regions(playing);
+        }
+        let track_response = timeline_track.show(ui, &mut track.regions, theme);
 
         if let Some(region_idx) = track_response.region_clicked {
-            *region_clicked = Some((track_idx, region_idx));
+            interactions.region_clicked = Some((track_idx, region_idx));
         }
 
         if let Some(beat_pos) = track_response.empty_clicked {
-            *empty_clicked = Some((track_idx, beat_pos));
+            interactions.empty_clicked = Some((track_idx, beat_pos));
+        }
+
+        if let Some((payload, beat_pos)) = track_response.dropped_payload {
+            interactions.dropped_payload = Some((track_idx, beat_pos, payload));
         }
 
         track_response.response.rect
@@ -1578,13 +2187,30 @@ impl<'a> Timeline<'a> {
         // Apply scroll adjustments (scroll_to_beat, auto_follow_playhead)
         self.apply_scroll_adjustments(&mut scroll_offset, &layout, *playhead_position);
 
+        // Keyboard shortcuts (only while focused). The focus widget sits
+        // behind everything else so it never takes hover or clicks.
+        let focus_response = ui.interact(
+            layout.available_rect,
+            self.id.unwrap_or_else(|| ui.id()).with("timeline_focus"),
+            Sense::focusable_noninteractive(),
+        );
+        focus_response
+            .widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Other, true, "Timeline"));
+        self.handle_shortcuts(ui, &focus_response, playhead_position, &mut interactions);
+
+        if self.highlight_playing {
+            interactions.playing_regions =
+                Self::regions_at_beat(tracks, &flat_list, *playhead_position);
+        }
+
         // === RENDER MAIN UI ===
         let response = ui
             .vertical(|ui| {
                 ui.spacing_mut().item_spacing.y = 0.0;
 
                 // Row 1: Corner + Ruler
-                self.render_ruler(ui, &layout, scroll_offset, theme);
+                interactions.playhead_moved |=
+                    self.render_ruler(ui, &layout, scroll_offset, playhead_position, theme);
 
                 // Row 2: Headers + Tracks
                 ui.horizontal(|ui| {
@@ -1650,11 +2276,14 @@ impl<'a> Timeline<'a> {
         self.render_region_markers(ui, &flat_list, &layout, scroll_offset, theme);
 
         // Playhead
-        interactions.playhead_moved =
+        interactions.playhead_moved |=
             self.render_playhead(ui, &layout, scroll_offset, playhead_position, theme);
 
+        Self::update_focus(ui, &focus_response, theme);
+
         // Build and return response
-        Self::build_response(response, interactions, *playhead_position)
+        let geometry = self.geometry(&layout, scroll_offset, flat_list.len());
+        Self::build_response(response, interactions, *playhead_position, geometry)
     }
 }
 
@@ -1663,3 +2292,189 @@ impl Default for Timeline<'_> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FadeCurve, FadeSettings, MidiData, MidiNote, PlaybackSettings};
+
+    #[test]
+    fn test_arrangement_json_round_trip() {
+        let midi_notes = vec![
+            MidiNote {
+                note: 60,
+                start: 0.0,
+                duration: 1.0,
+                velocity: 100,
+            },
+            MidiNote {
+                note: 67,
+                start: 1.5,
+                duration: 0.5,
+                velocity: 80,
+            },
+        ];
+        let tracks = vec![
+            Track::new("Vocals", Color32::from_rgb(220, 80, 60)).region(
+                Region::new("Verse", 0.0, 8.0)
+                    .fades(
+                        FadeSettings::new(0.5, 1.0)
+                            .fade_in_curve(FadeCurve::SCurve)
+                            .fade_out_curve(FadeCurve::Exponential),
+                    )
+                    .playback(PlaybackSettings::new().gain(0.5).pitch_shift(-2)),
+            ),
+            Track::new_folder("Keys", Color32::BLUE).child(
+                Track::new("Piano", Color32::from_rgb(60, 120, 220)).region(
+                    Region::midi_with_data("Chords", 4.0, 4.0, MidiData::from_notes(midi_notes))
+                        .color(Color32::from_rgb(255, 200, 0)),
+                ),
+            ),
+        ];
+        let markers = vec![
+            MarkerData::new(0.0, "Intro"),
+            MarkerData::tempo(8.0, 128.0),
+            MarkerData::time_signature(16.0, 3, 4),
+        ];
+
+        let json = Timeline::to_json(&tracks, &markers, 6.5).expect("export arrangement");
+        let arrangement = Timeline::from_json(&json).expect("import arrangement");
+
+        assert_eq!(arrangement.tracks, tracks);
+        assert_eq!(arrangement.markers, markers);
+        assert_eq!(arrangement.playhead, 6.5);
+    }
+
+    #[test]
+    fn test_regions_at_playhead_are_playing() {
+        let tracks = vec![
+            Track::new("A", Color32::RED)
+                .region(Region::new("A1", 0.0, 4.0))
+                .region(Region::new("A2", 4.0, 4.0)),
+            Track::new("B", Color32::BLUE).region(Region::new("B1", 3.0, 2.0)),
+        ];
+        let mut flat_list = Vec::new();
+        Timeline::build_flat_track_list(&tracks, &[], 0, 0, None, &mut flat_list);
+
+        let playing = Timeline::regions_at_beat(&tracks, &flat_list, 2.0);
+        assert_eq!(playing, vec![(0, 0)]);
+
+        // Region ends are exclusive
+        let playing = Timeline::regions_at_beat(&tracks, &flat_list, 4.0);
+        assert_eq!(playing, vec![(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn test_marquee_selects_regions_across_tracks() {
+        let tracks = vec![
+            Track::new("A", Color32::RED)
+                .region(Region::new("A1", 0.0, 4.0))
+                .region(Region::new("A2", 8.0, 4.0)),
+            Track::new("B", Color32::BLUE)
+                .region(Region::new("B1", 2.0, 4.0))
+                .region(Region::new("B2", 12.0, 2.0)),
+        ];
+        let mut flat_list = Vec::new();
+        Timeline::build_flat_track_list(&tracks, &[], 0, 0, None, &mut flat_list);
+
+        let timeline = Timeline::new().track_height(80.0).beat_width(60.0);
+        // Beats 1-5, from the middle of track A to the middle of track B
+        let marquee = Rect::from_min_max(pos2(60.0, 40.0), pos2(300.0, 120.0));
+
+        let hits = timeline.regions_in_marquee(&tracks, &flat_list, marquee);
+        assert_eq!(hits, vec![(0, 0), (1, 0)]);
+    }
+
+    #[test]
+    fn test_marquee_skips_regions_outside_box() {
+        let tracks = vec![Track::new("A", Color32::RED).region(Region::new("A1", 8.0, 4.0))];
+        let mut flat_list = Vec::new();
+        Timeline::build_flat_track_list(&tracks, &[], 0, 0, None, &mut flat_list);
+
+        let timeline = Timeline::new().track_height(80.0).beat_width(60.0);
+        let marquee = Rect::from_min_max(pos2(0.0, 0.0), pos2(400.0, 80.0));
+
+        assert!(timeline
+            .regions_in_marquee(&tracks, &flat_list, marquee)
+            .is_empty());
+    }
+
+    fn test_geometry() -> TimelineGeometry {
+        let layout = TimelineLayout {
+            timeline_width: 600.0,
+            timeline_height: 240.0,
+            content_width: 3840.0,
+            content_height: 400.0,
+            max_scroll_x: 3240.0,
+            max_scroll_y: 160.0,
+            available_rect: Rect::from_min_size(pos2(10.0, 20.0), vec2(800.0, 268.0)),
+        };
+        Timeline::new().geometry(&layout, vec2(150.0, 40.0), 5)
+    }
+
+    #[test]
+    fn test_geometry_beat_round_trip() {
+        let geometry = test_geometry();
+        for beat in [0.0, 0.25, 1.0, 3.5, 17.0, 63.75] {
+            let round_trip = geometry.x_to_beat(geometry.beat_to_x(beat));
+            assert!((round_trip - beat).abs() < 1e-4, "{beat} -> {round_trip}");
+        }
+        // Header (200) + origin (10) - scroll (150) + 2 beats * 60
+        assert!((geometry.beat_to_x(2.0) - 180.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_geometry_track_index_at_y() {
+        let geometry = test_geometry();
+        // Viewport starts at 20 + ruler 28 = 48; content is scrolled up by 40
+        assert_eq!(geometry.track_index_at_y(48.0), Some(0));
+        assert_eq!(geometry.track_index_at_y(48.0 + 41.0), Some(1));
+        assert_eq!(geometry.track_index_at_y(47.0), None);
+        assert!((geometry.track_rect(1).min.y - 88.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_track_striping_alternates_backgrounds() {
+        let theme = Theme::dark();
+
+        let plain = Timeline::new();
+        assert_eq!(plain.track_background(0, &theme), None);
+        assert_eq!(plain.track_background(1, &theme), None);
+
+        let striped = Timeline::new().track_striping(true);
+        let even = striped.track_background(0, &theme);
+        let odd = striped.track_background(1, &theme);
+        assert_ne!(even, odd);
+        assert_eq!(striped.track_background(2, &theme), even);
+        assert_eq!(striped.track_background(3, &theme), odd);
+    }
+
+    #[test]
+    fn test_loop_active_pulses_glow_alpha() {
+        assert_eq!(Timeline::new().loop_glow_alpha(0.3), None);
+
+        let looping = Timeline::new().loop_active(true);
+        let quarter = LOOP_PULSE_PERIOD / 4.0;
+        let peak = looping.loop_glow_alpha(quarter).unwrap();
+        let trough = looping.loop_glow_alpha(quarter * 3.0).unwrap();
+        assert!((peak - LOOP_GLOW_MAX_ALPHA).abs() < 1e-4);
+        assert!((trough - LOOP_GLOW_MIN_ALPHA).abs() < 1e-4);
+        assert_ne!(looping.loop_glow_alpha(0.0), looping.loop_glow_alpha(0.1));
+    }
+
+    #[test]
+    fn test_ruler_x_to_beat() {
+        let timeline = Timeline::new().beat_width(40.0).measures(4);
+        // Scrolled two beats in, x = 50 is a quarter beat past beat 3
+        assert!((timeline.ruler_x_to_beat(50.0, 80.0) - 3.25).abs() < 1e-4);
+        // Clamped to the arrangement
+        assert!(timeline.ruler_x_to_beat(-10.0, 0.0).abs() < 1e-4);
+        assert!((timeline.ruler_x_to_beat(10_000.0, 0.0) - 16.0).abs() < 1e-4);
+
+        let snapped = Timeline::new()
+            .beat_width(40.0)
+            .snap_to_grid(true)
+            .snap_grid_subdivision(2);
+        assert!((snapped.ruler_x_to_beat(50.0, 80.0) - 3.5).abs() < 1e-4);
+    }
+}
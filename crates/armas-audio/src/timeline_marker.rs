@@ -3,11 +3,12 @@
 //! Unified component for timeline markers (cue points, tempo, time signature).
 //! Shows a vertical line with a badge displaying marker-specific information.
 
+use crate::TimeDisplayMode;
 use armas_basic::theme::Theme;
 use egui::{Color32, Pos2, Rect, Response, Sense, Ui, Vec2};
 
 /// Timeline marker variant type
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum MarkerVariant {
     /// Generic cue point with custom label
     Cue(String),
@@ -48,20 +49,31 @@ impl MarkerVariant {
         }
     }
 
-    /// Get tooltip text
+    /// Get the marker type name
     #[must_use]
-    pub fn tooltip_text(&self, position: f32) -> String {
+    pub const fn kind_name(&self) -> &'static str {
         match self {
-            Self::Cue(label) => format!("{label} at {position:.1} beats"),
-            Self::Tempo(bpm) => format!("{bpm:.0} BPM at {position:.1} beats"),
-            Self::TimeSignature {
-                numerator,
-                denominator,
-            } => {
-                format!("{numerator}/{denominator} at {position:.1} beats")
-            }
+            Self::Cue(_) => "Cue",
+            Self::Tempo(_) => "Tempo",
+            Self::TimeSignature { .. } => "Time Signature",
         }
     }
+
+    /// Get tooltip text with the position formatted for the given display mode
+    #[must_use]
+    pub fn details_text(
+        &self,
+        position: f32,
+        mode: TimeDisplayMode,
+        beats_per_measure: u32,
+    ) -> String {
+        format!(
+            "{}\n{} at {}",
+            self.badge_text(),
+            self.kind_name(),
+            mode.format(position, beats_per_measure)
+        )
+    }
 }
 
 /// Timeline marker component
@@ -100,6 +112,7 @@ pub struct TimelineMarker<'a> {
     color: Option<Color32>,
     show_line: bool,
     show_tooltip: bool,
+    display_mode: TimeDisplayMode,
     id: Option<egui::Id>,
     vertical_range: (f32, f32),
 }
@@ -117,6 +130,8 @@ pub struct TimelineMarkerResponse {
     pub clicked: bool,
     /// Marker is hovered
     pub hovered: bool,
+    /// Tooltip text shown this frame (when hovered with tooltips enabled)
+    pub tooltip: Option<String>,
 }
 
 impl<'a> TimelineMarker<'a> {
@@ -136,6 +151,7 @@ impl<'a> TimelineMarker<'a> {
             color: None,
             show_line: true,
             show_tooltip: true,
+            display_mode: TimeDisplayMode::BarsBeats,
             id: None,
             vertical_range: (0.0, 1.0),
         }
@@ -225,6 +241,13 @@ impl<'a> TimelineMarker<'a> {
         self
     }
 
+    /// Set how the position is formatted in the tooltip (bars:beats or SMPTE)
+    #[must_use]
+    pub const fn display_mode(mut self, mode: TimeDisplayMode) -> Self {
+        self.display_mode = mode;
+        self
+    }
+
     /// Set vertical range as percentages
     #[must_use]
     pub const fn vertical_range(mut self, top_percent: f32, bottom_percent: f32) -> Self {
@@ -263,6 +286,7 @@ impl<'a> TimelineMarker<'a> {
                 variant_changed: false,
                 clicked: false,
                 hovered: false,
+                tooltip: None,
             }
         };
 
@@ -276,6 +300,7 @@ impl<'a> TimelineMarker<'a> {
             variant_changed: interaction.variant_changed,
             clicked: interaction.clicked,
             hovered: interaction.hovered,
+            tooltip: interaction.tooltip,
         }
     }
 
@@ -307,6 +332,7 @@ impl<'a> TimelineMarker<'a> {
             variant_changed: false,
             clicked: false,
             hovered: false,
+            tooltip: None,
         }
     }
 
@@ -378,6 +404,7 @@ impl<'a> TimelineMarker<'a> {
             variant_changed: false,
             clicked: badge_response.clicked(),
             hovered: badge_response.hovered(),
+            tooltip: None,
         };
 
         // Handle dragging
@@ -424,8 +451,14 @@ impl<'a> TimelineMarker<'a> {
         self.draw_triangle_flag(painter, x_pos, rect.min.y + badge_height, color);
 
         // Tooltip
-        if self.show_tooltip {
-            badge_response.on_hover_text(self.variant.tooltip_text(*self.position));
+        if self.show_tooltip && badge_response.hovered() {
+            let text = self.variant.details_text(
+                *self.position,
+                self.display_mode,
+                self.beats_per_measure,
+            );
+            armas_basic::tooltip(ui, &badge_response, text.clone());
+            interaction.tooltip = Some(text);
         }
 
         interaction
@@ -452,6 +485,7 @@ struct MarkerInteraction {
     variant_changed: bool,
     clicked: bool,
     hovered: bool,
+    tooltip: Option<String>,
 }
 
 #[cfg(test)]
@@ -472,4 +506,23 @@ mod tests {
         };
         assert_eq!(time_sig.badge_text(), "4/4");
     }
+
+    #[test]
+    fn test_marker_details_text() {
+        let cue = MarkerVariant::Cue("Chorus".to_string());
+        assert_eq!(
+            cue.details_text(17.0, TimeDisplayMode::BarsBeats, 4),
+            "Chorus\nCue at 5:2"
+        );
+
+        let tempo = MarkerVariant::Tempo(120.0);
+        let smpte = TimeDisplayMode::Smpte {
+            bpm: 120.0,
+            fps: 25,
+        };
+        assert_eq!(
+            tempo.details_text(8.0, smpte, 4),
+            "120 BPM\nTempo at 00:00:04:00"
+        );
+    }
 }
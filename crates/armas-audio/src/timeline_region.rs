@@ -3,6 +3,7 @@
 //! Unified component for timeline regions with draggable handles.
 //! Supports multiple region types: Selection, Loop, and Punch.
 
+use crate::TimeDisplayMode;
 use armas_basic::theme::Theme;
 use egui::{Color32, Pos2, Rect, Response, Sense, Ui, Vec2};
 
@@ -28,6 +29,34 @@ impl RegionVariant {
         }
     }
 
+    /// Get the variant display name
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Selection => "Selection",
+            Self::Loop => "Loop",
+            Self::Punch => "Punch",
+        }
+    }
+
+    /// Get tooltip text for a region spanning `start..end` beats
+    #[must_use]
+    pub fn details_text(
+        &self,
+        start: f32,
+        end: f32,
+        mode: TimeDisplayMode,
+        beats_per_measure: u32,
+    ) -> String {
+        format!(
+            "{}\n{} – {} ({:.2} beats)",
+            self.label(),
+            mode.format(start, beats_per_measure),
+            mode.format(end, beats_per_measure),
+            (end - start).max(0.0)
+        )
+    }
+
     /// Get default height for the variant
     #[must_use]
     pub const fn default_height(&self) -> f32 {
@@ -90,6 +119,8 @@ pub struct TimelineRegion<'a> {
     color: Option<Color32>,
     handle_width: f32,
     show_labels: bool,
+    show_tooltip: bool,
+    display_mode: TimeDisplayMode,
     id: Option<egui::Id>,
     clip_rect: Option<Rect>,
     vertical_range: (f32, f32),
@@ -106,6 +137,8 @@ pub struct TimelineRegionResponse {
     pub end_changed: bool,
     /// Region was clicked
     pub region_clicked: bool,
+    /// Tooltip text shown this frame (when hovered with tooltips enabled)
+    pub tooltip: Option<String>,
 }
 
 /// Helper struct for tracking interaction state
@@ -113,6 +146,7 @@ struct RegionInteraction {
     start_changed: bool,
     end_changed: bool,
     region_clicked: bool,
+    tooltip: Option<String>,
 }
 
 impl<'a> TimelineRegion<'a> {
@@ -132,6 +166,8 @@ impl<'a> TimelineRegion<'a> {
             color: None,
             handle_width: 8.0,
             show_labels: true,
+            show_tooltip: true,
+            display_mode: TimeDisplayMode::BarsBeats,
             id: None,
             clip_rect: None,
             vertical_range: (0.0, 1.0),
@@ -222,6 +258,20 @@ impl<'a> TimelineRegion<'a> {
         self
     }
 
+    /// Show or hide the hover tooltip with the region's range
+    #[must_use]
+    pub const fn show_tooltip(mut self, show: bool) -> Self {
+        self.show_tooltip = show;
+        self
+    }
+
+    /// Set how positions are formatted in the tooltip (bars:beats or SMPTE)
+    #[must_use]
+    pub const fn display_mode(mut self, mode: TimeDisplayMode) -> Self {
+        self.display_mode = mode;
+        self
+    }
+
     /// Set clip rect for rendering
     #[must_use]
     pub const fn clip_rect(mut self, clip_rect: Rect) -> Self {
@@ -257,6 +307,7 @@ impl<'a> TimelineRegion<'a> {
             start_changed: false,
             end_changed: false,
             region_clicked: false,
+            tooltip: None,
         };
 
         if ui.is_rect_visible(rect) {
@@ -266,8 +317,21 @@ impl<'a> TimelineRegion<'a> {
             let painter = self.get_painter(ui);
             let (start_x, end_x) = self.calculate_handle_positions(&rect, timeline_width);
 
-            interaction.region_clicked =
+            let region_response =
                 self.draw_region_background(ui, &painter, &rect, region_color, start_x, end_x);
+            if let Some(region_response) = region_response {
+                interaction.region_clicked = region_response.clicked();
+                if self.show_tooltip && region_response.hovered() {
+                    let text = self.variant.details_text(
+                        *self.start,
+                        *self.end,
+                        self.display_mode,
+                        self.beats_per_measure,
+                    );
+                    armas_basic::tooltip(ui, &region_response, text.clone());
+                    interaction.tooltip = Some(text);
+                }
+            }
 
             interaction.start_changed = self.draw_and_interact_handle(
                 ui,
@@ -303,6 +367,7 @@ impl<'a> TimelineRegion<'a> {
             start_changed: interaction.start_changed,
             end_changed: interaction.end_changed,
             region_clicked: interaction.region_clicked,
+            tooltip: interaction.tooltip,
         }
     }
 
@@ -342,6 +407,7 @@ impl<'a> TimelineRegion<'a> {
             start_changed: false,
             end_changed: false,
             region_clicked: false,
+            tooltip: None,
         }
     }
 
@@ -382,9 +448,9 @@ impl<'a> TimelineRegion<'a> {
         color: Color32,
         start_x: f32,
         end_x: f32,
-    ) -> bool {
+    ) -> Option<Response> {
         if end_x <= start_x {
-            return false;
+            return None;
         }
 
         let region_rect =
@@ -416,8 +482,7 @@ impl<'a> TimelineRegion<'a> {
         }
 
         let region_id = self.id.unwrap_or_else(|| ui.id()).with("region_area");
-        let region_response = ui.interact(region_rect, region_id, Sense::click());
-        region_response.clicked()
+        Some(ui.interact(region_rect, region_id, Sense::click()))
     }
 
     fn draw_and_interact_handle(
@@ -787,4 +852,10 @@ mod tests {
         let punch = TimelineRegion::new(&mut start, &mut end).variant(RegionVariant::Punch);
         assert_eq!(punch.variant, RegionVariant::Punch);
     }
+
+    #[test]
+    fn test_region_details_text() {
+        let text = RegionVariant::Loop.details_text(4.0, 12.0, TimeDisplayMode::BarsBeats, 4);
+        assert_eq!(text, "Loop\n2:1 – 4:1 (8.00 beats)");
+    }
 }
@@ -3,11 +3,23 @@
 //! A horizontal track row for DAW timelines that displays audio/MIDI regions.
 
 use armas_basic::components::cards::{Card, CardVariant};
+use armas_basic::ext::{drop_target_any, DragPayload};
 use armas_basic::theme::Theme;
+use egui::text::{LayoutJob, TextWrapping};
 use egui::{Color32, Pos2, Rect, Response, Sense, StrokeKind, Ui, Vec2};
+use serde::{Deserialize, Serialize};
+
+// Region label layout
+const REGION_LABEL_PADDING: f32 = 6.0;
+const REGION_LABEL_GAP: f32 = 4.0;
+const REGION_LABEL_FONT_SIZE: f32 = 12.0;
+
+// Highlight of regions under the playhead
+const PLAYING_FILL_ALPHA: u8 = 40;
+const PLAYING_STROKE_ALPHA: u8 = 160;
 
 /// MIDI note for MIDI regions
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct MidiNote {
     /// Note number (0-127, where 60 = middle C)
     pub note: u8,
@@ -20,7 +32,7 @@ pub struct MidiNote {
 }
 
 /// MIDI data for MIDI regions
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MidiData {
     /// MIDI notes to display
     /// If empty, a simulated pattern will be drawn
@@ -45,7 +57,7 @@ impl MidiData {
 ///
 /// For the timeline track, we only need time and value for display.
 /// The actual automation engine (audio-automation crate) handles curves.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct AutomationPoint {
     /// Time in beats (relative to region start)
     pub time: f32,
@@ -53,12 +65,20 @@ pub struct AutomationPoint {
     pub value: f32,
 }
 
+impl AutomationPoint {
+    /// Create a new automation point
+    #[must_use]
+    pub const fn new(time: f32, value: f32) -> Self {
+        Self { time, value }
+    }
+}
+
 /// Automation data for automation regions
 ///
 /// This is a simplified view for timeline display. For full automation editing
 /// with curves (Linear, Exponential, Bezier, etc.), use the `audio-automation`
 /// crate and convert to this format for display.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AutomationData {
     /// Automation points (already interpolated for display)
     /// If empty, a simulated curve will be drawn
@@ -84,7 +104,7 @@ impl AutomationData {
 }
 
 /// Region type with associated data
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub enum RegionType {
     /// Audio region (placeholder for waveform data)
     #[default]
@@ -95,8 +115,65 @@ pub enum RegionType {
     Automation(AutomationData),
 }
 
+/// Level of detail for waveform and MIDI previews inside regions
+///
+/// Lower qualities draw fewer waveform lines and MIDI blocks, and skip the
+/// preview on regions too narrow for it to be visible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RenderQuality {
+    /// Sparse previews for low-end hardware
+    Low,
+    /// Balanced detail
+    Medium,
+    /// Full detail
+    #[default]
+    High,
+}
+
+impl RenderQuality {
+    /// Horizontal distance between simulated waveform lines, in points
+    const fn waveform_spacing(self) -> f32 {
+        match self {
+            Self::Low => 12.0,
+            Self::Medium => 8.0,
+            Self::High => 4.0,
+        }
+    }
+
+    /// Number of simulated waveform lines across `width` points
+    fn waveform_lines(self, width: f32) -> usize {
+        (width / self.waveform_spacing()).max(0.0) as usize
+    }
+
+    /// Draw every n-th block of the simulated MIDI pattern
+    const fn midi_pattern_stride(self) -> usize {
+        match self {
+            Self::Low => 2,
+            Self::Medium | Self::High => 1,
+        }
+    }
+
+    /// Narrowest MIDI note still drawn, in points
+    const fn min_note_width(self) -> f32 {
+        match self {
+            Self::Low => 4.0,
+            Self::Medium => 2.0,
+            Self::High => 0.0,
+        }
+    }
+
+    /// Narrowest region that gets a content preview, in points
+    const fn min_content_width(self) -> f32 {
+        match self {
+            Self::Low => 32.0,
+            Self::Medium => 20.0,
+            Self::High => 12.0,
+        }
+    }
+}
+
 /// Fade curve types for region fades
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum FadeCurve {
     /// Linear fade
     #[default]
@@ -128,7 +205,7 @@ impl FadeCurve {
 }
 
 /// Fade settings for region
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FadeSettings {
     /// Fade in duration in beats
     pub fade_in: f32,
@@ -178,7 +255,7 @@ impl FadeSettings {
 }
 
 /// Playback settings for region
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlaybackSettings {
     /// Clip gain in linear scale (1.0 = 0dB, 2.0 = +6dB, 0.5 = -6dB)
     pub gain: f32,
@@ -264,7 +341,7 @@ impl PlaybackSettings {
 }
 
 /// A region (audio clip or MIDI clip) on the timeline
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Region {
     /// Region name
     pub name: String,
@@ -275,6 +352,7 @@ pub struct Region {
     /// Region type with data
     pub region_type: RegionType,
     /// Region color
+    #[serde(with = "crate::serde_color::option")]
     pub color: Option<Color32>,
     /// Whether the region is selected
     pub selected: bool,
@@ -434,6 +512,12 @@ impl Region {
         self.playback.set_gain_db(db);
         self
     }
+
+    /// Whether `beat` lies within `[start, start + duration)`
+    #[must_use]
+    pub fn contains_beat(&self, beat: f32) -> bool {
+        beat >= self.start && beat < self.start + self.duration
+    }
 }
 
 /// Region edge handle for resizing
@@ -463,6 +547,11 @@ pub struct TimelineTrackResponse {
     pub region_clicked: Option<usize>,
     /// Empty area clicked (position in beats)
     pub empty_clicked: Option<f32>,
+    /// Payload dropped on the track, and the position in beats it was dropped at
+    ///
+    /// Payloads come from [`armas_basic::ext::begin_drag`], e.g. items of a
+    /// draggable `TreeView`.
+    pub dropped_payload: Option<(DragPayload, f32)>,
 }
 
 /// Timeline track component for DAW
@@ -504,8 +593,14 @@ pub struct TimelineTrack {
     track_color: Option<Color32>,
     /// Background color
     background_color: Option<Color32>,
+    /// Grid line color override for measure lines (beat lines use a fainter tint)
+    grid_color: Option<Color32>,
     /// Region height as a ratio of track height (0.0-1.0), used when `region_height` is None
     region_height_ratio: f32,
+    /// Indices of regions to brighten as playing
+    playing_regions: Vec<usize>,
+    /// Detail of waveform/MIDI previews
+    render_quality: RenderQuality,
 }
 
 impl TimelineTrack {
@@ -521,7 +616,10 @@ impl TimelineTrack {
             beats_per_measure: 4,
             track_color: None,
             background_color: None,
+            grid_color: None,
             region_height_ratio: 0.9,
+            playing_regions: Vec::new(),
+            render_quality: RenderQuality::High,
         }
     }
 
@@ -577,6 +675,34 @@ impl TimelineTrack {
         self
     }
 
+    /// Set the track background color (drawn behind grid lines and regions)
+    #[must_use]
+    pub const fn background_color(mut self, color: Color32) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    /// Set the color of the measure/beat grid lines
+    #[must_use]
+    pub const fn grid_color(mut self, color: Color32) -> Self {
+        self.grid_color = Some(color);
+        self
+    }
+
+    /// Brighten these regions to show that the playhead is over them
+    #[must_use]
+    pub fn playing_regions(mut self, indices: Vec<usize>) -> Self {
+        self.playing_regions = indices;
+        self
+    }
+
+    /// Set the detail of waveform/MIDI previews (default: High)
+    #[must_use]
+    pub const fn render_quality(mut self, quality: RenderQuality) -> Self {
+        self.render_quality = quality;
+        self
+    }
+
     /// Show the timeline track
     pub fn show(
         self,
@@ -589,6 +715,7 @@ impl TimelineTrack {
 
         let mut region_clicked = None;
         let mut empty_clicked = None;
+        let mut dropped_payload = None;
 
         // Don't add any padding - allocate full height to match TrackHeader
         let content_height = self.height;
@@ -596,16 +723,22 @@ impl TimelineTrack {
             .region_height
             .unwrap_or_else(|| (self.height * self.region_height_ratio).max(20.0));
 
-        let card = Card::new()
+        let mut card = Card::new()
             .variant(CardVariant::Filled)
             .width(total_width)
             .height(self.height)
             .inner_margin(0.0)
             .fill(self.background_color.unwrap_or(Color32::TRANSPARENT));
+        // Square corners so background fills of adjacent tracks tile
+        if self.background_color.is_some() {
+            card = card.corner_radius(0.0);
+        }
 
         let card_response = card.show(ui, theme, |ui| {
             let (rect, response) =
                 ui.allocate_exact_size(Vec2::new(total_width, content_height), Sense::click());
+            // Region whose truncated label gets a full-name tooltip
+            let mut truncated_label = None;
 
             if ui.is_rect_visible(rect) {
                 let painter = ui.painter();
@@ -615,20 +748,21 @@ impl TimelineTrack {
                     let x = (beat as f32).mul_add(self.beat_width, rect.min.x);
                     let is_measure = beat % self.beats_per_measure == 0;
 
-                    let line_color = if is_measure {
-                        Color32::from_rgba_unmultiplied(
+                    let line_color = match (self.grid_color, is_measure) {
+                        (Some(grid_color), true) => grid_color,
+                        (Some(grid_color), false) => grid_color.gamma_multiply(0.5),
+                        (None, true) => Color32::from_rgba_unmultiplied(
                             theme.border().r(),
                             theme.border().g(),
                             theme.border().b(),
                             30,
-                        )
-                    } else {
-                        Color32::from_rgba_unmultiplied(
+                        ),
+                        (None, false) => Color32::from_rgba_unmultiplied(
                             theme.border().r(),
                             theme.border().g(),
                             theme.border().b(),
                             15,
-                        )
+                        ),
                     };
 
                     painter.line_segment(
@@ -659,7 +793,17 @@ impl TimelineTrack {
                     }
 
                     // Draw region
-                    self.draw_region(painter, region_rect, region, theme);
+                    let truncated = self.draw_region(painter, region_rect, region, theme);
+                    if self.playing_regions.contains(&i) {
+                        Self::draw_playing_highlight(painter, region_rect, theme);
+                    }
+                    if truncated
+                        && response
+                            .hover_pos()
+                            .is_some_and(|pos| region_rect.contains(pos))
+                    {
+                        truncated_label = Some((i, region_rect, region.name.clone()));
+                    }
 
                     // Draw interactive handles if region is selected
                     if region.selected {
@@ -682,6 +826,23 @@ impl TimelineTrack {
                 }
             }
 
+            // Accept dragged payloads, placed at the beat under the pointer
+            if let Some(payload) = drop_target_any(ui.ctx(), &response) {
+                if let Some(pos) = ui.ctx().pointer_interact_pos() {
+                    let beat_pos = (pos.x - rect.min.x) / self.beat_width;
+                    dropped_payload = Some((payload, beat_pos.max(0.0)));
+                }
+            }
+
+            if let Some((i, region_rect, name)) = truncated_label {
+                let label_response = ui.interact(
+                    region_rect,
+                    response.id.with(("region_label", i)),
+                    Sense::hover(),
+                );
+                armas_basic::tooltip(ui, &label_response, name);
+            }
+
             response
         });
 
@@ -689,11 +850,32 @@ impl TimelineTrack {
             response: card_response.response,
             region_clicked,
             empty_clicked,
+            dropped_payload,
         }
     }
 
+    /// Brighten a region the playhead is over
+    fn draw_playing_highlight(painter: &egui::Painter, rect: Rect, theme: &Theme) {
+        let radius = f32::from(theme.spacing.corner_radius_small);
+        painter.rect_filled(rect, radius, Color32::from_white_alpha(PLAYING_FILL_ALPHA));
+        painter.rect_stroke(
+            rect,
+            radius,
+            egui::Stroke::new(1.0, Color32::from_white_alpha(PLAYING_STROKE_ALPHA)),
+            StrokeKind::Inside,
+        );
+    }
+
     /// Draw a single region
-    fn draw_region(&self, painter: &egui::Painter, rect: Rect, region: &Region, theme: &Theme) {
+    ///
+    /// Returns true when the region name was truncated to fit.
+    fn draw_region(
+        &self,
+        painter: &egui::Painter,
+        rect: Rect,
+        region: &Region,
+        theme: &Theme,
+    ) -> bool {
         let region_color = region
             .color
             .or(self.track_color)
@@ -748,37 +930,45 @@ impl TimelineTrack {
             Color32::WHITE
         };
 
-        let name_galley = painter.layout_no_wrap(
-            region.name.clone(),
-            egui::FontId::proportional(12.0),
-            text_color,
-        );
-
-        let name_pos = Pos2::new(rect.min.x + 6.0, rect.min.y + 6.0);
-        painter.galley(name_pos, name_galley.clone(), text_color);
-
-        // Draw clip gain indicator right after name (if not unity)
-        if (region.playback.gain - 1.0).abs() > 0.01 {
+        // Clip gain indicator shown right after the name (if not unity)
+        let gain_galley = ((region.playback.gain - 1.0).abs() > 0.01).then(|| {
             let gain_db = region.playback.gain_db();
             let gain_text = if gain_db > 0.0 {
                 format!(" +{gain_db:.1}dB")
             } else {
                 format!(" {gain_db:.1}dB")
             };
-
-            let gain_pos = Pos2::new(name_pos.x + name_galley.rect.width() + 4.0, name_pos.y);
-
-            painter.text(
-                gain_pos,
-                egui::Align2::LEFT_TOP,
+            painter.layout_no_wrap(
                 gain_text,
                 egui::FontId::proportional(10.0),
                 theme.secondary(),
+            )
+        });
+
+        // Truncate the name to the space left by the padding and gain indicator
+        let gain_width = gain_galley
+            .as_ref()
+            .map_or(0.0, |galley| galley.rect.width() + REGION_LABEL_GAP);
+        let name_width = rect.width() - REGION_LABEL_PADDING * 2.0 - gain_width;
+        let name_galley =
+            painter.layout_job(region_label_job(&region.name, name_width, text_color));
+
+        let name_pos = Pos2::new(
+            rect.min.x + REGION_LABEL_PADDING,
+            rect.min.y + REGION_LABEL_PADDING,
+        );
+        painter.galley(name_pos, name_galley.clone(), text_color);
+
+        if let Some(gain_galley) = gain_galley {
+            let gain_pos = Pos2::new(
+                name_pos.x + name_galley.rect.width() + REGION_LABEL_GAP,
+                name_pos.y,
             );
+            painter.galley(gain_pos, gain_galley, theme.secondary());
         }
 
-        // Draw visualization based on region type
-        if !region.muted {
+        // Draw visualization based on region type, unless too narrow to see
+        if !region.muted && rect.width() >= self.render_quality.min_content_width() {
             match &region.region_type {
                 RegionType::Audio => self.draw_waveform_peaks(painter, rect, region_color, &[]),
                 RegionType::Midi(data) => self.draw_midi_pattern(painter, rect, region_color, data),
@@ -787,6 +977,8 @@ impl TimelineTrack {
                 }
             }
         }
+
+        name_galley.elided
     }
 
     /// Draw audio waveform visualization from peaks
@@ -805,11 +997,14 @@ impl TimelineTrack {
 
         if peaks.is_empty() {
             // Draw simulated waveform - use full vertical space
-            let num_lines = (content_rect.width() / 4.0) as i32;
+            let spacing = self.render_quality.waveform_spacing();
+            let num_lines = self.render_quality.waveform_lines(content_rect.width());
             for i in 0..num_lines {
-                let x = (i as f32).mul_add(4.0, content_rect.min.x);
+                let x = (i as f32).mul_add(spacing, content_rect.min.x);
+                // Sample the same curve at every quality so the shape stays put
+                let phase = i as f32 * spacing / 4.0;
                 // Range from 0.1 to 0.9 for more dynamic waveform (80% of space)
-                let height_factor = (i as f32 * 0.5).sin().mul_add(0.4, 0.5) * 0.9;
+                let height_factor = (phase * 0.5).sin().mul_add(0.4, 0.5) * 0.9;
                 let line_height = available_height * height_factor;
 
                 painter.line_segment(
@@ -876,7 +1071,10 @@ impl TimelineTrack {
 
             let region_width = content_rect.width();
 
-            for (lane, start_norm, duration_norm) in pattern {
+            for (lane, start_norm, duration_norm) in pattern
+                .into_iter()
+                .step_by(self.render_quality.midi_pattern_stride())
+            {
                 let y = (lane as f32).mul_add(lane_height, content_rect.min.y)
                     + (lane_height - note_height) / 2.0;
                 let x_start = content_rect.min.x + start_norm * region_width;
@@ -900,6 +1098,9 @@ impl TimelineTrack {
                 let x_start = (note.start / self.beats_per_measure as f32)
                     .mul_add(self.beat_width, content_rect.min.x);
                 let note_width = (note.duration / self.beats_per_measure as f32) * self.beat_width;
+                if note_width < self.render_quality.min_note_width() {
+                    continue;
+                }
 
                 // Vertical position based on note number (inverted: higher notes at top)
                 let y_normalized = f32::from(note.note - min_note) / note_range;
@@ -1167,3 +1368,58 @@ impl Default for TimelineTrack {
         Self::new()
     }
 }
+
+/// Single-line layout of a region name, elided with "…" past `max_width`
+fn region_label_job(name: &str, max_width: f32, color: Color32) -> LayoutJob {
+    let mut job = LayoutJob::simple_singleline(
+        name.to_owned(),
+        egui::FontId::proportional(REGION_LABEL_FONT_SIZE),
+        color,
+    );
+    job.wrap = TextWrapping::truncate_at_width(max_width.max(0.0));
+    job
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(job: LayoutJob) -> std::sync::Arc<egui::Galley> {
+        let ctx = egui::Context::default();
+        let _ = ctx.run(egui::RawInput::default(), |_| {});
+        ctx.fonts_mut(|fonts| fonts.layout_job(job))
+    }
+
+    #[test]
+    fn test_long_region_name_is_truncated_with_ellipsis() {
+        let galley = layout(region_label_job(
+            "Lead Vocal Comp Take 12 (Final)",
+            60.0,
+            Color32::WHITE,
+        ));
+
+        assert!(galley.elided);
+        assert!(galley.rect.width() <= 60.0);
+        let last = galley.rows.last().and_then(|row| row.glyphs.last());
+        assert_eq!(last.map(|glyph| glyph.chr), Some('…'));
+    }
+
+    #[test]
+    fn test_low_quality_draws_fewer_waveform_lines() {
+        let width = 240.0;
+        let low = RenderQuality::Low.waveform_lines(width);
+        let medium = RenderQuality::Medium.waveform_lines(width);
+        let high = RenderQuality::High.waveform_lines(width);
+
+        assert!(low < medium && medium < high);
+        assert_eq!(high, 60);
+    }
+
+    #[test]
+    fn test_short_region_name_is_not_truncated() {
+        let galley = layout(region_label_job("Verse", 200.0, Color32::WHITE));
+
+        assert!(!galley.elided);
+        assert_eq!(galley.text(), "Verse");
+    }
+}
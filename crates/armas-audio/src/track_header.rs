@@ -8,7 +8,7 @@ use armas_basic::{Button, ButtonVariant, Card, CardVariant};
 use egui::{Color32, Response, Sense, TextEdit, Ui, Vec2};
 
 /// Track control button state
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub struct TrackControls {
     /// Track is muted
     pub muted: bool,
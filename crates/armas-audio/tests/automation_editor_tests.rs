@@ -0,0 +1,58 @@
+//! Tests for `AutomationEditor` component using `egui_kittest`
+
+use armas_audio::{AutomationEditor, AutomationPoint};
+use armas_basic::ArmasContextExt;
+use egui_kittest::Harness;
+
+/// Test that marquee-selected points are copied and pasted at the playhead
+#[test]
+fn test_automation_editor_marquee_copy_paste() {
+    struct Lane {
+        points: Vec<AutomationPoint>,
+        rect: egui::Rect,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, lane: &mut Lane| {
+            let theme = ui.ctx().armas_theme();
+            let response = AutomationEditor::new(&mut lane.points)
+                .beats(8.0)
+                .beat_width(40.0)
+                .height(100.0)
+                .playhead(Some(4.0))
+                .show(ui, &theme);
+            lane.rect = response.response.rect;
+        },
+        Lane {
+            points: vec![
+                AutomationPoint::new(1.0, 0.5),
+                AutomationPoint::new(2.0, 0.25),
+                AutomationPoint::new(6.0, 0.75),
+            ],
+            rect: egui::Rect::NOTHING,
+        },
+    );
+    harness.run();
+
+    // Drag a marquee around the points at beats 1 and 2
+    let rect = harness.state().rect;
+    let start = rect.min + egui::vec2(20.0, 5.0);
+    let end = rect.min + egui::vec2(100.0, 95.0);
+    harness.hover_at(start);
+    harness.drag_at(start);
+    harness.step();
+    harness.hover_at(end);
+    harness.step();
+    harness.drop_at(end);
+    harness.run();
+
+    harness.event(egui::Event::Copy);
+    harness.run();
+    harness.event(egui::Event::Paste(String::new()));
+    harness.run();
+
+    let times: Vec<f32> = harness.state().points.iter().map(|p| p.time).collect();
+    assert_eq!(times, vec![1.0, 2.0, 4.0, 5.0, 6.0]);
+    assert_eq!(harness.state().points[2].value, 0.5);
+    assert_eq!(harness.state().points[3].value, 0.25);
+}
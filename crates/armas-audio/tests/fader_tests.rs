@@ -0,0 +1,156 @@
+//! Tests for Fader components using `egui_kittest`
+
+use armas_audio::fader::{Fader, FaderStrip};
+use armas_basic::ArmasContextExt;
+use egui_kittest::Harness;
+
+struct DragEvents {
+    value: f32,
+    rect: egui::Rect,
+    started: usize,
+    ended: usize,
+}
+
+/// Test that Fader renders without panicking
+#[test]
+fn test_fader_renders() {
+    let mut harness = Harness::new_ui(|ui| {
+        let theme = ui.ctx().armas_theme();
+        Fader::new(0.5).show(ui, &theme);
+    });
+
+    harness.run();
+}
+
+/// Test that the `FaderStrip` tuple API still returns the value
+#[test]
+fn test_fader_strip_compat_show() {
+    let mut harness = Harness::new_ui(|ui| {
+        let theme = ui.ctx().armas_theme();
+        let (_, value) = FaderStrip::new(0.6).show(ui, &theme);
+        assert!((value - 0.6).abs() < f32::EPSILON);
+    });
+
+    harness.run();
+}
+
+/// Test that a press reports `drag_started` once and a release reports `drag_ended` once
+#[test]
+fn test_fader_drag_start_and_end_events() {
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut DragEvents| {
+            let theme = ui.ctx().armas_theme();
+            let response = FaderStrip::new(state.value).show_response(ui, &theme);
+            state.value = response.value;
+            state.rect = response.response.rect;
+            state.started += usize::from(response.drag_started);
+            state.ended += usize::from(response.drag_ended);
+        },
+        DragEvents {
+            value: 0.5,
+            rect: egui::Rect::NOTHING,
+            started: 0,
+            ended: 0,
+        },
+    );
+    harness.run();
+
+    let rect = harness.state().rect;
+    let center = rect.center();
+    let top = egui::pos2(center.x, rect.min.y + 1.0);
+    harness.hover_at(center);
+    harness.drag_at(center);
+    harness.step();
+    harness.hover_at(center + egui::vec2(0.0, -10.0));
+    harness.step();
+    harness.hover_at(top);
+    harness.step();
+    assert_eq!(harness.state().started, 1);
+    assert_eq!(harness.state().ended, 0);
+
+    harness.drop_at(top);
+    harness.run();
+    assert_eq!(harness.state().started, 1);
+    assert_eq!(harness.state().ended, 1);
+    assert!(harness.state().value > 0.5);
+}
+
+/// Test that a press inside the touch target but outside the drawn fader grabs it
+#[test]
+fn test_fader_touch_target_grabs_outside_thumb() {
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut DragEvents| {
+            let theme = ui.ctx().armas_theme();
+            ui.add_space(40.0);
+            ui.horizontal(|ui| {
+                ui.add_space(40.0);
+                let response = Fader::new(state.value).touch_target(80.0).show(ui, &theme);
+                state.value = response.value;
+                state.rect = response.response.rect;
+                state.started += usize::from(response.drag_started);
+            });
+        },
+        DragEvents {
+            value: 0.5,
+            rect: egui::Rect::NOTHING,
+            started: 0,
+            ended: 0,
+        },
+    );
+    harness.run();
+
+    // The drawn fader keeps its size
+    let rect = harness.state().rect;
+    assert!((rect.width() - 30.0).abs() < f32::EPSILON);
+
+    // 15pt right of the fader: outside the thumb, inside the 80pt target
+    let press = egui::pos2(rect.max.x + 15.0, rect.center().y);
+    harness.hover_at(press);
+    harness.drag_at(press);
+    harness.step();
+    harness.hover_at(press + egui::vec2(0.0, -10.0));
+    harness.step();
+    harness.hover_at(egui::pos2(press.x, rect.min.y + 1.0));
+    harness.step();
+    harness.drop_at(egui::pos2(press.x, rect.min.y + 1.0));
+    harness.run();
+
+    assert_eq!(harness.state().started, 1);
+    assert!(harness.state().value > 0.5);
+}
+
+/// Test that a press outside the touch target leaves the fader alone
+#[test]
+fn test_fader_without_touch_target_ignores_outside_press() {
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut DragEvents| {
+            let theme = ui.ctx().armas_theme();
+            ui.add_space(40.0);
+            ui.horizontal(|ui| {
+                ui.add_space(40.0);
+                let response = Fader::new(state.value).show(ui, &theme);
+                state.value = response.value;
+                state.rect = response.response.rect;
+                state.started += usize::from(response.drag_started);
+            });
+        },
+        DragEvents {
+            value: 0.5,
+            rect: egui::Rect::NOTHING,
+            started: 0,
+            ended: 0,
+        },
+    );
+    harness.run();
+
+    let rect = harness.state().rect;
+    let press = egui::pos2(rect.max.x + 15.0, rect.center().y);
+    harness.hover_at(press);
+    harness.drag_at(press);
+    harness.step();
+    harness.drop_at(egui::pos2(press.x, rect.min.y + 1.0));
+    harness.run();
+
+    assert_eq!(harness.state().started, 0);
+    assert!((harness.state().value - 0.5).abs() < f32::EPSILON);
+}
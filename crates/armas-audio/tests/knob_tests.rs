@@ -318,3 +318,47 @@ fn test_knob_light_theme() {
 
     harness.run();
 }
+
+/// Test that a press reports `drag_started` once and a release reports `drag_ended` once
+#[test]
+fn test_knob_drag_start_and_end_events() {
+    struct DragEvents {
+        value: f32,
+        rect: egui::Rect,
+        started: usize,
+        ended: usize,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut DragEvents| {
+            let theme = ui.ctx().armas_theme();
+            let response = Knob::new(state.value).show(ui, &mut state.value, &theme);
+            state.rect = response.response.rect;
+            state.started += usize::from(response.drag_started);
+            state.ended += usize::from(response.drag_ended);
+        },
+        DragEvents {
+            value: 0.5,
+            rect: egui::Rect::NOTHING,
+            started: 0,
+            ended: 0,
+        },
+    );
+    harness.run();
+
+    let center = harness.state().rect.center();
+    harness.hover_at(center);
+    harness.drag_at(center);
+    harness.step();
+    harness.hover_at(center + egui::vec2(0.0, -10.0));
+    harness.step();
+    harness.hover_at(center + egui::vec2(0.0, -20.0));
+    harness.step();
+    assert_eq!(harness.state().started, 1);
+    assert_eq!(harness.state().ended, 0);
+
+    harness.drop_at(center + egui::vec2(0.0, -20.0));
+    harness.run();
+    assert_eq!(harness.state().started, 1);
+    assert_eq!(harness.state().ended, 1);
+}
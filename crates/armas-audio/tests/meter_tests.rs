@@ -1,6 +1,6 @@
 //! Tests for `AudioMeter` component using `egui_kittest`
 
-use armas_audio::meter::{AudioMeter, MeterStyle, ScalePosition};
+use armas_audio::meter::{AudioMeter, MeterOrientation, MeterStyle, ScalePosition};
 use armas_basic::ArmasContextExt;
 use egui::Color32;
 use egui_kittest::Harness;
@@ -318,3 +318,44 @@ fn test_meter_default() {
 
     harness.step();
 }
+
+/// Test horizontal `AudioMeter` allocates its width along x
+#[test]
+fn test_meter_horizontal() {
+    let mut harness = Harness::new_ui_state(
+        |ui, size: &mut egui::Vec2| {
+            let theme = ui.ctx().armas_theme();
+            let response = AudioMeter::new(0.5)
+                .orientation(MeterOrientation::Horizontal)
+                .width(240.0)
+                .height(12.0)
+                .show_scale()
+                .show(ui, &theme);
+            *size = response.response.rect.size();
+        },
+        egui::Vec2::ZERO,
+    );
+
+    harness.step();
+    assert_eq!(harness.state().x, 240.0);
+    assert!(harness.state().y > 12.0, "scale adds space below");
+}
+
+/// Test stereo `AudioMeter` reports both channels
+#[test]
+fn test_meter_stereo() {
+    let mut harness = Harness::new_ui_state(
+        |ui, levels: &mut (f32, Option<f32>)| {
+            let theme = ui.ctx().armas_theme();
+            let response = AudioMeter::stereo(0.7, 0.4)
+                .style(MeterStyle::Segmented(16))
+                .scale_left()
+                .show(ui, &theme);
+            *levels = (response.level, response.right_level);
+        },
+        (0.0, None),
+    );
+
+    harness.step();
+    assert_eq!(*harness.state(), (0.7, Some(0.4)));
+}
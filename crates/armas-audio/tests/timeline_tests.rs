@@ -0,0 +1,174 @@
+//! Tests for `Timeline` component using `egui_kittest`
+
+use armas_audio::{Timeline, TimelineGeometry, Track};
+use armas_basic::ext::{begin_drag, drag_payload};
+use armas_basic::ArmasContextExt;
+use egui::accesskit::Role;
+use egui::Color32;
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that a payload set via `begin_drag` is dropped on a track at a beat position
+#[test]
+fn test_timeline_drop_payload_on_track() {
+    struct TrackDrop {
+        geometry: Option<TimelineGeometry>,
+        dropped: Option<(usize, f32, String)>,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, drop: &mut TrackDrop| {
+            let theme = ui.ctx().armas_theme();
+            let mut tracks = vec![
+                Track::new("Drums", Color32::from_rgb(255, 100, 100)),
+                Track::new("Bass", Color32::from_rgb(100, 180, 255)),
+            ];
+            let mut playhead = 0.0;
+            let response =
+                Timeline::new()
+                    .beat_width(40.0)
+                    .show(ui, &mut tracks, &mut playhead, &theme);
+            drop.geometry = Some(response.geometry);
+            if let Some((track, beat, payload)) = response.dropped_payload {
+                if let Some(name) = payload.downcast::<String>() {
+                    drop.dropped = Some((track, beat, (*name).clone()));
+                }
+            }
+        },
+        TrackDrop {
+            geometry: None,
+            dropped: None,
+        },
+    );
+    harness.run();
+
+    begin_drag(&harness.ctx, String::from("kick.wav"));
+    let geometry = harness.state().geometry.expect("timeline shown");
+    let target = egui::pos2(geometry.beat_to_x(5.0), geometry.track_rect(1).center().y);
+    harness.hover_at(target);
+    harness.run();
+    assert!(drag_payload::<String>(&harness.ctx).is_some());
+
+    harness.drop_at(target);
+    harness.run();
+
+    let (track, beat, name) = harness.state().dropped.clone().expect("payload dropped");
+    assert_eq!(track, 1);
+    assert_eq!(name, "kick.wav");
+    assert!((beat - 5.0).abs() < 0.01, "dropped at beat {beat}");
+    assert!(drag_payload::<String>(&harness.ctx).is_none());
+}
+
+/// Test that Space reports a play toggle only while the timeline is focused
+#[test]
+fn test_timeline_space_requires_focus() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (play_toggles, geometry): &mut (usize, Option<TimelineGeometry>)| {
+            let theme = ui.ctx().armas_theme();
+            let mut tracks = vec![Track::new("Drums", Color32::from_rgb(255, 100, 100))];
+            let mut playhead = 0.0;
+            let response = Timeline::new().show(ui, &mut tracks, &mut playhead, &theme);
+            *play_toggles += usize::from(response.play_toggle_requested);
+            *geometry = Some(response.geometry);
+        },
+        (0, None),
+    );
+    harness.run();
+
+    harness.key_press(egui::Key::Space);
+    harness.run();
+    assert_eq!(harness.state().0, 0);
+
+    // Click inside the track area to focus the timeline
+    let geometry = harness.state().1.expect("timeline shown");
+    let pos = egui::pos2(geometry.beat_to_x(2.0), geometry.track_rect(0).center().y);
+    harness.hover_at(pos);
+    harness.event(egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed: true,
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.event(egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed: false,
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run();
+
+    harness.key_press(egui::Key::Space);
+    harness.run();
+    assert_eq!(harness.state().0, 1);
+}
+
+/// Test that clicking the ruler seeks the playhead and dragging along it scrubs
+#[test]
+fn test_timeline_ruler_click_seeks_playhead() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (playhead, geometry): &mut (f32, Option<TimelineGeometry>)| {
+            let theme = ui.ctx().armas_theme();
+            let mut tracks = vec![Track::new("Drums", Color32::from_rgb(255, 100, 100))];
+            let response = Timeline::new().beat_width(40.0).measures(4).show(
+                ui,
+                &mut tracks,
+                playhead,
+                &theme,
+            );
+            *geometry = Some(response.geometry);
+        },
+        (0.0, None),
+    );
+    harness.run();
+
+    let geometry = harness.state().1.expect("timeline shown");
+    let y = geometry.ruler_rect.center().y;
+    let pos = egui::pos2(geometry.beat_to_x(6.0), y);
+    harness.hover_at(pos);
+    harness.event(egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed: true,
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run();
+    let playhead = harness.state().0;
+    assert!((playhead - 6.0).abs() < 0.01, "playhead at {playhead}");
+
+    // Keep the button down and scrub to beat 9
+    let target = egui::pos2(geometry.beat_to_x(9.0), y);
+    harness.hover_at(target);
+    harness.run();
+    harness.event(egui::Event::PointerButton {
+        pos: target,
+        button: egui::PointerButton::Primary,
+        pressed: false,
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run();
+    let playhead = harness.state().0;
+    assert!((playhead - 9.0).abs() < 0.01, "playhead at {playhead}");
+}
+
+/// Test that clicking a track name focuses its text field so it can be renamed
+#[test]
+fn test_timeline_track_name_is_editable() {
+    let mut harness = Harness::new_ui_state(
+        |ui, tracks: &mut Vec<Track>| {
+            let theme = ui.ctx().armas_theme();
+            let mut playhead = 0.0;
+            Timeline::new()
+                .id("timeline")
+                .show(ui, tracks, &mut playhead, &theme);
+        },
+        vec![Track::new("Drums", Color32::from_rgb(255, 100, 100))],
+    );
+    harness.run();
+
+    harness.get_by_role(Role::TextInput).click();
+    harness.run();
+    harness.get_by_role(Role::TextInput).type_text("!");
+    harness.run();
+
+    assert_eq!(harness.state()[0].name, "Drums!");
+}
@@ -0,0 +1,198 @@
+//! Cross-fade transitions for swapping content
+//!
+//! Immediate mode can't keep the outgoing closure around, so a switch is
+//! shown by briefly dimming the container and fading the incoming content
+//! in from transparent.
+
+use super::{Animation, EasingFunction};
+use egui::{Color32, Id, Response, Shape, Ui};
+use std::hash::Hash;
+
+const DEFAULT_DURATION: f32 = 0.25;
+/// Opacity of the black wash over the container right after a switch
+const DIM_OPACITY: f32 = 0.15;
+
+/// Response from a cross-fade
+#[derive(Debug)]
+pub struct CrossfadeResponse<R> {
+    /// Return value of the content closure
+    pub inner: R,
+    /// Response covering the content
+    pub response: Response,
+    /// Opacity the content was drawn with this frame
+    pub opacity: f32,
+    /// Whether a transition is in progress
+    pub transitioning: bool,
+}
+
+/// Transition state kept between frames
+#[derive(Debug, Clone)]
+struct CrossfadeState {
+    key: Id,
+    fade: Animation<f32>,
+}
+
+impl CrossfadeState {
+    /// State resting fully visible on `key`
+    const fn settled(key: Id) -> Self {
+        let mut fade = Animation::new(1.0, 1.0, 0.0);
+        fade.start();
+        Self { key, fade }
+    }
+}
+
+/// Fades content in whenever its key changes
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::Ui;
+/// # fn example(ui: &mut Ui, active_tab: usize) {
+/// use armas_basic::animation::Crossfade;
+///
+/// Crossfade::new(active_tab).duration(0.4).show(ui, |ui| {
+///     ui.label(format!("Tab {active_tab}"));
+/// });
+/// # }
+/// ```
+pub struct Crossfade {
+    key: Id,
+    id: Option<Id>,
+    duration: f32,
+    easing: EasingFunction,
+}
+
+impl Crossfade {
+    /// Create a cross-fade keyed by a discriminant, e.g. the active tab index
+    pub fn new(key: impl Hash) -> Self {
+        Self {
+            key: Id::new(key),
+            id: None,
+            duration: DEFAULT_DURATION,
+            easing: EasingFunction::EaseOut,
+        }
+    }
+
+    /// Set custom ID for the transition state (needed when the layout around
+    /// it changes between frames)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the fade duration in seconds (default: 0.25)
+    #[must_use]
+    pub const fn duration(mut self, seconds: f32) -> Self {
+        self.duration = seconds;
+        self
+    }
+
+    /// Set the easing of the fade (default: ease out)
+    #[must_use]
+    pub const fn easing(mut self, easing: EasingFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Show the content, fading it in if the key changed
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> CrossfadeResponse<R> {
+        let id = self
+            .id
+            .unwrap_or_else(|| ui.auto_id_with("armas_crossfade"));
+        let dt = ui.input(|i| i.stable_dt);
+
+        let mut state = ui
+            .ctx()
+            .data(|d| d.get_temp::<CrossfadeState>(id))
+            .unwrap_or_else(|| CrossfadeState::settled(self.key));
+        self.advance(&mut state, dt);
+
+        let opacity = state.fade.value();
+        let transitioning = state.fade.is_running();
+        if transitioning {
+            ui.ctx().request_repaint();
+        }
+
+        // Reserve the wash behind the content; its rect is only known after
+        let wash = ui.painter().add(Shape::Noop);
+        let inner = ui.scope(|ui| {
+            ui.multiply_opacity(opacity);
+            add_contents(ui)
+        });
+        if transitioning {
+            let dim = Color32::BLACK.gamma_multiply(DIM_OPACITY * (1.0 - opacity));
+            ui.painter()
+                .set(wash, Shape::rect_filled(inner.response.rect, 0.0, dim));
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(id, state));
+
+        CrossfadeResponse {
+            inner: inner.inner,
+            response: inner.response,
+            opacity,
+            transitioning,
+        }
+    }
+
+    /// Step the fade by `dt`, restarting it if the key changed
+    fn advance(&self, state: &mut CrossfadeState, dt: f32) {
+        if state.key != self.key {
+            state.key = self.key;
+            state.fade = Animation::new(0.0, 1.0, self.duration).easing(self.easing);
+            state.fade.start();
+        }
+        state.fade.update(dt);
+    }
+}
+
+/// Show content that cross-fades whenever `key` changes
+///
+/// Shorthand for [`Crossfade::new`] with the default duration.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::Ui;
+/// # fn example(ui: &mut Ui, route: &str) {
+/// use armas_basic::animation::crossfade;
+///
+/// crossfade(ui, route, |ui| {
+///     ui.heading(route);
+/// });
+/// # }
+/// ```
+pub fn crossfade<R>(
+    ui: &mut Ui,
+    key: impl Hash,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> CrossfadeResponse<R> {
+    Crossfade::new(key).show(ui, add_contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_change_restarts_fade() {
+        let mut state = CrossfadeState::settled(Id::new(0));
+        Crossfade::new(0).advance(&mut state, 0.1);
+        assert!(!state.fade.is_running());
+        assert!((state.fade.value() - 1.0).abs() < 1e-4);
+
+        let crossfade = Crossfade::new(1).duration(0.2);
+        crossfade.advance(&mut state, 0.0);
+        assert!(state.fade.is_running());
+        assert!(state.fade.value().abs() < 1e-4);
+
+        crossfade.advance(&mut state, 0.2);
+        assert!(!state.fade.is_running());
+        assert!((state.fade.value() - 1.0).abs() < 1e-4);
+    }
+}
@@ -39,7 +39,9 @@ pub enum EasingFunction {
     ElasticOut,
     /// Bounce ease out
     BounceOut,
-    /// Custom cubic bezier curve
+    /// Custom cubic bezier curve, matching CSS `cubic-bezier()`
+    ///
+    /// The curve runs from (0, 0) to (1, 1); `y1`/`y2` outside 0-1 overshoot.
     Cubic {
         /// First control point X
         x1: f32,
@@ -53,6 +55,14 @@ pub enum EasingFunction {
 }
 
 impl EasingFunction {
+    /// Cubic bezier easing from CSS `cubic-bezier(x1, y1, x2, y2)` control points
+    ///
+    /// `x1` and `x2` are clamped to 0-1 so the curve stays a function of time.
+    #[must_use]
+    pub const fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self::Cubic { x1, y1, x2, y2 }
+    }
+
     /// Apply the easing function to a time value (0.0 to 1.0)
     #[must_use]
     pub fn apply(&self, t: f32) -> f32 {
@@ -185,44 +195,57 @@ fn bounce_out(t: f32) -> f32 {
     }
 }
 
-// Cubic bezier using Newton-Raphson to solve for t given x
-fn cubic_bezier(t: f32, x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
-    // First, we need to solve for the parameter value that gives us our input t
-    // in the x-dimension, then use that to calculate the y value
+/// Tolerance on x when solving a cubic bezier for its curve parameter
+const BEZIER_EPSILON: f32 = 1e-6;
 
-    // For standard easing curves, x1 and x2 should be in [0, 1]
+// Cubic bezier: solve for the curve parameter whose x is t, then return its y
+fn cubic_bezier(t: f32, x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
+    // For standard easing curves, x1 and x2 should be in [0, 1], which also
+    // keeps x monotonic in the curve parameter
     let x1 = x1.clamp(0.0, 1.0);
     let x2 = x2.clamp(0.0, 1.0);
 
-    // If the curve is linear in x, we can directly compute y
-    if x1 == 0.0 && x2 == 1.0 {
-        return t;
-    }
+    let param = solve_bezier_param(t, x1, x2);
 
-    // Use Newton-Raphson to find the t value for the given x
-    let mut guess = t;
-    for _ in 0..8 {
-        // Calculate x for current guess using cubic bezier formula
-        let guess_x = cubic_bezier_x(guess, x1, x2);
-        let error = guess_x - t;
+    // y never leaves the hull of its control points
+    let y_min = y1.min(y2).min(0.0);
+    let y_max = y1.max(y2).max(1.0);
+    cubic_bezier_y(param, y1, y2).clamp(y_min, y_max)
+}
 
-        if error.abs() < 0.001 {
-            break;
+// Newton-Raphson converges fast on gentle curves but can stall where the
+// curve is nearly vertical, so fall back to bisection when it does
+fn solve_bezier_param(x: f32, x1: f32, x2: f32) -> f32 {
+    let mut guess = x;
+    for _ in 0..8 {
+        let error = cubic_bezier_x(guess, x1, x2) - x;
+        if error.abs() < BEZIER_EPSILON {
+            return guess;
         }
 
-        // Calculate derivative
         let slope = cubic_bezier_x_derivative(guess, x1, x2);
-        if slope.abs() < 0.000_001 {
+        if slope.abs() < BEZIER_EPSILON {
             break;
         }
 
-        // Newton-Raphson step
-        guess -= error / slope;
-        guess = guess.clamp(0.0, 1.0);
+        guess = (guess - error / slope).clamp(0.0, 1.0);
     }
 
-    // Now calculate y using the found t value
-    cubic_bezier_y(guess, y1, y2)
+    let (mut low, mut high) = (0.0_f32, 1.0_f32);
+    guess = x;
+    for _ in 0..32 {
+        let guess_x = cubic_bezier_x(guess, x1, x2);
+        if (guess_x - x).abs() < BEZIER_EPSILON {
+            break;
+        }
+        if guess_x < x {
+            low = guess;
+        } else {
+            high = guess;
+        }
+        guess = f32::midpoint(low, high);
+    }
+    guess
 }
 
 // Calculate x coordinate of cubic bezier at parameter t
@@ -307,4 +330,40 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_cubic_bezier_matches_css() {
+        // CSS `ease`
+        let ease = EasingFunction::cubic_bezier(0.25, 0.1, 0.25, 1.0);
+        assert!((ease.apply(0.5) - 0.8024).abs() < 1e-3);
+
+        // Control points on the diagonal thirds are linear
+        let linear = EasingFunction::cubic_bezier(1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0);
+        assert!((linear.apply(0.3) - 0.3).abs() < 1e-4);
+
+        // x1 = 0, x2 = 1 still follows y rather than time
+        let steep_ends = EasingFunction::cubic_bezier(0.0, 1.0, 1.0, 0.0);
+        assert!((steep_ends.apply(0.5) - 0.5).abs() < 1e-3);
+        assert!(steep_ends.apply(0.1) > 0.3);
+    }
+
+    #[test]
+    fn test_cubic_bezier_overshoot_and_steep_curves() {
+        let overshoot = EasingFunction::cubic_bezier(0.34, 1.56, 0.64, 1.0);
+        let peak = (0..=100)
+            .map(|i| overshoot.apply(i as f32 / 100.0))
+            .fold(0.0, f32::max);
+        assert!(peak > 1.05 && peak <= 1.56, "peak {peak}");
+        assert!((overshoot.apply(1.0) - 1.0).abs() < 1e-4);
+
+        // Nearly vertical in the middle: still monotonic and within 0-1
+        let steep = EasingFunction::cubic_bezier(0.9, 0.0, 0.1, 1.0);
+        let mut previous = 0.0;
+        for i in 0..=100 {
+            let y = steep.apply(i as f32 / 100.0);
+            assert!((0.0..=1.0).contains(&y));
+            assert!(y >= previous - 1e-4, "not monotonic at {i}");
+            previous = y;
+        }
+    }
 }
@@ -1,3 +1,4 @@
+pub mod crossfade;
 /// Easing functions for smooth animations
 pub mod easing;
 /// Interpolation trait for animating values
@@ -6,12 +7,15 @@ pub mod momentum;
 pub mod staggered;
 pub mod velocity_drag;
 
+pub use crossfade::{crossfade, Crossfade, CrossfadeResponse};
 pub use easing::EasingFunction;
 pub use interpolate::Interpolate;
 pub use momentum::{
     ContinuousWithMomentum, MomentumBehavior, MomentumPosition, SnapToPageBoundaries,
 };
-pub use staggered::{AnimationSequence, LoopMode, LoopingAnimation, StaggeredAnimation};
+pub use staggered::{
+    AnimationSequence, LoopMode, LoopingAnimation, StaggerDirection, StaggeredAnimation,
+};
 pub use velocity_drag::{DoubleClickReset, DragMode, VelocityDrag, VelocityDragConfig};
 
 /// Animation state
@@ -166,6 +170,22 @@ impl SpringAnimation {
         }
     }
 
+    /// Create a spring that starts moving with an initial velocity
+    ///
+    /// Useful for continuing a gesture (e.g. a swipe-to-dismiss) with the
+    /// pointer's release velocity.
+    #[must_use]
+    pub const fn fling(initial: f32, target: f32, velocity: f32) -> Self {
+        Self::new(initial, target).with_velocity(velocity)
+    }
+
+    /// Set the initial velocity
+    #[must_use]
+    pub const fn with_velocity(mut self, velocity: f32) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
     /// Set spring parameters
     #[must_use]
     pub const fn params(mut self, stiffness: f32, damping: f32) -> Self {
@@ -176,18 +196,14 @@ impl SpringAnimation {
 
     /// Update the spring simulation using semi-implicit Euler integration
     pub fn update(&mut self, dt: f32) {
-        // Spring force: F = -k * (x - target)
-        let spring_force = -self.stiffness * (self.value - self.target);
-
-        // Damping force: F = -c * v
-        let damping_force = -self.damping * self.velocity;
-
-        // Total force and acceleration (assuming mass = 1)
-        let acceleration = spring_force + damping_force;
-
-        // Semi-implicit Euler integration (more stable than explicit Euler)
-        self.velocity += acceleration * dt;
-        self.value += self.velocity * dt;
+        spring_step(
+            &mut self.value,
+            &mut self.velocity,
+            self.target,
+            self.stiffness,
+            self.damping,
+            dt,
+        );
     }
 
     /// Set a new target value
@@ -204,6 +220,13 @@ impl SpringAnimation {
         position_error < position_threshold && velocity_mag < velocity_threshold
     }
 
+    /// Check if the spring has shot past its target and is still moving away from it
+    #[must_use]
+    pub fn is_overshooting(&self) -> bool {
+        let displacement = self.value - self.target;
+        displacement * self.velocity > 0.0
+    }
+
     /// Reset the spring to a new position with zero velocity
     pub const fn reset(&mut self, value: f32, target: f32) {
         self.value = value;
@@ -211,3 +234,185 @@ impl SpringAnimation {
         self.velocity = 0.0;
     }
 }
+
+/// Advance one axis of a damped spring using semi-implicit Euler integration
+fn spring_step(
+    value: &mut f32,
+    velocity: &mut f32,
+    target: f32,
+    stiffness: f32,
+    damping: f32,
+    dt: f32,
+) {
+    // Spring force: F = -k * (x - target)
+    let spring_force = -stiffness * (*value - target);
+
+    // Damping force: F = -c * v
+    let damping_force = -damping * *velocity;
+
+    // Total force and acceleration (assuming mass = 1)
+    let acceleration = spring_force + damping_force;
+
+    // Semi-implicit Euler integration (more stable than explicit Euler)
+    *velocity += acceleration * dt;
+    *value += *velocity * dt;
+}
+
+/// Two-dimensional spring animation, e.g. for a dragged card returning to rest
+///
+/// Each axis runs the same damped spring as [`SpringAnimation`], sharing
+/// stiffness and damping.
+#[derive(Debug, Clone)]
+pub struct SpringAnimation2D {
+    /// Current position
+    pub value: egui::Vec2,
+    /// Current velocity
+    pub velocity: egui::Vec2,
+    /// Target position
+    pub target: egui::Vec2,
+    /// Spring stiffness (higher = faster oscillation, typical: 100-300)
+    pub stiffness: f32,
+    /// Spring damping (higher = less oscillation, typical: 10-30)
+    pub damping: f32,
+}
+
+impl SpringAnimation2D {
+    /// Create a new 2D spring animation
+    #[must_use]
+    pub const fn new(initial: egui::Vec2, target: egui::Vec2) -> Self {
+        Self {
+            value: initial,
+            velocity: egui::Vec2::ZERO,
+            target,
+            stiffness: 200.0,
+            damping: 20.0,
+        }
+    }
+
+    /// Create a spring that starts moving with an initial velocity
+    ///
+    /// Useful for continuing a drag with the pointer's release velocity.
+    #[must_use]
+    pub const fn fling(initial: egui::Vec2, target: egui::Vec2, velocity: egui::Vec2) -> Self {
+        Self::new(initial, target).with_velocity(velocity)
+    }
+
+    /// Set the initial velocity
+    #[must_use]
+    pub const fn with_velocity(mut self, velocity: egui::Vec2) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// Set spring parameters
+    #[must_use]
+    pub const fn params(mut self, stiffness: f32, damping: f32) -> Self {
+        self.stiffness = stiffness;
+        self.damping = damping;
+        self
+    }
+
+    /// Update both axes of the spring simulation
+    pub fn update(&mut self, dt: f32) {
+        spring_step(
+            &mut self.value.x,
+            &mut self.velocity.x,
+            self.target.x,
+            self.stiffness,
+            self.damping,
+            dt,
+        );
+        spring_step(
+            &mut self.value.y,
+            &mut self.velocity.y,
+            self.target.y,
+            self.stiffness,
+            self.damping,
+            dt,
+        );
+    }
+
+    /// Set a new target position
+    pub const fn set_target(&mut self, target: egui::Vec2) {
+        self.target = target;
+    }
+
+    /// Check if the spring has approximately settled at the target on both axes
+    #[must_use]
+    pub fn is_settled(&self, position_threshold: f32, velocity_threshold: f32) -> bool {
+        let position_error = (self.value - self.target).abs();
+        let velocity_mag = self.velocity.abs();
+
+        position_error.max_elem() < position_threshold
+            && velocity_mag.max_elem() < velocity_threshold
+    }
+
+    /// Reset the spring to a new position with zero velocity
+    pub const fn reset(&mut self, value: egui::Vec2, target: egui::Vec2) {
+        self.value = value;
+        self.target = target;
+        self.velocity = egui::Vec2::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spring_initial_velocity() {
+        let spring = SpringAnimation::new(0.0, 1.0).with_velocity(5.0);
+        assert_eq!(spring.velocity, 5.0);
+        assert!(!spring.is_overshooting());
+    }
+
+    #[test]
+    fn test_spring_fling_overshoots_then_settles() {
+        let mut spring = SpringAnimation::fling(0.0, 1.0, 20.0).params(200.0, 5.0);
+        let mut max_value = spring.value;
+        let mut overshot = false;
+
+        for _ in 0..600 {
+            spring.update(1.0 / 60.0);
+            max_value = max_value.max(spring.value);
+            overshot |= spring.is_overshooting();
+        }
+
+        assert!(overshot);
+        assert!(max_value > 1.0);
+        assert!(spring.is_settled(0.01, 0.01));
+    }
+
+    #[test]
+    fn test_spring_2d_matches_scalar_per_axis() {
+        let mut spring = SpringAnimation2D::fling(
+            egui::vec2(40.0, -10.0),
+            egui::Vec2::ZERO,
+            egui::vec2(0.0, 300.0),
+        );
+        let mut x = SpringAnimation::new(40.0, 0.0);
+        let mut y = SpringAnimation::fling(-10.0, 0.0, 300.0);
+
+        for _ in 0..30 {
+            spring.update(1.0 / 60.0);
+            x.update(1.0 / 60.0);
+            y.update(1.0 / 60.0);
+        }
+        assert_eq!(spring.value, egui::vec2(x.value, y.value));
+        assert!(!spring.is_settled(0.01, 0.01));
+
+        for _ in 0..600 {
+            spring.update(1.0 / 60.0);
+        }
+        assert!(spring.is_settled(0.01, 0.01));
+    }
+
+    #[test]
+    fn test_spring_2d_settled_checks_both_axes() {
+        let mut spring = SpringAnimation2D::new(egui::Vec2::ZERO, egui::Vec2::ZERO);
+        assert!(spring.is_settled(0.01, 0.01));
+
+        spring.value.y = 5.0;
+        assert!(!spring.is_settled(0.01, 0.01));
+    }
+}
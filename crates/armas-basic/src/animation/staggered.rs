@@ -5,6 +5,18 @@
 
 use super::{Animation, AnimationState, EasingFunction, Interpolate};
 
+/// Order in which staggered items start
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StaggerDirection {
+    /// First item starts first
+    #[default]
+    Forward,
+    /// Last item starts first, e.g. for exit animations
+    Reverse,
+    /// Middle items start first and the edges follow
+    FromCenter,
+}
+
 /// Staggered animation system for multiple items
 ///
 /// Creates a choreographed animation where items animate in sequence
@@ -19,6 +31,8 @@ pub struct StaggeredAnimation<T: Interpolate> {
     pub duration: f32,
     /// Easing function for each item
     pub easing: EasingFunction,
+    /// Order in which items start
+    pub direction: StaggerDirection,
     /// Total elapsed time
     elapsed: f32,
     /// Number of items
@@ -42,6 +56,7 @@ impl<T: Interpolate> StaggeredAnimation<T> {
             stagger_delay,
             duration,
             easing: EasingFunction::EaseOut,
+            direction: StaggerDirection::Forward,
             elapsed: 0.0,
             item_count,
             start,
@@ -63,11 +78,34 @@ impl<T: Interpolate> StaggeredAnimation<T> {
         self
     }
 
+    /// Set the order in which items start
+    #[must_use]
+    pub const fn direction(mut self, direction: StaggerDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Update the animation
     pub fn update(&mut self, dt: f32) {
         self.elapsed += dt;
     }
 
+    /// Position of an item in the start order (0 starts first)
+    const fn slot(&self, index: usize) -> usize {
+        let last = self.item_count.saturating_sub(1);
+        match self.direction {
+            StaggerDirection::Forward => index,
+            StaggerDirection::Reverse => last.saturating_sub(index),
+            // Equal distances from the middle share a slot
+            StaggerDirection::FromCenter => (index * 2).abs_diff(last) / 2,
+        }
+    }
+
+    /// Time at which an item starts animating
+    const fn item_start_time(&self, index: usize) -> f32 {
+        (self.slot(index) as f32).mul_add(self.stagger_delay, self.base_delay)
+    }
+
     /// Reset the animation
     pub const fn reset(&mut self) {
         self.elapsed = 0.0;
@@ -75,7 +113,7 @@ impl<T: Interpolate> StaggeredAnimation<T> {
 
     /// Get the value for a specific item index
     pub fn value(&self, index: usize) -> T {
-        let item_start_time = self.base_delay + (index as f32 * self.stagger_delay);
+        let item_start_time = self.item_start_time(index);
         let item_end_time = item_start_time + self.duration;
 
         if self.elapsed < item_start_time {
@@ -98,7 +136,7 @@ impl<T: Interpolate> StaggeredAnimation<T> {
 
     /// Get the progress (0.0 to 1.0) for a specific item
     pub fn progress(&self, index: usize) -> f32 {
-        let item_start_time = self.base_delay + (index as f32 * self.stagger_delay);
+        let item_start_time = self.item_start_time(index);
         let item_end_time = item_start_time + self.duration;
 
         if self.elapsed < item_start_time {
@@ -110,11 +148,19 @@ impl<T: Interpolate> StaggeredAnimation<T> {
         }
     }
 
+    /// Get the eased progress (0.0 to 1.0) for a specific item
+    ///
+    /// Useful for driving opacity or offsets directly.
+    pub fn eased_progress(&self, index: usize) -> f32 {
+        self.easing.apply(self.progress(index))
+    }
+
     /// Check if all items have completed
     pub fn is_complete(&self) -> bool {
-        let last_item_end =
-            self.base_delay + ((self.item_count - 1) as f32 * self.stagger_delay) + self.duration;
-        self.elapsed >= last_item_end
+        let last_item_start = (0..self.item_count)
+            .map(|index| self.item_start_time(index))
+            .fold(self.base_delay, f32::max);
+        self.elapsed >= last_item_start + self.duration
     }
 
     /// Get opacity for item (useful for fade-in effects)
@@ -238,6 +284,9 @@ pub enum LoopMode {
     /// Loop infinitely
     Loop,
     /// Ping-pong (forward then backward)
+    ///
+    /// The return leg replays the forward curve in reverse, so easing is
+    /// mirrored rather than applied again from the end value.
     PingPong,
 }
 
@@ -283,7 +332,6 @@ impl<T: Interpolate> LoopingAnimation<T> {
                 LoopMode::PingPong => {
                     // Reverse direction
                     self.forward = !self.forward;
-                    std::mem::swap(&mut self.animation.start, &mut self.animation.end);
                     self.animation.reset();
                     self.animation.start();
                 }
@@ -295,14 +343,25 @@ impl<T: Interpolate> LoopingAnimation<T> {
 
     /// Get current value
     pub fn value(&self) -> T {
-        self.animation.value()
+        if self.forward {
+            return self.animation.value();
+        }
+        let t = self.animation.easing.apply(1.0 - self.animation.progress());
+        self.animation.start.interpolate(&self.animation.end, t)
     }
 
-    /// Get progress
+    /// Get progress through the current leg (0.0 to 1.0)
+    ///
+    /// In ping-pong mode this restarts from 0.0 at every turnaround.
     pub fn progress(&self) -> f32 {
         self.animation.progress()
     }
 
+    /// Whether the animation is on a forward leg (always true unless ping-ponging back)
+    pub const fn is_forward(&self) -> bool {
+        self.forward
+    }
+
     /// Reset the animation
     pub const fn reset(&mut self) {
         self.animation.reset();
@@ -330,6 +389,30 @@ mod tests {
         assert_eq!(anim.progress(2), 0.0);
     }
 
+    #[test]
+    fn test_stagger_direction_orders_items() {
+        let mut reverse =
+            StaggeredAnimation::new(0.0_f32, 1.0, 3, 0.1, 0.3).direction(StaggerDirection::Reverse);
+        reverse.elapsed = 0.15;
+        assert_eq!(reverse.progress(0), 0.0);
+        assert!(reverse.progress(2) > reverse.progress(1));
+        assert!(reverse.progress(1) > 0.0);
+
+        let mut center = StaggeredAnimation::new(0.0_f32, 1.0, 5, 0.1, 0.3)
+            .direction(StaggerDirection::FromCenter);
+        center.elapsed = 0.05;
+        assert!(center.progress(2) > 0.0);
+        assert_eq!(center.progress(1), 0.0);
+        center.elapsed = 0.15;
+        assert_eq!(center.progress(1), center.progress(3));
+        assert_eq!(center.progress(0), 0.0);
+
+        // The edges start last, two slots after the middle
+        center.elapsed = 0.2 + 0.3;
+        assert!(center.is_complete());
+        assert!((center.eased_progress(0) - 1.0).abs() < 1e-4);
+    }
+
     #[test]
     fn test_looping_animation() {
         let mut anim = LoopingAnimation::new(0.0_f32, 1.0, 1.0, LoopMode::Loop);
@@ -342,4 +425,29 @@ mod tests {
         anim.update(0.6); // Should loop
         assert!(anim.progress() < 0.5);
     }
+
+    #[test]
+    fn test_ping_pong_mirrors_easing() {
+        let mut anim = LoopingAnimation::new(0.0_f32, 1.0, 1.0, LoopMode::PingPong)
+            .easing(EasingFunction::EaseIn);
+        anim.update(0.0);
+        anim.update(0.25);
+        let forward_quarter = anim.value();
+        assert!(anim.is_forward());
+
+        // Turn around at the end, then play the return leg
+        anim.update(0.75);
+        assert!(!anim.is_forward());
+        assert_eq!(anim.progress(), 0.0);
+        assert!((anim.value() - 1.0).abs() < 1e-4);
+
+        anim.update(0.75);
+        assert!((anim.progress() - 0.75).abs() < 1e-4);
+        assert!((anim.value() - forward_quarter).abs() < 1e-4);
+
+        // Back at the start, the next leg goes forward again
+        anim.update(0.25);
+        assert!(anim.is_forward());
+        assert!(anim.value().abs() < 1e-4);
+    }
 }
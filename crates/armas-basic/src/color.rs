@@ -24,26 +24,119 @@ impl ColorStop {
     }
 }
 
+/// Shape of a gradient when sampled over a rect with [`Gradient::sample_at`]
+///
+/// Centers are relative to the rect: `(0.0, 0.0)` is its top-left corner and
+/// `(1.0, 1.0)` its bottom-right. Angles are in radians, clockwise from +x.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientKind {
+    /// Stops run across the rect along `angle`, corner to corner
+    Linear {
+        /// Direction of the gradient (0.0 runs left to right)
+        angle: f32,
+    },
+    /// Stops run outward from `center`, reaching the last stop at `radius`
+    Radial {
+        /// Center relative to the rect
+        center: Pos2,
+        /// Radius in points
+        radius: f32,
+    },
+    /// Stops sweep around `center`, wrapping from the last stop back to the first
+    Conic {
+        /// Center relative to the rect
+        center: Pos2,
+        /// Angle of the first stop
+        start_angle: f32,
+    },
+}
+
+impl Default for GradientKind {
+    fn default() -> Self {
+        Self::Linear { angle: 0.0 }
+    }
+}
+
 /// Gradient builder for creating various gradient types
 pub struct Gradient {
     stops: Vec<ColorStop>,
+    kind: GradientKind,
 }
 
 impl Gradient {
     /// Create a new gradient with stops
     #[must_use]
     pub const fn new(stops: Vec<ColorStop>) -> Self {
-        Self { stops }
+        Self {
+            stops,
+            kind: GradientKind::Linear { angle: 0.0 },
+        }
     }
 
     /// Create a simple two-color gradient
     #[must_use]
     pub fn linear(from: Color32, to: Color32) -> Self {
-        Self {
-            stops: vec![ColorStop::new(0.0, from), ColorStop::new(1.0, to)],
+        Self::new(vec![ColorStop::new(0.0, from), ColorStop::new(1.0, to)])
+    }
+
+    /// Set the shape used by [`Self::sample_at`] (default: linear, left to right)
+    #[must_use]
+    pub const fn kind(mut self, kind: GradientKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sample the color at `pos` of a gradient filling `rect`
+    #[must_use]
+    pub fn sample_at(&self, pos: Pos2, rect: Rect) -> Color32 {
+        let relative = |center: Pos2| rect.lerp_inside(center.to_vec2());
+        match self.kind {
+            GradientKind::Linear { angle } => {
+                let direction = Vec2::angled(angle);
+                // Half the rect's extent along the direction, so the corners
+                // land on the first and last stops as in CSS
+                let half_length = (rect.width() * direction.x).abs() / 2.0
+                    + (rect.height() * direction.y).abs() / 2.0;
+                if half_length <= f32::EPSILON {
+                    return self.sample(0.0);
+                }
+                let along = (pos - rect.center()).dot(direction);
+                self.sample(0.5 + along / (2.0 * half_length))
+            }
+            GradientKind::Radial { center, radius } => {
+                if radius <= f32::EPSILON {
+                    return self.sample(1.0);
+                }
+                self.sample(pos.distance(relative(center)) / radius)
+            }
+            GradientKind::Conic {
+                center,
+                start_angle,
+            } => {
+                let angle = (pos - relative(center)).angle() - start_angle;
+                self.sample_wrapped(angle.rem_euclid(2.0 * PI) / (2.0 * PI))
+            }
         }
     }
 
+    /// Sample treating the stops as a loop, so t = 0.0 and t = 1.0 match
+    fn sample_wrapped(&self, t: f32) -> Color32 {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return Color32::BLACK;
+        };
+        if t >= first.position && t <= last.position {
+            return self.sample(t);
+        }
+
+        // Across the seam, blend from the last stop to the first one
+        let seam = 1.0 - last.position + first.position;
+        if seam <= 0.0001 {
+            return last.color;
+        }
+        let from_last = (t - last.position).rem_euclid(1.0);
+        lerp_color(last.color, first.color, from_last / seam)
+    }
+
     /// Sample color at position t (0.0-1.0)
     #[must_use]
     pub fn sample(&self, t: f32) -> Color32 {
@@ -218,6 +311,35 @@ pub fn lerp_color(a: Color32, b: Color32, t: f32) -> Color32 {
     )
 }
 
+/// Interpolate between two colors in HSL space
+///
+/// Hue takes the shorter way around the color wheel, so blue to yellow passes
+/// through green or magenta instead of gray. A desaturated endpoint has no
+/// meaningful hue, so it borrows the other color's hue and only saturation
+/// and lightness blend.
+#[must_use]
+pub fn lerp_color_hsl(a: Color32, b: Color32, t: f32) -> Color32 {
+    let t = t.clamp(0.0, 1.0);
+    let (hue_a, sat_a, light_a) = to_hsl(a);
+    let (hue_b, sat_b, light_b) = to_hsl(b);
+
+    let (hue_a, hue_b) = match (sat_a <= f32::EPSILON, sat_b <= f32::EPSILON) {
+        (true, false) => (hue_b, hue_b),
+        (false, true) => (hue_a, hue_a),
+        _ => (hue_a, hue_b),
+    };
+    // Signed difference in -180..180 picks the shorter arc across the wrap
+    let delta = (hue_b - hue_a + 180.0).rem_euclid(360.0) - 180.0;
+
+    let rgb = from_hsl(
+        delta.mul_add(t, hue_a),
+        (sat_b - sat_a).mul_add(t, sat_a),
+        (light_b - light_a).mul_add(t, light_a),
+    );
+    let alpha = (f32::from(b.a()) - f32::from(a.a())).mul_add(t, f32::from(a.a()));
+    with_alpha(rgb, alpha.round() as u8)
+}
+
 /// Add alpha to a color
 #[must_use]
 pub fn with_alpha(color: Color32, alpha: u8) -> Color32 {
@@ -230,6 +352,7 @@ pub fn with_alpha(color: Color32, alpha: u8) -> Color32 {
 pub fn blend(a: Color32, b: Color32, t: f32, mode: BlendMode) -> Color32 {
     match mode {
         BlendMode::Normal => lerp_color(a, b, t),
+        BlendMode::Hsl => lerp_color_hsl(a, b, t),
         BlendMode::Multiply => {
             let r = ((f32::from(a.r()) / 255.0) * (f32::from(b.r()) / 255.0) * 255.0) as u8;
             let g = ((f32::from(a.g()) / 255.0) * (f32::from(b.g()) / 255.0) * 255.0) as u8;
@@ -268,6 +391,8 @@ pub fn blend(a: Color32, b: Color32, t: f32, mode: BlendMode) -> Color32 {
 pub enum BlendMode {
     /// Normal blend mode (no blending)
     Normal,
+    /// Like `Normal`, but interpolated in HSL (see [`lerp_color_hsl`])
+    Hsl,
     /// Multiply blend mode (darkens)
     Multiply,
     /// Screen blend mode (lightens)
@@ -292,10 +417,215 @@ pub fn saturate(color: Color32, amount: f32) -> Color32 {
     Color32::from_rgb((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8)
 }
 
+/// Convert a color to HSL
+///
+/// Returns (hue in degrees 0-360, saturation 0-1, lightness 0-1). Alpha is ignored.
+#[must_use]
+pub fn to_hsl(color: Color32) -> (f32, f32, f32) {
+    let (r, g, b) = rgb_unit(color);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let lightness = f32::midpoint(max, min);
+
+    if delta <= f32::EPSILON {
+        return (0.0, 0.0, lightness);
+    }
+
+    let saturation = delta / (1.0 - 2.0f32.mul_add(lightness, -1.0).abs());
+    (
+        hue_degrees(r, g, b, max, delta),
+        saturation.clamp(0.0, 1.0),
+        lightness,
+    )
+}
+
+/// Create an opaque color from HSL (hue in degrees, saturation and lightness 0-1)
+#[must_use]
+pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Color32 {
+    let s = saturation.clamp(0.0, 1.0);
+    let l = lightness.clamp(0.0, 1.0);
+    let chroma = (1.0 - 2.0f32.mul_add(l, -1.0).abs()) * s;
+    from_chroma(hue, chroma, l - chroma / 2.0)
+}
+
+/// Convert a color to HSV
+///
+/// Returns (hue in degrees 0-360, saturation 0-1, value 0-1). Alpha is ignored.
+#[must_use]
+pub fn to_hsv(color: Color32) -> (f32, f32, f32) {
+    let (r, g, b) = rgb_unit(color);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    if delta <= f32::EPSILON {
+        return (0.0, 0.0, max);
+    }
+
+    (hue_degrees(r, g, b, max, delta), delta / max, max)
+}
+
+/// Create an opaque color from HSV (hue in degrees, saturation and value 0-1)
+#[must_use]
+pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Color32 {
+    let v = value.clamp(0.0, 1.0);
+    let chroma = v * saturation.clamp(0.0, 1.0);
+    from_chroma(hue, chroma, v - chroma)
+}
+
+/// Format a color as `#RRGGBB`, or `#RRGGBBAA` when `include_alpha` is set
+#[must_use]
+pub fn to_hex(color: Color32, include_alpha: bool) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    if include_alpha {
+        format!("#{r:02X}{g:02X}{b:02X}{a:02X}")
+    } else {
+        format!("#{r:02X}{g:02X}{b:02X}")
+    }
+}
+
+/// Parse a hex color (`#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`, `#` optional)
+#[must_use]
+pub fn from_hex(hex: &str) -> Option<Color32> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    Color32::from_hex(&format!("#{hex}")).ok()
+}
+
+/// WCAG contrast ratio between two colors (1.0 to 21.0). Alpha is ignored.
+///
+/// Text should reach 4.5 against its background (WCAG AA).
+#[must_use]
+pub fn contrast_ratio(a: Color32, b: Color32) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// WCAG relative luminance of a color (0-1)
+fn relative_luminance(color: Color32) -> f32 {
+    let linear = |c: f32| {
+        if c <= 0.040_45 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let (r, g, b) = rgb_unit(color);
+    0.0722f32.mul_add(linear(b), 0.2126f32.mul_add(linear(r), 0.7152 * linear(g)))
+}
+
+fn rgb_unit(color: Color32) -> (f32, f32, f32) {
+    let [r, g, b, _] = color.to_srgba_unmultiplied();
+    (
+        f32::from(r) / 255.0,
+        f32::from(g) / 255.0,
+        f32::from(b) / 255.0,
+    )
+}
+
+fn hue_degrees(r: f32, g: f32, b: f32, max: f32, delta: f32) -> f32 {
+    let sector = if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    sector * 60.0
+}
+
+fn from_chroma(hue: f32, chroma: f32, offset: f32) -> Color32 {
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let channel = |c: f32| ((c + offset).clamp(0.0, 1.0) * 255.0).round() as u8;
+    Color32::from_rgb(channel(r), channel(g), channel(b))
+}
+
 /// Neon color palette presets for aceternity-style effects
 pub struct NeonPalette;
 
 impl NeonPalette {
+    /// Neon cyan
+    #[must_use]
+    pub const fn cyan() -> Color32 {
+        Color32::from_rgb(0, 255, 255)
+    }
+
+    /// Neon magenta
+    #[must_use]
+    pub const fn magenta() -> Color32 {
+        Color32::from_rgb(255, 0, 255)
+    }
+
+    /// Neon pink
+    #[must_use]
+    pub const fn pink() -> Color32 {
+        Color32::from_rgb(255, 20, 147)
+    }
+
+    /// Neon lime
+    #[must_use]
+    pub const fn lime() -> Color32 {
+        Color32::from_rgb(57, 255, 20)
+    }
+
+    /// Neon yellow
+    #[must_use]
+    pub const fn yellow() -> Color32 {
+        Color32::from_rgb(255, 240, 31)
+    }
+
+    /// Neon orange
+    #[must_use]
+    pub const fn orange() -> Color32 {
+        Color32::from_rgb(255, 95, 31)
+    }
+
+    /// Neon purple
+    #[must_use]
+    pub const fn purple() -> Color32 {
+        Color32::from_rgb(138, 43, 226)
+    }
+
+    /// Neon blue
+    #[must_use]
+    pub const fn blue() -> Color32 {
+        Color32::from_rgb(0, 191, 255)
+    }
+
+    /// The named neon colors in order, for assigning distinct colors to a series
+    pub fn iter() -> impl Iterator<Item = Color32> {
+        [
+            Self::cyan(),
+            Self::magenta(),
+            Self::pink(),
+            Self::lime(),
+            Self::yellow(),
+            Self::orange(),
+            Self::purple(),
+            Self::blue(),
+        ]
+        .into_iter()
+    }
+
+    /// Fully saturated, bright neon color for any hue in degrees
+    ///
+    /// Spacing hues evenly (e.g. `i as f32 * 360.0 / n as f32`) gives `n`
+    /// distinct colors.
+    #[must_use]
+    pub fn from_hue(hue: f32) -> Color32 {
+        from_hsl(hue, 1.0, 0.55)
+    }
+
     /// Cyberpunk neon palette (blues, purples, pinks)
     #[must_use]
     pub fn cyberpunk() -> Vec<Color32> {
@@ -407,6 +737,33 @@ mod tests {
         assert_eq!(mid.b(), 127);
     }
 
+    #[test]
+    fn test_lerp_color_hsl() {
+        let blue = Color32::from_rgb(0, 0, 255);
+        let yellow = Color32::from_rgb(255, 255, 0);
+        // RGB goes through gray; HSL stays saturated
+        let mid = lerp_color_hsl(blue, yellow, 0.5);
+        assert_eq!(to_hsl(mid).1, 1.0);
+        assert_eq!(lerp_color_hsl(blue, yellow, 0.0), blue);
+        assert_eq!(lerp_color_hsl(blue, yellow, 1.0), yellow);
+
+        // 350 degrees to 10 degrees wraps through red, not cyan
+        let (hue, _, _) = to_hsl(lerp_color_hsl(
+            from_hsl(350.0, 1.0, 0.5),
+            from_hsl(10.0, 1.0, 0.5),
+            0.5,
+        ));
+        assert!(!(1.0..359.0).contains(&hue), "hue {hue}");
+
+        // Gray keeps the red hue and only lightness/saturation blend
+        let red = Color32::from_rgb(255, 0, 0);
+        let (hue, sat, _) = to_hsl(lerp_color_hsl(Color32::WHITE, red, 0.5));
+        assert!(hue.abs() < 1.0 && (sat - 0.5).abs() < 0.01);
+
+        let faded = lerp_color_hsl(with_alpha(red, 0), red, 0.5);
+        assert_eq!(faded.a(), 128);
+    }
+
     #[test]
     fn test_gradient_sample() {
         let gradient = Gradient::linear(Color32::BLACK, Color32::WHITE);
@@ -414,6 +771,78 @@ mod tests {
         assert_eq!(mid.r(), 127);
     }
 
+    #[test]
+    fn test_gradient_sample_at_kinds() {
+        let rect = Rect::from_min_size(Pos2::ZERO, Vec2::new(200.0, 100.0));
+        let linear = Gradient::linear(Color32::BLACK, Color32::WHITE);
+        assert_eq!(linear.sample_at(rect.left_center(), rect), Color32::BLACK);
+        assert_eq!(linear.sample_at(rect.center(), rect).r(), 127);
+
+        // Pointing down: the top edge is the first stop
+        let vertical = Gradient::linear(Color32::BLACK, Color32::WHITE)
+            .kind(GradientKind::Linear { angle: PI / 2.0 });
+        assert_eq!(vertical.sample_at(rect.center_top(), rect), Color32::BLACK);
+        assert_eq!(
+            vertical.sample_at(rect.center_bottom(), rect),
+            Color32::WHITE
+        );
+
+        let radial = Gradient::linear(Color32::BLACK, Color32::WHITE).kind(GradientKind::Radial {
+            center: Pos2::new(0.5, 0.5),
+            radius: 50.0,
+        });
+        assert_eq!(radial.sample_at(rect.center(), rect), Color32::BLACK);
+        assert_eq!(
+            radial
+                .sample_at(rect.center() + Vec2::new(25.0, 0.0), rect)
+                .r(),
+            127
+        );
+        assert_eq!(radial.sample_at(rect.right_center(), rect), Color32::WHITE);
+    }
+
+    #[test]
+    fn test_gradient_conic_wraps_at_seam() {
+        let rect = Rect::from_min_size(Pos2::ZERO, Vec2::new(100.0, 100.0));
+        let conic = Gradient::new(vec![
+            ColorStop::new(0.25, Color32::from_rgb(255, 0, 0)),
+            ColorStop::new(0.75, Color32::from_rgb(0, 0, 255)),
+        ])
+        .kind(GradientKind::Conic {
+            center: Pos2::new(0.5, 0.5),
+            start_angle: 0.0,
+        });
+
+        // Just either side of the seam at +x the colors match
+        let above = conic.sample_at(rect.center() + Vec2::angled(-0.01) * 40.0, rect);
+        let below = conic.sample_at(rect.center() + Vec2::angled(0.01) * 40.0, rect);
+        assert!(above.r().abs_diff(below.r()) <= 2);
+        assert!(above.b().abs_diff(below.b()) <= 2);
+        // The seam sits halfway between the last and first stops
+        let seam = conic.sample_at(rect.right_center(), rect);
+        assert_eq!((seam.r(), seam.b()), (127, 127));
+        // A quarter turn clockwise (pointing down) is the first stop
+        assert_eq!(
+            conic.sample_at(rect.center_bottom(), rect),
+            Color32::from_rgb(255, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_neon_palette() {
+        let colors: Vec<Color32> = NeonPalette::iter().collect();
+        assert_eq!(colors[0], NeonPalette::cyan());
+        for (i, a) in colors.iter().enumerate() {
+            assert!(colors[i + 1..].iter().all(|b| a != b));
+        }
+
+        let (hue, saturation, lightness) = to_hsl(NeonPalette::from_hue(200.0));
+        assert!((hue - 200.0).abs() < 1.0);
+        assert!((saturation - 1.0).abs() < 0.01);
+        assert!(lightness > 0.5);
+        assert_eq!(NeonPalette::from_hue(-60.0), NeonPalette::from_hue(300.0));
+    }
+
     #[test]
     fn test_with_alpha() {
         let color = Color32::from_rgb(255, 0, 0);
@@ -428,4 +857,44 @@ mod tests {
         // Should remain gray since there's no color to saturate
         assert_eq!(saturated.r(), saturated.g());
     }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        let orange = Color32::from_rgb(255, 128, 0);
+        let (h, s, l) = to_hsl(orange);
+        assert!((h - 30.1).abs() < 0.5);
+        assert!((s - 1.0).abs() < 0.001);
+        assert_eq!(from_hsl(h, s, l), orange);
+
+        assert_eq!(from_hsl(120.0, 1.0, 0.5), Color32::from_rgb(0, 255, 0));
+        assert_eq!(to_hsl(Color32::WHITE), (0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_hsv_round_trip() {
+        let color = Color32::from_rgb(51, 102, 204);
+        let (h, s, v) = to_hsv(color);
+        assert!((h - 220.0).abs() < 0.5);
+        assert!((v - 0.8).abs() < 0.001);
+        assert_eq!(from_hsv(h, s, v), color);
+        assert_eq!(from_hsv(0.0, 0.0, 0.0), Color32::BLACK);
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(to_hex(Color32::from_rgb(255, 136, 0), false), "#FF8800");
+        assert_eq!(from_hex("ff8800"), Some(Color32::from_rgb(255, 136, 0)));
+        assert_eq!(from_hex("#f80"), Some(Color32::from_rgb(255, 136, 0)));
+        assert_eq!(from_hex("#zzz"), None);
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((contrast_ratio(Color32::BLACK, Color32::WHITE) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(Color32::WHITE, Color32::BLACK) - 21.0).abs() < 0.01);
+        let gray = Color32::from_rgb(119, 119, 119);
+        assert!((contrast_ratio(gray, gray) - 1.0).abs() < f32::EPSILON);
+        // #777 on white is the classic just-below-AA gray
+        assert!((contrast_ratio(gray, Color32::WHITE) - 4.48).abs() < 0.01);
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Collapsible content sections styled like shadcn/ui Accordion.
 //! Supports single or multiple open items with smooth spring animations.
+//! Each section is a [`Collapsible`].
 //!
 //! # Example
 //!
@@ -18,10 +19,10 @@
 //! # }
 //! ```
 
-use crate::animation::SpringAnimation;
+use super::Collapsible;
 use crate::ext::ArmasContextExt;
 use crate::Theme;
-use egui::{Pos2, Ui, Vec2};
+use egui::{Pos2, Response, Ui, Vec2};
 
 // shadcn Accordion constants
 const TRIGGER_PADDING_Y: f32 = 16.0; // py-4
@@ -68,92 +69,29 @@ impl Accordion {
         mut content_fn: impl FnMut(&mut Ui, usize),
     ) -> AccordionResponse {
         let theme = ui.ctx().armas_theme();
-        let dt = ui.input(|i| i.stable_dt);
-
-        // Load state
-        let state_id = self.id.with("accordion_state");
-        let mut open_indices: Vec<usize> = ui
-            .ctx()
-            .data_mut(|d| d.get_temp(state_id).unwrap_or_default());
-
-        // Load spring animations for each item
-        let mut springs: Vec<SpringAnimation> = ui.ctx().data_mut(|d| {
-            d.get_temp(self.id.with("accordion_springs"))
-                .unwrap_or_else(|| {
-                    self.titles
-                        .iter()
-                        .map(|_| SpringAnimation::new(0.0, 0.0).params(180.0, 22.0))
-                        .collect()
-                })
-        });
-
-        // Ensure springs vec matches titles length
-        while springs.len() < self.titles.len() {
-            springs.push(SpringAnimation::new(0.0, 0.0).params(180.0, 22.0));
-        }
+        let item_id = |idx: usize| self.id.with(("accordion_item", idx));
 
         let mut clicked = None;
-        let mut needs_repaint = false;
 
         for (idx, title) in self.titles.iter().enumerate() {
-            let is_open = open_indices.contains(&idx);
-
-            // Trigger
-            let trigger_clicked = self.show_trigger(ui, title, springs[idx].value, &theme);
+            let response = Collapsible::new(item_id(idx)).show(
+                ui,
+                |ui, openness| self.show_trigger(ui, title, openness, &theme),
+                |ui| {
+                    content_fn(ui, idx);
+                    ui.add_space(CONTENT_PADDING_BOTTOM);
+                },
+            );
 
-            if trigger_clicked {
+            if response.toggled {
                 clicked = Some(idx);
-                if is_open {
-                    open_indices.retain(|&i| i != idx);
-                } else {
-                    if !self.allow_multiple {
-                        open_indices.clear();
+                if response.open && !self.allow_multiple {
+                    for other in (0..self.titles.len()).filter(|&other| other != idx) {
+                        Collapsible::set_open(ui.ctx(), item_id(other), false);
                     }
-                    open_indices.push(idx);
                 }
             }
 
-            // Update spring target and simulate
-            let target = if open_indices.contains(&idx) {
-                1.0
-            } else {
-                0.0
-            };
-            springs[idx].set_target(target);
-            springs[idx].update(dt);
-
-            // Check if still animating (use looser threshold for smooth closing)
-            let is_animating = !springs[idx].is_settled(0.005, 0.1);
-            if is_animating {
-                needs_repaint = true;
-            }
-
-            // Content - show while animating OR while open
-            let anim_value = springs[idx].value.clamp(0.0, 1.0);
-            let should_show = anim_value > 0.001 || is_animating;
-            if should_show && anim_value > 0.0 {
-                let content_id = self.id.with(("content_height", idx));
-                let stored_height: f32 = ui
-                    .ctx()
-                    .data_mut(|d| d.get_temp(content_id).unwrap_or(50.0));
-
-                let animated_height = (stored_height + CONTENT_PADDING_BOTTOM) * anim_value;
-
-                let response = egui::Frame::new().show(ui, |ui| {
-                    ui.set_max_height(animated_height);
-                    ui.set_clip_rect(ui.max_rect());
-
-                    content_fn(ui, idx);
-                    ui.add_space(CONTENT_PADDING_BOTTOM);
-
-                    ui.min_rect().height()
-                });
-
-                let actual_height = response.inner / anim_value.max(0.01);
-                ui.ctx()
-                    .data_mut(|d| d.insert_temp(content_id, actual_height));
-            }
-
             // Bottom border
             let rect = ui.available_rect_before_wrap();
             ui.painter().hline(
@@ -164,23 +102,14 @@ impl Accordion {
             ui.allocate_space(Vec2::new(0.0, 1.0));
         }
 
-        if needs_repaint {
-            ui.ctx().request_repaint();
-        }
+        let open = (0..self.titles.len())
+            .filter(|&idx| Collapsible::is_open(ui.ctx(), item_id(idx)))
+            .collect();
 
-        // Save state
-        ui.ctx().data_mut(|d| {
-            d.insert_temp(state_id, open_indices.clone());
-            d.insert_temp(self.id.with("accordion_springs"), springs);
-        });
-
-        AccordionResponse {
-            clicked,
-            open: open_indices,
-        }
+        AccordionResponse { clicked, open }
     }
 
-    fn show_trigger(&self, ui: &mut Ui, title: &str, anim_value: f32, theme: &Theme) -> bool {
+    fn show_trigger(&self, ui: &mut Ui, title: &str, anim_value: f32, theme: &Theme) -> Response {
         let available_width = ui.available_width();
         let text_galley = ui.painter().layout_no_wrap(
             title.to_string(),
@@ -218,7 +147,8 @@ impl Accordion {
             );
         }
 
-        response.clicked()
+        response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, title));
+        response
     }
 
     fn draw_chevron(&self, ui: &mut Ui, center: Pos2, anim_value: f32, theme: &Theme) {
@@ -0,0 +1,278 @@
+//! Animated Number Component
+//!
+//! Odometer-style counter for dashboards and hero stats. When the value
+//! changes, the displayed number tweens from the old value to the new one and
+//! every digit that changes on the way rolls up into place, so rollover shows
+//! the intermediate digits.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui, revenue: f64) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::AnimatedNumber;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! AnimatedNumber::new(revenue)
+//!     .format(|v| format!("${v:.2}"))
+//!     .duration(1.2)
+//!     .show(ui, &theme);
+//! # }
+//! ```
+
+use crate::animation::{Animation, EasingFunction};
+use crate::Theme;
+use egui::{pos2, vec2, Color32, FontId, Id, Rect, Response, Sense, Ui};
+
+const DEFAULT_DURATION: f32 = 0.8;
+const DEFAULT_FONT_SIZE: f32 = 32.0;
+/// Time for one digit to roll into place, in seconds
+const DIGIT_ROLL_DURATION: f32 = 0.12;
+
+/// Response from an animated number
+#[derive(Debug, Clone)]
+pub struct AnimatedNumberResponse {
+    /// The UI response
+    pub response: Response,
+    /// Value shown this frame
+    pub displayed: f64,
+    /// Whether the number is still counting toward its value
+    pub animating: bool,
+}
+
+/// A digit rolling out while its replacement rolls in
+#[derive(Debug, Clone, Copy)]
+struct DigitRoll {
+    from: char,
+    progress: f32,
+}
+
+/// Counting state kept between frames
+#[derive(Debug, Clone)]
+struct AnimatedNumberState {
+    tween: Animation<f64>,
+    text: String,
+    /// Per character, counted from the right
+    rolls: Vec<Option<DigitRoll>>,
+}
+
+impl AnimatedNumberState {
+    /// State resting on `value`, shown as `text`
+    const fn settled(value: f64, text: String) -> Self {
+        let mut tween = Animation::new(value, value, 0.0);
+        tween.start();
+        Self {
+            tween,
+            text,
+            rolls: Vec::new(),
+        }
+    }
+}
+
+/// Number that counts to its new value with rolling digits
+pub struct AnimatedNumber {
+    value: f64,
+    id: Option<Id>,
+    format: Box<dyn Fn(f64) -> String>,
+    duration: f32,
+    easing: EasingFunction,
+    font_size: f32,
+    color: Option<Color32>,
+}
+
+impl AnimatedNumber {
+    /// Create a counter showing `value`
+    #[must_use]
+    pub fn new(value: f64) -> Self {
+        Self {
+            value,
+            id: None,
+            format: Box::new(|v| format!("{v:.0}")),
+            duration: DEFAULT_DURATION,
+            easing: EasingFunction::EaseOut,
+            font_size: DEFAULT_FONT_SIZE,
+            color: None,
+        }
+    }
+
+    /// Set custom ID (needed when several counters share a parent)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set how the value is turned into text (default: rounded integer)
+    #[must_use]
+    pub fn format(mut self, format: impl Fn(f64) -> String + 'static) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Set how long counting to a new value takes, in seconds (default: 0.8)
+    #[must_use]
+    pub const fn duration(mut self, seconds: f32) -> Self {
+        self.duration = seconds;
+        self
+    }
+
+    /// Set the easing of the count (default: ease out)
+    #[must_use]
+    pub const fn easing(mut self, easing: EasingFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Set the font size (default: 32)
+    #[must_use]
+    pub const fn font_size(mut self, size: f32) -> Self {
+        self.font_size = size;
+        self
+    }
+
+    /// Set the text color (default: theme foreground)
+    #[must_use]
+    pub const fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Show the number
+    pub fn show(self, ui: &mut Ui, theme: &Theme) -> AnimatedNumberResponse {
+        let id = self.id.unwrap_or_else(|| ui.id().with("animated_number"));
+        let dt = ui.input(|i| i.stable_dt);
+
+        let mut state = ui
+            .ctx()
+            .data(|d| d.get_temp::<AnimatedNumberState>(id))
+            .unwrap_or_else(|| AnimatedNumberState::settled(self.value, (self.format)(self.value)));
+        self.advance(&mut state, dt);
+
+        let displayed = state.tween.value();
+        let animating = state.tween.is_running() || state.rolls.iter().any(Option::is_some);
+        if animating {
+            ui.ctx().request_repaint();
+        }
+
+        let font_id = FontId::proportional(self.font_size);
+        let color = self.color.unwrap_or_else(|| theme.foreground());
+        let response = Self::paint(ui, &state, &font_id, color);
+
+        ui.ctx().data_mut(|d| d.insert_temp(id, state));
+
+        AnimatedNumberResponse {
+            response,
+            displayed,
+            animating,
+        }
+    }
+
+    /// Step the count by `dt`, starting a new count if the value changed
+    fn advance(&self, state: &mut AnimatedNumberState, dt: f32) {
+        if state.tween.end != self.value {
+            let from = state.tween.value();
+            state.tween = Animation::new(from, self.value, self.duration).easing(self.easing);
+            state.tween.start();
+        }
+        state.tween.update(dt);
+
+        for roll in state.rolls.iter_mut().flatten() {
+            roll.progress += dt / DIGIT_ROLL_DURATION;
+        }
+
+        // Digits that differ from last frame start rolling from the old one
+        let text = (self.format)(state.tween.value());
+        let old: Vec<char> = state.text.chars().rev().collect();
+        let new: Vec<char> = text.chars().rev().collect();
+        state.rolls.resize(new.len(), None);
+        for (i, (slot, &c)) in state.rolls.iter_mut().zip(&new).enumerate() {
+            match old.get(i) {
+                Some(&from) if from != c && from.is_ascii_digit() && c.is_ascii_digit() => {
+                    *slot = Some(DigitRoll {
+                        from,
+                        progress: 0.0,
+                    });
+                }
+                _ => {}
+            }
+            if slot.is_some_and(|roll| roll.progress >= 1.0) {
+                *slot = None;
+            }
+        }
+        state.text = text;
+    }
+
+    fn paint(
+        ui: &mut Ui,
+        state: &AnimatedNumberState,
+        font_id: &FontId,
+        color: Color32,
+    ) -> Response {
+        let chars: Vec<char> = state.text.chars().collect();
+        let galleys: Vec<_> = chars
+            .iter()
+            .map(|c| {
+                ui.painter()
+                    .layout_no_wrap(c.to_string(), font_id.clone(), color)
+            })
+            .collect();
+        let width: f32 = galleys.iter().map(|g| g.size().x).sum();
+        let height = ui.fonts_mut(|f| f.row_height(font_id));
+
+        let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        // Rolling digits slide within the row and are clipped to it
+        let painter = ui.painter_at(rect);
+        let mut x = rect.left();
+        for (i, galley) in galleys.into_iter().enumerate() {
+            let cell = Rect::from_min_size(pos2(x, rect.top()), vec2(galley.size().x, height));
+            x += galley.size().x;
+
+            let roll = state.rolls.get(chars.len() - 1 - i).copied().flatten();
+            let Some(roll) = roll else {
+                painter.galley(cell.min, galley, color);
+                continue;
+            };
+
+            let t = EasingFunction::EaseOut.apply(roll.progress.clamp(0.0, 1.0));
+            let outgoing = painter.layout_no_wrap(roll.from.to_string(), font_id.clone(), color);
+            painter.galley(
+                cell.min - vec2(0.0, height * t),
+                outgoing,
+                color.gamma_multiply(1.0 - t),
+            );
+            painter.galley(
+                cell.min + vec2(0.0, height * (1.0 - t)),
+                galley,
+                color.gamma_multiply(t),
+            );
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed_digits_roll() {
+        let mut state = AnimatedNumberState::settled(10.0, "10".to_string());
+        AnimatedNumber::new(19.0)
+            .duration(0.0)
+            .advance(&mut state, 0.0);
+
+        assert_eq!(state.text, "19");
+        // Only the ones digit changed
+        assert_eq!(state.rolls[0].map(|roll| roll.from), Some('0'));
+        assert!(state.rolls[1].is_none());
+
+        AnimatedNumber::new(19.0).advance(&mut state, DIGIT_ROLL_DURATION);
+        assert!(state.rolls.iter().all(Option::is_none));
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! User profile images and initials styled like shadcn/ui Avatar.
 
+use crate::ext::ArmasContextExt;
 use egui::{vec2, Response, Sense, Ui};
 
 // shadcn Avatar default size
@@ -150,3 +151,10 @@ impl Avatar {
         response
     }
 }
+
+impl egui::Widget for Avatar {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let theme = ui.ctx().armas_theme();
+        self.show(ui, &theme)
+    }
+}
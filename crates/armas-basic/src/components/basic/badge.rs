@@ -7,6 +7,7 @@
 //! - Destructive (red)
 //! - Outline (border only)
 
+use crate::ext::ArmasContextExt;
 use crate::Theme;
 use egui::{Color32, Pos2, Response, Ui, Vec2};
 
@@ -329,6 +330,13 @@ impl Default for Badge {
     }
 }
 
+impl egui::Widget for Badge {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let theme = ui.ctx().armas_theme();
+        self.show(ui, &theme).response
+    }
+}
+
 /// Response from a badge
 #[derive(Debug, Clone)]
 pub struct BadgeResponse {
@@ -0,0 +1,433 @@
+//! Code Block Component
+//!
+//! Read-only code display styled like a shadcn/ui code block.
+//! Features:
+//! - Syntax highlighting for Rust and TOML (plain text otherwise), themed
+//!   from the Armas palette
+//! - Optional line numbers
+//! - Copy button that places the code on the clipboard
+//! - Optional language selector
+//! - Long lines scroll horizontally instead of wrapping
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::CodeBlock;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let response = CodeBlock::new("fn main() {\n    println!(\"hi\");\n}", "rust").show(ui, &theme);
+//! if response.copied {
+//!     // the code is now on the clipboard
+//! }
+//! # }
+//! ```
+
+use super::copy_button::{copy_confirming, copy_to_clipboard};
+use crate::{Button, ButtonSize, ButtonVariant, Select, SelectOption, Theme};
+use egui::text::{LayoutJob, TextFormat};
+use egui::{Color32, FontId, Id, Response, Stroke, Ui};
+
+// shadcn-style constants
+const CORNER_RADIUS: f32 = 8.0; // rounded-lg
+const PADDING: f32 = 12.0; // p-3
+const HEADER_GAP: f32 = 8.0; // gap-2
+const LINE_NUMBER_GAP: f32 = 16.0; // gap-4
+const FONT_SIZE: f32 = 13.0;
+const SELECT_WIDTH: f32 = 110.0;
+const SELECT_HEIGHT: f32 = 28.0;
+
+/// Languages offered by the language selector
+const LANGUAGES: [(&str, &str); 3] = [("rust", "Rust"), ("toml", "TOML"), ("text", "Plain Text")];
+
+const RUST_KEYWORDS: [&str; 36] = [
+    "let", "mut", "fn", "if", "else", "for", "while", "loop", "match", "return", "struct", "enum",
+    "impl", "trait", "pub", "use", "mod", "const", "static", "async", "await", "unsafe", "extern",
+    "as", "break", "continue", "crate", "super", "self", "Self", "where", "type", "ref", "move",
+    "true", "false",
+];
+
+const RUST_TYPES: [&str; 25] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize", "f32",
+    "f64", "bool", "char", "str", "String", "Vec", "Option", "Result", "Some", "None", "Ok", "Err",
+];
+
+/// Response from a code block
+#[derive(Debug, Clone)]
+pub struct CodeBlockResponse {
+    /// The response of the whole block
+    pub response: Response,
+    /// Whether the copy button was clicked this frame
+    pub copied: bool,
+    /// The language currently used for highlighting
+    pub language: String,
+}
+
+/// Code block component
+pub struct CodeBlock {
+    id: Option<Id>,
+    code: String,
+    language: String,
+    line_numbers: bool,
+    copy_button: bool,
+    language_selector: bool,
+    max_height: Option<f32>,
+}
+
+impl CodeBlock {
+    /// Create a code block for `code` highlighted as `language`
+    ///
+    /// Supported languages are `rust`/`rs` and `toml`; anything else is shown
+    /// as plain text.
+    pub fn new(code: impl Into<String>, language: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            code: code.into(),
+            language: language.into(),
+            line_numbers: true,
+            copy_button: true,
+            language_selector: false,
+            max_height: None,
+        }
+    }
+
+    /// Set custom ID (important when using multiple code blocks)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Show or hide line numbers
+    #[must_use]
+    pub const fn line_numbers(mut self, show: bool) -> Self {
+        self.line_numbers = show;
+        self
+    }
+
+    /// Show or hide the copy button
+    #[must_use]
+    pub const fn copy_button(mut self, show: bool) -> Self {
+        self.copy_button = show;
+        self
+    }
+
+    /// Let the user switch the highlighting language
+    #[must_use]
+    pub const fn language_selector(mut self, show: bool) -> Self {
+        self.language_selector = show;
+        self
+    }
+
+    /// Limit the height; taller code scrolls vertically
+    #[must_use]
+    pub const fn max_height(mut self, height: f32) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
+    /// Show the code block
+    pub fn show(self, ui: &mut Ui, theme: &Theme) -> CodeBlockResponse {
+        let id = self.id.unwrap_or_else(|| ui.id().with("code_block"));
+        let language_id = id.with("language");
+        let copy_id = id.with("copy_state");
+
+        let mut language: String = if self.language_selector {
+            ui.ctx()
+                .data(|d| d.get_temp(language_id))
+                .unwrap_or_else(|| self.language.clone())
+        } else {
+            self.language.clone()
+        };
+        let mut copied = false;
+
+        let frame = egui::Frame::new()
+            .fill(theme.muted())
+            .stroke(Stroke::new(1.0, theme.border()))
+            .corner_radius(CORNER_RADIUS)
+            .inner_margin(PADDING);
+
+        let inner = frame.show(ui, |ui| {
+            ui.spacing_mut().item_spacing.y = HEADER_GAP;
+
+            if self.language_selector || self.copy_button {
+                ui.horizontal(|ui| {
+                    if self.language_selector {
+                        let options = LANGUAGES
+                            .iter()
+                            .map(|(value, label)| SelectOption::new(*value, *label))
+                            .collect();
+                        let response = Select::new(options)
+                            .id(id.with("language_select"))
+                            .selected(normalize_language(&language))
+                            .searchable(false)
+                            .width(SELECT_WIDTH)
+                            .height(SELECT_HEIGHT)
+                            .show(ui, theme);
+                        if let Some(value) = response.selected_value.filter(|_| response.changed) {
+                            language = value;
+                        }
+                    } else {
+                        ui.label(
+                            egui::RichText::new(language_label(&language))
+                                .size(FONT_SIZE)
+                                .color(theme.muted_foreground()),
+                        );
+                    }
+
+                    if self.copy_button {
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let label = if copy_confirming(ui.ctx(), copy_id) {
+                                "Copied!"
+                            } else {
+                                "Copy"
+                            };
+
+                            if Button::new(label)
+                                .variant(ButtonVariant::Ghost)
+                                .size(ButtonSize::Small)
+                                .show(ui, theme)
+                                .clicked()
+                            {
+                                copy_to_clipboard(ui.ctx(), copy_id, &self.code);
+                                copied = true;
+                            }
+                        });
+                    }
+                });
+            }
+
+            let font_id = FontId::monospace(FONT_SIZE);
+            let body = |ui: &mut Ui| {
+                ui.horizontal_top(|ui| {
+                    ui.spacing_mut().item_spacing.x = LINE_NUMBER_GAP;
+                    if self.line_numbers {
+                        ui.label(line_numbers_job(&self.code, &font_id, theme));
+                    }
+                    egui::ScrollArea::horizontal()
+                        .id_salt(id.with("scroll_x"))
+                        .show(ui, |ui| {
+                            ui.add(
+                                egui::Label::new(highlight(&self.code, &language, &font_id, theme))
+                                    .extend(),
+                            );
+                        });
+                });
+            };
+
+            if let Some(max_height) = self.max_height {
+                egui::ScrollArea::vertical()
+                    .id_salt(id.with("scroll_y"))
+                    .max_height(max_height)
+                    .show(ui, body);
+            } else {
+                body(ui);
+            }
+        });
+
+        if self.language_selector {
+            ui.ctx()
+                .data_mut(|d| d.insert_temp(language_id, language.clone()));
+        }
+
+        CodeBlockResponse {
+            response: inner.response,
+            copied,
+            language,
+        }
+    }
+}
+
+/// Map language aliases onto the selector values
+fn normalize_language(language: &str) -> &'static str {
+    match language.to_ascii_lowercase().as_str() {
+        "rust" | "rs" => "rust",
+        "toml" => "toml",
+        _ => "text",
+    }
+}
+
+fn language_label(language: &str) -> &'static str {
+    let value = normalize_language(language);
+    LANGUAGES
+        .iter()
+        .find(|(v, _)| *v == value)
+        .map_or("Plain Text", |(_, label)| label)
+}
+
+fn line_numbers_job(code: &str, font_id: &FontId, theme: &Theme) -> LayoutJob {
+    let count = code.lines().count().max(1);
+    let width = count.to_string().len();
+    let numbers: Vec<String> = (1..=count).map(|n| format!("{n:>width$}")).collect();
+    LayoutJob::simple(
+        numbers.join("\n"),
+        font_id.clone(),
+        theme.muted_foreground().gamma_multiply(0.6),
+        f32::INFINITY,
+    )
+}
+
+/// Highlight `code` as `language` using colors from the theme
+///
+/// Supports `rust`/`rs` and `toml`; other languages are laid out as plain text.
+/// The returned job never wraps.
+#[must_use]
+pub fn highlight(code: &str, language: &str, font_id: &FontId, theme: &Theme) -> LayoutJob {
+    let mut job = LayoutJob::default();
+    job.wrap.max_width = f32::INFINITY;
+
+    let mut append = |text: &str, color: Color32| {
+        job.append(
+            text,
+            0.0,
+            TextFormat {
+                font_id: font_id.clone(),
+                color,
+                ..Default::default()
+            },
+        );
+    };
+
+    for (index, line) in code.lines().enumerate() {
+        if index > 0 {
+            append("\n", theme.foreground());
+        }
+        match normalize_language(language) {
+            "rust" => highlight_rust_line(line, theme, &mut append),
+            "toml" => highlight_toml_line(line, theme, &mut append),
+            _ => append(line, theme.foreground()),
+        }
+    }
+
+    job
+}
+
+fn highlight_rust_line(line: &str, theme: &Theme, append: &mut impl FnMut(&str, Color32)) {
+    let chars: Vec<char> = line.chars().collect();
+    let text = |start: usize, end: usize| chars[start..end].iter().collect::<String>();
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        let start = pos;
+        let c = chars[pos];
+
+        if c == '/' && chars.get(pos + 1) == Some(&'/') {
+            append(&text(pos, chars.len()), theme.muted_foreground());
+            break;
+        } else if c == '"' {
+            pos += 1;
+            while pos < chars.len() {
+                if chars[pos] == '\\' {
+                    pos += 2;
+                } else {
+                    pos += 1;
+                    if chars[pos - 1] == '"' {
+                        break;
+                    }
+                }
+            }
+            pos = pos.min(chars.len());
+            append(&text(start, pos), theme.chart_5());
+        } else if c.is_ascii_digit() {
+            while pos < chars.len()
+                && (chars[pos].is_ascii_alphanumeric() || chars[pos] == '.' || chars[pos] == '_')
+            {
+                pos += 1;
+            }
+            append(&text(start, pos), theme.chart_2());
+        } else if c.is_alphabetic() || c == '_' {
+            while pos < chars.len() && (chars[pos].is_alphanumeric() || chars[pos] == '_') {
+                pos += 1;
+            }
+            let word = text(start, pos);
+            let color = if RUST_KEYWORDS.contains(&word.as_str()) {
+                theme.chart_1()
+            } else if RUST_TYPES.contains(&word.as_str()) || c.is_uppercase() {
+                theme.chart_4()
+            } else if chars.get(pos) == Some(&'(') || chars.get(pos) == Some(&'!') {
+                theme.chart_3()
+            } else {
+                theme.foreground()
+            };
+            append(&word, color);
+        } else {
+            while pos < chars.len()
+                && !(chars[pos].is_alphanumeric() || chars[pos] == '_' || chars[pos] == '"')
+                && !(chars[pos] == '/' && chars.get(pos + 1) == Some(&'/'))
+            {
+                pos += 1;
+            }
+            pos = pos.max(start + 1);
+            append(&text(start, pos), theme.foreground());
+        }
+    }
+}
+
+fn highlight_toml_line(line: &str, theme: &Theme, append: &mut impl FnMut(&str, Color32)) {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with('#') {
+        append(line, theme.muted_foreground());
+    } else if trimmed.starts_with('[') {
+        append(line, theme.chart_4());
+    } else if let Some(eq) = line.find('=') {
+        append(&line[..eq], theme.chart_1());
+        append("=", theme.foreground());
+        let value = &line[eq + 1..];
+        let trimmed_value = value.trim();
+        let color = if trimmed_value.starts_with('"') || trimmed_value.starts_with('\'') {
+            theme.chart_5()
+        } else if trimmed_value == "true" || trimmed_value == "false" {
+            theme.chart_4()
+        } else if trimmed_value.starts_with(|c: char| c.is_ascii_digit()) {
+            theme.chart_2()
+        } else {
+            theme.foreground()
+        };
+        append(value, color);
+    } else {
+        append(line, theme.foreground());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sections(job: &LayoutJob) -> Vec<(&str, Color32)> {
+        job.sections
+            .iter()
+            .map(|s| (&job.text[s.byte_range.clone()], s.format.color))
+            .collect()
+    }
+
+    #[test]
+    fn test_highlight_rust_tokens() {
+        let theme = Theme::dark();
+        let job = highlight(
+            "let x = \"hi\"; // note",
+            "rust",
+            &FontId::monospace(13.0),
+            &theme,
+        );
+        let sections = sections(&job);
+
+        assert_eq!(job.text, "let x = \"hi\"; // note");
+        assert!(sections.contains(&("let", theme.chart_1())));
+        assert!(sections.contains(&("\"hi\"", theme.chart_5())));
+        assert!(sections.contains(&("// note", theme.muted_foreground())));
+    }
+
+    #[test]
+    fn test_highlight_plain_text_keeps_lines() {
+        let theme = Theme::dark();
+        let job = highlight("a\nb", "markdown", &FontId::monospace(13.0), &theme);
+
+        assert_eq!(job.text, "a\nb");
+        assert!(job
+            .sections
+            .iter()
+            .all(|s| s.format.color == theme.foreground()));
+    }
+}
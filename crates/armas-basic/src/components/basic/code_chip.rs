@@ -0,0 +1,138 @@
+//! Code Chip Component
+//!
+//! Inline `<code>`-style chip that copies its text to the clipboard when
+//! clicked and briefly shows a "Copied!" confirmation. For multi-line
+//! snippets use [`CodeBlock`](crate::CodeBlock).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::CodeChip;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! ui.horizontal(|ui| {
+//!     ui.label("Install with");
+//!     if CodeChip::new("cargo add armas").show(ui, &theme).copied {
+//!         // the command is now on the clipboard
+//!     }
+//! });
+//! # }
+//! ```
+
+use super::copy_button::{copy_confirming, copy_to_clipboard};
+use crate::ext::ArmasContextExt;
+use crate::Theme;
+use egui::{CursorIcon, FontId, Id, Response, Sense, Stroke, Ui, Vec2};
+
+// shadcn-style constants
+const FONT_SIZE: f32 = 13.0;
+const PADDING_X: f32 = 6.0; // px-1.5
+const PADDING_Y: f32 = 2.0; // py-0.5
+const CORNER_RADIUS: f32 = 4.0; // rounded
+const COPIED_LABEL: &str = "Copied!";
+
+/// Response from a code chip
+#[derive(Debug, Clone)]
+pub struct CodeChipResponse {
+    /// The response of the chip
+    pub response: Response,
+    /// Whether the chip was clicked and its text copied this frame
+    pub copied: bool,
+    /// Whether the "Copied!" confirmation is showing
+    pub confirming: bool,
+}
+
+/// Inline code chip with click-to-copy
+pub struct CodeChip {
+    id: Option<Id>,
+    text: String,
+}
+
+impl CodeChip {
+    /// Create a chip showing (and copying) `text`
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            text: text.into(),
+        }
+    }
+
+    /// Set custom ID (needed when the same text appears more than once)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Show the chip
+    pub fn show(self, ui: &mut Ui, theme: &Theme) -> CodeChipResponse {
+        let id = self
+            .id
+            .unwrap_or_else(|| ui.id().with(("code_chip", &self.text)));
+        let copy_id = id.with("copy_state");
+        let font_id = FontId::monospace(FONT_SIZE);
+        let mut confirming = copy_confirming(ui.ctx(), copy_id);
+
+        // Size for the wider of the two labels so the chip doesn't jump
+        let painter = ui.painter();
+        let text_galley =
+            painter.layout_no_wrap(self.text.clone(), font_id.clone(), theme.foreground());
+        let copied_galley =
+            painter.layout_no_wrap(COPIED_LABEL.to_string(), font_id, theme.muted_foreground());
+        let size = Vec2::new(
+            text_galley.size().x.max(copied_galley.size().x) + PADDING_X * 2.0,
+            text_galley.size().y + PADDING_Y * 2.0,
+        );
+
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+        response.widget_info(|| {
+            egui::WidgetInfo::labeled(egui::WidgetType::Button, ui.is_enabled(), &self.text)
+        });
+        let response = response.on_hover_cursor(CursorIcon::PointingHand);
+
+        let copied = response.clicked();
+        if copied {
+            copy_to_clipboard(ui.ctx(), copy_id, &self.text);
+            confirming = true;
+        }
+
+        if ui.is_rect_visible(rect) {
+            let bg = if response.hovered() {
+                theme.accent()
+            } else {
+                theme.muted()
+            };
+            ui.painter().rect_filled(rect, CORNER_RADIUS, bg);
+            ui.painter().rect_stroke(
+                rect,
+                CORNER_RADIUS,
+                Stroke::new(1.0, theme.border()),
+                egui::StrokeKind::Inside,
+            );
+
+            let (galley, color) = if confirming {
+                (copied_galley, theme.muted_foreground())
+            } else {
+                (text_galley, theme.foreground())
+            };
+            ui.painter()
+                .galley(rect.center() - galley.size() / 2.0, galley, color);
+        }
+
+        CodeChipResponse {
+            response,
+            copied,
+            confirming,
+        }
+    }
+}
+
+impl egui::Widget for CodeChip {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let theme = ui.ctx().armas_theme();
+        self.show(ui, &theme).response
+    }
+}
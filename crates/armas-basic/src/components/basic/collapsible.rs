@@ -0,0 +1,178 @@
+//! Collapsible Component
+//!
+//! Expand/collapse primitive styled after shadcn/ui Collapsible.
+//! A header closure toggles a body closure, whose height is revealed with a
+//! spring animation. Open state is kept in memory, keyed by id, so other
+//! components (like [`Accordion`](super::Accordion)) can build on it.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use armas_basic::Collapsible;
+//!
+//! let response = Collapsible::new("details").show(
+//!     ui,
+//!     |ui, _openness| ui.button("Details"),
+//!     |ui| {
+//!         ui.label("Hidden until the header is clicked");
+//!     },
+//! );
+//! if response.toggled {
+//!     // ...
+//! }
+//! # }
+//! ```
+
+use crate::animation::SpringAnimation;
+use egui::{vec2, Id, Rect, Response, Sense, Ui, UiBuilder};
+
+// Spring used for the height reveal
+const SPRING_STIFFNESS: f32 = 180.0;
+const SPRING_DAMPING: f32 = 22.0;
+/// Openness below which the body is not shown
+const HIDDEN_OPENNESS: f32 = 0.001;
+
+/// Response from a collapsible
+#[derive(Debug, Clone)]
+pub struct CollapsibleResponse {
+    /// Response returned by the header closure
+    pub header: Response,
+    /// Whether the body is open (its target state; the reveal may still be animating)
+    pub open: bool,
+    /// Whether the header toggled the state this frame
+    pub toggled: bool,
+    /// How far the body is revealed (0.0 closed - 1.0 open)
+    pub openness: f32,
+    /// Height the body occupies this frame
+    pub body_height: f32,
+}
+
+/// Persistent state of a collapsible
+#[derive(Clone, Debug)]
+struct CollapsibleState {
+    open: bool,
+    spring: SpringAnimation,
+    /// Full height of the body, measured the last time it was shown
+    body_height: f32,
+}
+
+impl CollapsibleState {
+    const fn new(open: bool) -> Self {
+        let value = if open { 1.0 } else { 0.0 };
+        Self {
+            open,
+            spring: SpringAnimation::new(value, value).params(SPRING_STIFFNESS, SPRING_DAMPING),
+            body_height: 0.0,
+        }
+    }
+}
+
+/// Header and body where clicking the header reveals or hides the body
+pub struct Collapsible {
+    id: Id,
+    default_open: bool,
+}
+
+impl Collapsible {
+    /// Create a collapsible with the given ID
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            default_open: false,
+        }
+    }
+
+    /// Start open (only used before the state is stored)
+    #[must_use]
+    pub const fn default_open(mut self, open: bool) -> Self {
+        self.default_open = open;
+        self
+    }
+
+    /// Whether the collapsible with this id is open
+    pub fn is_open(ctx: &egui::Context, id: impl Into<Id>) -> bool {
+        let state_id = Self::state_id(id.into());
+        ctx.data(|d| d.get_temp::<CollapsibleState>(state_id))
+            .is_some_and(|state| state.open)
+    }
+
+    /// Open or close the collapsible with this id; the body animates to the new state
+    pub fn set_open(ctx: &egui::Context, id: impl Into<Id>, open: bool) {
+        let state_id = Self::state_id(id.into());
+        ctx.data_mut(|d| {
+            d.get_temp_mut_or_insert_with(state_id, || CollapsibleState::new(false))
+                .open = open;
+        });
+    }
+
+    fn state_id(id: Id) -> Id {
+        id.with("collapsible_state")
+    }
+
+    /// Show the collapsible
+    ///
+    /// `header` receives the current openness (0.0-1.0, e.g. to rotate a
+    /// chevron) and returns a response; clicking it toggles the body.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        header: impl FnOnce(&mut Ui, f32) -> Response,
+        body: impl FnOnce(&mut Ui),
+    ) -> CollapsibleResponse {
+        let state_id = Self::state_id(self.id);
+        let mut state: CollapsibleState = ui
+            .ctx()
+            .data(|d| d.get_temp(state_id))
+            .unwrap_or_else(|| CollapsibleState::new(self.default_open));
+
+        let header = header(ui, state.spring.value.clamp(0.0, 1.0));
+        let toggled = header.clicked();
+        if toggled {
+            state.open = !state.open;
+        }
+
+        state.spring.set_target(if state.open { 1.0 } else { 0.0 });
+        state.spring.update(ui.input(|i| i.stable_dt));
+        let animating = !state.spring.is_settled(0.005, 0.1);
+        if !animating {
+            let target = state.spring.target;
+            state.spring.reset(target, target);
+        }
+        let openness = state.spring.value.clamp(0.0, 1.0);
+
+        let mut body_height = 0.0;
+        if openness > HIDDEN_OPENNESS {
+            // Lay the body out at full size, clipped to the revealed part
+            let top_left = ui.available_rect_before_wrap().min;
+            let width = ui.available_width();
+            let mut body_ui = ui.new_child(
+                UiBuilder::new()
+                    .id_salt(self.id.with("body"))
+                    .max_rect(Rect::from_min_size(top_left, vec2(width, f32::INFINITY))),
+            );
+            let revealed = Rect::from_min_size(top_left, vec2(width, state.body_height * openness));
+            body_ui.set_clip_rect(revealed.intersect(ui.clip_rect()));
+            body(&mut body_ui);
+
+            state.body_height = body_ui.min_rect().height();
+            body_height = state.body_height * openness;
+            ui.allocate_exact_size(vec2(width, body_height), Sense::hover());
+        }
+
+        if animating {
+            ui.ctx().request_repaint();
+        }
+        let open = state.open;
+        ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
+
+        CollapsibleResponse {
+            header,
+            open,
+            toggled,
+            openness,
+            body_height,
+        }
+    }
+}
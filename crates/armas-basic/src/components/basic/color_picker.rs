@@ -0,0 +1,453 @@
+//! Color Picker Component
+//!
+//! Color selection styled like shadcn/ui form controls.
+//! Features:
+//! - Saturation/value square with hue strip
+//! - Optional alpha slider
+//! - Hex input with round-trip to HSV
+//! - Compact swatch trigger that opens the picker in a popover
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::ColorPicker;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let mut color = egui::Color32::from_rgb(59, 130, 246);
+//!
+//! let response = ColorPicker::new("accent")
+//!     .alpha(true)
+//!     .show(ui, &mut color, &theme);
+//!
+//! if response.changed {
+//!     // use `color`
+//! }
+//! # }
+//! ```
+
+use crate::color::{from_hex, from_hsv, to_hex, to_hsv, with_alpha};
+use crate::{Input, Popover, PopoverPosition, Theme};
+use egui::{pos2, vec2, Color32, Id, Mesh, Rect, Response, Sense, Stroke, Ui};
+
+// shadcn-style constants
+const PICKER_WIDTH: f32 = 224.0; // w-56
+const SV_HEIGHT: f32 = 144.0; // h-36
+const STRIP_HEIGHT: f32 = 12.0; // h-3
+const SWATCH_SIZE: f32 = 32.0; // size-8
+const PREVIEW_SIZE: f32 = 24.0; // size-6
+const THUMB_RADIUS: f32 = 6.0;
+const CORNER_RADIUS: f32 = 6.0; // rounded-md
+const GAP: f32 = 12.0; // gap-3
+const CHECKER_SIZE: f32 = 4.0;
+const MESH_STEPS: usize = 24;
+
+/// Editable HSV state behind a [`ColorPicker`]
+///
+/// Kept separately from the `Color32` so the hue survives while saturation
+/// or value are at zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorPickerState {
+    /// Hue in degrees (0-360)
+    pub hue: f32,
+    /// Saturation (0-1)
+    pub saturation: f32,
+    /// Value / brightness (0-1)
+    pub value: f32,
+    /// Alpha (0-1)
+    pub alpha: f32,
+    /// Text in the hex field
+    pub hex: String,
+}
+
+impl ColorPickerState {
+    /// Create state from a color
+    #[must_use]
+    pub fn from_color(color: Color32) -> Self {
+        let (hue, saturation, value) = to_hsv(color);
+        let alpha = f32::from(color.to_srgba_unmultiplied()[3]) / 255.0;
+        let mut state = Self {
+            hue,
+            saturation,
+            value,
+            alpha,
+            hex: String::new(),
+        };
+        state.sync_hex();
+        state
+    }
+
+    /// The color described by the current HSV and alpha values
+    #[must_use]
+    pub fn color(&self) -> Color32 {
+        with_alpha(
+            from_hsv(self.hue, self.saturation, self.value),
+            (self.alpha.clamp(0.0, 1.0) * 255.0).round() as u8,
+        )
+    }
+
+    /// Parse a hex string and update the HSV fields
+    ///
+    /// Returns false (leaving the color unchanged) if the text isn't a valid hex color.
+    pub fn set_hex(&mut self, hex: &str) -> bool {
+        self.hex = hex.to_string();
+        let Some(color) = from_hex(hex) else {
+            return false;
+        };
+
+        let (hue, saturation, value) = to_hsv(color);
+        // Keep the current hue for grays, where HSV hue is undefined
+        if saturation > 0.0 && value > 0.0 {
+            self.hue = hue;
+        }
+        self.saturation = saturation;
+        self.value = value;
+        self.alpha = f32::from(color.to_srgba_unmultiplied()[3]) / 255.0;
+        true
+    }
+
+    /// Refresh the hex text from the HSV fields
+    fn sync_hex(&mut self) {
+        let color = self.color();
+        self.hex = to_hex(color, color.a() < 255);
+    }
+}
+
+/// Response from a color picker
+#[derive(Debug, Clone)]
+pub struct ColorPickerResponse {
+    /// The UI response (the swatch in compact mode, the panel otherwise)
+    pub response: Response,
+    /// The chosen color
+    pub color: Color32,
+    /// Whether the color changed this frame
+    pub changed: bool,
+}
+
+/// Color picker component
+///
+/// Shows an inline saturation/value square, hue strip, optional alpha strip
+/// and hex input. In compact mode only a swatch is shown; clicking it opens
+/// the same controls in a popover.
+pub struct ColorPicker {
+    id: Id,
+    alpha: bool,
+    compact: bool,
+    width: f32,
+}
+
+impl ColorPicker {
+    /// Create a new color picker
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            alpha: false,
+            compact: false,
+            width: PICKER_WIDTH,
+        }
+    }
+
+    /// Show an alpha slider and include alpha in the hex value
+    #[must_use]
+    pub const fn alpha(mut self, alpha: bool) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Show a swatch trigger that opens the picker in a popover
+    #[must_use]
+    pub const fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Set the width of the picker panel
+    #[must_use]
+    pub const fn width(mut self, width: f32) -> Self {
+        self.width = width.max(120.0);
+        self
+    }
+
+    /// Show the color picker
+    pub fn show(self, ui: &mut Ui, color: &mut Color32, theme: &Theme) -> ColorPickerResponse {
+        let state_id = self.id.with("state");
+
+        // Re-derive state when the color was changed from outside
+        let mut state = ui
+            .ctx()
+            .data(|d| d.get_temp::<ColorPickerState>(state_id))
+            .filter(|state| state.color() == *color)
+            .unwrap_or_else(|| ColorPickerState::from_color(*color));
+
+        let (response, changed) = if self.compact {
+            self.show_compact(ui, &mut state, theme)
+        } else {
+            let inner = ui.vertical(|ui| self.show_panel(ui, &mut state, theme));
+            (inner.response, inner.inner)
+        };
+
+        if changed {
+            *color = state.color();
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
+
+        ColorPickerResponse {
+            response,
+            color: *color,
+            changed,
+        }
+    }
+
+    /// Swatch trigger with the picker panel in a popover
+    fn show_compact(
+        &self,
+        ui: &mut Ui,
+        state: &mut ColorPickerState,
+        theme: &Theme,
+    ) -> (Response, bool) {
+        let open_id = self.id.with("open");
+        let mut is_open = ui
+            .ctx()
+            .data(|d| d.get_temp::<bool>(open_id))
+            .unwrap_or(false);
+
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(SWATCH_SIZE, SWATCH_SIZE), Sense::click());
+        if ui.is_rect_visible(rect) {
+            paint_checkerboard(ui.painter(), rect.shrink(3.0), theme);
+            ui.painter()
+                .rect_filled(rect.shrink(3.0), CORNER_RADIUS - 2.0, state.color());
+            let border = if response.hovered() || is_open {
+                theme.ring()
+            } else {
+                theme.input()
+            };
+            ui.painter().rect_stroke(
+                rect,
+                CORNER_RADIUS,
+                Stroke::new(1.0, border),
+                egui::StrokeKind::Inside,
+            );
+        }
+
+        if response.clicked() {
+            is_open = !is_open;
+        }
+
+        let mut changed = false;
+        let mut popover = Popover::new(self.id.with("popover"))
+            .position(PopoverPosition::Bottom)
+            .width(self.width + GAP * 2.0);
+        popover.set_open(is_open);
+        let popover_response = popover.show(ui.ctx(), theme, rect, |ui| {
+            changed = self.show_panel(ui, state, theme);
+        });
+
+        if popover_response.clicked_outside || popover_response.should_close {
+            is_open = false;
+        }
+        ui.ctx().data_mut(|d| d.insert_temp(open_id, is_open));
+
+        (response, changed)
+    }
+
+    /// Full picker: SV square, hue strip, optional alpha strip, hex row
+    fn show_panel(&self, ui: &mut Ui, state: &mut ColorPickerState, theme: &Theme) -> bool {
+        ui.spacing_mut().item_spacing.y = GAP;
+        let mut changed = false;
+
+        changed |= self.sv_square(ui, state);
+        changed |= self.hue_strip(ui, state);
+        if self.alpha {
+            changed |= self.alpha_strip(ui, state, theme);
+        }
+        if changed {
+            state.sync_hex();
+        }
+
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 8.0;
+
+            let (preview_rect, _) =
+                ui.allocate_exact_size(vec2(PREVIEW_SIZE, PREVIEW_SIZE), Sense::hover());
+            paint_checkerboard(ui.painter(), preview_rect, theme);
+            ui.painter()
+                .rect_filled(preview_rect, CORNER_RADIUS - 2.0, state.color());
+            ui.painter().rect_stroke(
+                preview_rect,
+                CORNER_RADIUS - 2.0,
+                Stroke::new(1.0, theme.border()),
+                egui::StrokeKind::Inside,
+            );
+
+            let mut hex = state.hex.clone();
+            let input_response = Input::new("#RRGGBB")
+                .width(self.width - PREVIEW_SIZE - 8.0)
+                .show(ui, &mut hex, theme);
+            if input_response.changed && state.set_hex(&hex) {
+                changed = true;
+            } else if input_response.changed {
+                state.hex = hex;
+            }
+        });
+
+        changed
+    }
+
+    fn sv_square(&self, ui: &mut Ui, state: &mut ColorPickerState) -> bool {
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(self.width, SV_HEIGHT), Sense::click_and_drag());
+
+        let mut changed = false;
+        if response.dragged() || response.clicked() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                state.saturation = ((pos.x - rect.min.x) / rect.width()).clamp(0.0, 1.0);
+                state.value = 1.0 - ((pos.y - rect.min.y) / rect.height()).clamp(0.0, 1.0);
+                changed = true;
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            let mut mesh = Mesh::default();
+            for row in 0..=MESH_STEPS {
+                let v = 1.0 - row as f32 / MESH_STEPS as f32;
+                for col in 0..=MESH_STEPS {
+                    let s = col as f32 / MESH_STEPS as f32;
+                    let pos = pos2(
+                        s.mul_add(rect.width(), rect.min.x),
+                        (1.0 - v).mul_add(rect.height(), rect.min.y),
+                    );
+                    mesh.colored_vertex(pos, from_hsv(state.hue, s, v));
+                }
+            }
+            add_grid_triangles(&mut mesh, MESH_STEPS, MESH_STEPS);
+            ui.painter().add(mesh);
+
+            let thumb = pos2(
+                state.saturation.mul_add(rect.width(), rect.min.x),
+                (1.0 - state.value).mul_add(rect.height(), rect.min.y),
+            );
+            paint_thumb(
+                ui.painter(),
+                thumb,
+                from_hsv(state.hue, state.saturation, state.value),
+            );
+        }
+
+        changed
+    }
+
+    fn hue_strip(&self, ui: &mut Ui, state: &mut ColorPickerState) -> bool {
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(self.width, STRIP_HEIGHT), Sense::click_and_drag());
+
+        let mut changed = false;
+        if response.dragged() || response.clicked() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                state.hue = ((pos.x - rect.min.x) / rect.width()).clamp(0.0, 1.0) * 360.0;
+                changed = true;
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            let mut mesh = Mesh::default();
+            for y in [rect.min.y, rect.max.y] {
+                for i in 0..=MESH_STEPS {
+                    let t = i as f32 / MESH_STEPS as f32;
+                    let x = t.mul_add(rect.width(), rect.min.x);
+                    mesh.colored_vertex(pos2(x, y), from_hsv(t * 360.0, 1.0, 1.0));
+                }
+            }
+            add_grid_triangles(&mut mesh, 1, MESH_STEPS);
+            ui.painter().add(mesh);
+
+            let thumb = pos2(
+                (state.hue / 360.0).mul_add(rect.width(), rect.min.x),
+                rect.center().y,
+            );
+            paint_thumb(ui.painter(), thumb, from_hsv(state.hue, 1.0, 1.0));
+        }
+
+        changed
+    }
+
+    fn alpha_strip(&self, ui: &mut Ui, state: &mut ColorPickerState, theme: &Theme) -> bool {
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(self.width, STRIP_HEIGHT), Sense::click_and_drag());
+
+        let mut changed = false;
+        if response.dragged() || response.clicked() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                state.alpha = ((pos.x - rect.min.x) / rect.width()).clamp(0.0, 1.0);
+                changed = true;
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            paint_checkerboard(ui.painter(), rect, theme);
+
+            let opaque = from_hsv(state.hue, state.saturation, state.value);
+            let mut mesh = Mesh::default();
+            for y in [rect.min.y, rect.max.y] {
+                mesh.colored_vertex(pos2(rect.min.x, y), with_alpha(opaque, 0));
+                mesh.colored_vertex(pos2(rect.max.x, y), opaque);
+            }
+            add_grid_triangles(&mut mesh, 1, 1);
+            ui.painter().add(mesh);
+
+            let thumb = pos2(
+                state.alpha.mul_add(rect.width(), rect.min.x),
+                rect.center().y,
+            );
+            paint_thumb(ui.painter(), thumb, state.color());
+        }
+
+        changed
+    }
+}
+
+/// Triangulate a (rows+1) x (cols+1) vertex grid laid out row by row
+fn add_grid_triangles(mesh: &mut Mesh, rows: usize, cols: usize) {
+    let stride = (cols + 1) as u32;
+    for row in 0..rows as u32 {
+        for col in 0..cols as u32 {
+            let a = row * stride + col;
+            let b = a + 1;
+            let c = a + stride;
+            let d = c + 1;
+            mesh.add_triangle(a, b, c);
+            mesh.add_triangle(b, d, c);
+        }
+    }
+}
+
+/// Checkerboard backdrop that makes transparency visible
+pub(crate) fn paint_checkerboard(painter: &egui::Painter, rect: Rect, theme: &Theme) {
+    let painter = painter.with_clip_rect(rect);
+    painter.rect_filled(rect, 0.0, theme.background());
+    let cols = (rect.width() / CHECKER_SIZE).ceil() as usize;
+    let rows = (rect.height() / CHECKER_SIZE).ceil() as usize;
+    for row in 0..rows {
+        for col in (row % 2..cols).step_by(2) {
+            let min = rect.min + vec2(col as f32, row as f32) * CHECKER_SIZE;
+            painter.rect_filled(
+                Rect::from_min_size(min, vec2(CHECKER_SIZE, CHECKER_SIZE)),
+                0.0,
+                theme.muted(),
+            );
+        }
+    }
+}
+
+fn paint_thumb(painter: &egui::Painter, center: egui::Pos2, fill: Color32) {
+    painter.circle_filled(center, THUMB_RADIUS, fill);
+    painter.circle_stroke(center, THUMB_RADIUS, Stroke::new(2.0, Color32::WHITE));
+    painter.circle_stroke(
+        center,
+        THUMB_RADIUS + 1.0,
+        Stroke::new(1.0, Color32::from_black_alpha(80)),
+    );
+}
@@ -0,0 +1,132 @@
+//! Copy Button Component
+//!
+//! Copy-to-clipboard with a transient "Copied!" confirmation.
+//! The time of the last copy is kept in egui memory under the caller's id,
+//! so each button confirms independently. [`CodeBlock`](crate::CodeBlock),
+//! [`CodeChip`](crate::CodeChip) and the docs site use the same helpers, so
+//! every confirmation lasts [`COPIED_DURATION`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::CopyButton;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! CopyButton::new("cargo add armas").show(ui, &theme);
+//! # }
+//! ```
+
+use crate::Theme;
+use egui::{Context, CursorIcon, Id, Response, RichText, Sense, Ui};
+
+/// How long the confirmation stays up after a copy, in seconds
+pub const COPIED_DURATION: f64 = 1.5;
+
+const FONT_SIZE: f32 = 12.0;
+
+/// Copy `text` to the clipboard and start the confirmation for `id`
+pub fn copy_to_clipboard(ctx: &Context, id: Id, text: &str) {
+    ctx.copy_text(text.to_string());
+    let now = ctx.input(|i| i.time);
+    ctx.data_mut(|d| d.insert_temp(id, now));
+
+    // For WASM, also use web Clipboard API
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(window) = web_sys::window() {
+            let promise = window.navigator().clipboard().write_text(text);
+            let _ = wasm_bindgen_futures::JsFuture::from(promise);
+        }
+    }
+}
+
+/// Whether the confirmation for `id` is showing
+///
+/// Schedules a repaint for when it expires so the label swaps back.
+#[must_use]
+pub fn copy_confirming(ctx: &Context, id: Id) -> bool {
+    let copied_at: Option<f64> = ctx.data(|d| d.get_temp(id));
+    let now = ctx.input(|i| i.time);
+    let remaining = copied_at.map_or(0.0, |t| COPIED_DURATION - (now - t));
+    if remaining > 0.0 {
+        ctx.request_repaint_after_secs(remaining as f32);
+    }
+    remaining > 0.0
+}
+
+/// Small "Copy" text button that reads "Copied!" for a moment after a click
+pub struct CopyButton {
+    id: Option<Id>,
+    text: String,
+}
+
+impl CopyButton {
+    /// Create a button that copies `text`
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            text: text.into(),
+        }
+    }
+
+    /// Set custom ID (important when using multiple copy buttons)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Show the button
+    pub fn show(self, ui: &mut Ui, theme: &Theme) -> Response {
+        let id = self.id.unwrap_or_else(|| ui.id().with("copy_button"));
+        let label = if copy_confirming(ui.ctx(), id) {
+            "Copied!"
+        } else {
+            "Copy"
+        };
+        let response = ui
+            .add(
+                egui::Label::new(
+                    RichText::new(label)
+                        .size(FONT_SIZE)
+                        .color(theme.muted_foreground()),
+                )
+                .sense(Sense::click()),
+            )
+            .on_hover_cursor(CursorIcon::PointingHand);
+
+        if response.clicked() {
+            copy_to_clipboard(ui.ctx(), id, &self.text);
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_at(ctx: &Context, time: f64, mut f: impl FnMut(&Context)) {
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| f(ctx));
+    }
+
+    #[test]
+    fn test_confirmation_expires_after_duration() {
+        let ctx = Context::default();
+        let id = Id::new("demo").with("copy_state");
+
+        run_at(&ctx, 10.0, |ctx| assert!(!copy_confirming(ctx, id)));
+        run_at(&ctx, 10.0, |ctx| copy_to_clipboard(ctx, id, "let x = 1;"));
+        run_at(&ctx, 11.0, |ctx| assert!(copy_confirming(ctx, id)));
+        run_at(&ctx, 10.0 + COPIED_DURATION + 0.1, |ctx| {
+            assert!(!copy_confirming(ctx, id));
+        });
+    }
+}
@@ -113,6 +113,29 @@ impl Date {
         ((h + 6) % 7) as u32
     }
 
+    /// The following calendar day
+    #[must_use]
+    pub const fn next_day(&self) -> Self {
+        if self.day < Self::days_in_month(self.year, self.month) {
+            Self {
+                day: self.day + 1,
+                ..*self
+            }
+        } else if self.month < 12 {
+            Self {
+                year: self.year,
+                month: self.month + 1,
+                day: 1,
+            }
+        } else {
+            Self {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+            }
+        }
+    }
+
     /// Format as human-readable (e.g., "January 15, 2024")
     #[must_use]
     pub fn format_display(&self) -> String {
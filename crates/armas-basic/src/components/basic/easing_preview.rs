@@ -0,0 +1,364 @@
+//! Easing Preview Component
+//!
+//! Plots an [`EasingFunction`] over [0, 1] for designing animations.
+//! Features:
+//! - Dropdown to switch between the built-in easing functions
+//! - Dot moving along the curve to show the animation phase
+//! - Draggable control points for cubic-bezier easings
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use armas_basic::animation::EasingFunction;
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::EasingPreview;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let mut easing = EasingFunction::Cubic { x1: 0.25, y1: 0.1, x2: 0.25, y2: 1.0 };
+//!
+//! let response = EasingPreview::new("easing").show(ui, &mut easing, &theme);
+//! if let Some([x1, y1, x2, y2]) = response.bezier {
+//!     ui.label(format!("cubic-bezier({x1:.2}, {y1:.2}, {x2:.2}, {y2:.2})"));
+//! }
+//! # }
+//! ```
+
+use crate::animation::EasingFunction;
+use crate::{Select, SelectOption, Theme};
+use egui::{pos2, vec2, Id, Pos2, Rect, Response, Sense, Shape, Stroke, Ui};
+
+// shadcn-style constants
+const PLOT_SIZE: f32 = 224.0; // size-56
+const CORNER_RADIUS: f32 = 6.0; // rounded-md
+const PLOT_PADDING: f32 = 12.0;
+const GAP: f32 = 8.0; // gap-2
+const SELECT_HEIGHT: f32 = 32.0; // h-8
+const CURVE_WIDTH: f32 = 2.0;
+const CURVE_SAMPLES: usize = 64;
+const DOT_RADIUS: f32 = 4.0;
+const HANDLE_RADIUS: f32 = 5.0;
+const HANDLE_HIT_SIZE: f32 = 16.0;
+/// Values plotted below 0 and above 1, room for elastic overshoot
+const OVERSHOOT: f32 = 0.25;
+const DEFAULT_DURATION: f32 = 1.2;
+/// Rest at the end of each animation cycle, in seconds
+const CYCLE_PAUSE: f32 = 0.4;
+
+/// Easing functions offered in the dropdown, with their option values
+const PRESETS: &[(&str, &str, EasingFunction)] = &[
+    ("linear", "Linear", EasingFunction::Linear),
+    ("ease_in", "Ease In", EasingFunction::EaseIn),
+    ("ease_out", "Ease Out", EasingFunction::EaseOut),
+    ("ease_in_out", "Ease In Out", EasingFunction::EaseInOut),
+    ("quad_in", "Quad In", EasingFunction::QuadIn),
+    ("quad_out", "Quad Out", EasingFunction::QuadOut),
+    ("quad_in_out", "Quad In Out", EasingFunction::QuadInOut),
+    ("cubic_in", "Cubic In", EasingFunction::CubicIn),
+    ("cubic_out", "Cubic Out", EasingFunction::CubicOut),
+    ("cubic_in_out", "Cubic In Out", EasingFunction::CubicInOut),
+    ("expo_in", "Expo In", EasingFunction::ExpoIn),
+    ("expo_out", "Expo Out", EasingFunction::ExpoOut),
+    ("expo_in_out", "Expo In Out", EasingFunction::ExpoInOut),
+    ("elastic_in", "Elastic In", EasingFunction::ElasticIn),
+    ("elastic_out", "Elastic Out", EasingFunction::ElasticOut),
+    ("bounce_out", "Bounce Out", EasingFunction::BounceOut),
+    // CSS `ease`
+    (
+        "cubic_bezier",
+        "Cubic Bezier",
+        EasingFunction::Cubic {
+            x1: 0.25,
+            y1: 0.1,
+            x2: 0.25,
+            y2: 1.0,
+        },
+    ),
+];
+
+/// Response from an easing preview
+#[derive(Debug, Clone)]
+pub struct EasingPreviewResponse {
+    /// Response of the plot
+    pub response: Response,
+    /// Whether the easing was switched or its control points moved this frame
+    pub changed: bool,
+    /// Control points `[x1, y1, x2, y2]` when the easing is a cubic bezier
+    pub bezier: Option<[f32; 4]>,
+    /// Current animation phase (0.0-1.0) shown by the moving dot
+    pub phase: f32,
+}
+
+/// Plot of an easing curve with a function picker and bezier handles
+pub struct EasingPreview {
+    id: Id,
+    size: f32,
+    selector: bool,
+    animate: bool,
+    duration: f32,
+}
+
+impl EasingPreview {
+    /// Create a new easing preview
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            size: PLOT_SIZE,
+            selector: true,
+            animate: true,
+            duration: DEFAULT_DURATION,
+        }
+    }
+
+    /// Set the width and height of the plot
+    #[must_use]
+    pub const fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Show the dropdown for switching easing functions (default: true)
+    #[must_use]
+    pub const fn selector(mut self, show: bool) -> Self {
+        self.selector = show;
+        self
+    }
+
+    /// Animate a dot along the curve (default: true)
+    #[must_use]
+    pub const fn animate(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+
+    /// Set the duration of one animation cycle in seconds
+    #[must_use]
+    pub const fn duration(mut self, seconds: f32) -> Self {
+        self.duration = seconds;
+        self
+    }
+
+    /// Show the preview, editing `easing` in place
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        easing: &mut EasingFunction,
+        theme: &Theme,
+    ) -> EasingPreviewResponse {
+        let mut changed = false;
+        let previous_spacing = ui.spacing().item_spacing.y;
+        ui.spacing_mut().item_spacing.y = GAP;
+
+        if self.selector {
+            let options = PRESETS
+                .iter()
+                .map(|(value, label, _)| SelectOption::new(*value, *label))
+                .collect();
+            let response = Select::new(options)
+                .id(self.id.with("easing_select"))
+                .selected(preset_value(*easing))
+                .searchable(false)
+                .width(self.size)
+                .height(SELECT_HEIGHT)
+                .show(ui, theme);
+            if let Some(value) = response.selected_value.filter(|_| response.changed) {
+                if let Some((_, _, preset)) = PRESETS.iter().find(|(v, _, _)| *v == value) {
+                    if preset_value(*easing) != value {
+                        *easing = *preset;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        let (rect, response) = ui.allocate_exact_size(vec2(self.size, self.size), Sense::hover());
+        ui.spacing_mut().item_spacing.y = previous_spacing;
+        let plot = rect.shrink(PLOT_PADDING);
+
+        // Drag bezier control points
+        if let EasingFunction::Cubic { x1, y1, x2, y2 } = easing {
+            for (index, (x, y)) in [(x1, y1), (x2, y2)].into_iter().enumerate() {
+                let handle = Rect::from_center_size(
+                    to_screen(plot, *x, *y),
+                    vec2(HANDLE_HIT_SIZE, HANDLE_HIT_SIZE),
+                );
+                let handle_response = ui.interact(
+                    handle,
+                    self.id.with(("control_point", index)),
+                    Sense::drag(),
+                );
+                if handle_response.dragged() {
+                    if let Some(pointer) = handle_response.interact_pointer_pos() {
+                        let (new_x, new_y) = from_screen(plot, pointer);
+                        *x = new_x.clamp(0.0, 1.0);
+                        *y = new_y.clamp(-OVERSHOOT, 1.0 + OVERSHOOT);
+                        changed = true;
+                    }
+                }
+                if handle_response.hovered() || handle_response.dragged() {
+                    ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+                }
+            }
+        }
+
+        let phase = if self.animate && self.duration > 0.0 {
+            ui.ctx().request_repaint();
+            let cycle = self.duration + CYCLE_PAUSE;
+            let time = (ui.input(|i| i.time) % f64::from(cycle)) as f32;
+            (time / self.duration).min(1.0)
+        } else {
+            1.0
+        };
+
+        if ui.is_rect_visible(rect) {
+            self.paint(ui, rect, plot, *easing, phase, theme);
+        }
+
+        let bezier = match *easing {
+            EasingFunction::Cubic { x1, y1, x2, y2 } => Some([x1, y1, x2, y2]),
+            _ => None,
+        };
+        EasingPreviewResponse {
+            response,
+            changed,
+            bezier,
+            phase,
+        }
+    }
+
+    fn paint(
+        &self,
+        ui: &Ui,
+        rect: Rect,
+        plot: Rect,
+        easing: EasingFunction,
+        phase: f32,
+        theme: &Theme,
+    ) {
+        let painter = ui.painter();
+        painter.rect_filled(rect, CORNER_RADIUS, theme.card());
+        painter.rect_stroke(
+            rect,
+            CORNER_RADIUS,
+            Stroke::new(1.0, theme.border()),
+            egui::StrokeKind::Inside,
+        );
+
+        // 0 and 1 guides, with a linear reference diagonal
+        let guide = Stroke::new(1.0, theme.border());
+        for y in [0.0, 1.0] {
+            painter.line_segment([to_screen(plot, 0.0, y), to_screen(plot, 1.0, y)], guide);
+        }
+        painter.add(Shape::dashed_line(
+            &[to_screen(plot, 0.0, 0.0), to_screen(plot, 1.0, 1.0)],
+            guide,
+            4.0,
+            4.0,
+        ));
+
+        // Bezier control arms
+        if let EasingFunction::Cubic { x1, y1, x2, y2 } = easing {
+            let arm = Stroke::new(1.0, theme.muted_foreground());
+            let p1 = to_screen(plot, x1, y1);
+            let p2 = to_screen(plot, x2, y2);
+            painter.line_segment([to_screen(plot, 0.0, 0.0), p1], arm);
+            painter.line_segment([to_screen(plot, 1.0, 1.0), p2], arm);
+            for point in [p1, p2] {
+                painter.circle(point, HANDLE_RADIUS, theme.background(), arm);
+            }
+        }
+
+        painter.add(Shape::line(
+            curve_points(easing, plot),
+            Stroke::new(CURVE_WIDTH, theme.primary()),
+        ));
+
+        if self.animate {
+            let dot = to_screen(plot, phase, easing.apply(phase));
+            painter.circle_filled(dot, DOT_RADIUS, theme.primary());
+        }
+    }
+}
+
+/// Option value of an easing in the dropdown
+fn preset_value(easing: EasingFunction) -> &'static str {
+    if matches!(easing, EasingFunction::Cubic { .. }) {
+        return "cubic_bezier";
+    }
+    PRESETS
+        .iter()
+        .find(|(_, _, preset)| *preset == easing)
+        .map_or("linear", |(value, _, _)| value)
+}
+
+/// Screen position of a point on the curve (`x` is time, `y` is progress)
+fn to_screen(plot: Rect, x: f32, y: f32) -> Pos2 {
+    let range = 1.0 + 2.0 * OVERSHOOT;
+    pos2(
+        plot.left() + x * plot.width(),
+        plot.bottom() - (y + OVERSHOOT) / range * plot.height(),
+    )
+}
+
+/// Inverse of [`to_screen`]
+fn from_screen(plot: Rect, pos: Pos2) -> (f32, f32) {
+    let range = 1.0 + 2.0 * OVERSHOOT;
+    (
+        (pos.x - plot.left()) / plot.width(),
+        (plot.bottom() - pos.y) / plot.height() * range - OVERSHOOT,
+    )
+}
+
+/// Polyline of the easing curve over [0, 1]
+fn curve_points(easing: EasingFunction, plot: Rect) -> Vec<Pos2> {
+    (0..=CURVE_SAMPLES)
+        .map(|i| {
+            let t = i as f32 / CURVE_SAMPLES as f32;
+            to_screen(plot, t, easing.apply(t))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curves_pass_through_endpoints() {
+        let plot = Rect::from_min_size(pos2(10.0, 20.0), vec2(200.0, 200.0));
+        let start = to_screen(plot, 0.0, 0.0);
+        let end = to_screen(plot, 1.0, 1.0);
+
+        for (value, _, easing) in PRESETS {
+            let points = curve_points(*easing, plot);
+            let first = points[0];
+            let last = points[points.len() - 1];
+            assert!(
+                (first - start).length() < 0.5,
+                "{value} starts at {first:?}"
+            );
+            assert!((last - end).length() < 0.5, "{value} ends at {last:?}");
+        }
+    }
+
+    #[test]
+    fn test_screen_mapping_round_trips() {
+        let plot = Rect::from_min_size(pos2(10.0, 20.0), vec2(200.0, 160.0));
+        let (x, y) = from_screen(plot, to_screen(plot, 0.3, 1.1));
+        assert!((x - 0.3).abs() < 1e-5);
+        assert!((y - 1.1).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_preset_value_matches_variant() {
+        assert_eq!(preset_value(EasingFunction::BounceOut), "bounce_out");
+        let custom = EasingFunction::Cubic {
+            x1: 0.5,
+            y1: 0.0,
+            x2: 0.5,
+            y2: 1.0,
+        };
+        assert_eq!(preset_value(custom), "cubic_bezier");
+    }
+}
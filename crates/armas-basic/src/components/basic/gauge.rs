@@ -0,0 +1,262 @@
+//! Gauge Component
+//!
+//! Dashboard-style arc meter with colored threshold zones, a needle and a
+//! center value label. Unlike `CircularProgressBar`, the arc covers a
+//! configurable angular span and maps an arbitrary value range.
+
+use super::progress::arc_points;
+use crate::Theme;
+use egui::{Color32, Pos2, Response, Sense, Shape, Stroke, Ui, Vec2};
+
+const GAUGE_SIZE: f32 = 160.0;
+const GAUGE_STROKE: f32 = 10.0;
+const DEFAULT_SPAN: f32 = 270.0;
+/// Opacity multiplier for zone tints on the track
+const ZONE_TINT: f32 = 0.35;
+const NEEDLE_WIDTH: f32 = 2.0;
+const HUB_RADIUS: f32 = 5.0;
+/// Line segments per full circle of arc
+const ARC_RESOLUTION: f32 = 96.0;
+
+/// A colored value range drawn on the gauge track
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaugeZone {
+    /// Start of the range
+    pub from: f32,
+    /// End of the range
+    pub to: f32,
+    /// Zone color
+    pub color: Color32,
+}
+
+impl GaugeZone {
+    /// Create a zone covering `from..to` in value units
+    #[must_use]
+    pub const fn new(from: f32, to: f32, color: Color32) -> Self {
+        Self { from, to, color }
+    }
+}
+
+/// Arc gauge with threshold zones and a needle
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::Ui;
+/// # fn example(ui: &mut Ui) {
+/// use armas_basic::{Gauge, GaugeZone};
+/// use armas_basic::ext::ArmasContextExt;
+///
+/// let theme = ui.ctx().armas_theme();
+/// Gauge::new(72.0, 0.0, 100.0)
+///     .span_degrees(270.0)
+///     .zones(&[
+///         GaugeZone::new(0.0, 60.0, theme.chart_2()),
+///         GaugeZone::new(60.0, 85.0, theme.chart_3()),
+///         GaugeZone::new(85.0, 100.0, theme.destructive()),
+///     ])
+///     .suffix("%")
+///     .show(ui, &theme);
+/// # }
+/// ```
+pub struct Gauge {
+    value: f32,
+    min: f32,
+    max: f32,
+    span: f32,
+    zones: Vec<GaugeZone>,
+    size: f32,
+    stroke_width: f32,
+    show_value: bool,
+    suffix: String,
+    decimals: usize,
+}
+
+impl Gauge {
+    /// Create a gauge showing `value` within `min..max`
+    #[must_use]
+    pub const fn new(value: f32, min: f32, max: f32) -> Self {
+        Self {
+            value,
+            min,
+            max,
+            span: DEFAULT_SPAN,
+            zones: Vec::new(),
+            size: GAUGE_SIZE,
+            stroke_width: GAUGE_STROKE,
+            show_value: true,
+            suffix: String::new(),
+            decimals: 0,
+        }
+    }
+
+    /// Set the angular span of the arc in degrees (default: 270)
+    ///
+    /// The arc is centered on the top of the gauge, with the gap at the bottom.
+    #[must_use]
+    pub const fn span_degrees(mut self, degrees: f32) -> Self {
+        self.span = degrees.clamp(1.0, 360.0);
+        self
+    }
+
+    /// Set the threshold zones that tint the track
+    #[must_use]
+    pub fn zones(mut self, zones: &[GaugeZone]) -> Self {
+        self.zones = zones.to_vec();
+        self
+    }
+
+    /// Set the gauge size (diameter)
+    #[must_use]
+    pub const fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the arc stroke width
+    #[must_use]
+    pub const fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Show the value in the center (default: true)
+    #[must_use]
+    pub const fn show_value(mut self, show: bool) -> Self {
+        self.show_value = show;
+        self
+    }
+
+    /// Set a unit suffix for the value label (e.g. "%", " dB")
+    #[must_use]
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Set the number of decimals in the value label
+    #[must_use]
+    pub const fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Show the gauge
+    pub fn show(self, ui: &mut Ui, theme: &Theme) -> Response {
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(self.size), Sense::hover());
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            let center = rect.center();
+            let radius = (self.size - self.stroke_width) / 2.0;
+
+            // Track, tinted by the zones
+            self.draw_arc(ui, center, radius, self.min, self.max, theme.muted());
+            for zone in &self.zones {
+                self.draw_arc(
+                    ui,
+                    center,
+                    radius,
+                    zone.from,
+                    zone.to,
+                    zone.color.gamma_multiply(ZONE_TINT),
+                );
+            }
+
+            // Value arc in the color of the zone the value is in
+            let value_color = self
+                .zones
+                .iter()
+                .find(|zone| (zone.from..=zone.to).contains(&self.value))
+                .map_or_else(|| theme.primary(), |zone| zone.color);
+            self.draw_arc(ui, center, radius, self.min, self.value, value_color);
+
+            // Needle
+            let angle = self.needle_angle(self.value);
+            let tip = center + Vec2::angled(angle) * (radius - self.stroke_width);
+            painter.line_segment([center, tip], Stroke::new(NEEDLE_WIDTH, theme.foreground()));
+            painter.circle_filled(center, HUB_RADIUS, theme.foreground());
+
+            if self.show_value {
+                painter.text(
+                    center + Vec2::new(0.0, radius * 0.45),
+                    egui::Align2::CENTER_CENTER,
+                    format!("{:.*}{}", self.decimals, self.value, self.suffix),
+                    egui::FontId::proportional(self.size * 0.14),
+                    theme.foreground(),
+                );
+            }
+        }
+
+        response.widget_info(|| {
+            egui::WidgetInfo::labeled(
+                egui::WidgetType::ProgressIndicator,
+                true,
+                format!("{:.*}{}", self.decimals, self.value, self.suffix),
+            )
+        });
+        response
+    }
+
+    /// Fraction (0.0-1.0) of the span covered by `value`
+    fn fraction(&self, value: f32) -> f32 {
+        let range = self.max - self.min;
+        if range <= 0.0 {
+            0.0
+        } else {
+            ((value - self.min) / range).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Angle where the span starts, clockwise from the positive x axis
+    fn start_angle(&self) -> f32 {
+        // Center the gap on the bottom of the gauge (90° in screen coordinates)
+        (90.0 + (360.0 - self.span) / 2.0).to_radians()
+    }
+
+    /// Angle of the needle for `value`, in radians
+    fn needle_angle(&self, value: f32) -> f32 {
+        self.fraction(value)
+            .mul_add(self.span.to_radians(), self.start_angle())
+    }
+
+    /// Draw the part of the arc between two values
+    fn draw_arc(&self, ui: &Ui, center: Pos2, radius: f32, from: f32, to: f32, color: Color32) {
+        let start = self.needle_angle(from.min(to));
+        let sweep = self.needle_angle(from.max(to)) - start;
+        if sweep <= 0.0 {
+            return;
+        }
+        let segments = (sweep / std::f32::consts::TAU * ARC_RESOLUTION).ceil() as usize;
+        ui.painter().add(Shape::line(
+            arc_points(center, radius, start, sweep, segments),
+            Stroke::new(self.stroke_width, color),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needle_spans_configured_angle() {
+        let gauge = Gauge::new(0.0, 0.0, 100.0).span_degrees(270.0);
+
+        // 270° span starts bottom-left and ends bottom-right
+        assert!((gauge.needle_angle(0.0).to_degrees() - 135.0).abs() < 1e-3);
+        assert!((gauge.needle_angle(100.0).to_degrees() - 405.0).abs() < 1e-3);
+        // Halfway points straight up
+        assert!((gauge.needle_angle(50.0).to_degrees() - 270.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_needle_clamps_to_range() {
+        let gauge = Gauge::new(0.0, -60.0, 0.0).span_degrees(180.0);
+        let end = gauge.needle_angle(0.0);
+
+        assert_eq!(gauge.needle_angle(12.0), end);
+        assert_eq!(gauge.needle_angle(-100.0), gauge.needle_angle(-60.0));
+        assert!((end - gauge.needle_angle(-60.0) - std::f32::consts::PI).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,290 @@
+//! Gradient Editor Component
+//!
+//! Interactive editor for gradient color stops.
+//! Features:
+//! - Gradient preview bar
+//! - Draggable stop handles
+//! - Click the bar to add a stop, double-click a handle to remove it
+//! - Color picker for the selected stop
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use armas_basic::color::ColorStop;
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::GradientEditor;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let mut stops = vec![
+//!     ColorStop::new(0.0, egui::Color32::from_rgb(59, 130, 246)),
+//!     ColorStop::new(1.0, egui::Color32::from_rgb(236, 72, 153)),
+//! ];
+//!
+//! let response = GradientEditor::new("gradient").show(ui, &mut stops, &theme);
+//! if response.changed {
+//!     // rebuild `Gradient::new(stops.clone())`
+//! }
+//! # }
+//! ```
+
+use super::color_picker::paint_checkerboard;
+use crate::color::{ColorStop, Gradient};
+use crate::{ColorPicker, Theme};
+use egui::{pos2, vec2, Color32, Id, Rect, Response, Sense, Stroke, Ui};
+
+// shadcn-style constants
+const EDITOR_WIDTH: f32 = 224.0; // w-56
+const BAR_HEIGHT: f32 = 24.0; // h-6
+const HANDLE_SIZE: f32 = 12.0; // size-3
+const HANDLE_HIT_RADIUS: f32 = 6.0;
+const CORNER_RADIUS: f32 = 6.0; // rounded-md
+const GAP: f32 = 12.0; // gap-3
+const MIN_STOPS: usize = 2;
+
+/// Response from a gradient editor
+#[derive(Debug, Clone)]
+pub struct GradientEditorResponse {
+    /// The response of the gradient bar (including the handle row)
+    pub response: Response,
+    /// Whether any stop was added, removed, moved or recolored this frame
+    pub changed: bool,
+    /// Index of the selected stop
+    pub selected: Option<usize>,
+}
+
+/// Persisted interaction state
+#[derive(Clone, Copy, Default)]
+struct EditorState {
+    selected: Option<usize>,
+    dragging: Option<usize>,
+}
+
+/// Gradient editor component
+///
+/// Edits a `Vec<ColorStop>` in place. Stops are kept sorted by position
+/// whenever no handle is being dragged.
+pub struct GradientEditor {
+    id: Id,
+    width: f32,
+    show_picker: bool,
+}
+
+impl GradientEditor {
+    /// Create a new gradient editor
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            width: EDITOR_WIDTH,
+            show_picker: true,
+        }
+    }
+
+    /// Set the editor width
+    #[must_use]
+    pub const fn width(mut self, width: f32) -> Self {
+        self.width = width.max(120.0);
+        self
+    }
+
+    /// Show or hide the color picker for the selected stop
+    #[must_use]
+    pub const fn show_picker(mut self, show: bool) -> Self {
+        self.show_picker = show;
+        self
+    }
+
+    /// Show the gradient editor
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        stops: &mut Vec<ColorStop>,
+        theme: &Theme,
+    ) -> GradientEditorResponse {
+        let state_id = self.id.with("state");
+        let mut state: EditorState = ui.ctx().data(|d| d.get_temp(state_id)).unwrap_or_default();
+        state.selected = state.selected.filter(|&i| i < stops.len());
+        state.dragging = state.dragging.filter(|&i| i < stops.len());
+
+        let mut changed = false;
+
+        let inner = ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = GAP;
+
+            let (rect, response) = ui.allocate_exact_size(
+                vec2(self.width, BAR_HEIGHT + HANDLE_SIZE),
+                Sense::click_and_drag(),
+            );
+            let bar_rect = Rect::from_min_size(rect.min, vec2(self.width, BAR_HEIGHT));
+
+            changed |= Self::handle_input(ui, &response, bar_rect, stops, &mut state);
+
+            if ui.is_rect_visible(rect) {
+                Self::paint(ui, bar_rect, stops, state.selected, theme);
+            }
+
+            if self.show_picker {
+                if let Some(stop) = state.selected.and_then(|i| stops.get_mut(i)) {
+                    changed |= ColorPicker::new(self.id.with("stop_color"))
+                        .alpha(true)
+                        .width(self.width)
+                        .show(ui, &mut stop.color, theme)
+                        .changed;
+                }
+            }
+
+            response
+        });
+
+        ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
+
+        GradientEditorResponse {
+            response: inner.inner,
+            changed,
+            selected: state.selected,
+        }
+    }
+
+    /// Apply clicks and drags on the bar to the stops
+    fn handle_input(
+        ui: &Ui,
+        response: &Response,
+        bar_rect: Rect,
+        stops: &mut Vec<ColorStop>,
+        state: &mut EditorState,
+    ) -> bool {
+        let position_at = |x: f32| ((x - bar_rect.min.x) / bar_rect.width()).clamp(0.0, 1.0);
+        let mut changed = false;
+
+        if response.drag_started() {
+            let origin = ui.input(|i| i.pointer.press_origin());
+            state.dragging = origin.and_then(|pos| Self::stop_at(stops, bar_rect, pos.x));
+            if state.dragging.is_some() {
+                state.selected = state.dragging;
+            }
+        }
+
+        if response.dragged() {
+            if let (Some(index), Some(pos)) = (state.dragging, response.interact_pointer_pos()) {
+                let position = position_at(pos.x);
+                if stops[index].position != position {
+                    stops[index].position = position;
+                    changed = true;
+                }
+            }
+        }
+
+        if response.drag_stopped() {
+            state.dragging = None;
+        }
+
+        if let Some(pos) = response.interact_pointer_pos() {
+            let hit = Self::stop_at(stops, bar_rect, pos.x);
+            if response.double_clicked() {
+                if let Some(index) = hit.filter(|_| stops.len() > MIN_STOPS) {
+                    stops.remove(index);
+                    state.selected = None;
+                    changed = true;
+                }
+            } else if response.clicked() {
+                if hit.is_some() {
+                    state.selected = hit;
+                } else {
+                    let position = position_at(pos.x);
+                    let color = Self::gradient(stops).sample(position);
+                    stops.push(ColorStop::new(position, color));
+                    state.selected = Some(stops.len() - 1);
+                    changed = true;
+                }
+            }
+        }
+
+        if state.dragging.is_none() {
+            Self::sort_stops(stops, &mut state.selected);
+        }
+
+        changed
+    }
+
+    /// Index of the stop whose handle is under `x`, closest first
+    fn stop_at(stops: &[ColorStop], bar_rect: Rect, x: f32) -> Option<usize> {
+        stops
+            .iter()
+            .enumerate()
+            .map(|(i, stop)| {
+                let stop_x = stop.position.mul_add(bar_rect.width(), bar_rect.min.x);
+                (i, (stop_x - x).abs())
+            })
+            .filter(|&(_, distance)| distance <= HANDLE_HIT_RADIUS)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    }
+
+    /// Sort stops by position, keeping the selection on the same stop
+    fn sort_stops(stops: &mut [ColorStop], selected: &mut Option<usize>) {
+        if stops
+            .windows(2)
+            .all(|pair| pair[0].position <= pair[1].position)
+        {
+            return;
+        }
+
+        let mut order: Vec<usize> = (0..stops.len()).collect();
+        order.sort_by(|&a, &b| stops[a].position.total_cmp(&stops[b].position));
+        *selected = selected.and_then(|old| order.iter().position(|&i| i == old));
+
+        let sorted: Vec<ColorStop> = order.iter().map(|&i| stops[i].clone()).collect();
+        stops.clone_from_slice(&sorted);
+    }
+
+    fn gradient(stops: &[ColorStop]) -> Gradient {
+        let mut sorted = stops.to_vec();
+        sorted.sort_by(|a, b| a.position.total_cmp(&b.position));
+        Gradient::new(sorted)
+    }
+
+    fn paint(ui: &Ui, bar_rect: Rect, stops: &[ColorStop], selected: Option<usize>, theme: &Theme) {
+        let painter = ui.painter();
+
+        // Checkerboard behind the gradient so alpha is visible
+        paint_checkerboard(painter, bar_rect, theme);
+
+        painter.add(Self::gradient(stops).rect_mesh(bar_rect, true));
+        painter.rect_stroke(
+            bar_rect,
+            CORNER_RADIUS,
+            Stroke::new(1.0, theme.border()),
+            egui::StrokeKind::Inside,
+        );
+
+        for (i, stop) in stops.iter().enumerate() {
+            let x = stop.position.mul_add(bar_rect.width(), bar_rect.min.x);
+            let is_selected = selected == Some(i);
+
+            painter.line_segment(
+                [pos2(x, bar_rect.min.y), pos2(x, bar_rect.max.y)],
+                Stroke::new(1.0, Color32::from_white_alpha(160)),
+            );
+
+            let handle_rect = Rect::from_center_size(
+                pos2(x, bar_rect.max.y + HANDLE_SIZE / 2.0),
+                vec2(HANDLE_SIZE, HANDLE_SIZE),
+            );
+            painter.rect_filled(handle_rect, 3.0, stop.color);
+            painter.rect_stroke(
+                handle_rect,
+                3.0,
+                Stroke::new(
+                    if is_selected { 2.0 } else { 1.0 },
+                    if is_selected {
+                        theme.ring()
+                    } else {
+                        theme.border()
+                    },
+                ),
+                egui::StrokeKind::Outside,
+            );
+        }
+    }
+}
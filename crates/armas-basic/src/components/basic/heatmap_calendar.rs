@@ -0,0 +1,322 @@
+//! Heatmap Calendar Component
+//!
+//! GitHub-style contribution graph: one cell per day, arranged in week
+//! columns (Sunday at the top) with month labels and a Less/More legend.
+//! Each day's value is mapped through a [`Gradient`] color scale.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui, commits: &[f32]) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::{Date, HeatmapCalendar};
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let start = Date::new(2024, 1, 1).unwrap();
+//! let response = HeatmapCalendar::new(start, commits).show(ui, &theme);
+//! if let Some(day) = response.clicked {
+//!     println!("{}", day.format());
+//! }
+//! # }
+//! ```
+
+use super::date_picker::Date;
+use crate::color::Gradient;
+use crate::Theme;
+use egui::{pos2, vec2, Align2, Color32, FontId, Rect, Response, Sense, Ui, Vec2};
+
+// GitHub contribution graph proportions
+const CELL_SIZE: f32 = 11.0;
+const CELL_GAP: f32 = 3.0;
+const CELL_RADIUS: f32 = 2.0;
+const LABEL_FONT_SIZE: f32 = 11.0;
+const WEEKDAY_LABEL_WIDTH: f32 = 28.0;
+const MONTH_LABEL_HEIGHT: f32 = 16.0;
+const LEGEND_HEIGHT: f32 = 20.0;
+const LEGEND_STEPS: usize = 5;
+/// Minimum number of week columns between two month labels
+const MONTH_LABEL_MIN_COLUMNS: usize = 3;
+
+/// Response from a heatmap calendar
+#[derive(Debug, Clone)]
+pub struct HeatmapCalendarResponse {
+    /// The UI response for the whole calendar
+    pub response: Response,
+    /// Day under the pointer, if any
+    pub hovered: Option<Date>,
+    /// Day clicked this frame, if any
+    pub clicked: Option<Date>,
+}
+
+/// Contribution-graph calendar of daily values
+pub struct HeatmapCalendar {
+    start: Date,
+    values: Vec<f32>,
+    color_scale: Option<Gradient>,
+    max_value: Option<f32>,
+    cell_size: f32,
+    show_legend: bool,
+}
+
+impl HeatmapCalendar {
+    /// Create a calendar where `values[i]` belongs to the `i`-th day after `start`
+    #[must_use]
+    pub fn new(start: Date, values: &[f32]) -> Self {
+        Self {
+            start,
+            values: values.to_vec(),
+            color_scale: None,
+            max_value: None,
+            cell_size: CELL_SIZE,
+            show_legend: true,
+        }
+    }
+
+    /// Set the color scale (default: muted to primary)
+    ///
+    /// Position 0.0 of the gradient colors empty days, 1.0 the busiest.
+    #[must_use]
+    pub fn color_scale(mut self, scale: Gradient) -> Self {
+        self.color_scale = Some(scale);
+        self
+    }
+
+    /// Set the value that maps to the end of the scale (default: largest value)
+    #[must_use]
+    pub const fn max_value(mut self, max: f32) -> Self {
+        self.max_value = Some(max);
+        self
+    }
+
+    /// Set the day cell size
+    #[must_use]
+    pub const fn cell_size(mut self, size: f32) -> Self {
+        self.cell_size = size;
+        self
+    }
+
+    /// Show the Less/More legend (default: true)
+    #[must_use]
+    pub const fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    /// Show the calendar
+    pub fn show(mut self, ui: &mut Ui, theme: &Theme) -> HeatmapCalendarResponse {
+        let scale = self
+            .color_scale
+            .take()
+            .unwrap_or_else(|| Gradient::linear(theme.muted(), theme.primary()));
+        let max = self.resolved_max();
+
+        let pitch = self.cell_size + CELL_GAP;
+        let columns = self.columns();
+        let grid_size = vec2(columns as f32 * pitch - CELL_GAP, 7.0 * pitch - CELL_GAP);
+        let legend_height = if self.show_legend {
+            CELL_GAP + LEGEND_HEIGHT
+        } else {
+            0.0
+        };
+        let size = vec2(
+            WEEKDAY_LABEL_WIDTH + grid_size.x,
+            MONTH_LABEL_HEIGHT + grid_size.y + legend_height,
+        );
+
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+        let grid_rect = Rect::from_min_size(
+            rect.min + vec2(WEEKDAY_LABEL_WIDTH, MONTH_LABEL_HEIGHT),
+            grid_size,
+        );
+
+        let hovered_index = response
+            .hover_pos()
+            .and_then(|pos| self.index_at(grid_rect, pos));
+        let dates = self.dates();
+        let hovered = hovered_index.map(|i| dates[i]);
+        let clicked = if response.clicked() { hovered } else { None };
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            let font_id = FontId::proportional(LABEL_FONT_SIZE);
+
+            // Weekday labels on alternate rows, like GitHub
+            for (row, name) in [(1, "Mon"), (3, "Wed"), (5, "Fri")] {
+                painter.text(
+                    pos2(
+                        rect.left(),
+                        grid_rect.top() + row as f32 * pitch + self.cell_size / 2.0,
+                    ),
+                    Align2::LEFT_CENTER,
+                    name,
+                    font_id.clone(),
+                    theme.muted_foreground(),
+                );
+            }
+
+            let mut last_label_column: Option<usize> = None;
+            for (i, (date, value)) in dates.iter().zip(&self.values).enumerate() {
+                let (column, row) = self.cell_position(i);
+                let cell = self.cell_rect(grid_rect, column, row);
+
+                if (i == 0 || date.day == 1)
+                    && last_label_column.is_none_or(|c| column >= c + MONTH_LABEL_MIN_COLUMNS)
+                {
+                    painter.text(
+                        pos2(cell.left(), rect.top()),
+                        Align2::LEFT_TOP,
+                        &date.month_name()[..3],
+                        font_id.clone(),
+                        theme.muted_foreground(),
+                    );
+                    last_label_column = Some(column);
+                }
+
+                painter.rect_filled(cell, CELL_RADIUS, cell_color(&scale, *value, max));
+                if hovered_index == Some(i) {
+                    painter.rect_stroke(
+                        cell,
+                        CELL_RADIUS,
+                        (1.0, theme.foreground()),
+                        egui::StrokeKind::Outside,
+                    );
+                }
+            }
+
+            if self.show_legend {
+                self.draw_legend(ui, theme, &scale, rect);
+            }
+        }
+
+        let response = match hovered_index {
+            Some(i) => response.on_hover_text_at_pointer(format!(
+                "{} on {}",
+                self.values[i],
+                dates[i].format_display()
+            )),
+            None => response,
+        };
+
+        HeatmapCalendarResponse {
+            response,
+            hovered,
+            clicked,
+        }
+    }
+
+    /// Value mapped to the end of the color scale
+    fn resolved_max(&self) -> f32 {
+        self.max_value
+            .unwrap_or_else(|| self.values.iter().copied().fold(0.0, f32::max))
+            .max(f32::EPSILON)
+    }
+
+    /// Number of week columns needed for all values
+    fn columns(&self) -> usize {
+        (self.values.len() + self.start.day_of_week() as usize)
+            .div_ceil(7)
+            .max(1)
+    }
+
+    /// Week column and weekday row (0 = Sunday) of the `index`-th day
+    const fn cell_position(&self, index: usize) -> (usize, usize) {
+        let offset = index + self.start.day_of_week() as usize;
+        (offset / 7, offset % 7)
+    }
+
+    /// Index of the day cell under `pos`
+    fn index_at(&self, grid_rect: Rect, pos: egui::Pos2) -> Option<usize> {
+        if !grid_rect.contains(pos) {
+            return None;
+        }
+        let pitch = self.cell_size + CELL_GAP;
+        let column = ((pos.x - grid_rect.left()) / pitch) as usize;
+        let row = ((pos.y - grid_rect.top()) / pitch) as usize;
+        (column * 7 + row)
+            .checked_sub(self.start.day_of_week() as usize)
+            .filter(|&i| i < self.values.len())
+    }
+
+    fn cell_rect(&self, grid_rect: Rect, column: usize, row: usize) -> Rect {
+        let pitch = self.cell_size + CELL_GAP;
+        Rect::from_min_size(
+            grid_rect.min + vec2(column as f32 * pitch, row as f32 * pitch),
+            Vec2::splat(self.cell_size),
+        )
+    }
+
+    /// Calendar date of every value
+    fn dates(&self) -> Vec<Date> {
+        std::iter::successors(Some(self.start), |date| Some(date.next_day()))
+            .take(self.values.len())
+            .collect()
+    }
+
+    /// "Less [swatches] More" in the bottom-right corner
+    fn draw_legend(&self, ui: &Ui, theme: &Theme, scale: &Gradient, rect: Rect) {
+        let painter = ui.painter();
+        let font_id = FontId::proportional(LABEL_FONT_SIZE);
+        let center_y = rect.bottom() - LEGEND_HEIGHT / 2.0;
+
+        let more = painter.text(
+            pos2(rect.right(), center_y),
+            Align2::RIGHT_CENTER,
+            "More",
+            font_id.clone(),
+            theme.muted_foreground(),
+        );
+        let mut x = more.left() - CELL_GAP * 2.0;
+        for step in (0..LEGEND_STEPS).rev() {
+            let t = step as f32 / (LEGEND_STEPS - 1) as f32;
+            let swatch = Rect::from_min_size(
+                pos2(x - self.cell_size, center_y - self.cell_size / 2.0),
+                Vec2::splat(self.cell_size),
+            );
+            painter.rect_filled(swatch, CELL_RADIUS, scale.sample(t));
+            x = swatch.left() - CELL_GAP;
+        }
+        painter.text(
+            pos2(x - CELL_GAP, center_y),
+            Align2::RIGHT_CENTER,
+            "Less",
+            font_id,
+            theme.muted_foreground(),
+        );
+    }
+}
+
+/// Color of a day with `value`, on a scale ending at `max`
+fn cell_color(scale: &Gradient, value: f32, max: f32) -> Color32 {
+    scale.sample(value / max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_maps_through_color_scale() {
+        let scale = Gradient::linear(Color32::BLACK, Color32::WHITE);
+
+        assert_eq!(cell_color(&scale, 0.0, 10.0), Color32::BLACK);
+        assert_eq!(cell_color(&scale, 10.0, 10.0), Color32::WHITE);
+        assert_eq!(cell_color(&scale, 5.0, 10.0), scale.sample(0.5));
+        // Values beyond the max saturate at the end of the scale
+        assert_eq!(cell_color(&scale, 25.0, 10.0), Color32::WHITE);
+    }
+
+    #[test]
+    fn test_days_arranged_in_week_columns() {
+        // 2024-01-01 is a Monday: first cell on row 1 of column 0
+        let start = Date::new(2024, 1, 1).unwrap();
+        let calendar = HeatmapCalendar::new(start, &[1.0; 14]);
+
+        assert_eq!(calendar.cell_position(0), (0, 1));
+        assert_eq!(calendar.cell_position(5), (0, 6));
+        // The following Sunday starts a new week column
+        assert_eq!(calendar.cell_position(6), (1, 0));
+        assert_eq!(calendar.columns(), 3);
+        assert_eq!(calendar.dates()[13], Date::new(2024, 1, 14).unwrap());
+    }
+}
@@ -0,0 +1,280 @@
+//! Image Viewer Component
+//!
+//! Pan and zoom viewer for a texture, for previewing images and assets.
+//! Features:
+//! - Scroll to zoom, anchored at the cursor
+//! - Drag to pan
+//! - Double-click to fit the image to the viewport
+//! - Panning is clamped so part of the image always stays visible
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui, texture: &egui::TextureHandle) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::ImageViewer;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let response = ImageViewer::new(texture)
+//!     .id("preview")
+//!     .height(240.0)
+//!     .show(ui, &theme);
+//! ui.label(format!("{:.0}%", response.zoom * 100.0));
+//!
+//! if ui.button("Reset").clicked() {
+//!     ImageViewer::reset(ui.ctx(), "preview");
+//! }
+//! # }
+//! ```
+
+use crate::Theme;
+use egui::load::SizedTexture;
+use egui::{pos2, vec2, Color32, Id, Rect, Response, Sense, Stroke, Ui, Vec2};
+
+// shadcn-style constants
+const HEIGHT: f32 = 300.0;
+const CORNER_RADIUS: f32 = 6.0; // rounded-md
+const DEFAULT_MIN_ZOOM: f32 = 0.1;
+const DEFAULT_MAX_ZOOM: f32 = 16.0;
+/// Zoom factor per point of scroll
+const SCROLL_ZOOM_SPEED: f32 = 0.002;
+/// Points of the image that must stay inside the viewport while panning
+const MIN_VISIBLE: f32 = 32.0;
+
+/// Response from an image viewer
+#[derive(Debug, Clone)]
+pub struct ImageViewerResponse {
+    /// Response of the viewport
+    pub response: Response,
+    /// Current zoom (screen points per image pixel)
+    pub zoom: f32,
+    /// Offset of the image's top-left corner from the viewport's top-left corner
+    pub offset: Vec2,
+    /// Whether the zoom or offset changed this frame
+    pub changed: bool,
+}
+
+/// Persistent view state
+#[derive(Clone, Copy, Debug)]
+struct ViewState {
+    zoom: f32,
+    offset: Vec2,
+}
+
+/// Pan and zoom image viewer
+///
+/// The view persists in memory, keyed by the viewer's id.
+pub struct ImageViewer {
+    id: Option<Id>,
+    texture: SizedTexture,
+    width: Option<f32>,
+    height: f32,
+    min_zoom: f32,
+    max_zoom: f32,
+}
+
+impl ImageViewer {
+    /// Create a viewer for a texture (e.g. `&TextureHandle`)
+    #[must_use]
+    pub fn new(texture: impl Into<SizedTexture>) -> Self {
+        Self {
+            id: None,
+            texture: texture.into(),
+            width: None,
+            height: HEIGHT,
+            min_zoom: DEFAULT_MIN_ZOOM,
+            max_zoom: DEFAULT_MAX_ZOOM,
+        }
+    }
+
+    /// Set custom ID (required to call [`ImageViewer::reset`])
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the viewport width (default: available width)
+    #[must_use]
+    pub const fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the viewport height
+    #[must_use]
+    pub const fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Set the minimum zoom
+    #[must_use]
+    pub const fn min_zoom(mut self, zoom: f32) -> Self {
+        self.min_zoom = zoom;
+        self
+    }
+
+    /// Set the maximum zoom
+    #[must_use]
+    pub const fn max_zoom(mut self, zoom: f32) -> Self {
+        self.max_zoom = zoom;
+        self
+    }
+
+    /// Forget the view of the viewer with this id, so it fits the image again
+    pub fn reset(ctx: &egui::Context, id: impl Into<Id>) {
+        let state_id = id.into().with("state");
+        ctx.data_mut(|d| d.remove::<ViewState>(state_id));
+    }
+
+    /// Show the image viewer
+    pub fn show(self, ui: &mut Ui, theme: &Theme) -> ImageViewerResponse {
+        let width = self.width.unwrap_or_else(|| ui.available_width());
+        let (viewport, response) =
+            ui.allocate_exact_size(vec2(width, self.height), Sense::click_and_drag());
+        let id = self.id.unwrap_or(response.id);
+        let state_id = id.with("state");
+        let image_size = self.texture.size;
+        let fit = || self.fit(viewport.size(), image_size);
+
+        let stored: Option<ViewState> = ui.ctx().data(|d| d.get_temp(state_id));
+        let mut state = stored.unwrap_or_else(fit);
+        let before = state;
+
+        // Double-click to fit
+        if response.double_clicked() {
+            state = fit();
+        }
+
+        // Drag to pan
+        if response.dragged() {
+            state.offset += response.drag_delta();
+        }
+
+        // Scroll (or pinch) to zoom around the cursor
+        if let Some(pointer) = response.hover_pos() {
+            let (scroll, pinch) = ui.input(|i| (i.raw_scroll_delta.y, i.zoom_delta()));
+            let factor = (scroll * SCROLL_ZOOM_SPEED).exp() * pinch;
+            if factor != 1.0 {
+                let new_zoom = (state.zoom * factor).clamp(self.min_zoom, self.max_zoom);
+                state.offset =
+                    zoom_about(state.offset, state.zoom, new_zoom, pointer - viewport.min);
+                state.zoom = new_zoom;
+            }
+        }
+
+        state.offset = clamp_offset(state.offset, image_size * state.zoom, viewport.size());
+        let changed =
+            stored.is_some() && (state.zoom != before.zoom || state.offset != before.offset);
+        ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
+
+        let response = if response.dragged() {
+            response.on_hover_cursor(egui::CursorIcon::Grabbing)
+        } else {
+            response.on_hover_cursor(egui::CursorIcon::Grab)
+        };
+
+        if ui.is_rect_visible(viewport) {
+            let painter = ui.painter();
+            painter.rect_filled(viewport, CORNER_RADIUS, theme.muted());
+            let image_rect =
+                Rect::from_min_size(viewport.min + state.offset, image_size * state.zoom);
+            painter
+                .with_clip_rect(viewport.intersect(ui.clip_rect()))
+                .image(
+                    self.texture.id,
+                    image_rect,
+                    Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+                    Color32::WHITE,
+                );
+            painter.rect_stroke(
+                viewport,
+                CORNER_RADIUS,
+                Stroke::new(1.0, theme.border()),
+                egui::StrokeKind::Inside,
+            );
+        }
+
+        ImageViewerResponse {
+            response,
+            zoom: state.zoom,
+            offset: state.offset,
+            changed,
+        }
+    }
+
+    /// View that shows the whole image, centered
+    fn fit(&self, viewport: Vec2, image: Vec2) -> ViewState {
+        let zoom = if image.x > 0.0 && image.y > 0.0 {
+            (viewport.x / image.x)
+                .min(viewport.y / image.y)
+                .clamp(self.min_zoom, self.max_zoom)
+        } else {
+            1.0
+        };
+        ViewState {
+            zoom,
+            offset: (viewport - image * zoom) / 2.0,
+        }
+    }
+}
+
+/// Offset that keeps the image point under `anchor` fixed while zooming
+///
+/// `offset` and `anchor` are relative to the viewport's top-left corner.
+fn zoom_about(offset: Vec2, zoom: f32, new_zoom: f32, anchor: Vec2) -> Vec2 {
+    if zoom <= 0.0 {
+        return offset;
+    }
+    anchor - (anchor - offset) * (new_zoom / zoom)
+}
+
+/// Keep at least [`MIN_VISIBLE`] points of the image inside the viewport
+fn clamp_offset(offset: Vec2, image: Vec2, viewport: Vec2) -> Vec2 {
+    let clamp_axis = |offset: f32, image: f32, viewport: f32| {
+        let visible = MIN_VISIBLE.min(image).min(viewport);
+        offset.clamp(visible - image, viewport - visible)
+    };
+    vec2(
+        clamp_axis(offset.x, image.x, viewport.x),
+        clamp_axis(offset.y, image.y, viewport.y),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Image coordinate shown at `point` of the viewport
+    fn image_point(offset: Vec2, zoom: f32, point: Vec2) -> Vec2 {
+        (point - offset) / zoom
+    }
+
+    #[test]
+    fn test_zoom_about_keeps_anchor_fixed() {
+        let offset = vec2(20.0, -15.0);
+        let anchor = vec2(140.0, 90.0);
+        let before = image_point(offset, 1.5, anchor);
+
+        let zoomed = zoom_about(offset, 1.5, 4.0, anchor);
+        let after = image_point(zoomed, 4.0, anchor);
+
+        assert!((before - after).length() < 1e-4);
+    }
+
+    #[test]
+    fn test_clamp_offset_keeps_image_visible() {
+        let image = vec2(400.0, 300.0);
+        let viewport = vec2(200.0, 100.0);
+
+        // Panned far past the right/bottom edge
+        let clamped = clamp_offset(vec2(1000.0, 1000.0), image, viewport);
+        assert_eq!(clamped, vec2(200.0 - MIN_VISIBLE, 100.0 - MIN_VISIBLE));
+
+        // Panned far past the left/top edge
+        let clamped = clamp_offset(vec2(-1000.0, -1000.0), image, viewport);
+        assert_eq!(clamped, vec2(MIN_VISIBLE - 400.0, MIN_VISIBLE - 300.0));
+    }
+}
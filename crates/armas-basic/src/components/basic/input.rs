@@ -7,6 +7,7 @@
 //! - Icons (left and right)
 //! - Password masking
 
+use crate::ext::draw_focus_ring;
 use egui::{Color32, Response, Sense, Stroke, TextEdit, Ui, Vec2};
 
 // shadcn Input constants
@@ -408,7 +409,11 @@ impl Input {
                 text_edit = text_edit.id(id);
             }
 
-            return child_ui.add(text_edit);
+            let edit_response = child_ui.add(text_edit);
+            if edit_response.has_focus() {
+                draw_focus_ring(ui.painter(), rect, theme);
+            }
+            return edit_response;
         }
 
         response
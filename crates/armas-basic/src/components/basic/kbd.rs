@@ -2,6 +2,7 @@
 //!
 //! Keyboard shortcut display element.
 
+use crate::ext::ArmasContextExt;
 use crate::theme::Theme;
 use egui::{Response, Ui, Vec2};
 
@@ -57,6 +58,13 @@ impl Kbd {
     }
 }
 
+impl egui::Widget for Kbd {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let theme = ui.ctx().armas_theme();
+        self.show(ui, &theme)
+    }
+}
+
 fn render_key(ui: &mut Ui, text: &str, theme: &Theme) -> Response {
     let font_size = 12.0;
     let font_id = egui::FontId::proportional(font_size);
@@ -77,6 +85,7 @@ fn render_key(ui: &mut Ui, text: &str, theme: &Theme) -> Response {
     let size = Vec2::new((text_size.x + padding_x * 2.0).max(min_width), height);
 
     let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Label, true, text));
 
     if ui.is_rect_visible(rect) {
         let rounding = 4.0;
@@ -5,6 +5,7 @@
 //! - Spinner: Classic rotating bar spinner
 //! - Skeleton: Content placeholder with shimmer
 
+use crate::ext::ArmasContextExt;
 use egui::{Color32, Pos2, Rect, Response, Ui, Vec2};
 use std::f32::consts::PI;
 
@@ -153,6 +154,13 @@ impl Spinner {
     }
 }
 
+impl egui::Widget for Spinner {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let theme = ui.ctx().armas_theme();
+        self.show(ui, &theme)
+    }
+}
+
 /// Skeleton loader for placeholder content
 ///
 /// A shimmer effect that animates across a rectangular area,
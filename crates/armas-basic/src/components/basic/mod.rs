@@ -4,19 +4,35 @@
 
 pub mod accordion;
 pub mod alert;
+pub mod animated_number;
 pub mod avatar;
 pub mod badge;
+pub mod code_block;
+pub mod code_chip;
+pub mod collapsible;
+pub mod color_picker;
+pub mod copy_button;
 pub mod date_picker;
+pub mod easing_preview;
+pub mod gauge;
+pub mod gradient_editor;
+pub mod heatmap_calendar;
+pub mod image_viewer;
 pub mod input;
 pub mod kbd;
 pub mod loading;
+pub mod number_input;
 pub mod progress;
 pub mod radio;
 pub mod range_slider;
+pub mod rating;
+pub mod segmented_control;
 pub mod select;
 pub mod separator;
 pub mod slider;
+pub mod tag_input;
 pub mod textarea;
+pub mod theme_editor;
 pub mod three_value_slider;
 pub mod toggle;
 pub mod tooltip;
@@ -24,19 +40,35 @@ pub mod tooltip;
 // Re-exports
 pub use accordion::{Accordion, AccordionResponse};
 pub use alert::{alert, alert_destructive, Alert, AlertResponse, AlertVariant};
+pub use animated_number::{AnimatedNumber, AnimatedNumberResponse};
 pub use avatar::{Avatar, AvatarShape, AvatarSize};
 pub use badge::{Badge, BadgeResponse, BadgeVariant, NotificationBadge};
+pub use code_block::{highlight, CodeBlock, CodeBlockResponse};
+pub use code_chip::{CodeChip, CodeChipResponse};
+pub use collapsible::{Collapsible, CollapsibleResponse};
+pub use color_picker::{ColorPicker, ColorPickerResponse, ColorPickerState};
+pub use copy_button::{copy_confirming, copy_to_clipboard, CopyButton, COPIED_DURATION};
 pub use date_picker::{Date, DatePicker, DatePickerResponse};
+pub use easing_preview::{EasingPreview, EasingPreviewResponse};
+pub use gauge::{Gauge, GaugeZone};
+pub use gradient_editor::{GradientEditor, GradientEditorResponse};
+pub use heatmap_calendar::{HeatmapCalendar, HeatmapCalendarResponse};
+pub use image_viewer::{ImageViewer, ImageViewerResponse};
 pub use input::{Input, InputState, InputVariant, SearchInput};
 pub use kbd::Kbd;
 pub use loading::{Skeleton, Spinner};
-pub use progress::{CircularProgressBar, Progress};
+pub use number_input::{Num, NumberInput, NumberInputResponse};
+pub use progress::{CircularProgressBar, MultiRing, Progress};
 pub use radio::{Radio, RadioGroup, RadioGroupResponse, RadioResponse, RadioSize};
 pub use range_slider::{RangeSlider, RangeSliderResponse};
+pub use rating::{Rating, RatingResponse};
+pub use segmented_control::{SegmentedControl, SegmentedControlResponse};
 pub use select::{Select, SelectOption, SelectResponse};
 pub use separator::{Separator, SeparatorOrientation};
 pub use slider::{Slider, SliderResponse};
-pub use textarea::Textarea;
+pub use tag_input::{TagInput, TagInputResponse};
+pub use textarea::{PreviewMode, Textarea, TextareaResponse};
+pub use theme_editor::{ThemeEditor, ThemeEditorResponse};
 pub use three_value_slider::{ThreeValueSlider, ThreeValueSliderResponse, ValueThumbStyle};
 pub use toggle::{
     Toggle, ToggleGroup, ToggleGroupResponse, ToggleGroupState, ToggleResponse, ToggleSize,
@@ -0,0 +1,433 @@
+//! Number Input Component
+//!
+//! Numeric text field with stepper buttons, styled like shadcn/ui Input.
+//! Features:
+//! - Up/down stepper buttons; holding a button repeats with acceleration
+//! - Scroll wheel over the field adjusts the value
+//! - Text entry, validated and applied on Enter or when focus leaves
+//! - Optional min/max clamping
+//! - Works with integers and floats through the [`Num`] trait
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::NumberInput;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let mut voices = 8_u32;
+//! let response = NumberInput::new()
+//!     .min(1)
+//!     .max(64)
+//!     .show(ui, &mut voices, &theme);
+//! if response.changed {
+//!     // voices holds the new value
+//! }
+//! # }
+//! ```
+
+use crate::Theme;
+use egui::{
+    Color32, Id, Rect, Response, Sense, Stroke, TextEdit, Ui, Vec2, WidgetInfo, WidgetType,
+};
+
+// shadcn Input constants
+const CORNER_RADIUS: f32 = 6.0; // rounded-md
+const HEIGHT: f32 = 36.0; // h-9
+const PADDING_X: f32 = 12.0; // px-3
+const FONT_SIZE: f32 = 14.0; // text-sm
+const WIDTH: f32 = 120.0;
+const STEPPER_WIDTH: f32 = 24.0; // w-6
+const STEPPER_ICON_SIZE: f32 = 12.0; // size-3
+
+// Press-and-hold repeat timing (seconds)
+const REPEAT_DELAY: f64 = 0.4;
+const REPEAT_INTERVAL: f64 = 0.15;
+const MIN_REPEAT_INTERVAL: f64 = 0.02;
+/// How quickly the repeat interval shrinks while a stepper is held
+const REPEAT_ACCELERATION: f64 = 2.0;
+
+/// Numeric types usable with [`NumberInput`]
+///
+/// Implemented for all primitive integer and float types. Stepping is done in
+/// `f64`, so very large 64-bit integers may lose precision.
+pub trait Num:
+    Copy + PartialOrd + std::fmt::Display + std::str::FromStr + Send + Sync + 'static
+{
+    /// The default step (1 for every built-in type)
+    const ONE: Self;
+
+    /// Convert to `f64` for stepping
+    fn to_f64(self) -> f64;
+
+    /// Convert back from `f64` (integers are rounded)
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_num_lossless {
+    ($($t:ty),*) => {$(
+        impl Num for $t {
+            const ONE: Self = 1;
+
+            fn to_f64(self) -> f64 {
+                f64::from(self)
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value.round() as Self
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_num_lossy {
+    ($($t:ty),*) => {$(
+        impl Num for $t {
+            const ONE: Self = 1;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value.round() as Self
+            }
+        }
+    )*};
+}
+
+impl_num_lossless!(i8, i16, i32, u8, u16, u32);
+impl_num_lossy!(i64, i128, isize, u64, u128, usize);
+
+impl Num for f32 {
+    const ONE: Self = 1.0;
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as Self
+    }
+}
+
+impl Num for f64 {
+    const ONE: Self = 1.0;
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// Response from a number input
+#[derive(Debug, Clone)]
+pub struct NumberInputResponse<T> {
+    /// The response of the whole field
+    pub response: Response,
+    /// Current value
+    pub value: T,
+    /// Whether the value changed this frame
+    pub changed: bool,
+    /// Whether the text being edited does not parse as a number
+    pub invalid: bool,
+}
+
+/// Numeric input with stepper buttons
+pub struct NumberInput<T: Num> {
+    id: Option<Id>,
+    step: T,
+    min: Option<T>,
+    max: Option<T>,
+    decimals: Option<usize>,
+    width: f32,
+}
+
+impl<T: Num> NumberInput<T> {
+    /// Create a new number input with a step of 1 and no bounds
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            id: None,
+            step: T::ONE,
+            min: None,
+            max: None,
+            decimals: None,
+            width: WIDTH,
+        }
+    }
+
+    /// Set custom ID (important when using multiple number inputs)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the amount added or removed per step
+    #[must_use]
+    pub const fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Set the minimum value
+    #[must_use]
+    pub const fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the maximum value
+    #[must_use]
+    pub const fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Show a fixed number of decimal places (default: the value's `Display`)
+    #[must_use]
+    pub const fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Set the width
+    #[must_use]
+    pub const fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Show the number input
+    pub fn show(self, ui: &mut Ui, value: &mut T, theme: &Theme) -> NumberInputResponse<T> {
+        let (rect, mut response) =
+            ui.allocate_exact_size(Vec2::new(self.width, HEIGHT), Sense::hover());
+        let id = self.id.unwrap_or(response.id);
+        let text_id = id.with("text");
+        let hold_id = id.with("hold");
+
+        let previous = *value;
+        *value = self.clamp(*value);
+
+        let stepper_rect =
+            Rect::from_min_max(egui::pos2(rect.max.x - STEPPER_WIDTH, rect.min.y), rect.max);
+        let increment_rect = Rect::from_min_max(
+            stepper_rect.min,
+            egui::pos2(stepper_rect.max.x, stepper_rect.center().y),
+        );
+        let decrement_rect = Rect::from_min_max(
+            egui::pos2(stepper_rect.min.x, stepper_rect.center().y),
+            stepper_rect.max,
+        );
+        let text_rect = Rect::from_min_max(
+            egui::pos2(rect.min.x + PADDING_X, rect.min.y),
+            egui::pos2(stepper_rect.min.x - PADDING_X / 2.0, rect.max.y),
+        );
+
+        // Text entry: the buffer only lives in memory while the field is focused
+        let mut text: String = ui
+            .ctx()
+            .data(|d| d.get_temp(text_id))
+            .unwrap_or_else(|| self.format(*value));
+        let edit_response = Self::text_edit(ui, text_rect, id.with("edit"), &mut text, theme);
+        let parsed = text.trim().parse::<T>().ok();
+        let invalid = parsed.is_none();
+        if edit_response.lost_focus() {
+            if let Some(parsed) = parsed {
+                *value = self.clamp(parsed);
+            }
+            ui.ctx().data_mut(|d| d.remove::<String>(text_id));
+        } else if edit_response.has_focus() {
+            ui.ctx().data_mut(|d| d.insert_temp(text_id, text));
+        }
+
+        // Steppers
+        let mut steps = 0;
+        for (rect, label, direction) in [
+            (increment_rect, "Increment", 1),
+            (decrement_rect, "Decrement", -1),
+        ] {
+            let button = ui.interact(rect, id.with(label), Sense::click());
+            button.widget_info(|| WidgetInfo::labeled(WidgetType::Button, true, label));
+            steps += direction * Self::stepper_presses(ui, &button, hold_id.with(label));
+
+            let color = if button.hovered() {
+                theme.foreground()
+            } else {
+                theme.muted_foreground()
+            };
+            let icon = if direction > 0 {
+                crate::icon::chevron_up()
+            } else {
+                crate::icon::chevron_down()
+            };
+            let icon_rect = Rect::from_center_size(rect.center(), Vec2::splat(STEPPER_ICON_SIZE));
+            icon.render(ui.painter(), icon_rect, color);
+        }
+
+        // Scroll wheel over the field
+        if ui.rect_contains_pointer(rect) {
+            let delta = ui.input(|i| i.raw_scroll_delta.y);
+            if delta != 0.0 {
+                steps += if delta > 0.0 { 1 } else { -1 };
+                ui.ctx().input_mut(|i| i.smooth_scroll_delta = Vec2::ZERO);
+            }
+        }
+
+        if steps != 0 {
+            *value = self.step_by(*value, steps);
+            ui.ctx().data_mut(|d| d.remove::<String>(text_id));
+        }
+
+        let changed = (*value).partial_cmp(&previous) != Some(std::cmp::Ordering::Equal);
+        if changed {
+            response.mark_changed();
+        }
+
+        // Paint the frame
+        if ui.is_rect_visible(rect) {
+            let focused = edit_response.has_focus();
+            let border_color = if focused && invalid {
+                theme.destructive()
+            } else if focused {
+                theme.ring()
+            } else {
+                theme.input()
+            };
+            let painter = ui.painter();
+            painter.rect_stroke(
+                rect,
+                CORNER_RADIUS,
+                Stroke::new(if focused { 2.0 } else { 1.0 }, border_color),
+                egui::StrokeKind::Inside,
+            );
+            painter.vline(
+                stepper_rect.min.x,
+                stepper_rect.y_range(),
+                Stroke::new(1.0, theme.input()),
+            );
+        }
+
+        NumberInputResponse {
+            response,
+            value: *value,
+            changed,
+            invalid,
+        }
+    }
+
+    fn text_edit(ui: &mut Ui, rect: Rect, id: Id, text: &mut String, theme: &Theme) -> Response {
+        let mut child_ui = ui.new_child(egui::UiBuilder::new().max_rect(rect));
+        let visuals = &mut child_ui.style_mut().visuals;
+        for widget in [
+            &mut visuals.widgets.inactive,
+            &mut visuals.widgets.hovered,
+            &mut visuals.widgets.active,
+        ] {
+            widget.bg_fill = Color32::TRANSPARENT;
+            widget.bg_stroke = Stroke::NONE;
+        }
+        visuals.override_text_color = Some(theme.foreground());
+
+        child_ui.add(
+            TextEdit::singleline(text)
+                .id(id)
+                .desired_width(rect.width())
+                .frame(false)
+                .font(egui::FontId::proportional(FONT_SIZE))
+                .vertical_align(egui::Align::Center),
+        )
+    }
+
+    /// Number of steps a stepper button produces this frame
+    ///
+    /// One step on press, then repeats after [`REPEAT_DELAY`] with an interval
+    /// that shrinks the longer the button is held.
+    fn stepper_presses(ui: &Ui, button: &Response, hold_id: Id) -> i32 {
+        let now = ui.input(|i| i.time);
+        // (pressed at, next repeat at)
+        let hold: Option<(f64, f64)> = ui.ctx().data(|d| d.get_temp(hold_id));
+
+        if !button.is_pointer_button_down_on() {
+            ui.ctx().data_mut(|d| d.remove::<(f64, f64)>(hold_id));
+            // Press and release within a single frame
+            return i32::from(hold.is_none() && button.clicked());
+        }
+
+        ui.ctx().request_repaint();
+        match hold {
+            None => {
+                ui.ctx()
+                    .data_mut(|d| d.insert_temp(hold_id, (now, now + REPEAT_DELAY)));
+                1
+            }
+            Some((pressed_at, next)) if now >= next => {
+                let held_for = now - pressed_at;
+                let interval = (REPEAT_INTERVAL / held_for.mul_add(REPEAT_ACCELERATION, 1.0))
+                    .max(MIN_REPEAT_INTERVAL);
+                ui.ctx()
+                    .data_mut(|d| d.insert_temp(hold_id, (pressed_at, now + interval)));
+                1
+            }
+            Some(_) => 0,
+        }
+    }
+
+    fn clamp(&self, value: T) -> T {
+        let mut value = value;
+        if let Some(min) = self.min {
+            if value < min {
+                value = min;
+            }
+        }
+        if let Some(max) = self.max {
+            if value > max {
+                value = max;
+            }
+        }
+        value
+    }
+
+    fn step_by(&self, value: T, steps: i32) -> T {
+        let stepped = self.step.to_f64().mul_add(f64::from(steps), value.to_f64());
+        self.clamp(T::from_f64(stepped))
+    }
+
+    fn format(&self, value: T) -> String {
+        self.decimals.map_or_else(
+            || value.to_string(),
+            |decimals| format!("{:.decimals$}", value.to_f64()),
+        )
+    }
+}
+
+impl<T: Num> Default for NumberInput<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_by_clamps() {
+        let input = NumberInput::new().step(5).min(0).max(12);
+        assert_eq!(input.step_by(10, 1), 12);
+        assert_eq!(input.step_by(3, -1), 0);
+        assert_eq!(input.step_by(3, 1), 8);
+    }
+
+    #[test]
+    fn test_float_format_with_decimals() {
+        let input = NumberInput::new().step(0.1_f32).decimals(2);
+        assert_eq!(input.format(input.step_by(0.2, 1)), "0.30");
+    }
+}
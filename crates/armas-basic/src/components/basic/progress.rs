@@ -4,8 +4,10 @@
 //! Includes:
 //! - Progress: Simple horizontal progress bar (shadcn style)
 //! - `CircularProgressBar`: Circular/spinner progress
+//! - `MultiRing`: Concentric progress rings for several metrics
 
-use egui::{Color32, Pos2, Ui, Vec2};
+use crate::Theme;
+use egui::{Color32, Pos2, Ui, Vec2, WidgetInfo, WidgetType};
 use std::f32::consts::PI;
 
 const PROGRESS_HEIGHT: f32 = 8.0; // h-2 (8px)
@@ -14,6 +16,12 @@ const PROGRESS_CORNER_RADIUS: f32 = 9999.0; // rounded-full
 const CIRCULAR_SIZE: f32 = 48.0;
 const CIRCULAR_STROKE: f32 = 4.0;
 
+const MULTI_RING_SIZE: f32 = 120.0;
+const MULTI_RING_THICKNESS: f32 = 10.0;
+const MULTI_RING_GAP: f32 = 4.0;
+/// Track opacity, matching the circular progress track (20%)
+const TRACK_ALPHA: u8 = 51;
+
 /// Progress bar styled like shadcn/ui
 ///
 /// A simple horizontal progress indicator.
@@ -72,7 +80,7 @@ impl Progress {
     }
 
     /// Show the progress bar
-    pub fn show(self, ui: &mut Ui, theme: &crate::Theme) -> egui::Response {
+    pub fn show(self, ui: &mut Ui, theme: &Theme) -> egui::Response {
         let desired_width = self.width.unwrap_or_else(|| ui.available_width());
         let corner_radius = PROGRESS_CORNER_RADIUS.min(self.height / 2.0);
 
@@ -195,7 +203,7 @@ impl CircularProgressBar {
     }
 
     /// Show the circular progress
-    pub fn show(mut self, ui: &mut Ui, theme: &crate::Theme) -> egui::Response {
+    pub fn show(mut self, ui: &mut Ui, theme: &Theme) -> egui::Response {
         let (rect, response) = ui.allocate_exact_size(Vec2::splat(self.size), egui::Sense::hover());
 
         if ui.is_rect_visible(rect) {
@@ -259,24 +267,238 @@ impl CircularProgressBar {
         arc_length: f32,
         color: Color32,
     ) {
-        let segments = 32;
-        let angle_step = arc_length / segments as f32;
+        let points = arc_points(center, radius, start_angle, arc_length, 32);
+        for pair in points.windows(2) {
+            ui.painter().line_segment(
+                [pair[0], pair[1]],
+                egui::Stroke::new(self.stroke_width, color),
+            );
+        }
+    }
+}
 
-        for i in 0..segments {
-            let angle1 = start_angle + i as f32 * angle_step;
-            let angle2 = start_angle + (i + 1) as f32 * angle_step;
+/// Concentric progress rings sharing a center
+///
+/// Each ring is one metric: the first is outermost, later rings nest inside
+/// with the same thickness and gap. Content can be drawn in the middle.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::Ui;
+/// # fn example(ui: &mut Ui) {
+/// use armas_basic::MultiRing;
+/// use armas_basic::ext::ArmasContextExt;
+///
+/// let theme = ui.ctx().armas_theme();
+/// MultiRing::new()
+///     .ring(72.0, theme.chart_1())
+///     .ring(45.0, theme.chart_2())
+///     .ring(18.0, theme.chart_3())
+///     .show_with_center(ui, &theme, |ui| {
+///         ui.label("72 GB");
+///     });
+/// # }
+/// ```
+pub struct MultiRing {
+    rings: Vec<(f32, Color32)>,
+    size: f32,
+    thickness: f32,
+    gap: f32,
+    aria_label: Option<String>,
+}
 
-            let p1 = Pos2::new(
-                center.x + radius * angle1.cos(),
-                center.y + radius * angle1.sin(),
-            );
-            let p2 = Pos2::new(
-                center.x + radius * angle2.cos(),
-                center.y + radius * angle2.sin(),
-            );
+impl Default for MultiRing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MultiRing {
+    /// Create an empty ring cluster
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            rings: Vec::new(),
+            size: MULTI_RING_SIZE,
+            thickness: MULTI_RING_THICKNESS,
+            gap: MULTI_RING_GAP,
+            aria_label: None,
+        }
+    }
+
+    /// Add a ring inside the previous ones
+    ///
+    /// # Arguments
+    /// * `value` - Progress value from 0 to 100
+    /// * `color` - Fill color; the track is the same color at 20% opacity
+    #[must_use]
+    pub fn ring(mut self, value: f32, color: Color32) -> Self {
+        self.rings.push((value.clamp(0.0, 100.0), color));
+        self
+    }
+
+    /// Set the outer diameter
+    #[must_use]
+    pub const fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the stroke width of every ring
+    #[must_use]
+    pub const fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Set the space between neighbouring rings
+    #[must_use]
+    pub const fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
 
-            ui.painter()
-                .line_segment([p1, p2], egui::Stroke::new(self.stroke_width, color));
+    /// Set the label announced by screen readers (default: the percentages)
+    #[must_use]
+    pub fn aria_label(mut self, label: impl Into<String>) -> Self {
+        self.aria_label = Some(label.into());
+        self
+    }
+
+    /// Show the rings
+    pub fn show(self, ui: &mut Ui, theme: &Theme) -> egui::Response {
+        self.show_with_center(ui, theme, |_| {})
+    }
+
+    /// Show the rings with `content` centered inside the innermost one
+    pub fn show_with_center(
+        self,
+        ui: &mut Ui,
+        theme: &Theme,
+        content: impl FnOnce(&mut Ui),
+    ) -> egui::Response {
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(self.size), egui::Sense::hover());
+        let label = self.aria_label.clone().unwrap_or_else(|| {
+            self.rings
+                .iter()
+                .map(|(value, _)| format!("{}%", value.round()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::ProgressIndicator, true, &label));
+
+        let geometry = self.geometry();
+        if ui.is_rect_visible(rect) {
+            let center = rect.center();
+            for (&(_, color), &(radius, fraction)) in self.rings.iter().zip(&geometry) {
+                let track =
+                    Color32::from_rgba_unmultiplied(color.r(), color.g(), color.b(), TRACK_ALPHA);
+                ui.painter().circle_stroke(
+                    center,
+                    radius,
+                    egui::Stroke::new(self.thickness, track),
+                );
+                if fraction > 0.0 {
+                    let points = arc_points(center, radius, -PI / 2.0, fraction * 2.0 * PI, 64);
+                    ui.painter().add(egui::Shape::line(
+                        points,
+                        egui::Stroke::new(self.thickness, color),
+                    ));
+                }
+            }
+        }
+
+        // Center content fits inside the innermost ring
+        let inner_radius = geometry.last().map_or(self.size / 2.0, |&(radius, _)| {
+            radius - self.thickness / 2.0
+        });
+        if inner_radius > 0.0 {
+            let inner_rect = egui::Rect::from_center_size(
+                rect.center(),
+                Vec2::splat(inner_radius * std::f32::consts::SQRT_2),
+            );
+            let mut center_ui = ui.new_child(egui::UiBuilder::new().max_rect(inner_rect).layout(
+                egui::Layout::centered_and_justified(egui::Direction::TopDown),
+            ));
+            center_ui.visuals_mut().override_text_color = Some(theme.foreground());
+            content(&mut center_ui);
         }
+
+        response
+    }
+
+    /// Radius and fill fraction of each drawn ring, outermost first
+    ///
+    /// Rings that no longer fit inside the cluster are dropped.
+    fn geometry(&self) -> Vec<(f32, f32)> {
+        let pitch = self.thickness + self.gap;
+        self.rings
+            .iter()
+            .enumerate()
+            .map(|(i, &(value, _))| {
+                let radius = (i as f32).mul_add(-pitch, (self.size - self.thickness) / 2.0);
+                (radius, value / 100.0)
+            })
+            .take_while(|&(radius, _)| radius > self.thickness / 2.0)
+            .collect()
+    }
+}
+
+/// Points along an arc from `start_angle` sweeping `arc_length` radians
+///
+/// Angles are clockwise from the positive x axis (screen coordinates).
+/// Returns `segments + 1` points.
+pub(crate) fn arc_points(
+    center: Pos2,
+    radius: f32,
+    start_angle: f32,
+    arc_length: f32,
+    segments: usize,
+) -> Vec<Pos2> {
+    let segments = segments.max(1);
+    let angle_step = arc_length / segments as f32;
+    (0..=segments)
+        .map(|i| {
+            let angle = start_angle + i as f32 * angle_step;
+            Pos2::new(
+                center.x + radius * angle.cos(),
+                center.y + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_ring_nests_rings_inward() {
+        let rings = MultiRing::new()
+            .size(100.0)
+            .thickness(10.0)
+            .gap(4.0)
+            .ring(75.0, Color32::RED)
+            .ring(30.0, Color32::BLUE);
+
+        let geometry = rings.geometry();
+        assert_eq!(geometry.len(), 2);
+        // Outer ring sits flush with the edge, the next one a pitch inside
+        assert!((geometry[0].0 - 45.0).abs() < f32::EPSILON);
+        assert!((geometry[1].0 - 31.0).abs() < f32::EPSILON);
+        assert!((geometry[0].1 - 0.75).abs() < f32::EPSILON);
+        assert!((geometry[1].1 - 0.3).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_multi_ring_drops_rings_that_do_not_fit() {
+        let rings = (0..10).fold(MultiRing::new().size(60.0), |rings, _| {
+            rings.ring(50.0, Color32::RED)
+        });
+        let geometry = rings.geometry();
+
+        assert!(geometry.len() < 10);
+        assert!(geometry.iter().all(|&(radius, _)| radius > 0.0));
     }
 }
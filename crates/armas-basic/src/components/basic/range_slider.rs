@@ -1,8 +1,10 @@
 //! Range Slider Component
 //!
 //! Horizontal slider with two thumbs for selecting a range (min/max).
+//! Optionally shows a value tooltip above the thumb being dragged.
 
 use crate::ext::ArmasContextExt;
+use crate::{Tooltip, TooltipPosition};
 use egui::{pos2, vec2, Color32, Rect, Response, Sense, Stroke, Ui};
 
 /// Which thumb is being dragged
@@ -45,6 +47,8 @@ pub struct RangeSlider {
     step: Option<f32>,
     min_gap: f32,
     allow_range_drag: bool,
+    value_tooltip: bool,
+    format: Option<Box<dyn Fn(f32) -> String>>,
 }
 
 impl RangeSlider {
@@ -63,6 +67,8 @@ impl RangeSlider {
             step: None,
             min_gap: 0.0,
             allow_range_drag: true,
+            value_tooltip: false,
+            format: None,
         }
     }
 
@@ -129,6 +135,20 @@ impl RangeSlider {
         self
     }
 
+    /// Show a tooltip with the value above the thumb while it is dragged
+    #[must_use]
+    pub const fn value_tooltip(mut self, show: bool) -> Self {
+        self.value_tooltip = show;
+        self
+    }
+
+    /// Set a custom value formatter for the label and tooltip (overrides the suffix)
+    #[must_use]
+    pub fn format(mut self, format: impl Fn(f32) -> String + 'static) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
     /// Show the range slider
     pub fn show(
         self,
@@ -224,6 +244,24 @@ impl RangeSlider {
                 hovered_thumb,
                 &theme,
             );
+
+            // Value tooltip above the thumb being dragged
+            if !self.value_tooltip || !response.dragged() {
+                return;
+            }
+            if let Some((text, anchor)) = self.drag_tooltip(
+                &track_rect,
+                thumb_radius,
+                drag_state.target,
+                *min_value,
+                *max_value,
+            ) {
+                Tooltip::new(text).position(TooltipPosition::Top).show_at(
+                    ui,
+                    slider_id.with("value_tooltip"),
+                    anchor,
+                );
+            }
         });
 
         // Save state to memory if ID is set
@@ -275,12 +313,46 @@ impl RangeSlider {
     }
 
     fn format_value(&self, value: f32) -> String {
+        if let Some(format) = &self.format {
+            return format(value);
+        }
         self.suffix.as_ref().map_or_else(
             || format!("{value:.1}"),
             |suffix| format!("{value:.1}{suffix}"),
         )
     }
 
+    /// Tooltip text and anchor rect for the thumb(s) being dragged
+    fn drag_tooltip(
+        &self,
+        track_rect: &Rect,
+        thumb_radius: f32,
+        target: DragTarget,
+        min_value: f32,
+        max_value: f32,
+    ) -> Option<(String, Rect)> {
+        let thumb_rect = |value: f32| {
+            Rect::from_center_size(
+                pos2(self.value_to_x(value, track_rect), track_rect.center().y),
+                vec2(thumb_radius * 2.0, thumb_radius * 2.0),
+            )
+        };
+
+        match target {
+            DragTarget::Min => Some((self.format_value(min_value), thumb_rect(min_value))),
+            DragTarget::Max => Some((self.format_value(max_value), thumb_rect(max_value))),
+            DragTarget::Both => Some((
+                format!(
+                    "{} - {}",
+                    self.format_value(min_value),
+                    self.format_value(max_value)
+                ),
+                thumb_rect(min_value).union(thumb_rect(max_value)),
+            )),
+            DragTarget::None => None,
+        }
+    }
+
     fn apply_step(&self, value: f32) -> f32 {
         self.step
             .map_or(value, |step| (value / step).round() * step)
@@ -336,7 +408,9 @@ impl RangeSlider {
 
         // Handle drag start
         if response.drag_started() {
-            if let Some(pos) = response.interact_pointer_pos() {
+            // Pick the thumb under the press, not where the drag threshold was crossed
+            let press_pos = ui.input(|i| i.pointer.press_origin());
+            if let Some(pos) = press_pos.or_else(|| response.interact_pointer_pos()) {
                 drag_state.target = self.determine_target(
                     pos.x,
                     geometry.min_x,
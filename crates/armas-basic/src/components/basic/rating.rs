@@ -0,0 +1,267 @@
+//! Rating Component
+//!
+//! Star rating input.
+//! Features:
+//! - N icons (stars by default, any [`IconData`] can be used instead)
+//! - Optional half steps: clicking the left half of an icon selects `.5`
+//! - Hover preview of the value a click would select
+//! - Read-only display mode, which also renders fractional values
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::Rating;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let mut stars = 3.5;
+//! let response = Rating::new().allow_half(true).show(ui, &mut stars, &theme);
+//! if response.changed {
+//!     // stars holds the new rating
+//! }
+//! # }
+//! ```
+
+use crate::icon::{render_icon_data, render_icon_strokes, IconData, IconStroke, OwnedIconData};
+use crate::Theme;
+use egui::{pos2, vec2, Color32, Rect, Response, Sense, Ui, WidgetInfo};
+
+// shadcn-style constants
+const ICON_SIZE: f32 = 20.0; // size-5
+const GAP: f32 = 4.0; // gap-1
+const DEFAULT_COUNT: usize = 5;
+
+/// Response from a rating
+#[derive(Debug, Clone)]
+pub struct RatingResponse {
+    /// The underlying egui response
+    pub response: Response,
+    /// The selected value
+    pub value: f32,
+    /// Value a click would select, while hovering
+    pub hovered_value: Option<f32>,
+    /// Whether the value changed this frame
+    pub changed: bool,
+}
+
+/// Rating component
+pub struct Rating<'a> {
+    vertices: &'a [(f32, f32)],
+    indices: &'a [u32],
+    strokes: &'a [IconStroke],
+    viewbox_width: f32,
+    viewbox_height: f32,
+    count: usize,
+    size: f32,
+    allow_half: bool,
+    read_only: bool,
+    color: Option<Color32>,
+}
+
+impl Rating<'static> {
+    /// Create a five-star rating
+    #[must_use]
+    pub fn new() -> Self {
+        Rating::from_owned(crate::icon::star())
+    }
+}
+
+impl<'a> Rating<'a> {
+    /// Create a rating drawn with static [`IconData`]
+    #[must_use]
+    pub const fn with_icon(icon_data: &'a IconData) -> Self {
+        Self {
+            vertices: icon_data.vertices,
+            indices: icon_data.indices,
+            strokes: &[],
+            viewbox_width: icon_data.viewbox_width,
+            viewbox_height: icon_data.viewbox_height,
+            count: DEFAULT_COUNT,
+            size: ICON_SIZE,
+            allow_half: false,
+            read_only: false,
+            color: None,
+        }
+    }
+
+    /// Create a rating drawn with [`OwnedIconData`]
+    #[must_use]
+    pub fn from_owned(data: &'a OwnedIconData) -> Self {
+        Self {
+            vertices: &data.vertices,
+            indices: &data.indices,
+            strokes: &data.strokes,
+            viewbox_width: data.viewbox_width,
+            viewbox_height: data.viewbox_height,
+            count: DEFAULT_COUNT,
+            size: ICON_SIZE,
+            allow_half: false,
+            read_only: false,
+            color: None,
+        }
+    }
+
+    /// Set the number of icons (the maximum value)
+    #[must_use]
+    pub const fn count(mut self, count: usize) -> Self {
+        self.count = if count == 0 { 1 } else { count };
+        self
+    }
+
+    /// Set the icon size
+    #[must_use]
+    pub const fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Allow half steps (click the left half of an icon for `.5`)
+    #[must_use]
+    pub const fn allow_half(mut self, allow: bool) -> Self {
+        self.allow_half = allow;
+        self
+    }
+
+    /// Display only; ignores input
+    #[must_use]
+    pub const fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Set the filled icon color (default: theme `chart_3`)
+    #[must_use]
+    pub const fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Show the rating
+    pub fn show(self, ui: &mut Ui, value: &mut f32, theme: &Theme) -> RatingResponse {
+        let count = self.count;
+        let stride = self.size + GAP;
+        let width = stride * count as f32 - GAP;
+        let sense = if self.read_only {
+            Sense::hover()
+        } else {
+            Sense::click()
+        };
+        let (rect, mut response) = ui.allocate_exact_size(vec2(width, self.size), sense);
+
+        let hovered_value = if self.read_only {
+            None
+        } else {
+            response
+                .hover_pos()
+                .map(|pos| value_at(pos.x - rect.min.x, self.size, GAP, count, self.allow_half))
+        };
+
+        let mut changed = false;
+        if let Some(new_value) = hovered_value.filter(|_| response.clicked()) {
+            if new_value != *value {
+                *value = new_value;
+                changed = true;
+                response.mark_changed();
+            }
+        }
+
+        let current = *value;
+        response.widget_info(|| WidgetInfo::slider(!self.read_only, f64::from(current), "Rating"));
+
+        if ui.is_rect_visible(rect) {
+            let shown = hovered_value.unwrap_or(*value);
+            let filled_color = self.color.unwrap_or_else(|| theme.chart_3());
+            let empty_color = theme.muted();
+
+            for index in 0..count {
+                let icon_rect = Rect::from_min_size(
+                    pos2(rect.min.x + stride * index as f32, rect.min.y),
+                    vec2(self.size, self.size),
+                );
+                let fill = (shown - index as f32).clamp(0.0, 1.0);
+
+                if fill < 1.0 {
+                    self.render(ui.painter(), icon_rect, empty_color);
+                }
+                if fill > 0.0 {
+                    let mut clip = icon_rect;
+                    clip.max.x = icon_rect.min.x + icon_rect.width() * fill;
+                    let painter = ui.painter().with_clip_rect(clip.intersect(ui.clip_rect()));
+                    self.render(&painter, icon_rect, filled_color);
+                }
+            }
+        }
+
+        RatingResponse {
+            response,
+            value: *value,
+            hovered_value,
+            changed,
+        }
+    }
+
+    fn render(&self, painter: &egui::Painter, rect: Rect, color: Color32) {
+        render_icon_data(
+            painter,
+            rect,
+            self.vertices,
+            self.indices,
+            self.viewbox_width,
+            self.viewbox_height,
+            color,
+        );
+        render_icon_strokes(
+            painter,
+            rect,
+            self.strokes,
+            self.viewbox_width,
+            self.viewbox_height,
+            color,
+            None,
+        );
+    }
+}
+
+impl Default for Rating<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Value selected by a pointer `x` pixels from the left edge
+///
+/// Icon `i` (0-based) yields `i + 1`, or `i + 0.5` on its left half when
+/// half steps are allowed.
+fn value_at(x: f32, size: f32, gap: f32, count: usize, allow_half: bool) -> f32 {
+    let stride = size + gap;
+    let index = (x.max(0.0) / stride).floor().min((count - 1) as f32);
+    let local = x - index * stride;
+    if allow_half && local < size / 2.0 {
+        index + 0.5
+    } else {
+        index + 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_at_half_steps() {
+        // Left half of the third icon
+        assert_eq!(value_at(2.0 * 24.0 + 5.0, 20.0, 4.0, 5, true), 2.5);
+        // Right half of the third icon
+        assert_eq!(value_at(2.0 * 24.0 + 15.0, 20.0, 4.0, 5, true), 3.0);
+        // Without half steps the left half selects the whole icon
+        assert_eq!(value_at(2.0 * 24.0 + 5.0, 20.0, 4.0, 5, false), 3.0);
+    }
+
+    #[test]
+    fn test_value_at_clamps_to_count() {
+        assert_eq!(value_at(1000.0, 20.0, 4.0, 5, false), 5.0);
+        assert_eq!(value_at(-10.0, 20.0, 4.0, 5, true), 0.5);
+    }
+}
@@ -0,0 +1,209 @@
+//! Segmented Control Component
+//!
+//! iOS-style segmented control: a pill split into equal-width segments with
+//! a sliding selection highlight.
+//! Features:
+//! - Single selection
+//! - Spring-animated highlight
+//! - Left/Right arrow keys move the selection when focused
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::SegmentedControl;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let mut selected = 0;
+//! let response = SegmentedControl::new(["Day", "Week", "Month"]).show(ui, &mut selected, &theme);
+//! if response.changed {
+//!     // selected now holds the new index
+//! }
+//! # }
+//! ```
+
+use crate::animation::SpringAnimation;
+use crate::ext::draw_focus_ring;
+use crate::Theme;
+use egui::{
+    pos2, vec2, Align2, FontId, Id, Key, Rect, Response, Sense, Ui, WidgetInfo, WidgetType,
+};
+
+// shadcn-style constants
+const HEIGHT: f32 = 32.0; // h-8
+const PADDING: f32 = 2.0; // p-0.5
+const FONT_SIZE: f32 = 14.0; // text-sm
+const MIN_SEGMENT_WIDTH: f32 = 48.0;
+
+// Highlight spring (critically damped: damping = 2 * sqrt(stiffness))
+const SPRING_STIFFNESS: f32 = 400.0;
+const SPRING_DAMPING: f32 = 40.0;
+const MAX_DT: f32 = 1.0 / 30.0;
+
+/// Response from a segmented control
+#[derive(Debug, Clone)]
+pub struct SegmentedControlResponse {
+    /// The underlying egui response
+    pub response: Response,
+    /// The selected segment index
+    pub selected: usize,
+    /// Whether the selection changed this frame
+    pub changed: bool,
+    /// Current (animated) rect of the selection highlight
+    pub highlight_rect: Rect,
+}
+
+/// Segmented control with equal-width segments
+pub struct SegmentedControl {
+    id: Option<Id>,
+    labels: Vec<String>,
+    width: Option<f32>,
+    animate: bool,
+}
+
+impl SegmentedControl {
+    /// Create a segmented control from segment labels
+    pub fn new(labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            id: None,
+            labels: labels.into_iter().map(Into::into).collect(),
+            width: None,
+            animate: true,
+        }
+    }
+
+    /// Set custom ID (important when using multiple segmented controls)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the total width (default: available width)
+    #[must_use]
+    pub const fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Enable or disable the sliding highlight animation
+    #[must_use]
+    pub const fn animate(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+
+    /// Show the segmented control
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        selected: &mut usize,
+        theme: &Theme,
+    ) -> SegmentedControlResponse {
+        let count = self.labels.len().max(1);
+        *selected = (*selected).min(count - 1);
+
+        let min_width = MIN_SEGMENT_WIDTH.mul_add(count as f32, PADDING * 2.0);
+        let width = self
+            .width
+            .unwrap_or_else(|| ui.available_width())
+            .max(min_width);
+        let (rect, mut response) = ui.allocate_exact_size(vec2(width, HEIGHT), Sense::click());
+        let id = self.id.unwrap_or(response.id);
+
+        let inner = rect.shrink(PADDING);
+        let segment_width = inner.width() / count as f32;
+        let segment_rect = |index: f32| {
+            Rect::from_min_size(
+                pos2(index.mul_add(segment_width, inner.min.x), inner.min.y),
+                vec2(segment_width, inner.height()),
+            )
+        };
+
+        // Input
+        let previous = *selected;
+        if response.clicked() {
+            response.request_focus();
+            if let Some(pos) = response.interact_pointer_pos() {
+                let index = ((pos.x - inner.min.x) / segment_width).floor().max(0.0) as usize;
+                *selected = index.min(count - 1);
+            }
+        }
+        if response.has_focus() {
+            ui.input(|i| {
+                if i.key_pressed(Key::ArrowLeft) {
+                    *selected = selected.saturating_sub(1);
+                }
+                if i.key_pressed(Key::ArrowRight) {
+                    *selected = (*selected + 1).min(count - 1);
+                }
+            });
+        }
+        let changed = *selected != previous;
+        if changed {
+            response.mark_changed();
+        }
+
+        // Highlight animation
+        let state_id = id.with("highlight");
+        let target = *selected as f32;
+        let (position, velocity) = if self.animate {
+            let (value, velocity) = ui
+                .ctx()
+                .data(|d| d.get_temp(state_id))
+                .unwrap_or((target, 0.0));
+            let mut spring = SpringAnimation::new(value, target)
+                .with_velocity(velocity)
+                .params(SPRING_STIFFNESS, SPRING_DAMPING);
+            spring.update(ui.input(|i| i.stable_dt).min(MAX_DT));
+            if spring.is_settled(0.001, 0.01) {
+                spring.reset(target, target);
+            } else {
+                ui.ctx().request_repaint();
+            }
+            (spring.value, spring.velocity)
+        } else {
+            (target, 0.0)
+        };
+        ui.ctx()
+            .data_mut(|d| d.insert_temp(state_id, (position, velocity)));
+        let highlight_rect = segment_rect(position.clamp(0.0, (count - 1) as f32));
+
+        let label = self.labels.get(*selected).cloned().unwrap_or_default();
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::RadioGroup, true, &label));
+
+        // Paint
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            painter.rect_filled(rect, HEIGHT / 2.0, theme.muted());
+            painter.rect_filled(highlight_rect, inner.height() / 2.0, theme.background());
+            if response.has_focus() {
+                draw_focus_ring(painter, rect, theme);
+            }
+
+            for (index, label) in self.labels.iter().enumerate() {
+                let text_color = if index == *selected {
+                    theme.foreground()
+                } else {
+                    theme.muted_foreground()
+                };
+                painter.text(
+                    segment_rect(index as f32).center(),
+                    Align2::CENTER_CENTER,
+                    label,
+                    FontId::proportional(FONT_SIZE),
+                    text_color,
+                );
+            }
+        }
+
+        SegmentedControlResponse {
+            response,
+            selected: *selected,
+            changed,
+            highlight_rect,
+        }
+    }
+}
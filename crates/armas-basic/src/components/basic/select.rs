@@ -8,6 +8,7 @@
 //! - Disabled options
 //! - State persistence
 
+use crate::ext::draw_focus_ring;
 use crate::Theme;
 use egui::{
     vec2, Color32, CornerRadius, Key, Painter, Rect, Response, Sense, Stroke, TextEdit, Ui,
@@ -352,6 +353,8 @@ impl Select {
                 Stroke::new(2.0, ring_color),
                 egui::StrokeKind::Outside,
             );
+        } else if response.has_focus() {
+            draw_focus_ring(painter, rect, theme);
         }
 
         // Scale font and padding for small heights
@@ -2,6 +2,7 @@
 //!
 //! Simple horizontal or vertical divider line.
 
+use crate::ext::ArmasContextExt;
 use egui::{Response, Ui, Vec2};
 
 /// Separator orientation
@@ -98,3 +99,10 @@ impl Default for Separator {
         Self::new()
     }
 }
+
+impl egui::Widget for Separator {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let theme = ui.ctx().armas_theme();
+        self.show(ui, &theme)
+    }
+}
@@ -8,7 +8,7 @@
 //! - Labels and value display
 
 use crate::animation::{DragMode, VelocityDrag, VelocityDragConfig};
-use egui::{pos2, vec2, Color32, Rect, Sense, Stroke, Ui};
+use egui::{pos2, vec2, Color32, Rect, Sense, Stroke, Ui, WidgetInfo};
 
 // shadcn Slider constants
 const TRACK_HEIGHT: f32 = 6.0; // h-1.5 in tailwind (6px)
@@ -44,6 +44,7 @@ pub struct Slider {
     default_value: Option<f32>,
     velocity_mode: bool,
     sensitivity: f64,
+    aria_label: Option<String>,
 }
 
 impl Slider {
@@ -63,6 +64,7 @@ impl Slider {
             default_value: None,
             velocity_mode: false,
             sensitivity: 1.0,
+            aria_label: None,
         }
     }
 
@@ -142,6 +144,13 @@ impl Slider {
         self
     }
 
+    /// Set the label announced by screen readers (defaults to the visible label)
+    #[must_use]
+    pub fn aria_label(mut self, label: impl Into<String>) -> Self {
+        self.aria_label = Some(label.into());
+        self
+    }
+
     /// Show the slider
     pub fn show(self, ui: &mut Ui, value: &mut f32, theme: &crate::Theme) -> SliderResponse {
         let mut changed = false;
@@ -286,6 +295,13 @@ impl Slider {
                 }
             }
 
+            let label = self
+                .aria_label
+                .as_deref()
+                .or(self.label.as_deref())
+                .unwrap_or_default();
+            response.widget_info(|| WidgetInfo::slider(true, f64::from(*value), label));
+
             if ui.is_rect_visible(rect) {
                 let painter = ui.painter();
 
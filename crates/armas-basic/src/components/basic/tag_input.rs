@@ -0,0 +1,342 @@
+//! Tag Input Component
+//!
+//! Multi-value text field: typed text becomes a chip on Enter or comma,
+//! Backspace on an empty field removes the last chip, and each chip has its
+//! own remove button. Optional suggestions appear in a dropdown while typing.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui, tags: &mut Vec<String>) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::TagInput;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let suggestions = ["rust".to_string(), "egui".to_string()];
+//! let response = TagInput::new(tags)
+//!     .placeholder("Add tag...")
+//!     .suggestions(&suggestions)
+//!     .show(ui, &theme);
+//! if let Some(tag) = response.added {
+//!     println!("added {tag}");
+//! }
+//! # }
+//! ```
+
+use super::badge::{Badge, BadgeVariant};
+use crate::Theme;
+use egui::{vec2, Color32, CornerRadius, Id, Key, Rect, Response, Sense, Stroke, TextEdit, Ui};
+
+// shadcn Input / Select constants
+const CORNER_RADIUS: u8 = 6; // rounded-md
+const CORNER_RADIUS_SM: u8 = 4;
+const MIN_HEIGHT: f32 = 36.0; // h-9
+const PADDING_X: i8 = 8;
+const PADDING_Y: i8 = 4;
+const FONT_SIZE: f32 = 14.0; // text-sm
+const ITEM_HEIGHT: f32 = 32.0;
+const MIN_EDIT_WIDTH: f32 = 80.0;
+const CHIP_GAP: f32 = 4.0;
+
+/// Response from a tag input
+#[derive(Debug, Clone)]
+pub struct TagInputResponse {
+    /// Response of the field
+    pub response: Response,
+    /// Tag added this frame
+    pub added: Option<String>,
+    /// Tag removed this frame
+    pub removed: Option<String>,
+    /// Whether the tag list changed this frame
+    pub changed: bool,
+}
+
+/// Typing state kept between frames
+#[derive(Clone, Default)]
+struct TagInputState {
+    draft: String,
+    highlighted: Option<usize>,
+}
+
+/// Text field that collects values as removable chips
+pub struct TagInput<'a> {
+    tags: &'a mut Vec<String>,
+    id: Option<Id>,
+    placeholder: String,
+    suggestions: Vec<String>,
+    width: Option<f32>,
+}
+
+impl<'a> TagInput<'a> {
+    /// Create a tag input editing `tags`
+    pub const fn new(tags: &'a mut Vec<String>) -> Self {
+        Self {
+            tags,
+            id: None,
+            placeholder: String::new(),
+            suggestions: Vec::new(),
+            width: None,
+        }
+    }
+
+    /// Set custom ID (needed when several tag inputs share a parent)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set placeholder text shown while the field is empty
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Offer these values in a dropdown while typing
+    #[must_use]
+    pub fn suggestions(mut self, suggestions: &[String]) -> Self {
+        self.suggestions = suggestions.to_vec();
+        self
+    }
+
+    /// Set field width (default: 300)
+    #[must_use]
+    pub const fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Show the tag input
+    pub fn show(mut self, ui: &mut Ui, theme: &Theme) -> TagInputResponse {
+        let id = self.id.unwrap_or_else(|| ui.id().with("tag_input"));
+        let edit_id = id.with("edit");
+        let dropdown_id = id.with("dropdown");
+        let width = self.width.unwrap_or(300.0);
+
+        let mut state: TagInputState = ui.ctx().data(|d| d.get_temp(id)).unwrap_or_default();
+        let mut commit = None;
+        let mut removed = None;
+        // Backspace only removes a chip if there was no text to delete
+        let was_empty = state.draft.is_empty();
+
+        let focused = ui.memory(|m| m.has_focus(edit_id));
+        let border = if focused { theme.ring() } else { theme.input() };
+
+        let frame = egui::Frame::new()
+            .fill(theme.background())
+            .stroke(Stroke::new(1.0, border))
+            .corner_radius(CornerRadius::same(CORNER_RADIUS))
+            .inner_margin(egui::Margin::symmetric(PADDING_X, PADDING_Y))
+            .show(ui, |ui| {
+                let inner_width = width - f32::from(PADDING_X) * 2.0;
+                ui.set_width(inner_width);
+                ui.set_min_height(MIN_HEIGHT - f32::from(PADDING_Y) * 2.0);
+
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing = vec2(CHIP_GAP, CHIP_GAP);
+
+                    for (index, tag) in self.tags.iter().enumerate() {
+                        let chip = ui.push_id(("tag", index), |ui| {
+                            Badge::new(tag.as_str())
+                                .variant(BadgeVariant::Secondary)
+                                .removable()
+                                .show(ui, theme)
+                        });
+                        if chip.inner.removed {
+                            removed = Some(index);
+                        }
+                    }
+
+                    let hint = if self.tags.is_empty() {
+                        self.placeholder.as_str()
+                    } else {
+                        ""
+                    };
+                    ui.add(
+                        TextEdit::singleline(&mut state.draft)
+                            .id(edit_id)
+                            .hint_text(hint)
+                            .frame(false)
+                            .font(egui::FontId::proportional(FONT_SIZE))
+                            .desired_width(ui.available_width().max(MIN_EDIT_WIDTH)),
+                    )
+                })
+                .inner
+            });
+        let edit = frame.inner;
+
+        // Clicking anywhere on the field focuses the text edit
+        let response = frame.response.interact(Sense::click());
+        if response.clicked() {
+            edit.request_focus();
+        }
+
+        let matches = self.matching_suggestions(&state.draft);
+        state.highlighted = state.highlighted.filter(|&i| i < matches.len());
+
+        // Keep the dropdown up while the pointer is on it, since pressing
+        // an item takes focus away from the text edit
+        let pointer_in_dropdown = ui
+            .input(|i| i.pointer.hover_pos())
+            .zip(ui.ctx().memory(|m| m.area_rect(dropdown_id)))
+            .is_some_and(|(pos, rect)| rect.contains(pos));
+        let dropdown_open =
+            !matches.is_empty() && (edit.has_focus() || edit.lost_focus() || pointer_in_dropdown);
+
+        if dropdown_open {
+            if let Some(pick) =
+                Self::show_dropdown(ui, theme, dropdown_id, response.rect, &matches, &mut state)
+            {
+                commit = Some(pick);
+                edit.request_focus();
+            }
+        }
+
+        if edit.has_focus() && dropdown_open {
+            ui.input(|i| {
+                if i.key_pressed(Key::ArrowDown) {
+                    state.highlighted = Some(
+                        state
+                            .highlighted
+                            .map_or(0, |h| (h + 1).min(matches.len() - 1)),
+                    );
+                }
+                if i.key_pressed(Key::ArrowUp) {
+                    state.highlighted = state.highlighted.and_then(|h| h.checked_sub(1));
+                }
+            });
+        }
+
+        // Enter commits the highlighted suggestion or the typed text
+        if edit.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+            commit = Some(match state.highlighted.filter(|_| dropdown_open) {
+                Some(index) => matches[index].clone(),
+                None => state.draft.clone(),
+            });
+            edit.request_focus();
+        }
+
+        let mut added = None;
+        if let Some(text) = commit {
+            added = self.push_tag(&text);
+            state.draft.clear();
+            state.highlighted = None;
+        } else if state.draft.contains(',') {
+            // A comma (typed or pasted) commits everything before it
+            let mut parts: Vec<String> = state.draft.split(',').map(str::to_string).collect();
+            let rest = parts.pop().unwrap_or_default();
+            for part in parts {
+                added = self.push_tag(&part).or(added);
+            }
+            state.draft = rest;
+        }
+
+        // Backspace on an empty field removes the last chip
+        if removed.is_none()
+            && was_empty
+            && edit.has_focus()
+            && ui.input(|i| i.key_pressed(Key::Backspace))
+            && !self.tags.is_empty()
+        {
+            removed = Some(self.tags.len() - 1);
+        }
+
+        let removed = removed.map(|index| self.tags.remove(index));
+
+        ui.ctx().data_mut(|d| d.insert_temp(id, state));
+
+        TagInputResponse {
+            response,
+            changed: added.is_some() || removed.is_some(),
+            added,
+            removed,
+        }
+    }
+
+    /// Append a trimmed, non-empty, not yet present tag
+    fn push_tag(&mut self, text: &str) -> Option<String> {
+        let tag = text.trim();
+        if tag.is_empty() || self.tags.iter().any(|t| t == tag) {
+            return None;
+        }
+        self.tags.push(tag.to_string());
+        Some(tag.to_string())
+    }
+
+    /// Suggestions containing the typed text that aren't tags yet
+    fn matching_suggestions(&self, draft: &str) -> Vec<String> {
+        let query = draft.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        self.suggestions
+            .iter()
+            .filter(|s| s.to_lowercase().contains(&query) && !self.tags.contains(s))
+            .cloned()
+            .collect()
+    }
+
+    /// Suggestion list below the field, returning a clicked suggestion
+    fn show_dropdown(
+        ui: &Ui,
+        theme: &Theme,
+        dropdown_id: Id,
+        field_rect: Rect,
+        matches: &[String],
+        state: &mut TagInputState,
+    ) -> Option<String> {
+        let mut picked = None;
+        egui::Area::new(dropdown_id)
+            .fixed_pos(field_rect.left_bottom() + vec2(0.0, 4.0))
+            .order(egui::Order::Foreground)
+            .show(ui.ctx(), |ui| {
+                egui::Frame::new()
+                    .fill(theme.popover())
+                    .stroke(Stroke::new(1.0, theme.border()))
+                    .corner_radius(CornerRadius::same(CORNER_RADIUS))
+                    .inner_margin(4.0)
+                    .shadow(egui::epaint::Shadow {
+                        offset: [0, 4],
+                        blur: 8,
+                        spread: 0,
+                        color: Color32::from_black_alpha(60),
+                    })
+                    .show(ui, |ui| {
+                        ui.spacing_mut().item_spacing.y = 0.0;
+                        let row_width = field_rect.width() - 8.0;
+                        for (index, suggestion) in matches.iter().enumerate() {
+                            let (rect, row) = ui
+                                .allocate_exact_size(vec2(row_width, ITEM_HEIGHT), Sense::click());
+                            if row.hovered() {
+                                state.highlighted = Some(index);
+                            }
+                            let active = state.highlighted == Some(index);
+                            if active {
+                                ui.painter().rect_filled(
+                                    rect,
+                                    CornerRadius::same(CORNER_RADIUS_SM),
+                                    theme.accent(),
+                                );
+                            }
+                            ui.painter().text(
+                                rect.left_center() + vec2(8.0, 0.0),
+                                egui::Align2::LEFT_CENTER,
+                                suggestion,
+                                egui::FontId::proportional(FONT_SIZE),
+                                if active {
+                                    theme.accent_foreground()
+                                } else {
+                                    theme.popover_foreground()
+                                },
+                            );
+                            if row.clicked() {
+                                picked = Some(suggestion.clone());
+                            }
+                        }
+                    });
+            });
+        picked
+    }
+}
@@ -5,17 +5,38 @@
 //! - Labels and descriptions
 //! - Validation states (error, success, warning)
 //! - Character count limits
-//! - Resizable option
+//! - Optional resize grip to drag the height (persisted in memory)
+//! - Live markdown preview beside or below the editor
 
 use crate::ext::ArmasContextExt;
-use crate::{InputState, InputVariant};
-use egui::{Color32, Response, Stroke, TextEdit, Ui};
+use crate::{InputState, InputVariant, Theme};
+use egui::text::{LayoutJob, TextFormat};
+use egui::{vec2, Color32, CursorIcon, FontId, Rect, Response, Sense, Stroke, TextEdit, Ui};
 
 // shadcn Textarea constants
 const CORNER_RADIUS: f32 = 6.0; // rounded-md
 const MIN_HEIGHT: f32 = 80.0; // Minimum height
 const PADDING: f32 = 12.0; // px-3 py-2
 const FONT_SIZE: f32 = 14.0; // text-sm
+const GRIP_SIZE: f32 = 12.0;
+const PREVIEW_GAP: f32 = 8.0; // gap-2
+
+// Markdown preview heading sizes for `#` to `######`
+const HEADING_SIZES: [f32; 6] = [24.0, 20.0, 18.0, 16.0, 14.0, 14.0]; // text-2xl .. text-sm
+/// Opacity of body text, so bold and headings stand out without a bold font
+const BODY_TEXT_OPACITY: f32 = 0.85;
+
+/// Where the live markdown preview is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PreviewMode {
+    /// No preview
+    #[default]
+    Off,
+    /// Preview to the right of the editor
+    Beside,
+    /// Preview below the editor
+    Below,
+}
 
 /// Response from the textarea
 #[derive(Debug, Clone)]
@@ -26,6 +47,8 @@ pub struct TextareaResponse {
     pub text: String,
     /// Whether text changed this frame
     pub changed: bool,
+    /// Height of the editor box
+    pub height: f32,
 }
 
 /// Multi-line text input field styled like shadcn/ui
@@ -40,6 +63,7 @@ pub struct Textarea {
     rows: usize,
     max_chars: Option<usize>,
     resizable: bool,
+    preview: PreviewMode,
     disabled: bool,
 }
 
@@ -56,7 +80,8 @@ impl Textarea {
             width: None,
             rows: 4,
             max_chars: None,
-            resizable: true,
+            resizable: false,
+            preview: PreviewMode::Off,
             disabled: false,
         }
     }
@@ -124,13 +149,22 @@ impl Textarea {
         self
     }
 
-    /// Set whether the textarea is resizable
+    /// Show a grip in the bottom-right corner to drag the height
+    ///
+    /// The dragged height persists in memory, keyed by the textarea's id.
     #[must_use]
     pub const fn resizable(mut self, resizable: bool) -> Self {
         self.resizable = resizable;
         self
     }
 
+    /// Show a live markdown preview of the text
+    #[must_use]
+    pub const fn markdown_preview(mut self, mode: PreviewMode) -> Self {
+        self.preview = mode;
+        self
+    }
+
     /// Set disabled state
     #[must_use]
     pub const fn disabled(mut self, disabled: bool) -> Self {
@@ -152,8 +186,12 @@ impl Textarea {
         }
 
         let width = self.width.unwrap_or(300.0);
+        let height_id = self
+            .id
+            .unwrap_or_else(|| ui.next_auto_id())
+            .with("textarea_height");
 
-        let response = ui
+        let (response, height) = ui
             .vertical(|ui| {
                 ui.spacing_mut().item_spacing.y = 6.0; // gap-1.5
 
@@ -193,77 +231,66 @@ impl Textarea {
                     });
                 }
 
-                // Calculate height based on rows
+                // Calculate height based on rows, or the dragged height
                 let line_height = ui.text_style_height(&egui::TextStyle::Body);
                 let min_height = (line_height * self.rows as f32 + PADDING * 2.0).max(MIN_HEIGHT);
-
-                // Border color based on state
-                let border_color = match self.state {
-                    InputState::Normal => theme.input(),
-                    InputState::Success => theme.chart_2(),
-                    InputState::Error => theme.destructive(),
-                    InputState::Warning => theme.chart_3(),
-                };
-
-                // Background color
-                let bg_color = if self.disabled || self.variant == InputVariant::Filled {
-                    theme.muted()
+                let mut height = if self.resizable {
+                    ui.ctx()
+                        .data(|d| d.get_temp(height_id))
+                        .unwrap_or(min_height)
+                        .max(min_height)
                 } else {
-                    theme.background()
+                    min_height
                 };
 
-                // Text color
-                let text_color = if self.disabled {
-                    theme.muted_foreground()
-                } else {
-                    theme.foreground()
+                let (response, editor_rect) = match self.preview {
+                    PreviewMode::Off => self.editor(ui, text, width, height, &theme),
+                    PreviewMode::Beside => {
+                        ui.horizontal_top(|ui| {
+                            ui.spacing_mut().item_spacing.x = PREVIEW_GAP;
+                            let editor = self.editor(ui, text, width, height, &theme);
+                            Self::preview(ui, text, width, editor.1.height(), &theme);
+                            editor
+                        })
+                        .inner
+                    }
+                    PreviewMode::Below => {
+                        let editor = self.editor(ui, text, width, height, &theme);
+                        Self::preview(ui, text, width, editor.1.height(), &theme);
+                        editor
+                    }
                 };
 
-                // Frame for the textarea
-                let frame = egui::Frame::NONE
-                    .fill(bg_color)
-                    .stroke(Stroke::new(1.0, border_color))
-                    .corner_radius(CORNER_RADIUS)
-                    .inner_margin(PADDING);
-
-                let response = frame.show(ui, |ui| {
-                    ui.set_width(width - PADDING * 2.0);
-                    ui.set_min_height(min_height - PADDING * 2.0);
-
-                    // Style the text edit
-                    ui.style_mut().visuals.widgets.inactive.bg_fill = Color32::TRANSPARENT;
-                    ui.style_mut().visuals.widgets.hovered.bg_fill = Color32::TRANSPARENT;
-                    ui.style_mut().visuals.widgets.active.bg_fill = Color32::TRANSPARENT;
-                    ui.style_mut().visuals.widgets.inactive.bg_stroke = Stroke::NONE;
-                    ui.style_mut().visuals.widgets.hovered.bg_stroke = Stroke::NONE;
-                    ui.style_mut().visuals.widgets.active.bg_stroke = Stroke::NONE;
-                    ui.style_mut().visuals.override_text_color = Some(text_color);
-                    ui.style_mut()
-                        .text_styles
-                        .insert(egui::TextStyle::Body, egui::FontId::proportional(FONT_SIZE));
-
-                    let mut text_edit = TextEdit::multiline(text)
-                        .hint_text(&self.placeholder)
-                        .desired_width(width - PADDING * 4.0)
-                        .desired_rows(self.rows)
-                        .frame(false)
-                        .interactive(!self.disabled);
-
-                    if !self.resizable {
-                        text_edit = text_edit.desired_rows(self.rows);
+                // Resize grip in the bottom-right corner
+                if self.resizable && !self.disabled {
+                    let grip_rect = Rect::from_min_size(
+                        editor_rect.max - vec2(GRIP_SIZE, GRIP_SIZE),
+                        vec2(GRIP_SIZE, GRIP_SIZE),
+                    );
+                    let grip = ui
+                        .interact(grip_rect, height_id.with("grip"), Sense::drag())
+                        .on_hover_cursor(CursorIcon::ResizeVertical);
+                    grip.widget_info(|| {
+                        egui::WidgetInfo::labeled(egui::WidgetType::Other, true, "Resize")
+                    });
+                    if grip.dragged() {
+                        height = (height + grip.drag_delta().y).max(min_height);
                     }
+                    ui.ctx().data_mut(|d| d.insert_temp(height_id, height));
 
-                    let response = ui.add(text_edit);
-
-                    // Enforce max characters
-                    if let Some(max) = self.max_chars {
-                        if text.len() > max {
-                            text.truncate(max);
-                        }
+                    let color = if grip.hovered() || grip.dragged() {
+                        theme.foreground()
+                    } else {
+                        theme.muted_foreground()
+                    };
+                    let corner = grip_rect.max - vec2(3.0, 3.0);
+                    for offset in [4.0, 8.0] {
+                        ui.painter().line_segment(
+                            [corner - vec2(offset, 0.0), corner - vec2(0.0, offset)],
+                            Stroke::new(1.0, color),
+                        );
                     }
-
-                    response
-                });
+                }
 
                 // Description/helper text
                 if let Some(desc) = &self.description {
@@ -276,7 +303,7 @@ impl Textarea {
                     ui.label(egui::RichText::new(desc).size(12.0).color(desc_color));
                 }
 
-                response.inner
+                (response, height)
             })
             .inner;
 
@@ -295,8 +322,101 @@ impl Textarea {
             response,
             text: text_clone,
             changed,
+            height,
         }
     }
+
+    /// Draw the editor box; returns the text edit response and the box rect
+    fn editor(
+        &self,
+        ui: &mut Ui,
+        text: &mut String,
+        width: f32,
+        height: f32,
+        theme: &Theme,
+    ) -> (Response, Rect) {
+        // Border color based on state
+        let border_color = match self.state {
+            InputState::Normal => theme.input(),
+            InputState::Success => theme.chart_2(),
+            InputState::Error => theme.destructive(),
+            InputState::Warning => theme.chart_3(),
+        };
+
+        // Background color
+        let bg_color = if self.disabled || self.variant == InputVariant::Filled {
+            theme.muted()
+        } else {
+            theme.background()
+        };
+
+        // Text color
+        let text_color = if self.disabled {
+            theme.muted_foreground()
+        } else {
+            theme.foreground()
+        };
+
+        // Frame for the textarea
+        let frame = egui::Frame::NONE
+            .fill(bg_color)
+            .stroke(Stroke::new(1.0, border_color))
+            .corner_radius(CORNER_RADIUS)
+            .inner_margin(PADDING);
+
+        let response = frame.show(ui, |ui| {
+            ui.set_width(width - PADDING * 2.0);
+            ui.set_min_height(height - PADDING * 2.0);
+
+            // Style the text edit
+            ui.style_mut().visuals.widgets.inactive.bg_fill = Color32::TRANSPARENT;
+            ui.style_mut().visuals.widgets.hovered.bg_fill = Color32::TRANSPARENT;
+            ui.style_mut().visuals.widgets.active.bg_fill = Color32::TRANSPARENT;
+            ui.style_mut().visuals.widgets.inactive.bg_stroke = Stroke::NONE;
+            ui.style_mut().visuals.widgets.hovered.bg_stroke = Stroke::NONE;
+            ui.style_mut().visuals.widgets.active.bg_stroke = Stroke::NONE;
+            ui.style_mut().visuals.override_text_color = Some(text_color);
+            ui.style_mut()
+                .text_styles
+                .insert(egui::TextStyle::Body, egui::FontId::proportional(FONT_SIZE));
+
+            let text_edit = TextEdit::multiline(text)
+                .hint_text(&self.placeholder)
+                .desired_width(width - PADDING * 4.0)
+                .desired_rows(self.rows)
+                .frame(false)
+                .interactive(!self.disabled);
+
+            let response = ui.add(text_edit);
+
+            // Enforce max characters
+            if let Some(max) = self.max_chars {
+                if text.len() > max {
+                    text.truncate(max);
+                }
+            }
+
+            response
+        });
+
+        (response.inner, response.response.rect)
+    }
+
+    /// Draw the rendered markdown in a box matching the editor's size
+    fn preview(ui: &mut Ui, text: &str, width: f32, height: f32, theme: &Theme) {
+        egui::Frame::NONE
+            .fill(theme.muted().gamma_multiply(0.5))
+            .stroke(Stroke::new(1.0, theme.border()))
+            .corner_radius(CORNER_RADIUS)
+            .inner_margin(PADDING)
+            .show(ui, |ui| {
+                ui.set_width(width - PADDING * 2.0);
+                ui.set_min_height(height - PADDING * 2.0);
+                let mut job = markdown_job(text, theme);
+                job.wrap.max_width = width - PADDING * 2.0;
+                ui.label(job);
+            });
+    }
 }
 
 impl Default for Textarea {
@@ -304,3 +424,127 @@ impl Default for Textarea {
         Self::new("")
     }
 }
+
+/// Lay out markdown text: headings, `**bold**`, `*italic*`, `` `code` ``,
+/// `- ` list items and `> ` quotes
+fn markdown_job(text: &str, theme: &Theme) -> LayoutJob {
+    let body = FontId::proportional(FONT_SIZE);
+    let body_color = theme.foreground().gamma_multiply(BODY_TEXT_OPACITY);
+    let mut job = LayoutJob::default();
+
+    for (index, line) in text.lines().enumerate() {
+        if index > 0 {
+            job.append("\n", 0.0, TextFormat::simple(body.clone(), body_color));
+        }
+        let trimmed = line.trim_start();
+        let hashes = trimmed.chars().take_while(|c| *c == '#').count();
+
+        if (1..=HEADING_SIZES.len()).contains(&hashes) && trimmed[hashes..].starts_with(' ') {
+            let font = FontId::proportional(HEADING_SIZES[hashes - 1]);
+            append_inline(&mut job, trimmed[hashes..].trim_start(), &font, theme, true);
+        } else if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            job.append("•  ", 0.0, TextFormat::simple(body.clone(), body_color));
+            append_inline(&mut job, item, &body, theme, false);
+        } else if let Some(quote) = trimmed.strip_prefix("> ") {
+            job.append(
+                quote,
+                0.0,
+                TextFormat {
+                    font_id: body.clone(),
+                    color: theme.muted_foreground(),
+                    italics: true,
+                    ..Default::default()
+                },
+            );
+        } else {
+            append_inline(&mut job, line, &body, theme, false);
+        }
+    }
+    job
+}
+
+/// Append a line, toggling bold, italics and code at their markers
+fn append_inline(job: &mut LayoutJob, line: &str, font: &FontId, theme: &Theme, strong: bool) {
+    let (mut bold, mut italic, mut code) = (false, false, false);
+    let mut run = String::new();
+    let mut flush = |run: &mut String, bold: bool, italic: bool, code: bool| {
+        if run.is_empty() {
+            return;
+        }
+        let format = if code {
+            TextFormat {
+                font_id: FontId::monospace(font.size * 0.9),
+                color: theme.foreground(),
+                background: theme.muted(),
+                ..Default::default()
+            }
+        } else {
+            TextFormat {
+                font_id: font.clone(),
+                color: if strong || bold {
+                    theme.foreground()
+                } else {
+                    theme.foreground().gamma_multiply(BODY_TEXT_OPACITY)
+                },
+                italics: italic,
+                ..Default::default()
+            }
+        };
+        job.append(run, 0.0, format);
+        run.clear();
+    };
+
+    let mut rest = line;
+    while let Some(ch) = rest.chars().next() {
+        if !code && rest.starts_with("**") {
+            flush(&mut run, bold, italic, code);
+            bold = !bold;
+            rest = &rest[2..];
+        } else if ch == '`' {
+            flush(&mut run, bold, italic, code);
+            code = !code;
+            rest = &rest[1..];
+        } else if !code && ch == '*' {
+            flush(&mut run, bold, italic, code);
+            italic = !italic;
+            rest = &rest[1..];
+        } else {
+            run.push(ch);
+            rest = &rest[ch.len_utf8()..];
+        }
+    }
+    flush(&mut run, bold, italic, code);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section_text(job: &LayoutJob, index: usize) -> &str {
+        &job.text[job.sections[index].byte_range.clone()]
+    }
+
+    #[test]
+    fn test_markdown_heading_is_larger() {
+        let theme = Theme::dark();
+        let job = markdown_job("# Title\nbody", &theme);
+
+        assert_eq!(job.text, "Title\nbody");
+        assert_eq!(section_text(&job, 0), "Title");
+        assert!(job.sections[0].format.font_id.size > job.sections[2].format.font_id.size);
+    }
+
+    #[test]
+    fn test_markdown_bold_strips_markers() {
+        let theme = Theme::dark();
+        let job = markdown_job("plain **bold** plain", &theme);
+
+        assert_eq!(job.text, "plain bold plain");
+        assert_eq!(section_text(&job, 1), "bold");
+        assert_eq!(job.sections[1].format.color, theme.foreground());
+        assert_ne!(job.sections[0].format.color, theme.foreground());
+    }
+}
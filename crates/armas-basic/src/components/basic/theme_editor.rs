@@ -0,0 +1,320 @@
+//! Theme Editor Component
+//!
+//! Live editor for a [`Theme`].
+//! Features:
+//! - Swatches for every color token, with a color picker for the selected one
+//! - Sliders for the spacing and corner radius scales
+//! - Live preview of common components using the edited theme
+//! - "Export JSON" action that serializes the theme
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::ThemeEditor;
+//!
+//! let mut theme = ui.ctx().armas_theme();
+//! let response = ThemeEditor::new("theme_editor").show(ui, &mut theme);
+//! if response.changed {
+//!     ui.ctx().set_armas_theme(theme);
+//! }
+//! if let Some(json) = response.exported {
+//!     // save `json` to disk
+//! }
+//! # }
+//! ```
+
+use crate::theme::{ColorPalette, Spacing};
+use crate::{Badge, Button, ButtonVariant, ColorPicker, Slider, Theme, Toggle};
+use egui::{vec2, Color32, Id, Response, Sense, Stroke, Ui, WidgetInfo, WidgetType};
+
+// shadcn-style constants
+const EDITOR_WIDTH: f32 = 320.0;
+const SWATCH_SIZE: f32 = 24.0; // size-6
+const SWATCH_RADIUS: f32 = 4.0; // rounded
+const SWATCH_GAP: f32 = 6.0; // gap-1.5
+const SECTION_GAP: f32 = 16.0; // gap-4
+const HEADING_SIZE: f32 = 14.0; // text-sm
+const MAX_SPACING: f32 = 64.0;
+const MAX_RADIUS: f32 = 32.0;
+
+/// Token selected when the editor is first shown
+const DEFAULT_TOKEN: &str = "primary";
+
+/// Response from a theme editor
+#[derive(Debug, Clone)]
+pub struct ThemeEditorResponse {
+    /// The response of the whole editor
+    pub response: Response,
+    /// Whether any token changed this frame
+    pub changed: bool,
+    /// Name of the selected color token
+    pub selected_token: &'static str,
+    /// Pretty-printed theme JSON, when "Export JSON" was clicked this frame
+    pub exported: Option<String>,
+}
+
+/// Theme editor component
+///
+/// Edits a [`Theme`] in place. The editor itself is drawn with the theme
+/// being edited, so changes preview immediately.
+pub struct ThemeEditor {
+    id: Id,
+    width: f32,
+    show_preview: bool,
+}
+
+impl ThemeEditor {
+    /// Create a new theme editor
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            width: EDITOR_WIDTH,
+            show_preview: true,
+        }
+    }
+
+    /// Set the editor width
+    #[must_use]
+    pub const fn width(mut self, width: f32) -> Self {
+        self.width = width.max(200.0);
+        self
+    }
+
+    /// Show or hide the component preview
+    #[must_use]
+    pub const fn show_preview(mut self, show: bool) -> Self {
+        self.show_preview = show;
+        self
+    }
+
+    /// Show the theme editor
+    pub fn show(self, ui: &mut Ui, theme: &mut Theme) -> ThemeEditorResponse {
+        let selected_id = self.id.with("selected_token");
+        let mut selected: &'static str = ui
+            .ctx()
+            .data(|d| d.get_temp(selected_id))
+            .unwrap_or(DEFAULT_TOKEN);
+
+        let mut changed = false;
+        let mut exported = None;
+
+        let inner = ui.vertical(|ui| {
+            ui.set_width(self.width);
+            ui.spacing_mut().item_spacing.y = SECTION_GAP;
+
+            let style = theme.clone();
+            Self::heading(ui, "Colors", &style);
+            selected = Self::swatches(ui, &mut theme.colors, selected, &style);
+
+            if let Some((_, rgb)) = color_tokens(&mut theme.colors)
+                .into_iter()
+                .find(|(name, _)| *name == selected)
+            {
+                let mut color = Color32::from_rgb(rgb[0], rgb[1], rgb[2]);
+                if ColorPicker::new(self.id.with("picker").with(selected))
+                    .width(self.width)
+                    .show(ui, &mut color, &style)
+                    .changed
+                {
+                    *rgb = [color.r(), color.g(), color.b()];
+                    changed = true;
+                }
+            }
+
+            Self::heading(ui, "Spacing", &style);
+            changed |= self.spacing_sliders(ui, &mut theme.spacing, &style);
+
+            Self::heading(ui, "Radius", &style);
+            changed |= self.radius_sliders(ui, &mut theme.spacing, &style);
+
+            if self.show_preview {
+                Self::heading(ui, "Preview", &style);
+                Self::preview(ui, self.id, theme);
+            }
+
+            if Button::new("Export JSON")
+                .variant(ButtonVariant::Outline)
+                .show(ui, theme)
+                .clicked()
+            {
+                if let Ok(json) = serde_json::to_string_pretty(theme) {
+                    ui.ctx().copy_text(json.clone());
+                    exported = Some(json);
+                }
+            }
+        });
+
+        ui.ctx().data_mut(|d| d.insert_temp(selected_id, selected));
+
+        ThemeEditorResponse {
+            response: inner.response,
+            changed,
+            selected_token: selected,
+            exported,
+        }
+    }
+
+    fn heading(ui: &mut Ui, text: &str, theme: &Theme) {
+        ui.label(
+            egui::RichText::new(text)
+                .size(HEADING_SIZE)
+                .strong()
+                .color(theme.foreground()),
+        );
+    }
+
+    /// Grid of color swatches; returns the (possibly newly) selected token
+    fn swatches(
+        ui: &mut Ui,
+        colors: &mut ColorPalette,
+        mut selected: &'static str,
+        theme: &Theme,
+    ) -> &'static str {
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = vec2(SWATCH_GAP, SWATCH_GAP);
+            for (name, rgb) in color_tokens(colors) {
+                let is_selected = name == selected;
+                let (rect, response) =
+                    ui.allocate_exact_size(vec2(SWATCH_SIZE, SWATCH_SIZE), Sense::click());
+                response.widget_info(|| {
+                    WidgetInfo::selected(WidgetType::SelectableLabel, true, is_selected, name)
+                });
+
+                if ui.is_rect_visible(rect) {
+                    let painter = ui.painter();
+                    painter.rect_filled(
+                        rect,
+                        SWATCH_RADIUS,
+                        Color32::from_rgb(rgb[0], rgb[1], rgb[2]),
+                    );
+                    let stroke = if is_selected {
+                        Stroke::new(2.0, theme.ring())
+                    } else {
+                        Stroke::new(1.0, theme.border())
+                    };
+                    painter.rect_stroke(rect, SWATCH_RADIUS, stroke, egui::StrokeKind::Outside);
+                }
+
+                if response.on_hover_text(name).clicked() {
+                    selected = name;
+                }
+            }
+        });
+        selected
+    }
+
+    fn spacing_sliders(&self, ui: &mut Ui, spacing: &mut Spacing, theme: &Theme) -> bool {
+        let mut changed = false;
+        for (name, value) in [
+            ("xxs", &mut spacing.xxs),
+            ("xs", &mut spacing.xs),
+            ("sm", &mut spacing.sm),
+            ("md", &mut spacing.md),
+            ("lg", &mut spacing.lg),
+            ("xl", &mut spacing.xl),
+            ("xxl", &mut spacing.xxl),
+        ] {
+            changed |= Slider::new(0.0, MAX_SPACING)
+                .id(self.id.with("spacing").with(name))
+                .label(name)
+                .suffix("px")
+                .step(1.0)
+                .width(self.width)
+                .show(ui, value, theme)
+                .changed;
+        }
+        changed
+    }
+
+    fn radius_sliders(&self, ui: &mut Ui, spacing: &mut Spacing, theme: &Theme) -> bool {
+        let mut changed = false;
+        for (name, value) in [
+            ("corner_radius_micro", &mut spacing.corner_radius_micro),
+            ("corner_radius_tiny", &mut spacing.corner_radius_tiny),
+            ("corner_radius_small", &mut spacing.corner_radius_small),
+            ("corner_radius", &mut spacing.corner_radius),
+            ("corner_radius_large", &mut spacing.corner_radius_large),
+        ] {
+            let mut radius = f32::from(*value);
+            if Slider::new(0.0, MAX_RADIUS)
+                .id(self.id.with("radius").with(name))
+                .label(name)
+                .suffix("px")
+                .step(1.0)
+                .width(self.width)
+                .show(ui, &mut radius, theme)
+                .changed
+            {
+                *value = radius.round() as u8;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    fn preview(ui: &mut Ui, id: Id, theme: &Theme) {
+        ui.horizontal_wrapped(|ui| {
+            Button::new("Primary").show(ui, theme);
+            Button::new("Secondary")
+                .variant(ButtonVariant::Secondary)
+                .show(ui, theme);
+            Button::new("Outline")
+                .variant(ButtonVariant::Outline)
+                .show(ui, theme);
+            Badge::new("Badge").show(ui, theme);
+            let mut checked = true;
+            Toggle::new()
+                .id(id.with("preview_toggle"))
+                .show(ui, &mut checked, theme);
+        });
+    }
+}
+
+/// Every color token with its serialized name
+fn color_tokens(colors: &mut ColorPalette) -> Vec<(&'static str, &mut [u8; 3])> {
+    vec![
+        ("background", &mut colors.background),
+        ("foreground", &mut colors.foreground),
+        ("card", &mut colors.card),
+        ("card_foreground", &mut colors.card_foreground),
+        ("popover", &mut colors.popover),
+        ("popover_foreground", &mut colors.popover_foreground),
+        ("primary", &mut colors.primary),
+        ("primary_foreground", &mut colors.primary_foreground),
+        ("secondary", &mut colors.secondary),
+        ("secondary_foreground", &mut colors.secondary_foreground),
+        ("muted", &mut colors.muted),
+        ("muted_foreground", &mut colors.muted_foreground),
+        ("accent", &mut colors.accent),
+        ("accent_foreground", &mut colors.accent_foreground),
+        ("destructive", &mut colors.destructive),
+        ("destructive_foreground", &mut colors.destructive_foreground),
+        ("border", &mut colors.border),
+        ("input", &mut colors.input),
+        ("ring", &mut colors.ring),
+        ("chart_1", &mut colors.chart_1),
+        ("chart_2", &mut colors.chart_2),
+        ("chart_3", &mut colors.chart_3),
+        ("chart_4", &mut colors.chart_4),
+        ("chart_5", &mut colors.chart_5),
+        ("hover", &mut colors.hover),
+        ("focus", &mut colors.focus),
+        ("sidebar", &mut colors.sidebar),
+        ("sidebar_foreground", &mut colors.sidebar_foreground),
+        ("sidebar_primary", &mut colors.sidebar_primary),
+        (
+            "sidebar_primary_foreground",
+            &mut colors.sidebar_primary_foreground,
+        ),
+        ("sidebar_accent", &mut colors.sidebar_accent),
+        (
+            "sidebar_accent_foreground",
+            &mut colors.sidebar_accent_foreground,
+        ),
+        ("sidebar_border", &mut colors.sidebar_border),
+        ("sidebar_ring", &mut colors.sidebar_ring),
+    ]
+}
@@ -1,8 +1,10 @@
 //! Three Value Slider Component
 //!
 //! Horizontal slider with three thumbs: min bound, current value, and max bound.
+//! Optionally shows a value tooltip above the thumb being dragged.
 
 use crate::ext::ArmasContextExt;
+use crate::{Tooltip, TooltipPosition};
 use egui::{pos2, vec2, Color32, Rect, Response, Sense, Stroke, Ui};
 
 /// Which thumb is being dragged
@@ -75,6 +77,8 @@ pub struct ThreeValueSlider {
     step: Option<f32>,
     min_gap: f32,
     value_thumb_style: ValueThumbStyle,
+    value_tooltip: bool,
+    format: Option<Box<dyn Fn(f32) -> String>>,
 }
 
 impl ThreeValueSlider {
@@ -93,6 +97,8 @@ impl ThreeValueSlider {
             step: None,
             min_gap: 0.0,
             value_thumb_style: ValueThumbStyle::Diamond,
+            value_tooltip: false,
+            format: None,
         }
     }
 
@@ -159,6 +165,20 @@ impl ThreeValueSlider {
         self
     }
 
+    /// Show a tooltip with the value above the thumb while it is dragged
+    #[must_use]
+    pub const fn value_tooltip(mut self, show: bool) -> Self {
+        self.value_tooltip = show;
+        self
+    }
+
+    /// Set a custom value formatter for the labels and tooltip (overrides the suffix)
+    #[must_use]
+    pub fn format(mut self, format: impl Fn(f32) -> String + 'static) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
     /// Show the three value slider
     pub fn show(
         self,
@@ -266,6 +286,30 @@ impl ThreeValueSlider {
                 hovered_thumb,
                 &theme,
             );
+
+            // Value tooltip above the thumb being dragged
+            if !self.value_tooltip || !response.dragged() {
+                return;
+            }
+            let dragged_value = match drag_state.target {
+                DragTarget::Min => *min_bound,
+                DragTarget::Value => *value,
+                DragTarget::Max => *max_bound,
+                DragTarget::None => return,
+            };
+            let text = self.format_value(dragged_value);
+            let anchor = Rect::from_center_size(
+                pos2(
+                    self.value_to_x(dragged_value, &track_rect),
+                    track_rect.center().y,
+                ),
+                vec2(thumb_radius * 2.0, thumb_radius * 2.0),
+            );
+            Tooltip::new(text).position(TooltipPosition::Top).show_at(
+                ui,
+                slider_id.with("value_tooltip"),
+                anchor,
+            );
         });
 
         // Save state to memory if ID is set
@@ -322,6 +366,9 @@ impl ThreeValueSlider {
     }
 
     fn format_value(&self, value: f32) -> String {
+        if let Some(format) = &self.format {
+            return format(value);
+        }
         self.suffix.as_ref().map_or_else(
             || format!("{value:.1}"),
             |suffix| format!("{value:.1}{suffix}"),
@@ -371,7 +418,9 @@ impl ThreeValueSlider {
 
         // Handle drag start
         if response.drag_started() {
-            if let Some(pos) = response.interact_pointer_pos() {
+            // Pick the thumb under the press, not where the drag threshold was crossed
+            let press_pos = ui.input(|i| i.pointer.press_origin());
+            if let Some(pos) = press_pos.or_else(|| response.interact_pointer_pos()) {
                 drag_state.target =
                     self.determine_target(pos.x, geometry.min_x, geometry.value_x, geometry.max_x);
                 drag_state.drag_start_value = match drag_state.target {
@@ -8,9 +8,11 @@
 //! - Disabled state
 
 use crate::animation::SpringAnimation;
-use crate::ext::ArmasContextExt;
+use crate::ext::{draw_focus_ring, ArmasContextExt};
 use crate::Theme;
-use egui::{pos2, vec2, Color32, CornerRadius, Response, Sense, Stroke, Ui, Vec2};
+use egui::{
+    pos2, vec2, Color32, CornerRadius, Response, Sense, Stroke, Ui, Vec2, WidgetInfo, WidgetType,
+};
 
 // shadcn Switch dimensions
 const SWITCH_WIDTH: f32 = 44.0; // w-11
@@ -63,6 +65,7 @@ pub struct Toggle {
     label: Option<String>,
     description: Option<String>,
     disabled: bool,
+    aria_label: Option<String>,
     // Use spring animation for smooth, physics-based toggle animation
     toggle_spring: SpringAnimation,
 }
@@ -78,6 +81,7 @@ impl Toggle {
             label: None,
             description: None,
             disabled: false,
+            aria_label: None,
             // Smooth spring animation for natural toggle feel
             toggle_spring: SpringAnimation::new(0.0, 0.0).params(800.0, 30.0),
         }
@@ -125,6 +129,13 @@ impl Toggle {
         self
     }
 
+    /// Set the label announced by screen readers (defaults to the visible label)
+    #[must_use]
+    pub fn aria_label(mut self, label: impl Into<String>) -> Self {
+        self.aria_label = Some(label.into());
+        self
+    }
+
     /// Show the toggle and return whether it changed
     pub fn show(
         &mut self,
@@ -179,6 +190,9 @@ impl Toggle {
                             self.draw_checkbox(ui, rect, *checked, theme);
                         }
                     }
+                    if response.has_focus() {
+                        draw_focus_ring(ui.painter(), rect, theme);
+                    }
                 }
 
                 // Handle click
@@ -187,6 +201,16 @@ impl Toggle {
                     response.mark_changed();
                 }
 
+                // Both variants are announced as checkboxes with their checked state
+                let label = self
+                    .aria_label
+                    .as_deref()
+                    .or(self.label.as_deref())
+                    .unwrap_or_default();
+                response.widget_info(|| {
+                    WidgetInfo::selected(WidgetType::Checkbox, !self.disabled, *checked, label)
+                });
+
                 // Label and description
                 if self.label.is_some() || self.description.is_some() {
                     ui.add_space(theme.spacing.sm);
@@ -4,14 +4,29 @@
 //! Appears on hover with configurable delay and position.
 
 use crate::ext::ArmasContextExt;
-use egui::{pos2, vec2, Color32, FontId, Rect, Response, Shape, Stroke, Ui, Vec2};
+use egui::{
+    pos2, vec2, Color32, FontId, Rect, Response, Sense, Shape, Stroke, Ui, Vec2, WidgetInfo,
+    WidgetType,
+};
 
 // shadcn Tooltip constants
 const CORNER_RADIUS: f32 = 6.0; // rounded-md
 const PADDING_X: f32 = 12.0; // px-3
 const PADDING_Y: f32 = 6.0; // py-1.5
-const FONT_SIZE: f32 = 12.0; // text-xs
+pub(crate) const FONT_SIZE: f32 = 12.0; // text-xs
 const ARROW_SIZE: f32 = 5.0; // size-2.5 (10px / 2 for triangle)
+pub(crate) const GAP: f32 = 4.0; // distance from the target without an arrow
+
+/// Tooltip background for custom content, such as a button's shortcut hint
+///
+/// Text inside should use [`FONT_SIZE`] and `theme.background()`.
+pub(crate) fn frame(theme: &crate::Theme) -> egui::Frame {
+    // shadcn uses inverted colors: bg-foreground text-background
+    egui::Frame::new()
+        .fill(theme.foreground())
+        .corner_radius(CORNER_RADIUS)
+        .inner_margin(vec2(PADDING_X, PADDING_Y))
+}
 
 /// Tooltip position relative to the target
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -89,7 +104,6 @@ impl Tooltip {
 
     /// Show tooltip for a UI element
     pub fn show(self, ui: &mut Ui, target_response: &Response) -> bool {
-        let theme = ui.ctx().armas_theme();
         let is_hovered = target_response.hovered();
 
         // Use egui's memory to track hover start time
@@ -122,6 +136,17 @@ impl Tooltip {
             return false;
         }
 
+        self.show_at(ui, target_response.id, target_response.rect);
+        true
+    }
+
+    /// Show the tooltip next to `target_rect` right away, without hover tracking
+    ///
+    /// Useful for tooltips driven by other interactions, such as a slider
+    /// thumb being dragged. `id` identifies the overlay layer.
+    pub fn show_at(&self, ui: &Ui, id: egui::Id, target_rect: Rect) {
+        let theme = ui.ctx().armas_theme();
+
         // Calculate tooltip content size
         let font_id = FontId::proportional(FONT_SIZE);
         let padding = vec2(PADDING_X, PADDING_Y);
@@ -142,20 +167,16 @@ impl Tooltip {
         let arrow_offset = if self.show_arrow {
             ARROW_SIZE + 2.0
         } else {
-            4.0
+            GAP
         };
 
         // Determine position
-        let target_rect = target_response.rect;
         let position = self.determine_position(ui, target_rect, tooltip_size, arrow_offset);
         let tooltip_rect =
             self.calculate_tooltip_rect(target_rect, tooltip_size, arrow_offset, position);
 
         // Draw tooltip as an overlay (above everything else)
-        let layer_id = egui::LayerId::new(
-            egui::Order::Tooltip,
-            target_response.id.with("tooltip_layer"),
-        );
+        let layer_id = egui::LayerId::new(egui::Order::Tooltip, id.with("tooltip_layer"));
         let painter = ui.ctx().layer_painter(layer_id);
 
         // Background
@@ -169,7 +190,9 @@ impl Tooltip {
         // Text
         painter.galley(tooltip_rect.min + padding, text_galley, text_color);
 
-        true
+        // Painted text is invisible to screen readers, so describe it too
+        ui.interact(tooltip_rect, id.with("tooltip_text"), Sense::hover())
+            .widget_info(|| WidgetInfo::labeled(WidgetType::Label, true, &self.text));
     }
 
     /// Determine the best position for the tooltip
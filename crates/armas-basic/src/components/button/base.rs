@@ -7,7 +7,10 @@
 //! - Ghost: No background, hover shows accent
 //! - Link: Text style with underline on hover
 
-use egui::{Color32, Response, Sense, Ui, Vec2};
+use crate::components::basic::tooltip;
+use crate::ext::draw_focus_ring;
+use crate::Kbd;
+use egui::{Color32, Response, Sense, Ui, Vec2, WidgetInfo, WidgetType};
 
 // shadcn Button constants
 const CORNER_RADIUS: f32 = 6.0; // rounded-md
@@ -28,6 +31,71 @@ pub enum ButtonVariant {
     Link,
 }
 
+impl ButtonVariant {
+    /// Background, text and border colors for this variant
+    pub(crate) fn colors(
+        self,
+        theme: &crate::Theme,
+        hovered: bool,
+        enabled: bool,
+    ) -> (Color32, Color32, Color32) {
+        if enabled {
+            match self {
+                Self::Default => {
+                    let bg = if hovered {
+                        theme.primary().gamma_multiply(0.9) // hover:bg-primary/90
+                    } else {
+                        theme.primary()
+                    };
+                    (bg, theme.primary_foreground(), Color32::TRANSPARENT)
+                }
+                Self::Secondary => {
+                    let bg = if hovered {
+                        theme.secondary().gamma_multiply(0.8) // hover:bg-secondary/80
+                    } else {
+                        theme.secondary()
+                    };
+                    (bg, theme.secondary_foreground(), Color32::TRANSPARENT)
+                }
+                Self::Outline => {
+                    let bg = if hovered {
+                        theme.accent()
+                    } else {
+                        Color32::TRANSPARENT
+                    };
+                    let text = if hovered {
+                        theme.accent_foreground()
+                    } else {
+                        theme.foreground()
+                    };
+                    (bg, text, theme.border())
+                }
+                Self::Ghost => {
+                    let bg = if hovered {
+                        theme.accent()
+                    } else {
+                        Color32::TRANSPARENT
+                    };
+                    let text = if hovered {
+                        theme.accent_foreground()
+                    } else {
+                        theme.foreground()
+                    };
+                    (bg, text, Color32::TRANSPARENT)
+                }
+                Self::Link => (Color32::TRANSPARENT, theme.primary(), Color32::TRANSPARENT),
+            }
+        } else {
+            // Disabled: opacity-50
+            (
+                theme.primary().gamma_multiply(0.5),
+                theme.primary_foreground().gamma_multiply(0.5),
+                Color32::TRANSPARENT,
+            )
+        }
+    }
+}
+
 /// Button size following shadcn/ui
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum ButtonSize {
@@ -43,7 +111,7 @@ pub enum ButtonSize {
 }
 
 impl ButtonSize {
-    const fn height(self) -> f32 {
+    pub(crate) const fn height(self) -> f32 {
         match self {
             Self::Xs => 22.0,
             Self::Small => 32.0,
@@ -52,7 +120,7 @@ impl ButtonSize {
         }
     }
 
-    const fn padding_x(self) -> f32 {
+    pub(crate) const fn padding_x(self) -> f32 {
         match self {
             Self::Xs => 6.0,
             Self::Small => 12.0,   // px-3
@@ -61,7 +129,7 @@ impl ButtonSize {
         }
     }
 
-    const fn font_size(self) -> f32 {
+    pub(crate) const fn font_size(self) -> f32 {
         match self {
             Self::Xs => 11.0,
             _ => 14.0, // text-sm
@@ -78,6 +146,8 @@ pub struct Button {
     full_width: bool,
     min_width: Option<f32>,
     custom_height: Option<f32>,
+    aria_label: Option<String>,
+    shortcut_hint: Option<String>,
 }
 
 impl Button {
@@ -91,6 +161,8 @@ impl Button {
             full_width: false,
             min_width: None,
             custom_height: None,
+            aria_label: None,
+            shortcut_hint: None,
         }
     }
 
@@ -136,6 +208,22 @@ impl Button {
         self
     }
 
+    /// Set the label announced by screen readers (defaults to the button text)
+    #[must_use]
+    pub fn aria_label(mut self, label: impl Into<String>) -> Self {
+        self.aria_label = Some(label.into());
+        self
+    }
+
+    /// Show a keyboard shortcut in a tooltip on hover, e.g. `"⌘S"` or `"Ctrl+S"`
+    ///
+    /// The tooltip shows the button label next to the keys rendered as [`Kbd`].
+    #[must_use]
+    pub fn shortcut_hint(mut self, keys: impl Into<String>) -> Self {
+        self.shortcut_hint = Some(keys.into());
+        self
+    }
+
     /// Show the button
     pub fn show(self, ui: &mut Ui, theme: &crate::Theme) -> Response {
         let sense = if self.enabled {
@@ -167,6 +255,8 @@ impl Button {
 
         let button_size = Vec2::new(button_width, height);
         let (rect, mut response) = ui.allocate_exact_size(button_size, sense);
+        let label = self.aria_label.as_deref().unwrap_or(&self.text);
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, self.enabled, label));
 
         if self.enabled && response.hovered() {
             response = response.on_hover_cursor(egui::CursorIcon::PointingHand);
@@ -175,63 +265,8 @@ impl Button {
         if ui.is_rect_visible(rect) {
             let hovered = response.hovered() && self.enabled;
 
-            // Get colors based on variant and state
-            let (bg_color, text_color, border_color) = if self.enabled {
-                match self.variant {
-                    ButtonVariant::Default => {
-                        let bg = if hovered {
-                            theme.primary().gamma_multiply(0.9) // hover:bg-primary/90
-                        } else {
-                            theme.primary()
-                        };
-                        (bg, theme.primary_foreground(), Color32::TRANSPARENT)
-                    }
-                    ButtonVariant::Secondary => {
-                        let bg = if hovered {
-                            theme.secondary().gamma_multiply(0.8) // hover:bg-secondary/80
-                        } else {
-                            theme.secondary()
-                        };
-                        (bg, theme.secondary_foreground(), Color32::TRANSPARENT)
-                    }
-                    ButtonVariant::Outline => {
-                        let bg = if hovered {
-                            theme.accent()
-                        } else {
-                            Color32::TRANSPARENT
-                        };
-                        let text = if hovered {
-                            theme.accent_foreground()
-                        } else {
-                            theme.foreground()
-                        };
-                        (bg, text, theme.border())
-                    }
-                    ButtonVariant::Ghost => {
-                        let bg = if hovered {
-                            theme.accent()
-                        } else {
-                            Color32::TRANSPARENT
-                        };
-                        let text = if hovered {
-                            theme.accent_foreground()
-                        } else {
-                            theme.foreground()
-                        };
-                        (bg, text, Color32::TRANSPARENT)
-                    }
-                    ButtonVariant::Link => {
-                        (Color32::TRANSPARENT, theme.primary(), Color32::TRANSPARENT)
-                    }
-                }
-            } else {
-                // Disabled: opacity-50
-                (
-                    theme.primary().gamma_multiply(0.5),
-                    theme.primary_foreground().gamma_multiply(0.5),
-                    Color32::TRANSPARENT,
-                )
-            };
+            let (bg_color, text_color, border_color) =
+                self.variant.colors(theme, hovered, self.enabled);
 
             // Draw background
             if bg_color != Color32::TRANSPARENT {
@@ -264,10 +299,47 @@ impl Button {
                     egui::Stroke::new(1.0, text_color),
                 );
             }
+
+            if response.has_focus() {
+                draw_focus_ring(ui.painter(), rect, theme);
+            }
+        }
+
+        if let Some(keys) = &self.shortcut_hint {
+            if response.hovered() {
+                Self::show_shortcut_hint(ui, &response, &self.text, keys, theme);
+            }
         }
 
         response
     }
+
+    /// Tooltip above the button with its label and shortcut keys
+    fn show_shortcut_hint(
+        ui: &Ui,
+        response: &Response,
+        label: &str,
+        keys: &str,
+        theme: &crate::Theme,
+    ) {
+        let anchor = egui::pos2(response.rect.center().x, response.rect.top() - tooltip::GAP);
+        egui::Area::new(response.id.with("shortcut_hint"))
+            .order(egui::Order::Tooltip)
+            .pivot(egui::Align2::CENTER_BOTTOM)
+            .fixed_pos(anchor)
+            .interactable(false)
+            .show(ui.ctx(), |ui| {
+                tooltip::frame(theme).show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = 8.0;
+                        // Also colors the "+" between keys
+                        ui.visuals_mut().override_text_color = Some(theme.background());
+                        ui.label(egui::RichText::new(label).size(tooltip::FONT_SIZE));
+                        Kbd::new(keys).show(ui, theme);
+                    });
+                });
+            });
+    }
 }
 
 // Keep old variant name as alias for backwards compatibility during migration
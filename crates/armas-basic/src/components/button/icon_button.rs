@@ -3,8 +3,8 @@
 //! A button variant specifically designed for rendering icons with Material Design 3 styling.
 
 use crate::components::button::ButtonVariant;
-use crate::icon::{render_icon_data, IconData, OwnedIconData};
-use egui::{Color32, Response, Sense, Ui, Vec2};
+use crate::icon::{render_icon_data, render_icon_strokes, IconData, IconStroke, OwnedIconData};
+use egui::{Color32, Response, Sense, Ui, Vec2, WidgetInfo, WidgetType};
 
 /// Icon Button component
 ///
@@ -37,6 +37,7 @@ use egui::{Color32, Response, Sense, Ui, Vec2};
 pub struct IconButton<'a> {
     vertices: &'a [(f32, f32)],
     indices: &'a [u32],
+    strokes: &'a [IconStroke],
     viewbox_width: f32,
     viewbox_height: f32,
     variant: ButtonVariant,
@@ -45,6 +46,7 @@ pub struct IconButton<'a> {
     enabled: bool,
     icon_color: Option<Color32>,
     hover_icon_color: Option<Color32>,
+    aria_label: Option<String>,
 }
 
 impl<'a> IconButton<'a> {
@@ -54,6 +56,7 @@ impl<'a> IconButton<'a> {
         Self {
             vertices: icon_data.vertices,
             indices: icon_data.indices,
+            strokes: &[],
             viewbox_width: icon_data.viewbox_width,
             viewbox_height: icon_data.viewbox_height,
             variant: ButtonVariant::Default,
@@ -62,6 +65,7 @@ impl<'a> IconButton<'a> {
             enabled: true,
             icon_color: None,
             hover_icon_color: None,
+            aria_label: None,
         }
     }
 
@@ -71,6 +75,7 @@ impl<'a> IconButton<'a> {
         Self {
             vertices: &data.vertices,
             indices: &data.indices,
+            strokes: &data.strokes,
             viewbox_width: data.viewbox_width,
             viewbox_height: data.viewbox_height,
             variant: ButtonVariant::Default,
@@ -79,6 +84,7 @@ impl<'a> IconButton<'a> {
             enabled: true,
             icon_color: None,
             hover_icon_color: None,
+            aria_label: None,
         }
     }
 
@@ -124,6 +130,13 @@ impl<'a> IconButton<'a> {
         self
     }
 
+    /// Set the label announced by screen readers (icon buttons have no text otherwise)
+    #[must_use]
+    pub fn aria_label(mut self, label: impl Into<String>) -> Self {
+        self.aria_label = Some(label.into());
+        self
+    }
+
     /// Show the icon button
     pub fn show(self, ui: &mut Ui, theme: &crate::Theme) -> Response {
         let total_size = Vec2::splat(self.size + self.padding * 2.0);
@@ -135,6 +148,8 @@ impl<'a> IconButton<'a> {
         };
 
         let (rect, response) = ui.allocate_exact_size(total_size, sense);
+        let label = self.aria_label.as_deref().unwrap_or_default();
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, self.enabled, label));
 
         if ui.is_rect_visible(rect) {
             // Determine colors based on variant and state
@@ -220,6 +235,15 @@ impl<'a> IconButton<'a> {
                 self.viewbox_height,
                 icon_color,
             );
+            render_icon_strokes(
+                ui.painter(),
+                icon_rect,
+                self.strokes,
+                self.viewbox_width,
+                self.viewbox_height,
+                icon_color,
+                None,
+            );
         }
 
         response
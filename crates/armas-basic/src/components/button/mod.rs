@@ -2,6 +2,8 @@
 
 pub mod base;
 pub mod icon_button;
+pub mod split_button;
 
 pub use base::{Button, ButtonSize, ButtonVariant};
 pub use icon_button::IconButton;
+pub use split_button::{SplitButton, SplitButtonResponse};
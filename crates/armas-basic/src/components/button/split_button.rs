@@ -0,0 +1,244 @@
+//! Split Button Component
+//!
+//! A primary action on the left joined to a chevron on the right that opens
+//! a [`Menu`] of secondary actions. Both halves share one rounded shape with
+//! a divider between them, styled per [`ButtonVariant`].
+
+use crate::components::button::{ButtonSize, ButtonVariant};
+use crate::components::navigation::{Menu, MenuItem};
+use crate::ext::draw_focus_ring;
+use crate::{icon, PopoverPosition, Theme};
+use egui::{
+    vec2, Color32, CornerRadius, Id, Rect, Response, Sense, Stroke, StrokeKind, Ui, Vec2,
+    WidgetInfo, WidgetType,
+};
+
+// shadcn Button constants
+const CORNER_RADIUS: u8 = 6; // rounded-md
+const CHEVRON_SIZE: f32 = 16.0; // size-4
+const DIVIDER_INSET: f32 = 6.0;
+const MENU_MIN_WIDTH: f32 = 160.0;
+
+/// Response from a split button
+#[derive(Debug, Clone)]
+pub struct SplitButtonResponse {
+    /// Response covering both halves
+    pub response: Response,
+    /// Whether the primary (left) half was clicked this frame
+    pub primary_clicked: bool,
+    /// Index of the menu item chosen this frame
+    pub selected: Option<usize>,
+    /// Whether the menu is open
+    pub is_open: bool,
+}
+
+/// Primary action button with a dropdown of secondary actions
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::Ui;
+/// # fn example(ui: &mut Ui) {
+/// use armas_basic::components::button::SplitButton;
+/// use armas_basic::components::navigation::MenuItem;
+/// use armas_basic::ext::ArmasContextExt;
+///
+/// let theme = ui.ctx().armas_theme();
+/// let response = SplitButton::new("Save", "save_actions")
+///     .menu([MenuItem::new("Save as..."), MenuItem::new("Save all")])
+///     .show(ui, &theme);
+/// if response.primary_clicked {
+///     // save
+/// }
+/// if response.selected == Some(0) {
+///     // save as
+/// }
+/// # }
+/// ```
+pub struct SplitButton {
+    text: String,
+    id: Id,
+    items: Vec<MenuItem>,
+    variant: ButtonVariant,
+    size: ButtonSize,
+    enabled: bool,
+}
+
+impl SplitButton {
+    /// Create a split button; `id` keys the open state of its menu
+    pub fn new(text: impl Into<String>, id: impl Into<Id>) -> Self {
+        Self {
+            text: text.into(),
+            id: id.into(),
+            items: Vec::new(),
+            variant: ButtonVariant::Default,
+            size: ButtonSize::Default,
+            enabled: true,
+        }
+    }
+
+    /// Set the secondary actions shown in the dropdown
+    #[must_use]
+    pub fn menu(mut self, items: impl IntoIterator<Item = MenuItem>) -> Self {
+        self.items = items.into_iter().collect();
+        self
+    }
+
+    /// Set the button variant
+    #[must_use]
+    pub const fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set the button size
+    #[must_use]
+    pub const fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set enabled state
+    #[must_use]
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Show the split button
+    pub fn show(self, ui: &mut Ui, theme: &Theme) -> SplitButtonResponse {
+        let open_id = self.id.with("open");
+        let mut is_open = self.enabled && ui.ctx().data(|d| d.get_temp(open_id)).unwrap_or(false);
+
+        let height = self.size.height();
+        let padding_x = self.size.padding_x();
+        let font_id = egui::FontId::proportional(self.size.font_size());
+        let galley = ui
+            .painter()
+            .layout_no_wrap(self.text.clone(), font_id, Color32::PLACEHOLDER);
+        let primary_width = padding_x.mul_add(2.0, galley.size().x);
+        let toggle_width = height;
+
+        let (rect, _) =
+            ui.allocate_exact_size(vec2(primary_width + toggle_width, height), Sense::hover());
+        let primary_rect = Rect::from_min_size(rect.min, vec2(primary_width, height));
+        let toggle_rect =
+            Rect::from_min_max(egui::pos2(primary_rect.right(), rect.top()), rect.max);
+
+        let sense = if self.enabled {
+            Sense::click()
+        } else {
+            Sense::hover()
+        };
+        let primary = ui.interact(primary_rect, self.id.with("primary"), sense);
+        let toggle = ui.interact(toggle_rect, self.id.with("toggle"), sense);
+        primary.widget_info(|| WidgetInfo::labeled(WidgetType::Button, self.enabled, &self.text));
+        toggle.widget_info(|| {
+            WidgetInfo::labeled(
+                WidgetType::Button,
+                self.enabled,
+                format!("{} options", self.text),
+            )
+        });
+
+        if toggle.clicked() {
+            is_open = !is_open;
+        }
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            let radius = f32::from(CORNER_RADIUS);
+            let (_, text_color, border_color) = self.variant.colors(theme, false, self.enabled);
+
+            for (half, corners) in [
+                (
+                    &primary,
+                    CornerRadius {
+                        nw: CORNER_RADIUS,
+                        sw: CORNER_RADIUS,
+                        ..Default::default()
+                    },
+                ),
+                (
+                    &toggle,
+                    CornerRadius {
+                        ne: CORNER_RADIUS,
+                        se: CORNER_RADIUS,
+                        ..Default::default()
+                    },
+                ),
+            ] {
+                let hovered = half.hovered() && self.enabled;
+                let (bg, _, _) = self.variant.colors(theme, hovered, self.enabled);
+                painter.rect_filled(half.rect, corners, bg);
+            }
+
+            if border_color != Color32::TRANSPARENT {
+                painter.rect_stroke(
+                    rect,
+                    radius,
+                    Stroke::new(1.0, border_color),
+                    StrokeKind::Inside,
+                );
+            }
+
+            // Divider between the halves, in the text color so it reads on
+            // filled variants too
+            painter.line_segment(
+                [
+                    toggle_rect.left_top() + vec2(0.0, DIVIDER_INSET),
+                    toggle_rect.left_bottom() - vec2(0.0, DIVIDER_INSET),
+                ],
+                Stroke::new(1.0, text_color.gamma_multiply(0.3)),
+            );
+
+            let text_pos = primary_rect.center() - galley.size() / 2.0;
+            painter.galley(text_pos, galley, text_color);
+
+            let chevron_rect =
+                Rect::from_center_size(toggle_rect.center(), Vec2::splat(CHEVRON_SIZE));
+            icon::chevron_down().render(painter, chevron_rect, text_color);
+
+            for half in [&primary, &toggle] {
+                if half.has_focus() {
+                    draw_focus_ring(painter, half.rect, theme);
+                }
+            }
+        }
+
+        let mut menu = Menu::new(self.id.with("menu"))
+            .open(is_open)
+            .position(PopoverPosition::Bottom)
+            .width(rect.width().max(MENU_MIN_WIDTH));
+        let menu_response = menu.show(ui.ctx(), rect, |menu| {
+            for item in &self.items {
+                let mut builder = menu.item(item.label.clone());
+                if let Some(icon) = &item.icon {
+                    builder = builder.icon(icon.clone());
+                }
+                if let Some(shortcut) = &item.shortcut {
+                    builder = builder.shortcut(shortcut.clone());
+                }
+                builder = builder.disabled(item.disabled);
+                if item.destructive {
+                    let _ = builder.destructive();
+                }
+            }
+        });
+
+        // The chevron lies outside the menu, so its own click also counts
+        // as an outside click
+        if (menu_response.clicked_outside && !toggle.clicked()) || menu_response.selected.is_some()
+        {
+            is_open = false;
+        }
+        ui.ctx().data_mut(|d| d.insert_temp(open_id, is_open));
+
+        SplitButtonResponse {
+            primary_clicked: primary.clicked(),
+            response: primary.union(toggle).with_new_rect(rect),
+            selected: menu_response.selected,
+            is_open,
+        }
+    }
+}
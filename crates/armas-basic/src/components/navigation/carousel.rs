@@ -0,0 +1,374 @@
+//! Carousel Component
+//!
+//! Horizontally sliding content carousel styled like shadcn/ui Carousel.
+//! Features:
+//! - Previous/next buttons and indicator dots
+//! - Drag or swipe between slides, snapping with the release momentum
+//! - Spring-animated slide transitions
+//! - Optional autoplay that pauses while hovered
+//! - Loops from the last slide back to the first (can be disabled)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui) {
+//! use std::time::Duration;
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::Carousel;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let response = Carousel::new(3, |ui, index| {
+//!     ui.label(format!("Slide {}", index + 1));
+//! })
+//! .autoplay(Duration::from_secs(4))
+//! .show(ui, &theme);
+//! if response.changed {
+//!     // response.index is the new slide
+//! }
+//! # }
+//! ```
+
+use crate::animation::SpringAnimation;
+use crate::Theme;
+use egui::{pos2, vec2, Id, Rect, Response, Sense, Stroke, Ui, WidgetInfo, WidgetType};
+use std::time::Duration;
+
+// shadcn-style constants
+const HEIGHT: f32 = 200.0;
+const BUTTON_SIZE: f32 = 32.0; // size-8
+const BUTTON_INSET: f32 = 8.0;
+const BUTTON_ICON_SIZE: f32 = 16.0; // size-4
+const DOT_SIZE: f32 = 8.0; // size-2
+const DOT_GAP: f32 = 8.0; // gap-2
+const DOTS_HEIGHT: f32 = 24.0;
+
+// Slide spring (slightly overdamped so slides don't wobble)
+const SPRING_STIFFNESS: f32 = 300.0;
+const SPRING_DAMPING: f32 = 36.0;
+const MAX_DT: f32 = 1.0 / 30.0;
+/// Seconds of release velocity projected forward when picking the snap slide
+const MOMENTUM_PROJECTION: f32 = 0.15;
+
+/// Response from a carousel
+#[derive(Debug, Clone)]
+pub struct CarouselResponse {
+    /// Response of the slide viewport
+    pub response: Response,
+    /// Index of the active slide
+    pub index: usize,
+    /// Whether the active slide changed this frame
+    pub changed: bool,
+}
+
+/// Persistent carousel state, positions are measured in slides
+#[derive(Clone, Copy, Default)]
+struct CarouselState {
+    /// Animated position (may leave `0..count` while wrapping around)
+    position: f32,
+    velocity: f32,
+    /// Slide the spring is heading to
+    target: i64,
+    /// Position when the current drag started
+    drag_origin: f32,
+    /// Autoplay time accumulated since the last advance
+    autoplay_elapsed: f32,
+}
+
+/// Sliding content carousel
+pub struct Carousel<F> {
+    id: Option<Id>,
+    count: usize,
+    content: F,
+    width: Option<f32>,
+    height: f32,
+    autoplay: Option<Duration>,
+    looping: bool,
+    show_buttons: bool,
+    show_dots: bool,
+}
+
+impl<F: FnMut(&mut Ui, usize)> Carousel<F> {
+    /// Create a carousel of `count` slides; `content` draws slide `index`
+    pub const fn new(count: usize, content: F) -> Self {
+        Self {
+            id: None,
+            count,
+            content,
+            width: None,
+            height: HEIGHT,
+            autoplay: None,
+            looping: true,
+            show_buttons: true,
+            show_dots: true,
+        }
+    }
+
+    /// Set custom ID (important when using multiple carousels)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the slide width (default: available width)
+    #[must_use]
+    pub const fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the slide height
+    #[must_use]
+    pub const fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Advance to the next slide every `interval` (paused while hovered)
+    #[must_use]
+    pub const fn autoplay(mut self, interval: Duration) -> Self {
+        self.autoplay = Some(interval);
+        self
+    }
+
+    /// Wrap around from the last slide to the first (default: true)
+    #[must_use]
+    pub const fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Show or hide the previous/next buttons
+    #[must_use]
+    pub const fn show_buttons(mut self, show: bool) -> Self {
+        self.show_buttons = show;
+        self
+    }
+
+    /// Show or hide the indicator dots
+    #[must_use]
+    pub const fn show_dots(mut self, show: bool) -> Self {
+        self.show_dots = show;
+        self
+    }
+
+    /// Show the carousel
+    pub fn show(mut self, ui: &mut Ui, theme: &Theme) -> CarouselResponse {
+        let count = i64::try_from(self.count.max(1)).unwrap_or(i64::MAX);
+        let width = self.width.unwrap_or_else(|| ui.available_width());
+        let (viewport, response) =
+            ui.allocate_exact_size(vec2(width, self.height), Sense::click_and_drag());
+        let id = self.id.unwrap_or(response.id);
+        let state_id = id.with("state");
+        let mut state: CarouselState = ui.ctx().data(|d| d.get_temp(state_id)).unwrap_or_default();
+        let previous = state.target.rem_euclid(count);
+        let dt = ui.input(|i| i.stable_dt).min(MAX_DT);
+
+        // Drag / swipe
+        if response.drag_started() {
+            state.drag_origin = state.position;
+        }
+        if response.dragged() {
+            let dragged = response.total_drag_delta().map_or(0.0, |d| d.x);
+            state.position = state.drag_origin - dragged / width.max(1.0);
+            state.velocity = 0.0;
+        } else if response.drag_stopped() {
+            let release_velocity = -ui.input(|i| i.pointer.velocity().x) / width.max(1.0);
+            let projected = release_velocity.mul_add(MOMENTUM_PROJECTION, state.position);
+            state.target = projected.round() as i64;
+            state.velocity = release_velocity;
+        }
+
+        // Autoplay
+        let paused = ui.rect_contains_pointer(viewport) || response.dragged();
+        if let Some(interval) = self.autoplay {
+            if !paused {
+                state.autoplay_elapsed += ui.input(|i| i.stable_dt);
+                if state.autoplay_elapsed >= interval.as_secs_f32() {
+                    state.autoplay_elapsed = 0.0;
+                    state.target += 1;
+                    if !self.looping && state.target >= count {
+                        state.target = 0;
+                    }
+                }
+                let remaining = interval.as_secs_f32() - state.autoplay_elapsed;
+                ui.ctx().request_repaint_after_secs(remaining.max(0.0));
+            }
+        }
+
+        // Slide animation
+        if !response.dragged() {
+            let mut spring = SpringAnimation::new(state.position, state.target as f32)
+                .with_velocity(state.velocity)
+                .params(SPRING_STIFFNESS, SPRING_DAMPING);
+            spring.update(dt);
+            if spring.is_settled(0.001, 0.01) {
+                // Fold wrapped positions back into 0..count
+                let index = state.target.rem_euclid(count);
+                state.target = index;
+                spring.reset(index as f32, index as f32);
+            } else {
+                ui.ctx().request_repaint();
+            }
+            state.position = spring.value;
+            state.velocity = spring.velocity;
+        }
+
+        self.paint_slides(ui, viewport, state.position, count);
+
+        // Buttons and dots sit on top of the slides
+        if self.show_buttons && self.count > 1 {
+            state.target += self.buttons(ui, viewport, id, state.target, count, theme);
+        }
+        if self.show_dots && self.count > 1 {
+            let dots_rect = Rect::from_min_size(
+                pos2(viewport.min.x, viewport.max.y),
+                vec2(width, DOTS_HEIGHT),
+            );
+            ui.allocate_rect(dots_rect, Sense::hover());
+            if let Some(index) = Self::dots(ui, dots_rect, id, previous, count, theme) {
+                state.target += index - previous;
+            }
+        }
+        if !self.looping {
+            state.target = state.target.clamp(0, count - 1);
+        }
+        if state.target as f32 != state.position {
+            ui.ctx().request_repaint();
+        }
+
+        let index = state.target.rem_euclid(count);
+        ui.ctx().data_mut(|d| d.insert_temp(state_id, state));
+        response.widget_info(|| {
+            WidgetInfo::labeled(
+                WidgetType::Other,
+                true,
+                format!("Slide {} of {}", index + 1, count),
+            )
+        });
+
+        CarouselResponse {
+            response,
+            index: index as usize,
+            changed: index != previous,
+        }
+    }
+
+    fn paint_slides(&mut self, ui: &mut Ui, viewport: Rect, position: f32, count: i64) {
+        let clip = viewport.intersect(ui.clip_rect());
+        let first = position.floor() as i64;
+        for slot in first..=first + 1 {
+            if !self.looping && !(0..count).contains(&slot) {
+                continue;
+            }
+            let offset = (slot as f32 - position) * viewport.width();
+            if offset.abs() >= viewport.width() {
+                continue;
+            }
+            let slide_rect = viewport.translate(vec2(offset, 0.0));
+            let mut slide_ui = ui.new_child(
+                egui::UiBuilder::new()
+                    .max_rect(slide_rect)
+                    .id_salt(("carousel_slide", slot)),
+            );
+            slide_ui.set_clip_rect(clip);
+            (self.content)(&mut slide_ui, slot.rem_euclid(count) as usize);
+        }
+    }
+
+    fn button_rects(viewport: Rect) -> [Rect; 2] {
+        let y = viewport.center().y;
+        [
+            Rect::from_center_size(
+                pos2(viewport.min.x + BUTTON_INSET + BUTTON_SIZE / 2.0, y),
+                vec2(BUTTON_SIZE, BUTTON_SIZE),
+            ),
+            Rect::from_center_size(
+                pos2(viewport.max.x - BUTTON_INSET - BUTTON_SIZE / 2.0, y),
+                vec2(BUTTON_SIZE, BUTTON_SIZE),
+            ),
+        ]
+    }
+
+    /// Previous/next buttons; returns the change to the target slide
+    fn buttons(
+        &self,
+        ui: &Ui,
+        viewport: Rect,
+        id: Id,
+        target: i64,
+        count: i64,
+        theme: &Theme,
+    ) -> i64 {
+        let mut delta = 0;
+        let buttons = [
+            (crate::icon::chevron_left(), "Previous slide", -1),
+            (crate::icon::chevron_right(), "Next slide", 1),
+        ];
+        for (rect, (icon, label, step)) in Self::button_rects(viewport).into_iter().zip(buttons) {
+            let enabled = self.looping || (0..count).contains(&(target + step));
+            let response = ui.interact(rect, id.with(label), Sense::click());
+            response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, enabled, label));
+            if enabled && response.clicked() {
+                delta += step;
+            }
+
+            let painter = ui.painter();
+            let fill = if enabled && response.hovered() {
+                theme.accent()
+            } else {
+                theme.background()
+            };
+            painter.circle(
+                rect.center(),
+                BUTTON_SIZE / 2.0,
+                fill,
+                Stroke::new(1.0, theme.border()),
+            );
+            let color = if enabled {
+                theme.foreground()
+            } else {
+                theme.muted_foreground().gamma_multiply(0.5)
+            };
+            icon.render(
+                painter,
+                Rect::from_center_size(rect.center(), vec2(BUTTON_ICON_SIZE, BUTTON_ICON_SIZE)),
+                color,
+            );
+        }
+        delta
+    }
+
+    /// Draw the indicator dots; returns the clicked slide
+    fn dots(ui: &Ui, rect: Rect, id: Id, active: i64, count: i64, theme: &Theme) -> Option<i64> {
+        let total = DOT_SIZE * count as f32 + DOT_GAP * (count - 1) as f32;
+        let start_x = rect.center().x - total / 2.0;
+        let mut clicked = None;
+        for index in 0..count {
+            let center = pos2(
+                (DOT_SIZE + DOT_GAP).mul_add(index as f32, start_x + DOT_SIZE / 2.0),
+                rect.center().y,
+            );
+            let hit = Rect::from_center_size(center, vec2(DOT_SIZE + DOT_GAP, DOTS_HEIGHT));
+            let response = ui.interact(hit, id.with(("dot", index)), Sense::click());
+            let label = format!("Go to slide {}", index + 1);
+            response.widget_info(|| {
+                WidgetInfo::selected(WidgetType::Button, true, index == active, &label)
+            });
+            if response.clicked() {
+                clicked = Some(index);
+            }
+
+            let color = if index == active {
+                theme.foreground()
+            } else if response.hovered() {
+                theme.muted_foreground()
+            } else {
+                theme.muted_foreground().gamma_multiply(0.4)
+            };
+            ui.painter().circle_filled(center, DOT_SIZE / 2.0, color);
+        }
+        clicked
+    }
+}
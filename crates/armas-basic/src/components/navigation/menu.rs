@@ -16,7 +16,7 @@
 use crate::components::basic::Kbd;
 use crate::icon;
 use crate::{Popover, PopoverPosition, PopoverStyle};
-use egui::{vec2, Color32, Id, Key, Rect, Sense, Ui};
+use egui::{pos2, vec2, Color32, Id, Key, Pos2, Rect, Sense, Ui, Vec2};
 use std::collections::HashSet;
 
 // ============================================================================
@@ -69,8 +69,9 @@ struct MenuRenderContext<'a> {
 // Constants (matching shadcn Tailwind values)
 // ============================================================================
 
-// Content: min-w-[8rem] = 128px
+// Content: min-w-[8rem] = 128px, p-1 = 4px
 const CONTENT_MIN_WIDTH: f32 = 128.0;
+const CONTENT_PADDING: f32 = 4.0;
 
 // Item: px-2 = 8px, py-1.5 = 6px, text-sm = 14px, gap-2 = 8px, rounded-sm = 2px
 const ITEM_PADDING_X: f32 = 8.0;
@@ -350,7 +351,7 @@ impl Menu {
             popover: Popover::new(id.with("popover"))
                 .position(PopoverPosition::Bottom)
                 .style(PopoverStyle::Default) // shadcn: rounded-md border shadow-md
-                .padding(4.0), // p-1 = 4px (shadcn)
+                .padding(CONTENT_PADDING),
             is_open: None,
             width: 200.0,
         }
@@ -383,6 +384,55 @@ impl Menu {
         ctx: &egui::Context,
         anchor_rect: Rect,
         content: impl FnOnce(&mut MenuBuilder),
+    ) -> MenuResponse {
+        self.show_placed(ctx, MenuPlacement::Anchor(anchor_rect), content)
+    }
+
+    /// Show the menu as a context menu at a screen position
+    ///
+    /// The menu opens below and to the right of `pos`, flipping up or left
+    /// (and shifting if still needed) to stay on screen. It is open unless
+    /// [`Menu::open`] says otherwise, and dismisses itself on an outside
+    /// click, Escape or choosing an item: `is_open` in the response turns
+    /// false and the caller should stop showing it.
+    ///
+    /// ```rust,no_run
+    /// # use egui::Ui;
+    /// # fn example(ui: &mut Ui) {
+    /// use armas_basic::components::navigation::Menu;
+    ///
+    /// let response = ui.label("Right-click me");
+    /// let pos_id = ui.id().with("context_pos");
+    /// if response.secondary_clicked() {
+    ///     let pos = response.interact_pointer_pos();
+    ///     ui.ctx().data_mut(|d| d.insert_temp(pos_id, pos));
+    /// }
+    /// let pos: Option<egui::Pos2> = ui.ctx().data(|d| d.get_temp(pos_id)).flatten();
+    /// if let Some(pos) = pos {
+    ///     let menu = Menu::new("context").show_at(ui.ctx(), pos, |menu| {
+    ///         menu.item("Rename");
+    ///         menu.item("Delete").destructive();
+    ///     });
+    ///     if !menu.is_open {
+    ///         ui.ctx().data_mut(|d| d.remove::<Option<egui::Pos2>>(pos_id));
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn show_at(
+        &mut self,
+        ctx: &egui::Context,
+        pos: Pos2,
+        content: impl FnOnce(&mut MenuBuilder),
+    ) -> MenuResponse {
+        self.show_placed(ctx, MenuPlacement::Point(pos), content)
+    }
+
+    fn show_placed(
+        &mut self,
+        ctx: &egui::Context,
+        placement: MenuPlacement,
+        content: impl FnOnce(&mut MenuBuilder),
     ) -> MenuResponse {
         let theme = crate::ext::ArmasContextExt::armas_theme(ctx);
 
@@ -395,9 +445,11 @@ impl Menu {
         let (mut is_open, mut selected_index) = self.load_state(ctx);
         let mut submenu_state = SubmenuState::load(ctx, self.id);
 
-        // Override with external control if set
+        // Override with external control if set; context menus start open
         if let Some(external_open) = self.is_open {
             is_open = external_open;
+        } else if matches!(placement, MenuPlacement::Point(_)) {
+            is_open = true;
         }
 
         // Handle keyboard navigation (only when open)
@@ -420,7 +472,7 @@ impl Menu {
 
         let menu_id = self.id;
         let menu_width = self.width;
-        let popover_response = self.popover.show(ctx, &theme, anchor_rect, |ui| {
+        let render = |ui: &mut Ui| {
             ui.spacing_mut().item_spacing = vec2(0.0, 1.0);
 
             let mut ctx = MenuRenderContext {
@@ -436,7 +488,17 @@ impl Menu {
                 &mut submenu_state,
                 &mut response,
             );
-        });
+        };
+        let popover_response = match placement {
+            MenuPlacement::Anchor(anchor_rect) => {
+                self.popover.show(ctx, &theme, anchor_rect, render)
+            }
+            MenuPlacement::Point(pos) => {
+                let size = self.estimated_size(ctx, &items);
+                let origin = context_menu_origin(pos, size, ctx.content_rect());
+                self.popover.show_at(ctx, &theme, origin, render)
+            }
+        };
 
         if popover_response.clicked_outside {
             response.clicked_outside = true;
@@ -445,8 +507,14 @@ impl Menu {
         }
 
         // Close submenus when an item is selected
-        if response.selected.is_some() {
+        if let Some(idx) = response.selected {
             submenu_state.close_all();
+            // Context menus also dismiss themselves, except for toggles
+            if matches!(placement, MenuPlacement::Point(_))
+                && matches!(items[idx].kind, MenuItemKind::Item { .. })
+            {
+                is_open = false;
+            }
         }
 
         // Update response with final open state
@@ -459,6 +527,25 @@ impl Menu {
         response
     }
 
+    /// Size of the open menu: measured last frame, or estimated from the items
+    fn estimated_size(&self, ctx: &egui::Context, items: &[MenuItemData]) -> Vec2 {
+        if let Some(rect) = ctx.memory(|m| m.area_rect(self.id.with("popover"))) {
+            return rect.size();
+        }
+        let items_height: f32 = items
+            .iter()
+            .map(|item| match item.kind {
+                MenuItemKind::Separator => SEPARATOR_MARGIN_Y.mul_add(2.0, 1.0),
+                _ => ITEM_HEIGHT,
+            })
+            .sum();
+        let spacing = items.len().saturating_sub(1) as f32;
+        vec2(
+            self.width,
+            CONTENT_PADDING.mul_add(2.0, items_height + spacing),
+        )
+    }
+
     // ========================================================================
     // State Management
     // ========================================================================
@@ -521,6 +608,34 @@ impl Menu {
     }
 }
 
+/// Where a menu is placed
+#[derive(Clone, Copy)]
+enum MenuPlacement {
+    /// Next to an anchor rect, following the popover position
+    Anchor(Rect),
+    /// At a screen point, as a context menu
+    Point(Pos2),
+}
+
+/// Top-left corner for a context menu of `size` opened at `pos`
+///
+/// Opens below and to the right of `pos`, flips up or left when there isn't
+/// room, then shifts to stay inside `screen`.
+fn context_menu_origin(pos: Pos2, size: Vec2, screen: Rect) -> Pos2 {
+    let axis = |pos: f32, size: f32, min: f32, max: f32| {
+        let start = if pos + size > max && pos - size >= min {
+            pos - size
+        } else {
+            pos
+        };
+        start.clamp(min, (max - size).max(min))
+    };
+    pos2(
+        axis(pos.x, size.x, screen.min.x, screen.max.x),
+        axis(pos.y, size.y, screen.min.y, screen.max.y),
+    )
+}
+
 // ============================================================================
 // Rendering Functions (free functions to avoid borrow issues)
 // ============================================================================
@@ -1028,7 +1143,7 @@ impl ItemVariant {
 // ============================================================================
 
 fn navigate_down(selected_index: &mut Option<usize>, items: &[MenuItemData]) {
-    let start_idx = selected_index.map(|i| i + 1).unwrap_or(0);
+    let start_idx = selected_index.map_or(0, |i| i + 1);
 
     for (i, item) in items.iter().enumerate().skip(start_idx) {
         if item.is_selectable() {
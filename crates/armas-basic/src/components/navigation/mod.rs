@@ -3,21 +3,27 @@
 //! Components for navigating through the application.
 
 pub mod breadcrumbs;
+pub mod carousel;
 pub mod command;
 pub mod menu;
 pub mod pagination;
+pub mod radial_menu;
 pub mod sidebar;
 pub mod tabs;
 pub mod tree_view;
+pub mod wizard;
 
 // Re-exports
 pub use breadcrumbs::{Breadcrumbs, BreadcrumbsResponse};
+pub use carousel::{Carousel, CarouselResponse};
 pub use command::{Command, CommandResponse};
-pub use menu::{Menu, MenuResponse};
+pub use menu::{Menu, MenuItem, MenuResponse};
 pub use pagination::Pagination;
+pub use radial_menu::{RadialMenu, RadialMenuResponse};
 pub use sidebar::{CollapsibleMode, Sidebar, SidebarResponse, SidebarState, SidebarVariant};
 pub use tabs::Tabs;
 pub use tree_view::{TreeItem, TreeView, TreeViewResponse};
+pub use wizard::{Wizard, WizardResponse};
 
 // Backwards compatibility aliases
 #[doc(hidden)]
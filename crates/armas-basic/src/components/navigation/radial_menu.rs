@@ -0,0 +1,415 @@
+//! Radial Menu Component
+//!
+//! Pie menu for touch and pen workflows. Items are laid out as wedges around
+//! a center point and picked by pointer direction:
+//! - Opens at the cursor (or a fixed center) with a quick scale/fade
+//! - Highlights the wedge under the pointer
+//! - Reports the selected index when the pointer is released
+//! - Releasing inside the center dead zone, or pressing Escape, cancels
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui, menu_open: &mut bool) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::RadialMenu;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let response = ui.button("Hold for tools");
+//! if response.is_pointer_button_down_on() {
+//!     *menu_open = true;
+//! }
+//!
+//! let menu = RadialMenu::new("tools")
+//!     .open(*menu_open)
+//!     .icon_item("✏", "Draw")
+//!     .icon_item("⌫", "Erase")
+//!     .item("Select")
+//!     .item("Move")
+//!     .show(ui.ctx(), &theme);
+//!
+//! if let Some(index) = menu.selected {
+//!     println!("Picked tool {index}");
+//! }
+//! *menu_open = menu.is_open;
+//! # }
+//! ```
+
+use crate::Theme;
+use egui::{vec2, Align2, Color32, FontId, Id, Key, Mesh, Pos2, Sense, Stroke};
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+// Dimensions
+const RADIUS: f32 = 96.0;
+const INNER_RADIUS: f32 = 28.0;
+const WEDGE_GAP: f32 = 2.0;
+const LABEL_SIZE: f32 = 14.0; // text-sm
+const ICON_SIZE: f32 = 16.0; // size-4
+const ICON_LABEL_GAP: f32 = 4.0; // gap-1
+
+// Animation
+const OPEN_DURATION: f32 = 0.12;
+const OPEN_START_SCALE: f32 = 0.85;
+
+/// A single wedge in a radial menu
+#[derive(Clone, Debug)]
+struct RadialMenuItem {
+    label: String,
+    icon: Option<String>,
+}
+
+/// Persisted open state
+#[derive(Clone, Copy, Default)]
+struct RadialMenuState {
+    open: bool,
+    center: Pos2,
+}
+
+/// Response from showing a radial menu
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RadialMenuResponse {
+    /// Index of the item picked this frame (on pointer release)
+    pub selected: Option<usize>,
+    /// Index of the wedge currently under the pointer
+    pub hovered: Option<usize>,
+    /// Whether the menu was dismissed without a selection this frame
+    pub cancelled: bool,
+    /// Whether the menu is still open after this frame
+    pub is_open: bool,
+}
+
+/// Radial (pie) menu
+///
+/// Item 0 is centered at the top and the rest follow clockwise.
+pub struct RadialMenu {
+    id: Id,
+    items: Vec<RadialMenuItem>,
+    is_open: Option<bool>,
+    center: Option<Pos2>,
+    radius: f32,
+    inner_radius: f32,
+}
+
+impl RadialMenu {
+    /// Create a new radial menu
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            items: Vec::new(),
+            is_open: None,
+            center: None,
+            radius: RADIUS,
+            inner_radius: INNER_RADIUS,
+        }
+    }
+
+    /// Add an item
+    #[must_use]
+    pub fn item(mut self, label: impl Into<String>) -> Self {
+        self.items.push(RadialMenuItem {
+            label: label.into(),
+            icon: None,
+        });
+        self
+    }
+
+    /// Add an item with an icon (emoji or symbol) above the label
+    #[must_use]
+    pub fn icon_item(mut self, icon: impl Into<String>, label: impl Into<String>) -> Self {
+        self.items.push(RadialMenuItem {
+            label: label.into(),
+            icon: Some(icon.into()),
+        });
+        self
+    }
+
+    /// Set the menu to be open (for external control)
+    #[must_use]
+    pub const fn open(mut self, is_open: bool) -> Self {
+        self.is_open = Some(is_open);
+        self
+    }
+
+    /// Open at a fixed point instead of the pointer position
+    #[must_use]
+    pub const fn center(mut self, center: Pos2) -> Self {
+        self.center = Some(center);
+        self
+    }
+
+    /// Set the outer radius
+    #[must_use]
+    pub const fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius.max(48.0);
+        self
+    }
+
+    /// Set the radius of the center dead zone
+    #[must_use]
+    pub const fn inner_radius(mut self, radius: f32) -> Self {
+        self.inner_radius = radius.max(0.0);
+        self
+    }
+
+    /// Show the radial menu
+    #[must_use]
+    pub fn show(self, ctx: &egui::Context, theme: &Theme) -> RadialMenuResponse {
+        let state_id = self.id.with("state");
+        let mut state: RadialMenuState = ctx.data(|d| d.get_temp(state_id)).unwrap_or_default();
+        let was_open = state.open;
+
+        if let Some(external_open) = self.is_open {
+            state.open = external_open;
+        }
+
+        let mut response = RadialMenuResponse::default();
+
+        if !state.open || self.items.is_empty() {
+            state.open = false;
+            ctx.data_mut(|d| d.insert_temp(state_id, state));
+            return response;
+        }
+
+        if !was_open {
+            state.center = self.opening_center(ctx);
+        }
+        if let Some(center) = self.center {
+            state.center = center;
+        }
+
+        let progress = ctx.animate_bool_with_time(self.id.with("open"), was_open, OPEN_DURATION);
+        let radius = self.radius * egui::lerp(OPEN_START_SCALE..=1.0, progress);
+        let inner_radius = self.inner_radius.min(radius * 0.5);
+
+        let pointer = ctx.input(|i| i.pointer.latest_pos());
+        response.hovered =
+            pointer.and_then(|pos| wedge_at(state.center, pos, self.items.len(), inner_radius));
+
+        // The release that opened the menu must not also close it
+        if was_open {
+            if ctx.input(|i| i.key_pressed(Key::Escape)) {
+                state.open = false;
+                response.cancelled = true;
+            } else if ctx.input(|i| i.pointer.any_released()) {
+                state.open = false;
+                response.selected = response.hovered;
+                response.cancelled = response.hovered.is_none();
+            }
+        }
+
+        egui::Area::new(self.id.with("area"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(state.center - vec2(radius, radius))
+            .show(ctx, |ui| {
+                // Swallow clicks so widgets underneath don't react to the release
+                let (rect, _) =
+                    ui.allocate_exact_size(vec2(radius, radius) * 2.0, Sense::click_and_drag());
+                self.paint(
+                    ui.painter(),
+                    rect.center(),
+                    radius,
+                    inner_radius,
+                    progress,
+                    response.hovered,
+                    theme,
+                );
+            });
+
+        if !was_open || progress < 1.0 {
+            ctx.request_repaint();
+        }
+
+        response.is_open = state.open;
+        ctx.data_mut(|d| d.insert_temp(state_id, state));
+        response
+    }
+
+    /// Pointer position at the moment of opening, kept fully on screen
+    fn opening_center(&self, ctx: &egui::Context) -> Pos2 {
+        let screen = ctx.content_rect();
+        let pointer = ctx.input(|i| i.pointer.latest_pos());
+        let center = pointer.unwrap_or_else(|| screen.center());
+
+        let margin = vec2(self.radius, self.radius);
+        let inner = screen.shrink2(margin);
+        if inner.is_positive() {
+            center.clamp(inner.min, inner.max)
+        } else {
+            screen.center()
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn paint(
+        &self,
+        painter: &egui::Painter,
+        center: Pos2,
+        radius: f32,
+        inner_radius: f32,
+        opacity: f32,
+        hovered: Option<usize>,
+        theme: &Theme,
+    ) {
+        let count = self.items.len();
+        let wedge = TAU / count as f32;
+
+        painter.circle_filled(
+            center + vec2(0.0, 4.0),
+            radius,
+            Color32::from_black_alpha((40.0 * opacity) as u8),
+        );
+
+        for (i, item) in self.items.iter().enumerate() {
+            let is_hovered = hovered == Some(i);
+            let mid = (i as f32).mul_add(wedge, -FRAC_PI_2);
+            let fill = if is_hovered {
+                theme.accent()
+            } else {
+                theme.popover()
+            };
+            painter.add(wedge_mesh(
+                center,
+                inner_radius,
+                radius,
+                mid - wedge / 2.0,
+                mid + wedge / 2.0,
+                fill.gamma_multiply(opacity),
+            ));
+
+            let text_color = if is_hovered {
+                theme.accent_foreground()
+            } else {
+                theme.popover_foreground()
+            }
+            .gamma_multiply(opacity);
+
+            let label_pos =
+                center + vec2(mid.cos(), mid.sin()) * f32::midpoint(inner_radius, radius);
+            if let Some(icon) = &item.icon {
+                let offset = 0.5 * (ICON_SIZE + ICON_LABEL_GAP);
+                painter.text(
+                    label_pos - vec2(0.0, offset),
+                    Align2::CENTER_CENTER,
+                    icon,
+                    FontId::proportional(ICON_SIZE),
+                    text_color,
+                );
+                painter.text(
+                    label_pos + vec2(0.0, offset),
+                    Align2::CENTER_CENTER,
+                    &item.label,
+                    FontId::proportional(LABEL_SIZE),
+                    text_color,
+                );
+            } else {
+                painter.text(
+                    label_pos,
+                    Align2::CENTER_CENTER,
+                    &item.label,
+                    FontId::proportional(LABEL_SIZE),
+                    text_color,
+                );
+            }
+        }
+
+        // Wedge separators
+        if count > 1 {
+            for i in 0..count {
+                let angle = (i as f32 - 0.5).mul_add(wedge, -FRAC_PI_2);
+                let dir = vec2(angle.cos(), angle.sin());
+                painter.line_segment(
+                    [center + dir * inner_radius, center + dir * radius],
+                    Stroke::new(WEDGE_GAP, theme.border().gamma_multiply(opacity)),
+                );
+            }
+        }
+
+        let border = Stroke::new(1.0, theme.border().gamma_multiply(opacity));
+        painter.circle_stroke(center, radius, border);
+        if inner_radius > 0.0 {
+            painter.circle(
+                center,
+                inner_radius,
+                theme.background().gamma_multiply(opacity),
+                border,
+            );
+        }
+    }
+}
+
+/// Index of the wedge in direction of `pos` from `center`
+///
+/// Angles follow screen space (y down), so 90° points straight down. Item 0 is
+/// centered at the top and indices increase clockwise. Returns None inside the
+/// dead zone.
+fn wedge_at(center: Pos2, pos: Pos2, count: usize, inner_radius: f32) -> Option<usize> {
+    let delta = pos - center;
+    if count == 0 || delta.length() < inner_radius.max(1.0) {
+        return None;
+    }
+
+    let wedge = TAU / count as f32;
+    let angle = delta.y.atan2(delta.x) + FRAC_PI_2 + wedge / 2.0;
+    let index = (angle.rem_euclid(TAU) / wedge) as usize;
+    Some(index % count)
+}
+
+/// Filled annular sector between two angles (radians)
+fn wedge_mesh(
+    center: Pos2,
+    inner_radius: f32,
+    outer_radius: f32,
+    start: f32,
+    end: f32,
+    color: Color32,
+) -> Mesh {
+    let segments = (((end - start) / 0.1).ceil() as u32).max(4);
+    let mut mesh = Mesh::default();
+
+    for s in 0..=segments {
+        let angle = egui::lerp(start..=end, s as f32 / segments as f32);
+        let dir = vec2(angle.cos(), angle.sin());
+        mesh.colored_vertex(center + dir * inner_radius, color);
+        mesh.colored_vertex(center + dir * outer_radius, color);
+
+        if s > 0 {
+            let base = s * 2;
+            mesh.add_triangle(base - 2, base - 1, base);
+            mesh.add_triangle(base - 1, base + 1, base);
+        }
+    }
+
+    mesh
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::pos2;
+
+    #[test]
+    fn test_wedge_at_four_items() {
+        let center = pos2(100.0, 100.0);
+        let at_degrees = |degrees: f32| {
+            let angle = degrees.to_radians();
+            center + vec2(angle.cos(), angle.sin()) * 50.0
+        };
+
+        // Screen space: -90° is up, 0° is right, 90° is down, 180° is left
+        assert_eq!(wedge_at(center, at_degrees(-90.0), 4, 10.0), Some(0));
+        assert_eq!(wedge_at(center, at_degrees(0.0), 4, 10.0), Some(1));
+        assert_eq!(wedge_at(center, at_degrees(90.0), 4, 10.0), Some(2));
+        assert_eq!(wedge_at(center, at_degrees(180.0), 4, 10.0), Some(3));
+        // Wedge 0 spans -135°..-45°
+        assert_eq!(wedge_at(center, at_degrees(-50.0), 4, 10.0), Some(0));
+        assert_eq!(wedge_at(center, at_degrees(-40.0), 4, 10.0), Some(1));
+    }
+
+    #[test]
+    fn test_wedge_at_dead_zone() {
+        let center = pos2(0.0, 0.0);
+        assert_eq!(wedge_at(center, pos2(5.0, 0.0), 4, 10.0), None);
+        assert_eq!(wedge_at(center, pos2(50.0, 0.0), 0, 10.0), None);
+    }
+}
@@ -2,7 +2,11 @@
 //!
 //! Tab navigation styled like shadcn/ui Tabs.
 //! Features a muted background container with animated active indicator.
+//! When the tabs don't fit, the strip scrolls horizontally between chevron
+//! buttons and the active tab is kept in view. While the list has keyboard
+//! focus, the arrow keys, Home and End move the selection.
 
+use crate::ext::draw_focus_ring;
 use egui::{Pos2, Ui, Vec2};
 
 // shadcn Tabs constants
@@ -13,6 +17,9 @@ const TRIGGER_RADIUS: f32 = 6.0; // rounded-md
 const TRIGGER_PADDING_X: f32 = 8.0; // px-2
 const TRIGGER_GAP: f32 = 6.0; // gap-1.5
 const FONT_SIZE: f32 = 14.0; // text-sm
+const CHEVRON_WIDTH: f32 = 24.0;
+const CHEVRON_ICON_SIZE: f32 = 16.0; // size-4
+const SCROLL_SPEED: f32 = 12.0;
 
 /// Response from the tabs component
 #[derive(Debug, Clone)]
@@ -23,6 +30,10 @@ pub struct TabsResponse {
     pub selected: Option<usize>,
     /// Whether the selection changed this frame
     pub changed: bool,
+    /// Whether the tabs overflow the available width (chevrons are shown)
+    pub overflowing: bool,
+    /// Horizontal scroll offset of the tab strip in points
+    pub scroll_offset: f32,
 }
 
 /// Tabs component for switching between content sections
@@ -96,6 +107,8 @@ impl Tabs {
                 response: empty_response,
                 selected: None,
                 changed: false,
+                overflowing: false,
+                scroll_offset: 0.0,
             };
         }
 
@@ -142,28 +155,99 @@ impl Tabs {
             + TRIGGER_GAP * (self.labels.len().saturating_sub(1)) as f32
             + LIST_PADDING * 2.0;
 
+        // Overflow: the strip scrolls horizontally between chevron buttons
+        let available_width = ui.available_width();
+        let overflowing = total_width > available_width
+            && available_width > CHEVRON_WIDTH.mul_add(2.0, LIST_PADDING * 2.0);
+        let list_width = if overflowing {
+            available_width
+        } else {
+            total_width
+        };
+
         // Allocate space for the TabsList container
-        let (list_rect, list_response) =
-            ui.allocate_exact_size(Vec2::new(total_width, LIST_HEIGHT), egui::Sense::hover());
+        let (list_rect, list_response) = ui.allocate_exact_size(
+            Vec2::new(list_width, LIST_HEIGHT),
+            egui::Sense::focusable_noninteractive(),
+        );
 
         // Draw TabsList background (bg-muted rounded-lg)
         ui.painter()
             .rect_filled(list_rect, LIST_RADIUS, theme.muted());
 
+        let strip_rect = if overflowing {
+            list_rect.shrink2(Vec2::new(CHEVRON_WIDTH, 0.0))
+        } else {
+            list_rect
+        };
+        let max_offset = (total_width - strip_rect.width()).max(0.0);
+
+        // Scroll state: (current offset, target offset, last active index)
+        let scroll_id = list_response.id.with("scroll");
+        let (mut scroll_offset, mut scroll_target, last_active): (f32, f32, Option<usize>) = ui
+            .ctx()
+            .data(|d| d.get_temp(scroll_id))
+            .unwrap_or((0.0, 0.0, None));
+
         let mut selected = None;
         let inner_height = LIST_HEIGHT - LIST_PADDING * 2.0;
 
-        // Calculate cumulative x positions
-        let mut x_positions: Vec<f32> = Vec::with_capacity(self.labels.len());
-        let mut current_x = list_rect.min.x + LIST_PADDING;
+        // Calculate cumulative x positions (relative to the unscrolled strip)
+        let mut offsets: Vec<f32> = Vec::with_capacity(self.labels.len());
+        let mut current_x = LIST_PADDING;
         for (i, width) in tab_widths.iter().enumerate() {
-            x_positions.push(current_x);
+            offsets.push(current_x);
             current_x += width;
             if i < self.labels.len() - 1 {
                 current_x += TRIGGER_GAP;
             }
         }
 
+        if overflowing {
+            Self::show_chevrons(
+                ui,
+                list_rect,
+                strip_rect,
+                scroll_id,
+                &mut scroll_target,
+                max_offset,
+                theme,
+            );
+        }
+
+        // Scroll the active tab into view when it changes
+        if last_active != Some(self.active_index) {
+            let start = offsets[self.active_index] - LIST_PADDING;
+            let end = offsets[self.active_index] + tab_widths[self.active_index] + LIST_PADDING;
+            if start < scroll_target {
+                scroll_target = start;
+            } else if end > scroll_target + strip_rect.width() {
+                scroll_target = end - strip_rect.width();
+            }
+            if last_active.is_none() {
+                scroll_offset = scroll_target.clamp(0.0, max_offset);
+            }
+        }
+        scroll_target = scroll_target.clamp(0.0, max_offset);
+
+        if self.animate {
+            let t = (SCROLL_SPEED * dt).min(1.0);
+            scroll_offset += (scroll_target - scroll_offset) * t;
+            if (scroll_target - scroll_offset).abs() > 0.5 {
+                ui.ctx().request_repaint();
+            } else {
+                scroll_offset = scroll_target;
+            }
+        } else {
+            scroll_offset = scroll_target;
+        }
+
+        let x_positions: Vec<f32> = offsets
+            .iter()
+            .map(|offset| strip_rect.min.x + offset - scroll_offset)
+            .collect();
+        let painter = ui.painter_at(strip_rect);
+
         // Draw animated active indicator background
         if !tab_widths.is_empty() {
             // Interpolate position and width for smooth animation
@@ -181,8 +265,7 @@ impl Tabs {
             );
 
             // Active tab gets bg-background with subtle shadow
-            ui.painter()
-                .rect_filled(active_rect, TRIGGER_RADIUS, theme.background());
+            painter.rect_filled(active_rect, TRIGGER_RADIUS, theme.background());
         }
 
         // Draw tab triggers
@@ -193,7 +276,8 @@ impl Tabs {
             );
 
             let is_active = index == self.active_index;
-            let is_hovered = ui.rect_contains_pointer(tab_rect);
+            let is_hovered =
+                ui.rect_contains_pointer(tab_rect) && ui.rect_contains_pointer(strip_rect);
 
             // Text color: foreground for active, muted-foreground for inactive
             let text_color = if is_active {
@@ -203,7 +287,7 @@ impl Tabs {
             };
 
             // Draw label
-            ui.painter().text(
+            painter.text(
                 tab_rect.center(),
                 egui::Align2::CENTER_CENTER,
                 label,
@@ -218,6 +302,24 @@ impl Tabs {
             }
         }
 
+        // Keyboard: arrows, Home and End move the selection while focused
+        if list_response.has_focus() {
+            let key_target = Self::keyboard_target(ui, self.active_index, self.labels.len());
+            if let Some(index) = key_target.filter(|&index| index != self.active_index) {
+                selected = Some(index);
+                self.active_index = index;
+            }
+
+            draw_focus_ring(ui.painter(), list_rect, theme);
+        }
+
+        ui.ctx().data_mut(|d| {
+            d.insert_temp(
+                scroll_id,
+                (scroll_offset, scroll_target, Some(self.active_index)),
+            );
+        });
+
         // Update active if changed
         let changed = selected.is_some();
         if let Some(new_index) = selected {
@@ -236,6 +338,79 @@ impl Tabs {
             response: list_response,
             selected,
             changed,
+            overflowing,
+            scroll_offset,
+        }
+    }
+
+    /// The tab index the arrow keys, Home or End pressed this frame select
+    fn keyboard_target(ui: &Ui, active: usize, count: usize) -> Option<usize> {
+        let last = count.saturating_sub(1);
+        ui.input(|i| {
+            if i.key_pressed(egui::Key::ArrowLeft) {
+                Some(active.saturating_sub(1))
+            } else if i.key_pressed(egui::Key::ArrowRight) {
+                Some((active + 1).min(last))
+            } else if i.key_pressed(egui::Key::Home) {
+                Some(0)
+            } else if i.key_pressed(egui::Key::End) {
+                Some(last)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Draw the overflow chevrons and apply chevron clicks and wheel scrolling
+    fn show_chevrons(
+        ui: &Ui,
+        list_rect: egui::Rect,
+        strip_rect: egui::Rect,
+        scroll_id: egui::Id,
+        scroll_target: &mut f32,
+        max_offset: f32,
+        theme: &crate::Theme,
+    ) {
+        // Chevron buttons page the strip by most of its visible width
+        let page = strip_rect.width() * 0.75;
+        let left_rect =
+            egui::Rect::from_min_max(list_rect.min, Pos2::new(strip_rect.min.x, list_rect.max.y));
+        let right_rect =
+            egui::Rect::from_min_max(Pos2::new(strip_rect.max.x, list_rect.min.y), list_rect.max);
+        let chevrons = [
+            (
+                left_rect,
+                crate::icon::chevron_left(),
+                -page,
+                *scroll_target > 0.0,
+            ),
+            (
+                right_rect,
+                crate::icon::chevron_right(),
+                page,
+                *scroll_target < max_offset,
+            ),
+        ];
+        for (index, (rect, icon, delta, enabled)) in chevrons.into_iter().enumerate() {
+            let response = ui.interact(rect, scroll_id.with(index), egui::Sense::click());
+            if enabled && response.clicked() {
+                *scroll_target += delta;
+            }
+            let color = if !enabled {
+                theme.muted_foreground().gamma_multiply(0.4)
+            } else if response.hovered() {
+                theme.foreground()
+            } else {
+                theme.muted_foreground()
+            };
+            let icon_rect =
+                egui::Rect::from_center_size(rect.center(), Vec2::splat(CHEVRON_ICON_SIZE));
+            icon.render(ui.painter(), icon_rect, color);
+        }
+
+        if ui.rect_contains_pointer(strip_rect) {
+            let delta = ui.input(|i| i.smooth_scroll_delta);
+            *scroll_target -= delta.x + delta.y;
         }
     }
 }
@@ -3,9 +3,12 @@
 //! A hierarchical tree view for displaying nested items like files/folders.
 
 use crate::ext::ArmasContextExt;
+use crate::layout::VirtualList;
 use egui::{Pos2, Response, Sense, Ui, Vec2};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
 // ============================================================================
@@ -72,6 +75,19 @@ pub struct TreeViewResponse {
     pub selected: Option<PathBuf>,
     /// Branch expanded/collapsed this frame
     pub toggled: Option<PathBuf>,
+    /// Indices into the flattened node list that were rendered, when
+    /// [`TreeView::virtualized`] is enabled
+    pub visible: Option<Range<usize>>,
+}
+
+/// A node of the expanded tree in display order
+struct FlatNode {
+    /// Index into `TreeView::items`
+    index: usize,
+    depth: usize,
+    is_last: bool,
+    /// Whether each ancestor was the last child of its parent
+    levels_last: Vec<bool>,
 }
 
 // ============================================================================
@@ -146,6 +162,8 @@ pub struct TreeView {
     items: Vec<TreeItem>,
     root_path: String,
     show_lines: bool,
+    draggable: bool,
+    virtualized: bool,
 }
 
 impl TreeView {
@@ -193,6 +211,26 @@ impl TreeView {
         self
     }
 
+    /// Let items be dragged onto drop targets
+    ///
+    /// A dragged item is carried as a [`TreeItem`] payload, see
+    /// [`crate::ext::begin_drag`].
+    #[must_use]
+    pub const fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    /// Only render the nodes inside the scroll viewport
+    ///
+    /// The expanded tree is flattened each frame and rows are laid out by
+    /// index, so large hierarchies cost only as much as the visible rows.
+    #[must_use]
+    pub const fn virtualized(mut self, virtualized: bool) -> Self {
+        self.virtualized = virtualized;
+        self
+    }
+
     /// Get selected item
     #[must_use]
     pub const fn selected(&self) -> Option<&PathBuf> {
@@ -229,9 +267,42 @@ impl TreeView {
 
         let mut selected_this_frame = None;
         let mut toggled_this_frame = None;
+        let mut visible = None;
 
         let (rect, response) = ui.allocate_exact_size(Vec2::new(width, height), Sense::hover());
 
+        if self.virtualized {
+            let nodes = self.flatten();
+            ui.scope_builder(egui::UiBuilder::new().max_rect(rect), |ui| {
+                let list = VirtualList::new(nodes.len(), ITEM_HEIGHT + ITEM_GAP)
+                    .id("tree_view_scroll")
+                    .height(height)
+                    .show(ui, |ui, row| {
+                        let node = &nodes[row];
+                        let item = self.items[node.index].clone();
+                        let params = ShowItemParams {
+                            item: &item,
+                            width,
+                            depth: node.depth,
+                            is_last: node.is_last,
+                            levels_last: &node.levels_last,
+                            selected: &mut selected_this_frame,
+                            toggled: &mut toggled_this_frame,
+                            theme,
+                        };
+                        self.show_item(ui, params);
+                    });
+                visible = Some(list.visible);
+            });
+
+            return TreeViewResponse {
+                response,
+                selected: selected_this_frame,
+                toggled: toggled_this_frame,
+                visible,
+            };
+        }
+
         ui.scope_builder(egui::UiBuilder::new().max_rect(rect), |ui| {
             egui::ScrollArea::vertical()
                 .id_salt("tree_view_scroll")
@@ -254,6 +325,7 @@ impl TreeView {
             response,
             selected: selected_this_frame,
             toggled: toggled_this_frame,
+            visible,
         }
     }
 
@@ -366,8 +438,13 @@ impl TreeView {
             }
 
             let item_width = (params.width - indent - ITEM_PADDING_X).max(40.0);
+            let sense = if self.draggable {
+                Sense::click_and_drag()
+            } else {
+                Sense::click()
+            };
             let (rect, response) =
-                ui.allocate_exact_size(Vec2::new(item_width, ITEM_HEIGHT), Sense::click());
+                ui.allocate_exact_size(Vec2::new(item_width, ITEM_HEIGHT), sense);
             let hovered = response.hovered();
 
             // Background
@@ -404,6 +481,27 @@ impl TreeView {
                 text_color,
             );
 
+            response.widget_info(|| {
+                egui::WidgetInfo::labeled(egui::WidgetType::Button, true, &params.item.name)
+            });
+
+            // Drag the item as a payload, with its name following the pointer
+            if response.drag_started() {
+                crate::ext::begin_drag(ui.ctx(), params.item.clone());
+            }
+            if response.dragged() {
+                if let Some(pointer) = ui.ctx().pointer_interact_pos() {
+                    let layer = egui::LayerId::new(egui::Order::Tooltip, response.id);
+                    ui.ctx().layer_painter(layer).text(
+                        pointer + Vec2::new(12.0, 0.0),
+                        egui::Align2::LEFT_CENTER,
+                        &display_name,
+                        egui::FontId::proportional(13.0),
+                        params.theme.foreground(),
+                    );
+                }
+            }
+
             // Handle click
             if response.clicked() {
                 if params.item.is_directory {
@@ -434,15 +532,68 @@ impl TreeView {
             .cloned()
             .collect();
 
-        // Sort: folders first, then by name
-        items.sort_by(|a, b| match (a.is_directory, b.is_directory) {
-            (true, false) => std::cmp::Ordering::Less,
-            (false, true) => std::cmp::Ordering::Greater,
-            _ => a.name.cmp(&b.name),
-        });
-
+        items.sort_by(display_order);
         items
     }
+
+    /// Expanded tree in display order, for virtualized rendering
+    ///
+    /// Children are grouped by parent once, so flattening is linear in the
+    /// number of items rather than scanning all items per branch.
+    fn flatten(&self) -> Vec<FlatNode> {
+        let mut children: HashMap<&Path, Vec<usize>> = HashMap::new();
+        for (index, item) in self.items.iter().enumerate() {
+            if let Some(parent) = item.path.parent() {
+                children.entry(parent).or_default().push(index);
+            }
+        }
+        for indices in children.values_mut() {
+            indices.sort_by(|&a, &b| display_order(&self.items[a], &self.items[b]));
+        }
+
+        let mut nodes = Vec::new();
+        let mut levels_last = Vec::new();
+        let root = PathBuf::from(&self.root_path);
+        self.flatten_level(&children, &root, &mut levels_last, &mut nodes);
+        nodes
+    }
+
+    fn flatten_level(
+        &self,
+        children: &HashMap<&Path, Vec<usize>>,
+        parent: &Path,
+        levels_last: &mut Vec<bool>,
+        nodes: &mut Vec<FlatNode>,
+    ) {
+        let Some(indices) = children.get(parent) else {
+            return;
+        };
+        for (i, &index) in indices.iter().enumerate() {
+            let item = &self.items[index];
+            let is_last = i == indices.len() - 1;
+            nodes.push(FlatNode {
+                index,
+                depth: levels_last.len(),
+                is_last,
+                levels_last: levels_last.clone(),
+            });
+
+            if item.is_directory && self.is_expanded(&item.path) {
+                levels_last.push(is_last);
+                self.flatten_level(children, &item.path, levels_last, nodes);
+                levels_last.pop();
+            }
+        }
+    }
+}
+
+/// Sort order of siblings: folders first, then by name
+fn display_order(a: &TreeItem, b: &TreeItem) -> Ordering {
+    match (a.is_directory, b.is_directory) {
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        _ => a.name.cmp(&b.name),
+    }
 }
 
 // Backwards compatibility aliases
@@ -0,0 +1,257 @@
+//! Wizard Component
+//!
+//! Multi-step flow: a numbered step header above the active step's panel,
+//! with Back / Next / Finish buttons. The panel callback reports whether the
+//! step is valid, and Next / Finish stay disabled until it is.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use egui::Ui;
+//! # fn example(ui: &mut Ui, name: &mut String, email: &mut String) {
+//! use armas_basic::ext::ArmasContextExt;
+//! use armas_basic::Wizard;
+//!
+//! let theme = ui.ctx().armas_theme();
+//! let response = Wizard::new("signup")
+//!     .steps(["Name", "Email", "Confirm"])
+//!     .show(ui, &theme, |ui, step| match step {
+//!         0 => {
+//!             ui.text_edit_singleline(name);
+//!             !name.is_empty()
+//!         }
+//!         1 => {
+//!             ui.text_edit_singleline(email);
+//!             email.contains('@')
+//!         }
+//!         _ => {
+//!             ui.label(format!("{name} <{email}>"));
+//!             true
+//!         }
+//!     });
+//! if response.finished {
+//!     // submit the form
+//! }
+//! # }
+//! ```
+
+use crate::{Button, ButtonVariant, Theme};
+use egui::{pos2, vec2, Align, Id, Layout, Response, Sense, Stroke, Ui};
+
+// shadcn-style constants
+const INDICATOR_SIZE: f32 = 32.0; // size-8
+const CONNECTOR_WIDTH: f32 = 2.0;
+const CONNECTOR_GAP: f32 = 8.0;
+const LABEL_GAP: f32 = 6.0;
+const LABEL_FONT_SIZE: f32 = 13.0;
+const SECTION_GAP: f32 = 16.0; // gap-4
+
+/// Response from a wizard
+#[derive(Debug, Clone)]
+pub struct WizardResponse {
+    /// Response of the whole wizard
+    pub response: Response,
+    /// Index of the active step after this frame
+    pub step: usize,
+    /// Whether the active step changed this frame
+    pub changed: bool,
+    /// Whether the active step's panel reported itself valid
+    pub valid: bool,
+    /// Whether Finish was clicked on the last step this frame
+    pub finished: bool,
+}
+
+/// Step header with a content panel and Back / Next / Finish buttons
+pub struct Wizard {
+    id: Id,
+    steps: Vec<String>,
+    step: Option<usize>,
+    finish_label: String,
+}
+
+impl Wizard {
+    /// Create a new wizard
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            steps: Vec::new(),
+            step: None,
+            finish_label: "Finish".to_string(),
+        }
+    }
+
+    /// Set the step titles shown in the header
+    #[must_use]
+    pub fn steps(mut self, steps: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.steps = steps.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Jump to a step, overriding the remembered one
+    #[must_use]
+    pub const fn active_step(mut self, step: usize) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Set the label of the button on the last step (default: "Finish")
+    #[must_use]
+    pub fn finish_label(mut self, label: impl Into<String>) -> Self {
+        self.finish_label = label.into();
+        self
+    }
+
+    /// Show the wizard
+    ///
+    /// `content` draws the panel for the given step index and returns whether
+    /// the step is valid; Next and Finish are disabled while it is not.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        theme: &Theme,
+        content: impl FnOnce(&mut Ui, usize) -> bool,
+    ) -> WizardResponse {
+        let state_id = self.id.with("step");
+        let last = self.steps.len().saturating_sub(1);
+        let mut step = self
+            .step
+            .or_else(|| ui.ctx().data(|d| d.get_temp(state_id)))
+            .unwrap_or(0)
+            .min(last);
+        let initial_step = step;
+        let mut finished = false;
+        let mut valid = false;
+
+        let inner = ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = SECTION_GAP;
+            self.show_header(ui, theme, step);
+
+            valid = ui
+                .push_id(("wizard_step", step), |ui| content(ui, step))
+                .inner;
+
+            ui.horizontal(|ui| {
+                if Button::new("Back")
+                    .variant(ButtonVariant::Outline)
+                    .enabled(step > 0)
+                    .show(ui, theme)
+                    .clicked()
+                {
+                    step -= 1;
+                }
+
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    let is_last = step == last;
+                    let label = if is_last {
+                        self.finish_label.as_str()
+                    } else {
+                        "Next"
+                    };
+                    if Button::new(label).enabled(valid).show(ui, theme).clicked() {
+                        if is_last {
+                            finished = true;
+                        } else {
+                            step += 1;
+                        }
+                    }
+                });
+            });
+        });
+
+        ui.ctx().data_mut(|d| d.insert_temp(state_id, step));
+
+        WizardResponse {
+            response: inner.response,
+            step,
+            changed: step != initial_step,
+            valid,
+            finished,
+        }
+    }
+
+    /// Numbered indicators joined by connectors, with titles underneath
+    fn show_header(&self, ui: &mut Ui, theme: &Theme, active: usize) {
+        let count = self.steps.len();
+        if count == 0 {
+            return;
+        }
+
+        let width = ui.available_width();
+        let height = INDICATOR_SIZE + LABEL_GAP + LABEL_FONT_SIZE + 4.0;
+        let (rect, _) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+        if !ui.is_rect_visible(rect) {
+            return;
+        }
+
+        let painter = ui.painter();
+        let column = width / count as f32;
+        let center_y = rect.top() + INDICATOR_SIZE / 2.0;
+        let center_x = |i: usize| (i as f32 + 0.5).mul_add(column, rect.left());
+
+        for (i, title) in self.steps.iter().enumerate() {
+            let center = pos2(center_x(i), center_y);
+            let reached = i <= active;
+
+            if i + 1 < count {
+                let color = if i < active {
+                    theme.primary()
+                } else {
+                    theme.border()
+                };
+                painter.line_segment(
+                    [
+                        pos2(center.x + INDICATOR_SIZE / 2.0 + CONNECTOR_GAP, center_y),
+                        pos2(
+                            center_x(i + 1) - INDICATOR_SIZE / 2.0 - CONNECTOR_GAP,
+                            center_y,
+                        ),
+                    ],
+                    Stroke::new(CONNECTOR_WIDTH, color),
+                );
+            }
+
+            let (fill, text_color) = if reached {
+                (theme.primary(), theme.primary_foreground())
+            } else {
+                (theme.background(), theme.muted_foreground())
+            };
+            painter.circle_filled(center, INDICATOR_SIZE / 2.0, fill);
+            if !reached {
+                painter.circle_stroke(
+                    center,
+                    INDICATOR_SIZE / 2.0 - 0.5,
+                    Stroke::new(1.0, theme.border()),
+                );
+            }
+            if i < active {
+                // Completed: checkmark as two lines
+                let size = INDICATOR_SIZE * 0.4;
+                let stroke = Stroke::new(2.0, text_color);
+                let middle = center + vec2(-size * 0.05, size * 0.3);
+                painter.line_segment([center + vec2(-size * 0.35, 0.0), middle], stroke);
+                painter.line_segment([middle, center + vec2(size * 0.35, -size * 0.35)], stroke);
+            } else {
+                painter.text(
+                    center,
+                    egui::Align2::CENTER_CENTER,
+                    (i + 1).to_string(),
+                    egui::FontId::proportional(14.0),
+                    text_color,
+                );
+            }
+
+            let title_color = if i == active {
+                theme.foreground()
+            } else {
+                theme.muted_foreground()
+            };
+            painter.text(
+                pos2(center.x, rect.top() + INDICATOR_SIZE + LABEL_GAP),
+                egui::Align2::CENTER_TOP,
+                title,
+                egui::FontId::proportional(LABEL_FONT_SIZE),
+                title_color,
+            );
+        }
+    }
+}
@@ -0,0 +1,337 @@
+//! Coachmark Component
+//!
+//! Product-tour overlay that spotlights a target widget. The screen is dimmed
+//! except for a cutout around the target, and a callout next to it shows the
+//! step's content with Skip / Back / Next controls. The caller owns the step
+//! index and advances it from the response.
+
+use crate::{Button, ButtonSize, ButtonVariant, Theme};
+use egui::{pos2, Align, Align2, Color32, Id, Key, Layout, Rect, Sense, Shape, Stroke, Ui};
+
+// shadcn-style constants
+const OVERLAY_ALPHA: u8 = 128; // bg-black/50, like Dialog
+const CUTOUT_PADDING: f32 = 4.0;
+const RING_WIDTH: f32 = 2.0;
+const CALLOUT_WIDTH: f32 = 288.0; // w-72
+const CALLOUT_PADDING: f32 = 16.0; // p-4
+const CALLOUT_GAP: f32 = 12.0;
+const CORNER_RADIUS: f32 = 8.0; // rounded-lg
+const ARROW_SIZE: f32 = 6.0;
+const SCREEN_MARGIN: f32 = 8.0;
+/// Space below the target needed to place the callout there
+const MIN_SPACE_BELOW: f32 = 160.0;
+
+/// Response from a coachmark
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CoachmarkResponse {
+    /// Next was clicked on a step before the last one
+    pub next: bool,
+    /// Back was clicked
+    pub prev: bool,
+    /// The tour was skipped (Skip or Escape)
+    pub skipped: bool,
+    /// Done was clicked on the last step
+    pub finished: bool,
+}
+
+/// Tour step overlay that spotlights a target rect
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::{Context, Rect};
+/// # fn example(ctx: &Context, targets: &[Rect], step: &mut Option<usize>) {
+/// use armas_basic::ext::ArmasContextExt;
+/// use armas_basic::Coachmark;
+///
+/// let theme = ctx.armas_theme();
+/// if let Some(index) = *step {
+///     let response = Coachmark::new("tour", targets[index])
+///         .step(index, targets.len())
+///         .title("Transport")
+///         .description("Start and stop playback here.")
+///         .show(ctx, &theme, |_| {});
+///     if response.next {
+///         *step = Some(index + 1);
+///     } else if response.prev {
+///         *step = Some(index - 1);
+///     } else if response.finished || response.skipped {
+///         *step = None;
+///     }
+/// }
+/// # }
+/// ```
+pub struct Coachmark {
+    id: Id,
+    target: Rect,
+    step: usize,
+    step_count: usize,
+    title: Option<String>,
+    description: Option<String>,
+    padding: f32,
+}
+
+impl Coachmark {
+    /// Create a coachmark spotlighting `target` (e.g. a widget's `response.rect`)
+    pub fn new(id: impl Into<Id>, target: Rect) -> Self {
+        Self {
+            id: id.into(),
+            target,
+            step: 0,
+            step_count: 1,
+            title: None,
+            description: None,
+            padding: CUTOUT_PADDING,
+        }
+    }
+
+    /// Set the current step index and the number of steps in the tour
+    #[must_use]
+    pub fn step(mut self, index: usize, count: usize) -> Self {
+        self.step_count = count.max(1);
+        self.step = index.min(self.step_count - 1);
+        self
+    }
+
+    /// Set the step title
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the step description
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the space between the target and the edge of the cutout
+    #[must_use]
+    pub const fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Show the coachmark; `content` adds extra widgets below the description
+    pub fn show(
+        self,
+        ctx: &egui::Context,
+        theme: &Theme,
+        content: impl FnOnce(&mut Ui),
+    ) -> CoachmarkResponse {
+        let mut response = CoachmarkResponse::default();
+        let screen = ctx.content_rect();
+        let cutout = self.target.expand(self.padding);
+
+        // Backdrop with a cutout: four rects around the target, which also
+        // swallow clicks outside it while leaving the target interactive
+        egui::Area::new(self.id.with("coachmark_backdrop"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(screen.min)
+            .show(ctx, |ui| {
+                let backdrop = Color32::from_black_alpha(OVERLAY_ALPHA);
+                for (i, rect) in backdrop_rects(screen, cutout).into_iter().enumerate() {
+                    if rect.is_positive() {
+                        ui.interact(rect, self.id.with(("coachmark_block", i)), Sense::click());
+                        ui.painter().rect_filled(rect, 0.0, backdrop);
+                    }
+                }
+                ui.painter().rect_stroke(
+                    cutout,
+                    0.0,
+                    Stroke::new(RING_WIDTH, theme.ring()),
+                    egui::StrokeKind::Outside,
+                );
+            });
+
+        // Callout below the target, or above it when there is no room
+        let below = screen.bottom() - cutout.bottom() >= MIN_SPACE_BELOW;
+        let x = (cutout.center().x - CALLOUT_WIDTH / 2.0).clamp(
+            screen.left() + SCREEN_MARGIN,
+            (screen.right() - CALLOUT_WIDTH - SCREEN_MARGIN).max(screen.left() + SCREEN_MARGIN),
+        );
+        let (pos, pivot) = if below {
+            (pos2(x, cutout.bottom() + CALLOUT_GAP), Align2::LEFT_TOP)
+        } else {
+            (pos2(x, cutout.top() - CALLOUT_GAP), Align2::LEFT_BOTTOM)
+        };
+
+        egui::Area::new(self.id.with("coachmark_callout"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(pos)
+            .pivot(pivot)
+            .show(ctx, |ui| {
+                let frame = egui::Frame::NONE
+                    .fill(theme.popover())
+                    .stroke(Stroke::new(1.0, theme.border()))
+                    .corner_radius(CORNER_RADIUS)
+                    .shadow(egui::epaint::Shadow {
+                        offset: [0, 4],
+                        blur: 16,
+                        spread: 0,
+                        color: Color32::from_black_alpha(60),
+                    })
+                    .inner_margin(CALLOUT_PADDING);
+
+                let callout = frame
+                    .show(ui, |ui| {
+                        ui.set_width(CALLOUT_WIDTH - 2.0 * CALLOUT_PADDING);
+                        self.callout_contents(ui, theme, content, &mut response);
+                    })
+                    .response
+                    .rect;
+
+                // Arrow pointing at the target
+                let tip_x = cutout.center().x.clamp(
+                    callout.left() + CORNER_RADIUS * 2.0,
+                    callout.right() - CORNER_RADIUS * 2.0,
+                );
+                let (edge, tip) = if below {
+                    (callout.top(), callout.top() - ARROW_SIZE)
+                } else {
+                    (callout.bottom(), callout.bottom() + ARROW_SIZE)
+                };
+                ui.painter().add(Shape::convex_polygon(
+                    vec![
+                        pos2(tip_x - ARROW_SIZE, edge),
+                        pos2(tip_x, tip),
+                        pos2(tip_x + ARROW_SIZE, edge),
+                    ],
+                    theme.popover(),
+                    Stroke::NONE,
+                ));
+            });
+
+        if ctx.input(|i| i.key_pressed(Key::Escape)) {
+            response.skipped = true;
+        }
+
+        response
+    }
+
+    fn callout_contents(
+        &self,
+        ui: &mut Ui,
+        theme: &Theme,
+        content: impl FnOnce(&mut Ui),
+        response: &mut CoachmarkResponse,
+    ) {
+        ui.spacing_mut().item_spacing.y = 8.0;
+
+        if self.step_count > 1 {
+            ui.label(
+                egui::RichText::new(format!("Step {} of {}", self.step + 1, self.step_count))
+                    .size(12.0)
+                    .color(theme.muted_foreground()),
+            );
+        }
+        if let Some(title) = &self.title {
+            ui.label(
+                egui::RichText::new(title)
+                    .size(16.0)
+                    .strong()
+                    .color(theme.popover_foreground()),
+            );
+        }
+        if let Some(description) = &self.description {
+            ui.label(
+                egui::RichText::new(description)
+                    .size(14.0)
+                    .color(theme.muted_foreground()),
+            );
+        }
+        content(ui);
+
+        ui.add_space(4.0);
+        let is_last = self.step + 1 == self.step_count;
+        ui.horizontal(|ui| {
+            if Button::new("Skip")
+                .variant(ButtonVariant::Ghost)
+                .size(ButtonSize::Small)
+                .show(ui, theme)
+                .clicked()
+            {
+                response.skipped = true;
+            }
+
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                let next_label = if is_last { "Done" } else { "Next" };
+                if Button::new(next_label)
+                    .size(ButtonSize::Small)
+                    .show(ui, theme)
+                    .clicked()
+                {
+                    if is_last {
+                        response.finished = true;
+                    } else {
+                        response.next = true;
+                    }
+                }
+                if self.step > 0
+                    && Button::new("Back")
+                        .variant(ButtonVariant::Outline)
+                        .size(ButtonSize::Small)
+                        .show(ui, theme)
+                        .clicked()
+                {
+                    response.prev = true;
+                }
+            });
+        });
+    }
+}
+
+/// Rects covering `screen` except `cutout`: top, bottom, left and right bands
+fn backdrop_rects(screen: Rect, cutout: Rect) -> [Rect; 4] {
+    let cutout = cutout.intersect(screen);
+    [
+        Rect::from_min_max(screen.min, pos2(screen.max.x, cutout.min.y)),
+        Rect::from_min_max(pos2(screen.min.x, cutout.max.y), screen.max),
+        Rect::from_min_max(
+            pos2(screen.min.x, cutout.min.y),
+            pos2(cutout.min.x, cutout.max.y),
+        ),
+        Rect::from_min_max(
+            pos2(cutout.max.x, cutout.min.y),
+            pos2(screen.max.x, cutout.max.y),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{vec2, Pos2};
+
+    #[test]
+    fn test_backdrop_leaves_cutout_transparent() {
+        let screen = Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0));
+        let cutout = Rect::from_min_size(pos2(100.0, 200.0), vec2(120.0, 40.0));
+        let rects = backdrop_rects(screen, cutout);
+
+        // Nothing is painted over the cutout
+        for rect in rects {
+            assert!(
+                !rect.intersects(cutout.shrink(0.01)),
+                "{rect:?} covers the cutout"
+            );
+        }
+
+        // The bands cover the rest of the screen exactly
+        let covered: f32 = rects.iter().map(Rect::area).sum();
+        assert!((covered + cutout.area() - screen.area()).abs() < 1e-3);
+        for point in [
+            pos2(99.0, 220.0),
+            pos2(221.0, 220.0),
+            pos2(150.0, 199.0),
+            pos2(150.0, 241.0),
+        ] {
+            assert!(
+                rects.iter().any(|r| r.contains(point)),
+                "{point:?} is not dimmed"
+            );
+        }
+    }
+}
@@ -1,17 +1,21 @@
 //! Overlay components
 //!
 //! Components that appear above other content including dialogs,
-//! sheets, drawers, popovers, and toasts.
+//! sheets, drawers, popovers, toasts, and coachmarks.
 
+pub mod coachmark;
 pub mod dialog;
 pub mod drawer;
+pub mod notification_center;
 pub mod popover;
 pub mod sheet;
 pub mod toast;
 
 // Re-exports
+pub use coachmark::{Coachmark, CoachmarkResponse};
 pub use dialog::{dialog_footer, Dialog, DialogResponse, DialogSize};
 pub use drawer::{Drawer, DrawerResponse, DrawerSnapPoint};
+pub use notification_center::{Notification, NotificationCenter, NotificationCenterResponse};
 pub use popover::{Popover, PopoverColor, PopoverPosition, PopoverResponse, PopoverStyle};
 pub use sheet::{Sheet, SheetResponse, SheetSide, SheetSize};
 pub use toast::{ToastManager, ToastPosition, ToastVariant};
@@ -0,0 +1,440 @@
+//! Notification Center Component
+//!
+//! Persistent inbox of notifications behind a bell button. Unlike toasts,
+//! notifications stay until dismissed; unread ones are counted on a badge
+//! over the bell, and the list opens in a popover grouped by age.
+
+use crate::components::button::{ButtonVariant, IconButton};
+use crate::{icon, Button, ButtonSize, NotificationBadge, Popover, PopoverPosition, Theme};
+use egui::text::{LayoutJob, TextWrapping};
+use egui::{
+    pos2, vec2, Align, Color32, FontId, Galley, Id, Layout, Rect, Response, Sense, Ui, UiBuilder,
+};
+use std::sync::Arc;
+
+// shadcn-style constants
+const BELL_SIZE: f32 = 20.0;
+const BELL_PADDING: f32 = 8.0;
+const BADGE_SIZE: f32 = 16.0;
+const POPOVER_WIDTH: f32 = 360.0;
+const LIST_MAX_HEIGHT: f32 = 360.0;
+const ROW_HEIGHT: f32 = 36.0;
+const ROW_HEIGHT_WITH_DESCRIPTION: f32 = 54.0;
+const ROW_PADDING_X: f32 = 8.0;
+const UNREAD_DOT_RADIUS: f32 = 4.0;
+const DISMISS_ICON_SIZE: f32 = 10.0;
+const CORNER_RADIUS: f32 = 6.0; // rounded-md
+const DESCRIPTION_GAP: f32 = 2.0;
+/// Space reserved at the right of a row for the dismiss button
+const DISMISS_AREA_WIDTH: f32 = 32.0;
+
+/// A notification in a [`NotificationCenter`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// Identifier assigned by [`NotificationCenter::push`]
+    pub id: u64,
+    /// Title line
+    pub title: String,
+    /// Optional second line
+    pub description: Option<String>,
+    /// When the notification arrived, in egui input time (`ctx.input(|i| i.time)`)
+    ///
+    /// `None` is stamped with the current time the next time the center is shown.
+    pub timestamp: Option<f64>,
+    /// Whether the notification has been read
+    pub read: bool,
+}
+
+impl Notification {
+    /// Create an unread notification
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            id: 0,
+            title: title.into(),
+            description: None,
+            timestamp: None,
+            read: false,
+        }
+    }
+
+    /// Set the second line
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set when the notification arrived, in egui input time
+    #[must_use]
+    pub const fn timestamp(mut self, time: f64) -> Self {
+        self.timestamp = Some(time);
+        self
+    }
+}
+
+/// Response from a notification center
+#[derive(Debug, Clone)]
+pub struct NotificationCenterResponse {
+    /// Response of the bell button
+    pub response: Response,
+    /// Notification clicked this frame (it is marked read)
+    pub clicked: Option<u64>,
+    /// Notification dismissed this frame (it is removed)
+    pub dismissed: Option<u64>,
+    /// Whether the popover is open
+    pub open: bool,
+}
+
+/// Bell button with an unread badge and a popover listing notifications
+///
+/// Keep the center across frames (e.g. in your app state) and push
+/// notifications into it as they arrive.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::Ui;
+/// # fn example(ui: &mut Ui, center: &mut armas_basic::NotificationCenter) {
+/// use armas_basic::ext::ArmasContextExt;
+/// use armas_basic::Notification;
+///
+/// let theme = ui.ctx().armas_theme();
+/// if ui.button("Render finished").clicked() {
+///     center.push(Notification::new("Render finished").description("mixdown.wav"));
+/// }
+///
+/// let response = center.show(ui, &theme);
+/// if let Some(id) = response.clicked {
+///     // open whatever notification `id` refers to
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct NotificationCenter {
+    id: Id,
+    items: Vec<Notification>,
+    next_id: u64,
+}
+
+impl NotificationCenter {
+    /// Create an empty notification center
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            items: Vec::new(),
+            next_id: 1,
+        }
+    }
+
+    /// Add a notification, returning its id
+    pub fn push(&mut self, mut notification: Notification) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        notification.id = id;
+        self.items.push(notification);
+        id
+    }
+
+    /// All notifications, in insertion order
+    #[must_use]
+    pub fn items(&self) -> &[Notification] {
+        &self.items
+    }
+
+    /// Number of unread notifications
+    #[must_use]
+    pub fn unread_count(&self) -> usize {
+        self.items.iter().filter(|n| !n.read).count()
+    }
+
+    /// Mark a notification as read
+    pub fn mark_read(&mut self, id: u64) {
+        if let Some(notification) = self.items.iter_mut().find(|n| n.id == id) {
+            notification.read = true;
+        }
+    }
+
+    /// Mark every notification as read
+    pub fn mark_all_read(&mut self) {
+        for notification in &mut self.items {
+            notification.read = true;
+        }
+    }
+
+    /// Remove a notification
+    pub fn dismiss(&mut self, id: u64) {
+        self.items.retain(|n| n.id != id);
+    }
+
+    /// Remove all notifications
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Show the bell and, when open, the notification list
+    pub fn show(&mut self, ui: &mut Ui, theme: &Theme) -> NotificationCenterResponse {
+        let ctx = ui.ctx().clone();
+        let open_id = self.id.with("open");
+        let mut open = ctx.data(|d| d.get_temp::<bool>(open_id)).unwrap_or(false);
+
+        let now = ui.input(|i| i.time);
+        for notification in &mut self.items {
+            notification.timestamp.get_or_insert(now);
+        }
+
+        let bell = IconButton::from_owned(icon::bell())
+            .variant(ButtonVariant::Ghost)
+            .size(BELL_SIZE)
+            .padding(BELL_PADDING)
+            .aria_label("Notifications")
+            .show(ui, theme);
+        if bell.clicked() {
+            open = !open;
+        }
+
+        // Unread count over the top-right corner of the bell
+        let unread = self.unread_count();
+        if unread > 0 {
+            let badge_rect = Rect::from_center_size(
+                bell.rect.right_top() + vec2(-BADGE_SIZE / 4.0, BADGE_SIZE / 4.0),
+                vec2(BADGE_SIZE, BADGE_SIZE),
+            );
+            let mut badge_ui = ui.new_child(UiBuilder::new().max_rect(badge_rect));
+            NotificationBadge::new(unread)
+                .size(BADGE_SIZE)
+                .show(&mut badge_ui, theme);
+        }
+
+        let mut response = NotificationCenterResponse {
+            response: bell.clone(),
+            clicked: None,
+            dismissed: None,
+            open,
+        };
+
+        let mut popover = Popover::new(self.id.with("popover"))
+            .position(PopoverPosition::Bottom)
+            .width(POPOVER_WIDTH)
+            .open(open);
+        let popover_response = popover.show(&ctx, theme, bell.rect, |ui| {
+            self.show_list(ui, theme, now, &mut response);
+        });
+        if popover_response.should_close || ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+            open = false;
+        }
+
+        if let Some(id) = response.clicked {
+            self.mark_read(id);
+        }
+        if let Some(id) = response.dismissed {
+            self.dismiss(id);
+        }
+
+        ctx.data_mut(|d| d.insert_temp(open_id, open));
+        response.open = open;
+        response
+    }
+
+    fn show_list(
+        &mut self,
+        ui: &mut Ui,
+        theme: &Theme,
+        now: f64,
+        response: &mut NotificationCenterResponse,
+    ) {
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new("Notifications")
+                    .size(14.0)
+                    .strong()
+                    .color(theme.foreground()),
+            );
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                if self.unread_count() > 0
+                    && Button::new("Mark all as read")
+                        .variant(ButtonVariant::Ghost)
+                        .size(ButtonSize::Small)
+                        .show(ui, theme)
+                        .clicked()
+                {
+                    self.mark_all_read();
+                }
+            });
+        });
+        ui.separator();
+
+        if self.items.is_empty() {
+            ui.add_space(12.0);
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    egui::RichText::new("No notifications")
+                        .size(13.0)
+                        .color(theme.muted_foreground()),
+                );
+            });
+            ui.add_space(12.0);
+            return;
+        }
+
+        // Newest first
+        let mut order: Vec<usize> = (0..self.items.len()).collect();
+        order.sort_by(|&a, &b| {
+            let time = |i: usize| self.items[i].timestamp.unwrap_or(now);
+            time(b).total_cmp(&time(a))
+        });
+
+        egui::ScrollArea::vertical()
+            .id_salt(self.id.with("list"))
+            .max_height(LIST_MAX_HEIGHT)
+            .show(ui, |ui| {
+                let mut current_group = None;
+                for index in order {
+                    let notification = &self.items[index];
+                    let group = time_group(now - notification.timestamp.unwrap_or(now));
+                    if current_group != Some(group) {
+                        current_group = Some(group);
+                        ui.add_space(4.0);
+                        ui.label(
+                            egui::RichText::new(group)
+                                .size(12.0)
+                                .color(theme.muted_foreground()),
+                        );
+                    }
+                    self.show_item(ui, theme, notification, response);
+                }
+            });
+    }
+
+    fn show_item(
+        &self,
+        ui: &mut Ui,
+        theme: &Theme,
+        notification: &Notification,
+        response: &mut NotificationCenterResponse,
+    ) {
+        let height = if notification.description.is_some() {
+            ROW_HEIGHT_WITH_DESCRIPTION
+        } else {
+            ROW_HEIGHT
+        };
+
+        // The row is allocated before its contents so the dismiss button
+        // sits on top of it and receives its own clicks
+        let (rect, row) =
+            ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::click());
+        row.widget_info(|| {
+            egui::WidgetInfo::labeled(egui::WidgetType::Button, true, &notification.title)
+        });
+        if row.clicked() {
+            response.clicked = Some(notification.id);
+        }
+        if row.hovered() {
+            ui.painter()
+                .rect_filled(rect, CORNER_RADIUS, theme.accent());
+        }
+
+        if !notification.read {
+            ui.painter().circle_filled(
+                pos2(rect.left() + ROW_PADDING_X, rect.center().y),
+                UNREAD_DOT_RADIUS,
+                theme.primary(),
+            );
+        }
+
+        // Text is painted rather than added as labels so the row is the
+        // only widget carrying the title
+        let text_left = rect.left() + ROW_PADDING_X * 2.0 + UNREAD_DOT_RADIUS * 2.0;
+        let text_width = rect.right() - DISMISS_AREA_WIDTH - text_left;
+        let title_color = if notification.read {
+            theme.muted_foreground()
+        } else {
+            theme.foreground()
+        };
+        let title = truncated_galley(ui, &notification.title, 14.0, title_color, text_width);
+        let description = notification.description.as_ref().map(|description| {
+            truncated_galley(ui, description, 12.0, theme.muted_foreground(), text_width)
+        });
+        let text_height = title.size().y
+            + description
+                .as_ref()
+                .map_or(0.0, |galley| galley.size().y + DESCRIPTION_GAP);
+        let mut y = rect.center().y - text_height / 2.0;
+        let title_height = title.size().y;
+        ui.painter().galley(pos2(text_left, y), title, title_color);
+        if let Some(description) = description {
+            y += title_height + DESCRIPTION_GAP;
+            ui.painter()
+                .galley(pos2(text_left, y), description, theme.muted_foreground());
+        }
+
+        let dismiss_rect = Rect::from_min_max(
+            pos2(rect.right() - DISMISS_AREA_WIDTH, rect.top()),
+            rect.right_bottom(),
+        );
+        let mut dismiss_ui = ui.new_child(
+            UiBuilder::new()
+                .id_salt(("notification_dismiss", notification.id))
+                .max_rect(dismiss_rect)
+                .layout(Layout::right_to_left(Align::Center)),
+        );
+        if IconButton::from_owned(icon::close())
+            .variant(ButtonVariant::Ghost)
+            .size(DISMISS_ICON_SIZE)
+            .padding(6.0)
+            .aria_label(format!("Dismiss {}", notification.title))
+            .show(&mut dismiss_ui, theme)
+            .clicked()
+        {
+            response.dismissed = Some(notification.id);
+        }
+    }
+}
+
+/// Single-line galley cut off with an ellipsis at `width`
+fn truncated_galley(ui: &Ui, text: &str, size: f32, color: Color32, width: f32) -> Arc<Galley> {
+    let mut job = LayoutJob::simple_singleline(text.to_string(), FontId::proportional(size), color);
+    job.wrap = TextWrapping::truncate_at_width(width.max(0.0));
+    ui.painter().layout_job(job)
+}
+
+/// Heading for notifications that arrived `age` seconds ago
+fn time_group(age: f64) -> &'static str {
+    if age < 60.0 {
+        "Just now"
+    } else if age < 3600.0 {
+        "Last hour"
+    } else if age < 86_400.0 {
+        "Today"
+    } else {
+        "Older"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mark_read_updates_unread_count() {
+        let mut center = NotificationCenter::new("inbox");
+        let first = center.push(Notification::new("Export finished"));
+        let second = center.push(Notification::new("Plugin updated"));
+        assert_ne!(first, second);
+        assert_eq!(center.unread_count(), 2);
+
+        center.mark_read(first);
+        assert_eq!(center.unread_count(), 1);
+
+        center.dismiss(second);
+        assert_eq!(center.unread_count(), 0);
+        assert_eq!(center.items().len(), 1);
+    }
+
+    #[test]
+    fn test_time_groups() {
+        assert_eq!(time_group(5.0), "Just now");
+        assert_eq!(time_group(600.0), "Last hour");
+        assert_eq!(time_group(7200.0), "Today");
+        assert_eq!(time_group(200_000.0), "Older");
+    }
+}
@@ -188,18 +188,50 @@ impl Popover {
         anchor_rect: Rect,
         content: impl FnOnce(&mut Ui),
     ) -> PopoverResponse {
-        let mut response = PopoverResponse::default();
-
         // Check if should be open
         let is_open = self.external_is_open.unwrap_or(false);
         if !is_open {
-            return response;
+            return PopoverResponse::default();
         }
 
         // Calculate position
         let position = self.determine_position(ctx, anchor_rect);
         let popover_pos = self.calculate_popover_position(anchor_rect, position);
 
+        self.show_open(ctx, theme, popover_pos, anchor_rect, content)
+    }
+
+    /// Show the popover with its top-left corner at `pos`
+    ///
+    /// Used for context menus, where the caller has already picked an
+    /// on-screen position. Clicks exactly at `pos` don't count as outside.
+    pub fn show_at(
+        &mut self,
+        ctx: &egui::Context,
+        theme: &Theme,
+        pos: Pos2,
+        content: impl FnOnce(&mut Ui),
+    ) -> PopoverResponse {
+        if !self.external_is_open.unwrap_or(false) {
+            return PopoverResponse::default();
+        }
+        self.show_open(
+            ctx,
+            theme,
+            pos,
+            Rect::from_min_size(pos, Vec2::ZERO),
+            content,
+        )
+    }
+
+    fn show_open(
+        &self,
+        ctx: &egui::Context,
+        theme: &Theme,
+        popover_pos: Pos2,
+        anchor_rect: Rect,
+        content: impl FnOnce(&mut Ui),
+    ) -> PopoverResponse {
         // Get styling
         let (bg_color, border_color) = self.get_colors(theme);
         let (stroke_width, rounding, padding) = self.get_style_params(theme);
@@ -217,9 +249,7 @@ impl Popover {
         let area_response = self.render_popover(ctx, theme, popover_pos, &style, content);
 
         // Handle click outside
-        response = self.check_click_outside(ctx, &area_response.response.rect, anchor_rect);
-
-        response
+        self.check_click_outside(ctx, &area_response.response.rect, anchor_rect)
     }
 
     // ========================================================================
@@ -1,10 +1,43 @@
-//! Context extension for Armas theme storage
+//! Context extension for Armas theme storage and drag-and-drop
 //!
 //! This extension trait allows storing the Armas theme in egui's context,
-//! eliminating the need to pass theme to every component.
+//! eliminating the need to pass theme to every component. It also carries
+//! typed drag-and-drop payloads between components.
 
 use crate::Theme;
-use egui::Context;
+use egui::{Context, Response, Stroke, StrokeKind};
+use std::any::Any;
+use std::sync::Arc;
+
+const DROP_HIGHLIGHT_RADIUS: f32 = 4.0;
+
+/// A payload being dragged between components
+///
+/// Set with [`begin_drag`]. Components that accept any payload (like
+/// `TimelineTrack`) hand it back untyped; use [`DragPayload::downcast`] to
+/// get the value.
+#[derive(Clone)]
+pub struct DragPayload(Arc<dyn Any + Send + Sync>);
+
+impl DragPayload {
+    /// Get the payload if it is a `T`
+    #[must_use]
+    pub fn downcast<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        self.0.clone().downcast().ok()
+    }
+
+    /// Whether the payload is a `T`
+    #[must_use]
+    pub fn is<T: Any + Send + Sync>(&self) -> bool {
+        self.0.is::<T>()
+    }
+}
+
+impl std::fmt::Debug for DragPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DragPayload").finish_non_exhaustive()
+    }
+}
 
 /// Extension trait for storing Armas theme in egui Context
 ///
@@ -25,6 +58,10 @@ use egui::Context;
 ///     // ...
 /// }
 /// ```
+///
+/// Simple display components (`Badge`, `Spinner`, `Kbd`, `Separator`,
+/// `Avatar`) implement [`egui::Widget`] and read the theme from here, so
+/// they work with `ui.add(...)`.
 pub trait ArmasContextExt {
     /// Get the current Armas theme from context
     ///
@@ -65,3 +102,96 @@ impl ArmasContextExt for Context {
         self.data_mut(|d| d.insert_temp(egui::Id::new("armas_theme"), theme));
     }
 }
+
+/// Start dragging a payload
+///
+/// The payload stays available until the pointer is released or Escape
+/// is pressed. Call this when a drag source starts being dragged.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use armas_basic::ext::{begin_drag, drop_target};
+/// # fn example(ui: &mut egui::Ui) {
+/// let response = ui.label("kick.wav").interact(egui::Sense::drag());
+/// if response.drag_started() {
+///     begin_drag(ui.ctx(), String::from("kick.wav"));
+/// }
+///
+/// let target = ui.group(|ui| ui.label("Drop here")).response;
+/// if let Some(file) = drop_target::<String>(ui.ctx(), &target) {
+///     println!("dropped {file}");
+/// }
+/// # }
+/// ```
+pub fn begin_drag<T: Any + Send + Sync>(ctx: &Context, payload: T) {
+    egui::DragAndDrop::set_payload(ctx, DragPayload(Arc::new(payload)));
+}
+
+/// The payload being dragged, if there is one and it is a `T`
+#[must_use]
+pub fn drag_payload<T: Any + Send + Sync>(ctx: &Context) -> Option<Arc<T>> {
+    egui::DragAndDrop::payload::<DragPayload>(ctx)?.downcast()
+}
+
+/// Make a response accept dropped payloads of type `T`
+///
+/// Highlights the response while a `T` is dragged over it, and returns
+/// the payload when it is released there.
+#[must_use]
+pub fn drop_target<T: Any + Send + Sync>(ctx: &Context, response: &Response) -> Option<Arc<T>> {
+    drop_zone(ctx, response, DragPayload::is::<T>)?.downcast()
+}
+
+/// Make a response accept dropped payloads of any type
+///
+/// Like [`drop_target`], for components that leave the payload type to
+/// the caller.
+#[must_use]
+pub fn drop_target_any(ctx: &Context, response: &Response) -> Option<DragPayload> {
+    drop_zone(ctx, response, |_| true)
+}
+
+/// Whether focused components draw a focus ring (default: true)
+#[must_use]
+pub fn focus_rings_enabled(ctx: &Context) -> bool {
+    ctx.data(|d| d.get_temp(egui::Id::new("armas_focus_rings")))
+        .unwrap_or(true)
+}
+
+/// Turn the keyboard focus ring drawn by interactive components on or off
+///
+/// See [`crate::ext::draw_focus_ring`].
+pub fn set_focus_rings(ctx: &Context, enabled: bool) {
+    ctx.data_mut(|d| d.insert_temp(egui::Id::new("armas_focus_rings"), enabled));
+}
+
+/// Highlight `response` while an accepted payload hovers it, and take the
+/// payload when it is released there
+fn drop_zone(
+    ctx: &Context,
+    response: &Response,
+    accepts: impl Fn(&DragPayload) -> bool,
+) -> Option<DragPayload> {
+    let payload = egui::DragAndDrop::payload::<DragPayload>(ctx)?;
+    // `hovered` is always false while another widget is being dragged
+    if !accepts(&payload) || !response.contains_pointer() {
+        return None;
+    }
+
+    if ctx.input(|i| i.pointer.any_released()) {
+        egui::DragAndDrop::clear_payload(ctx);
+        return Some((*payload).clone());
+    }
+
+    let theme = ctx.armas_theme();
+    let painter = ctx.layer_painter(response.layer_id);
+    painter.rect(
+        response.rect,
+        DROP_HIGHLIGHT_RADIUS,
+        theme.primary().gamma_multiply(0.1),
+        Stroke::new(1.5, theme.ring()),
+        StrokeKind::Inside,
+    );
+    None
+}
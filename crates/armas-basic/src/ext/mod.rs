@@ -6,5 +6,8 @@
 pub mod context;
 pub mod painter;
 
-pub use context::ArmasContextExt;
-pub use painter::{neon_circle, neon_line, PainterExt};
+pub use context::{
+    begin_drag, drag_payload, drop_target, drop_target_any, focus_rings_enabled, set_focus_rings,
+    ArmasContextExt, DragPayload,
+};
+pub use painter::{draw_focus_ring, neon_circle, neon_line, PainterExt};
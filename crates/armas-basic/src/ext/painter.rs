@@ -3,7 +3,18 @@
 //! Provides additional rendering capabilities beyond egui's built-in painter,
 //! including blur approximation, glow effects, and shadows.
 
-use egui::{Color32, CornerRadius, Painter, Pos2, Rect, Shape, Stroke, Vec2};
+use super::context::focus_rings_enabled;
+use crate::Theme;
+use egui::{Color32, CornerRadius, Painter, Pos2, Rect, Shape, Stroke, StrokeKind, Vec2};
+
+const FOCUS_RING_OFFSET: f32 = 2.0;
+const FOCUS_RING_WIDTH: f32 = 2.0;
+const FOCUS_RING_RADIUS: u8 = 8;
+const NEON_CORE_WIDTH: f32 = 2.0;
+const NEON_GLOW_LAYERS: usize = 5;
+/// Extra stroke width of the outermost glow pass at full intensity
+const NEON_GLOW_SPREAD: f32 = 8.0;
+const NEON_GLOW_MAX_ALPHA: u8 = 80;
 
 /// Extension trait for egui's Painter with advanced effects
 pub trait PainterExt {
@@ -54,6 +65,17 @@ pub trait PainterExt {
     ///
     /// Useful for creating spotlight or highlight effects
     fn radial_glow(&self, center: Pos2, radius: f32, color: Color32, falloff: f32);
+
+    /// Draw a glowing rectangle outline
+    ///
+    /// Matches [`neon_line`]: widening, fading passes fake the bloom under a
+    /// solid core. `glow` scales the bloom (0.0 draws only the core).
+    fn neon_rect(&self, rect: Rect, radius: f32, color: Color32, glow: f32);
+
+    /// Draw a glowing polyline through `points`
+    ///
+    /// Corners are joined, unlike [`neon_line`]'s separate segments.
+    fn neon_path(&self, points: &[Pos2], color: Color32, glow: f32);
 }
 
 impl PainterExt for Painter {
@@ -244,6 +266,45 @@ impl PainterExt for Painter {
             self.circle_filled(center, layer_radius, glow_color);
         }
     }
+
+    fn neon_rect(&self, rect: Rect, radius: f32, color: Color32, glow: f32) {
+        for stroke in neon_glow_strokes(color, glow) {
+            self.rect_stroke(rect, radius, stroke, StrokeKind::Middle);
+        }
+        self.rect_stroke(
+            rect,
+            radius,
+            Stroke::new(NEON_CORE_WIDTH, color),
+            StrokeKind::Middle,
+        );
+    }
+
+    fn neon_path(&self, points: &[Pos2], color: Color32, glow: f32) {
+        if points.len() < 2 {
+            return;
+        }
+        for stroke in neon_glow_strokes(color, glow) {
+            self.add(Shape::line(points.to_vec(), stroke));
+        }
+        self.add(Shape::line(
+            points.to_vec(),
+            Stroke::new(NEON_CORE_WIDTH, color),
+        ));
+    }
+}
+
+/// Glow passes under a neon core, widest and faintest last
+fn neon_glow_strokes(color: Color32, glow: f32) -> impl Iterator<Item = Stroke> {
+    (0..NEON_GLOW_LAYERS).filter_map(move |i| {
+        let t = i as f32 / NEON_GLOW_LAYERS as f32;
+        let alpha = (((1.0 - t) * glow * 255.0) as u8).min(NEON_GLOW_MAX_ALPHA);
+        (alpha > 0).then(|| {
+            Stroke::new(
+                (glow * NEON_GLOW_SPREAD).mul_add(t, NEON_CORE_WIDTH),
+                Color32::from_rgba_unmultiplied(color.r(), color.g(), color.b(), alpha),
+            )
+        })
+    })
 }
 
 /// Helper function to interpolate between colors
@@ -318,6 +379,23 @@ pub fn neon_circle(
     painter.circle_filled(center, radius, color);
 }
 
+/// Draw the keyboard focus ring around a component
+///
+/// An outline in the theme's primary color, offset from `rect`. Interactive
+/// components call this while they have focus; it draws nothing when focus
+/// rings are turned off with [`crate::ext::set_focus_rings`].
+pub fn draw_focus_ring(painter: &Painter, rect: Rect, theme: &Theme) {
+    if !focus_rings_enabled(painter.ctx()) {
+        return;
+    }
+    painter.rect_stroke(
+        rect.expand(FOCUS_RING_OFFSET),
+        CornerRadius::same(FOCUS_RING_RADIUS),
+        Stroke::new(FOCUS_RING_WIDTH, theme.primary()),
+        StrokeKind::Outside,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -332,4 +410,26 @@ mod tests {
         assert_eq!(gray.g(), 127);
         assert_eq!(gray.b(), 127);
     }
+
+    #[test]
+    fn test_neon_shapes_draw_glow_under_core() {
+        let ctx = egui::Context::default();
+        let output = ctx.run(egui::RawInput::default(), |ctx| {
+            let painter = ctx.layer_painter(egui::LayerId::background());
+            let rect = Rect::from_min_size(Pos2::ZERO, Vec2::splat(50.0));
+            painter.neon_rect(rect, 4.0, Color32::RED, 1.0);
+            painter.neon_path(
+                &[Pos2::ZERO, Pos2::new(20.0, 0.0), Pos2::new(20.0, 20.0)],
+                Color32::RED,
+                0.0,
+            );
+        });
+
+        // Glow passes then the core for the rect, only the core without glow
+        assert_eq!(output.shapes.len(), NEON_GLOW_LAYERS + 1 + 1);
+        let Shape::Rect(core) = &output.shapes[NEON_GLOW_LAYERS].shape else {
+            panic!("expected the rect core");
+        };
+        assert_eq!(core.stroke, Stroke::new(NEON_CORE_WIDTH, Color32::RED));
+    }
 }
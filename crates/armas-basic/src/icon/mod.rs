@@ -4,7 +4,10 @@
 //! window/UI icons parsed from embedded SVGs at runtime.
 
 // Re-export the generic icon infrastructure
-pub use armas_icon::{render_icon, render_icon_data, Icon, IconData, OwnedIconData};
+pub use armas_icon::{
+    render_icon, render_icon_data, render_icon_data_fit, render_icon_strokes,
+    render_icon_strokes_fit, Icon, IconData, IconFit, IconStroke, IconSubpath, OwnedIconData,
+};
 
 use armas_icon::OwnedIconData as OID;
 use std::sync::OnceLock;
@@ -19,6 +22,9 @@ static ERROR: OnceLock<OID> = OnceLock::new();
 static CHEVRON_LEFT: OnceLock<OID> = OnceLock::new();
 static CHEVRON_RIGHT: OnceLock<OID> = OnceLock::new();
 static CHEVRON_DOWN: OnceLock<OID> = OnceLock::new();
+static CHEVRON_UP: OnceLock<OID> = OnceLock::new();
+static STAR: OnceLock<OID> = OnceLock::new();
+static BELL: OnceLock<OID> = OnceLock::new();
 
 /// Close icon (X)
 pub fn close() -> &'static OwnedIconData {
@@ -64,3 +70,23 @@ pub fn chevron_down() -> &'static OwnedIconData {
         )
     })
 }
+
+/// Chevron up icon (^)
+pub fn chevron_up() -> &'static OwnedIconData {
+    CHEVRON_UP.get_or_init(|| {
+        parse(
+            include_str!("../../icons/window/chevron_up.svg"),
+            "chevron_up",
+        )
+    })
+}
+
+/// Star icon (filled)
+pub fn star() -> &'static OwnedIconData {
+    STAR.get_or_init(|| parse(include_str!("../../icons/window/star.svg"), "star"))
+}
+
+/// Bell icon (notifications)
+pub fn bell() -> &'static OwnedIconData {
+    BELL.get_or_init(|| parse(include_str!("../../icons/window/bell.svg"), "bell"))
+}
@@ -0,0 +1,486 @@
+//! Dock Area Layout
+//!
+//! Panels docked into fixed regions around a central area, like the tool
+//! windows of an IDE. Each region shows its panels as tabs; drag a tab onto
+//! another region to re-dock it there.
+
+use crate::ext::ArmasContextExt;
+use crate::Theme;
+use egui::{
+    pos2, vec2, Align2, CursorIcon, FontId, Id, Pos2, Rect, Response, Sense, Stroke, Ui, UiBuilder,
+    WidgetInfo, WidgetType,
+};
+use serde::{Deserialize, Serialize};
+
+// shadcn-style constants
+const SIDE_WIDTH: f32 = 220.0;
+const EDGE_HEIGHT: f32 = 160.0;
+const TAB_BAR_HEIGHT: f32 = 32.0; // h-8
+const TAB_PADDING_X: f32 = 12.0; // px-3
+const FONT_SIZE: f32 = 13.0;
+const CONTENT_PADDING: f32 = 8.0; // p-2
+const PREVIEW_RADIUS: f32 = 6.0; // rounded-md
+/// Share of the area along each edge that docks into that edge when dropped
+const EDGE_DROP_FRACTION: f32 = 0.2;
+
+/// Region of a dock area a panel can be docked into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DockRegion {
+    /// Full-height column on the left
+    Left,
+    /// Full-height column on the right
+    Right,
+    /// Row above the center
+    Top,
+    /// Row below the center
+    Bottom,
+    /// Remaining space in the middle
+    Center,
+}
+
+impl DockRegion {
+    const ALL: [Self; 5] = [
+        Self::Left,
+        Self::Right,
+        Self::Top,
+        Self::Bottom,
+        Self::Center,
+    ];
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Tabs docked into one region
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DockTabs {
+    panels: Vec<String>,
+    active: usize,
+}
+
+/// Which panels are docked where
+///
+/// Panels are identified by name, which is also their tab title.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DockLayout {
+    regions: [DockTabs; 5],
+}
+
+impl DockLayout {
+    /// Create an empty layout
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Dock a panel into a region
+    #[must_use]
+    pub fn panel(mut self, region: DockRegion, panel: impl Into<String>) -> Self {
+        self.dock(panel, region);
+        self
+    }
+
+    /// Panels docked into a region, in tab order
+    #[must_use]
+    pub fn panels(&self, region: DockRegion) -> &[String] {
+        &self.regions[region.index()].panels
+    }
+
+    /// Visible panel of a region
+    #[must_use]
+    pub fn active(&self, region: DockRegion) -> Option<&str> {
+        let tabs = &self.regions[region.index()];
+        tabs.panels.get(tabs.active).map(String::as_str)
+    }
+
+    /// Region a panel is docked into
+    #[must_use]
+    pub fn region_of(&self, panel: &str) -> Option<DockRegion> {
+        DockRegion::ALL
+            .into_iter()
+            .find(|region| self.panels(*region).iter().any(|p| p == panel))
+    }
+
+    /// Dock a panel as the last tab of a region and make it visible
+    ///
+    /// A panel that is already docked elsewhere is moved. Returns whether the
+    /// layout changed.
+    pub fn dock(&mut self, panel: impl Into<String>, region: DockRegion) -> bool {
+        let panel = panel.into();
+        if self.region_of(&panel) == Some(region) {
+            return self.activate(&panel);
+        }
+        self.remove(&panel);
+        let tabs = &mut self.regions[region.index()];
+        tabs.panels.push(panel);
+        tabs.active = tabs.panels.len() - 1;
+        true
+    }
+
+    /// Make a panel the visible tab of its region; returns whether it changed
+    pub fn activate(&mut self, panel: &str) -> bool {
+        for tabs in &mut self.regions {
+            if let Some(index) = tabs.panels.iter().position(|p| p == panel) {
+                let changed = tabs.active != index;
+                tabs.active = index;
+                return changed;
+            }
+        }
+        false
+    }
+
+    /// Remove a panel, returning the region it was docked into
+    pub fn remove(&mut self, panel: &str) -> Option<DockRegion> {
+        for region in DockRegion::ALL {
+            let tabs = &mut self.regions[region.index()];
+            if let Some(index) = tabs.panels.iter().position(|p| p == panel) {
+                tabs.panels.remove(index);
+                if tabs.active > index || tabs.active >= tabs.panels.len() {
+                    tabs.active = tabs.active.saturating_sub(1);
+                }
+                return Some(region);
+            }
+        }
+        None
+    }
+}
+
+/// Response from a dock area
+#[derive(Debug, Clone)]
+pub struct DockAreaResponse {
+    /// Response covering the whole area
+    pub response: Response,
+    /// Whether a panel was re-docked or a tab selected this frame
+    pub changed: bool,
+}
+
+/// Dockable panel container
+///
+/// Edits the caller's [`DockLayout`] in place, so the app owns the layout and
+/// can change it between frames (e.g. docking a panel from a menu command).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::Ui;
+/// # fn example(ui: &mut Ui) {
+/// use armas_basic::layout::{DockArea, DockLayout, DockRegion};
+///
+/// let mut layout = DockLayout::new()
+///     .panel(DockRegion::Left, "Explorer")
+///     .panel(DockRegion::Center, "main.rs")
+///     .panel(DockRegion::Bottom, "Terminal");
+///
+/// DockArea::new(&mut layout).show(ui, |ui, panel| {
+///     ui.label(panel);
+/// });
+/// # }
+/// ```
+pub struct DockArea<'a> {
+    id: Option<Id>,
+    layout: &'a mut DockLayout,
+    side_width: f32,
+    edge_height: f32,
+}
+
+impl<'a> DockArea<'a> {
+    /// Create a dock area that shows and edits `layout`
+    #[must_use]
+    pub const fn new(layout: &'a mut DockLayout) -> Self {
+        Self {
+            id: None,
+            layout,
+            side_width: SIDE_WIDTH,
+            edge_height: EDGE_HEIGHT,
+        }
+    }
+
+    /// Set custom ID (important when using multiple dock areas)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the width of the left and right regions
+    #[must_use]
+    pub const fn side_width(mut self, width: f32) -> Self {
+        self.side_width = width;
+        self
+    }
+
+    /// Set the height of the top and bottom regions
+    #[must_use]
+    pub const fn edge_height(mut self, height: f32) -> Self {
+        self.edge_height = height;
+        self
+    }
+
+    /// Show the dock area, filling the available space
+    ///
+    /// `content` draws the panel with the given name.
+    pub fn show(self, ui: &mut Ui, mut content: impl FnMut(&mut Ui, &str)) -> DockAreaResponse {
+        let theme = ui.ctx().armas_theme();
+        let area = ui.available_rect_before_wrap();
+        let id = self.id.unwrap_or_else(|| ui.id().with("dock_area"));
+        let drag_id = id.with("dragging");
+
+        let before = self.layout.clone();
+        let mut dragging: Option<String> = ui.ctx().data(|d| d.get_temp(drag_id));
+
+        let rects = self.region_rects(self.layout, area);
+        for region in DockRegion::ALL {
+            if let Some(rect) = rects[region.index()] {
+                Self::region(
+                    ui,
+                    rect,
+                    id,
+                    region,
+                    self.layout,
+                    &mut dragging,
+                    &mut content,
+                    &theme,
+                );
+            }
+        }
+
+        let response = ui.allocate_rect(area, Sense::hover());
+
+        // Re-dock a dragged tab where it is dropped
+        if let Some(panel) = dragging.clone() {
+            ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+            let pointer = ui.input(|i| i.pointer.interact_pos());
+            if let Some(pos) = pointer.filter(|pos| area.contains(*pos)) {
+                let target = drop_target(area, pos);
+                let preview = rects[target.index()].unwrap_or_else(|| self.zone_rect(area, target));
+                ui.painter().rect(
+                    preview.shrink(2.0),
+                    PREVIEW_RADIUS,
+                    theme.primary().gamma_multiply(0.15),
+                    Stroke::new(1.0, theme.primary()),
+                    egui::StrokeKind::Inside,
+                );
+                if ui.input(|i| i.pointer.any_released()) {
+                    self.layout.dock(panel, target);
+                }
+            }
+            if !ui.input(|i| i.pointer.any_down()) {
+                dragging = None;
+            }
+        }
+
+        let changed = *self.layout != before;
+        ui.ctx().data_mut(|d| match &dragging {
+            Some(panel) => d.insert_temp(drag_id, panel.clone()),
+            None => d.remove::<String>(drag_id),
+        });
+
+        DockAreaResponse { response, changed }
+    }
+
+    /// Draw one region: its tab bar and the active panel
+    #[allow(clippy::too_many_arguments)]
+    fn region(
+        ui: &mut Ui,
+        rect: Rect,
+        id: Id,
+        region: DockRegion,
+        layout: &mut DockLayout,
+        dragging: &mut Option<String>,
+        content: &mut impl FnMut(&mut Ui, &str),
+        theme: &Theme,
+    ) {
+        let bar = Rect::from_min_size(rect.min, vec2(rect.width(), TAB_BAR_HEIGHT));
+        let body = Rect::from_min_max(pos2(rect.min.x, bar.max.y), rect.max);
+        let clip = rect.intersect(ui.clip_rect());
+        let painter = ui.painter().with_clip_rect(clip);
+        painter.rect_filled(rect, 0.0, theme.background());
+        painter.rect_filled(bar, 0.0, theme.muted());
+
+        // Tabs
+        let font = FontId::proportional(FONT_SIZE);
+        let active = layout.active(region).map(str::to_owned);
+        let mut x = bar.min.x;
+        for panel in layout.panels(region).to_vec() {
+            let galley = painter.layout_no_wrap(panel.clone(), font.clone(), theme.foreground());
+            let tab = Rect::from_min_size(
+                pos2(x, bar.min.y),
+                vec2(galley.size().x + TAB_PADDING_X * 2.0, TAB_BAR_HEIGHT),
+            );
+            x = tab.max.x;
+
+            let is_active = active.as_deref() == Some(panel.as_str());
+            let response = ui
+                .interact(
+                    tab.intersect(clip),
+                    id.with(("tab", &panel)),
+                    Sense::click_and_drag(),
+                )
+                .on_hover_cursor(CursorIcon::Grab);
+            response.widget_info(|| {
+                WidgetInfo::selected(WidgetType::SelectableLabel, true, is_active, &panel)
+            });
+            if response.clicked() {
+                layout.activate(&panel);
+            }
+            if response.drag_started() {
+                *dragging = Some(panel.clone());
+            }
+
+            if is_active {
+                painter.rect_filled(tab, 0.0, theme.background());
+            }
+            let color = if is_active || response.hovered() {
+                theme.foreground()
+            } else {
+                theme.muted_foreground()
+            };
+            painter.galley(
+                Align2::CENTER_CENTER
+                    .align_size_within_rect(galley.size(), tab)
+                    .min,
+                galley,
+                color,
+            );
+        }
+
+        // Active panel
+        if let Some(panel) = layout.active(region).map(str::to_owned) {
+            let inner = body.shrink(CONTENT_PADDING);
+            let mut panel_ui = ui.new_child(
+                UiBuilder::new()
+                    .max_rect(inner)
+                    .id_salt(("dock_panel", &panel)),
+            );
+            panel_ui.set_clip_rect(body.intersect(ui.clip_rect()));
+            content(&mut panel_ui, &panel);
+        }
+
+        painter.rect_stroke(
+            rect,
+            0.0,
+            Stroke::new(1.0, theme.border()),
+            egui::StrokeKind::Inside,
+        );
+    }
+
+    /// Rects of the non-empty regions (the center is always shown)
+    fn region_rects(&self, layout: &DockLayout, area: Rect) -> [Option<Rect>; 5] {
+        let size = |region: DockRegion, size: f32, max: f32| {
+            if layout.panels(region).is_empty() {
+                0.0
+            } else {
+                size.min(max / 3.0)
+            }
+        };
+        let left = size(DockRegion::Left, self.side_width, area.width());
+        let right = size(DockRegion::Right, self.side_width, area.width());
+        let top = size(DockRegion::Top, self.edge_height, area.height());
+        let bottom = size(DockRegion::Bottom, self.edge_height, area.height());
+        let (x0, x1) = (area.min.x + left, area.max.x - right);
+
+        let shown = |region: DockRegion, rect: Rect| {
+            (region == DockRegion::Center || !layout.panels(region).is_empty()).then_some(rect)
+        };
+        [
+            shown(
+                DockRegion::Left,
+                Rect::from_min_max(area.min, pos2(x0, area.max.y)),
+            ),
+            shown(
+                DockRegion::Right,
+                Rect::from_min_max(pos2(x1, area.min.y), area.max),
+            ),
+            shown(
+                DockRegion::Top,
+                Rect::from_min_max(pos2(x0, area.min.y), pos2(x1, area.min.y + top)),
+            ),
+            shown(
+                DockRegion::Bottom,
+                Rect::from_min_max(pos2(x0, area.max.y - bottom), pos2(x1, area.max.y)),
+            ),
+            shown(
+                DockRegion::Center,
+                Rect::from_min_max(pos2(x0, area.min.y + top), pos2(x1, area.max.y - bottom)),
+            ),
+        ]
+    }
+
+    /// Where an empty region would appear if a panel were docked into it
+    fn zone_rect(&self, area: Rect, region: DockRegion) -> Rect {
+        let width = self.side_width.min(area.width() / 3.0);
+        let height = self.edge_height.min(area.height() / 3.0);
+        match region {
+            DockRegion::Left => Rect::from_min_max(area.min, pos2(area.min.x + width, area.max.y)),
+            DockRegion::Right => Rect::from_min_max(pos2(area.max.x - width, area.min.y), area.max),
+            DockRegion::Top => Rect::from_min_max(area.min, pos2(area.max.x, area.min.y + height)),
+            DockRegion::Bottom => {
+                Rect::from_min_max(pos2(area.min.x, area.max.y - height), area.max)
+            }
+            DockRegion::Center => area,
+        }
+    }
+}
+
+/// Region a panel dropped at `pos` docks into
+///
+/// Positions near an edge of the area dock into that edge, anything else
+/// docks into the center.
+fn drop_target(area: Rect, pos: Pos2) -> DockRegion {
+    let edge_x = area.width() * EDGE_DROP_FRACTION;
+    let edge_y = area.height() * EDGE_DROP_FRACTION;
+    [
+        (DockRegion::Left, pos.x - area.min.x, edge_x),
+        (DockRegion::Right, area.max.x - pos.x, edge_x),
+        (DockRegion::Top, pos.y - area.min.y, edge_y),
+        (DockRegion::Bottom, area.max.y - pos.y, edge_y),
+    ]
+    .into_iter()
+    .filter(|(_, distance, edge)| *distance < *edge)
+    .min_by(|a, b| (a.1 / a.2).total_cmp(&(b.1 / b.2)))
+    .map_or(DockRegion::Center, |(region, ..)| region)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ide_layout() -> DockLayout {
+        DockLayout::new()
+            .panel(DockRegion::Left, "Explorer")
+            .panel(DockRegion::Left, "Outline")
+            .panel(DockRegion::Center, "main.rs")
+    }
+
+    #[test]
+    fn test_dock_moves_panel_between_regions() {
+        let mut layout = ide_layout();
+        assert!(layout.dock("Outline", DockRegion::Right));
+
+        assert_eq!(layout.region_of("Outline"), Some(DockRegion::Right));
+        assert_eq!(layout.panels(DockRegion::Left), ["Explorer"]);
+        assert_eq!(layout.active(DockRegion::Left), Some("Explorer"));
+        assert_eq!(layout.active(DockRegion::Right), Some("Outline"));
+    }
+
+    #[test]
+    fn test_dock_into_same_region_activates() {
+        let mut layout = ide_layout();
+        assert_eq!(layout.active(DockRegion::Left), Some("Outline"));
+        assert!(layout.dock("Explorer", DockRegion::Left));
+        assert_eq!(layout.panels(DockRegion::Left), ["Explorer", "Outline"]);
+        assert_eq!(layout.active(DockRegion::Left), Some("Explorer"));
+        assert!(!layout.dock("Explorer", DockRegion::Left));
+    }
+
+    #[test]
+    fn test_drop_target_edges_and_center() {
+        let area = Rect::from_min_size(Pos2::ZERO, vec2(1000.0, 500.0));
+        assert_eq!(drop_target(area, pos2(50.0, 250.0)), DockRegion::Left);
+        assert_eq!(drop_target(area, pos2(950.0, 250.0)), DockRegion::Right);
+        assert_eq!(drop_target(area, pos2(500.0, 20.0)), DockRegion::Top);
+        assert_eq!(drop_target(area, pos2(500.0, 480.0)), DockRegion::Bottom);
+        assert_eq!(drop_target(area, pos2(500.0, 250.0)), DockRegion::Center);
+    }
+}
@@ -4,6 +4,10 @@
 //! - AspectRatio - Maintain aspect ratio with fit/fill modes
 //! - Table - Responsive table with shadcn/ui styling
 //! - BentoGrid - Variable-sized tile grid layout
+//! - SplitPane - Resizable two-panel layout
+//! - DockArea - Tabbed panels docked around a central area
+//! - VirtualList - Scrolling list that only renders visible rows
+//! - responsive - Branch layout on width breakpoints (Sm/Md/Lg/Xl)
 //!
 //! ## For everything else, use egui's built-ins:
 //! - **Vertical/Horizontal layouts:** `ui.vertical()`, `ui.horizontal()`
@@ -16,8 +20,16 @@
 
 mod aspect_ratio;
 mod bento_grid;
+mod dock_area;
+mod responsive;
+mod split_pane;
 mod table;
+mod virtual_list;
 
 pub use aspect_ratio::{AspectRatio, ContentMode};
 pub use bento_grid::{BentoGrid, GridSpan};
+pub use dock_area::{DockArea, DockAreaResponse, DockLayout, DockRegion};
+pub use responsive::{responsive, Breakpoint, Breakpoints};
+pub use split_pane::{SplitPane, SplitPaneResponse};
 pub use table::{cell, cell_ui, header_row, row, table, TableCells, TableRows};
+pub use virtual_list::{VirtualList, VirtualListResponse};
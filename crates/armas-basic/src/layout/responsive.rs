@@ -0,0 +1,104 @@
+//! Responsive Layout
+//!
+//! Classifies the available width into Tailwind-style breakpoints, so a
+//! layout can branch like a web page with media queries (e.g. show a
+//! hamburger menu instead of a sidebar on narrow screens).
+
+/// Width class of the available space, ordered from narrow to wide
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Narrower than the `md` breakpoint (phones)
+    Sm,
+    /// At least `md` wide (tablets)
+    Md,
+    /// At least `lg` wide (laptops)
+    Lg,
+    /// At least `xl` wide (desktops)
+    Xl,
+}
+
+/// Minimum widths of the breakpoints, in points
+///
+/// Defaults match Tailwind: `md` 768, `lg` 1024, `xl` 1280.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Breakpoints {
+    /// Minimum width of [`Breakpoint::Md`]
+    pub md: f32,
+    /// Minimum width of [`Breakpoint::Lg`]
+    pub lg: f32,
+    /// Minimum width of [`Breakpoint::Xl`]
+    pub xl: f32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            md: 768.0,
+            lg: 1024.0,
+            xl: 1280.0,
+        }
+    }
+}
+
+impl Breakpoints {
+    /// Breakpoint that a width falls into
+    #[must_use]
+    pub fn classify(&self, width: f32) -> Breakpoint {
+        if width >= self.xl {
+            Breakpoint::Xl
+        } else if width >= self.lg {
+            Breakpoint::Lg
+        } else if width >= self.md {
+            Breakpoint::Md
+        } else {
+            Breakpoint::Sm
+        }
+    }
+}
+
+/// Call `add_contents` with the breakpoint of the available width
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::Ui;
+/// # fn example(ui: &mut Ui) {
+/// use armas_basic::layout::{responsive, Breakpoint, Breakpoints};
+///
+/// responsive(ui, Breakpoints::default(), |ui, breakpoint| {
+///     if breakpoint >= Breakpoint::Md {
+///         ui.label("Sidebar");
+///     } else {
+///         ui.label("☰");
+///     }
+/// });
+/// # }
+/// ```
+pub fn responsive<R>(
+    ui: &mut egui::Ui,
+    breakpoints: Breakpoints,
+    add_contents: impl FnOnce(&mut egui::Ui, Breakpoint) -> R,
+) -> R {
+    let breakpoint = breakpoints.classify(ui.available_width());
+    add_contents(ui, breakpoint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_breakpoints_classify_widths() {
+        let breakpoints = Breakpoints::default();
+        assert_eq!(breakpoints.classify(600.0), Breakpoint::Sm);
+        assert_eq!(breakpoints.classify(768.0), Breakpoint::Md);
+        assert_eq!(breakpoints.classify(1100.0), Breakpoint::Lg);
+        assert_eq!(breakpoints.classify(1400.0), Breakpoint::Xl);
+    }
+
+    #[test]
+    fn test_breakpoints_are_ordered() {
+        assert!(Breakpoint::Sm < Breakpoint::Md);
+        assert!(Breakpoint::Lg < Breakpoint::Xl);
+    }
+}
@@ -0,0 +1,250 @@
+//! Split Pane Layout
+//!
+//! Two panels separated by a draggable divider, like the resizable panels in
+//! shadcn/ui.
+
+use crate::ext::ArmasContextExt;
+use egui::{pos2, CursorIcon, Id, Rect, Response, Sense, Stroke, Ui, UiBuilder};
+
+// shadcn Resizable constants
+const DIVIDER_THICKNESS: f32 = 1.0; // w-px
+const DIVIDER_HIT_SIZE: f32 = 8.0;
+const HANDLE_LENGTH: f32 = 16.0; // h-4
+const HANDLE_THICKNESS: f32 = 4.0;
+
+/// Direction panels are laid out in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SplitOrientation {
+    /// First panel on the left, second on the right
+    Horizontal,
+    /// First panel on top, second below
+    Vertical,
+}
+
+/// Response from a split pane
+#[derive(Debug, Clone)]
+pub struct SplitPaneResponse {
+    /// Response of the divider
+    pub response: Response,
+    /// Share of the space given to the first panel (0.0-1.0)
+    pub ratio: f32,
+    /// Whether the divider was dragged this frame
+    pub changed: bool,
+}
+
+/// Resizable two-panel layout
+///
+/// The split ratio persists in memory, keyed by the pane's id.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::Ui;
+/// # fn example(ui: &mut Ui) {
+/// use armas_basic::layout::SplitPane;
+///
+/// SplitPane::horizontal()
+///     .ratio(0.3)
+///     .min_sizes(120.0, 200.0)
+///     .show(
+///         ui,
+///         |left| {
+///             left.label("Browser");
+///         },
+///         |right| {
+///             right.label("Editor");
+///         },
+///     );
+/// # }
+/// ```
+pub struct SplitPane {
+    id: Option<Id>,
+    orientation: SplitOrientation,
+    ratio: f32,
+    min_first: f32,
+    min_second: f32,
+}
+
+impl SplitPane {
+    /// Panels side by side (divider is vertical)
+    #[must_use]
+    pub const fn horizontal() -> Self {
+        Self::new(SplitOrientation::Horizontal)
+    }
+
+    /// Panels stacked (divider is horizontal)
+    #[must_use]
+    pub const fn vertical() -> Self {
+        Self::new(SplitOrientation::Vertical)
+    }
+
+    const fn new(orientation: SplitOrientation) -> Self {
+        Self {
+            id: None,
+            orientation,
+            ratio: 0.5,
+            min_first: 0.0,
+            min_second: 0.0,
+        }
+    }
+
+    /// Set custom ID (important when using multiple split panes)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the initial share of the first panel (0.0-1.0)
+    #[must_use]
+    pub const fn ratio(mut self, ratio: f32) -> Self {
+        self.ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the minimum size in points of the first and second panel
+    #[must_use]
+    pub const fn min_sizes(mut self, first: f32, second: f32) -> Self {
+        self.min_first = first;
+        self.min_second = second;
+        self
+    }
+
+    /// Show the split pane, filling the available space
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        first: impl FnOnce(&mut Ui),
+        second: impl FnOnce(&mut Ui),
+    ) -> SplitPaneResponse {
+        let theme = ui.ctx().armas_theme();
+        let rect = ui.available_rect_before_wrap();
+        let id = self.id.unwrap_or_else(|| ui.id().with("split_pane"));
+        let horizontal = self.orientation == SplitOrientation::Horizontal;
+
+        let (start, length) = if horizontal {
+            (rect.min.x, rect.width())
+        } else {
+            (rect.min.y, rect.height())
+        };
+        let available = (length - DIVIDER_THICKNESS).max(0.0);
+        let mut ratio = self.clamp_ratio(
+            ui.ctx().data(|d| d.get_temp(id)).unwrap_or(self.ratio),
+            available,
+        );
+
+        // Divider
+        let divider_at = |ratio: f32| start + available * ratio;
+        let hit_rect = |at: f32| {
+            if horizontal {
+                Rect::from_min_max(
+                    pos2(at - DIVIDER_HIT_SIZE / 2.0, rect.min.y),
+                    pos2(at + DIVIDER_HIT_SIZE / 2.0, rect.max.y),
+                )
+            } else {
+                Rect::from_min_max(
+                    pos2(rect.min.x, at - DIVIDER_HIT_SIZE / 2.0),
+                    pos2(rect.max.x, at + DIVIDER_HIT_SIZE / 2.0),
+                )
+            }
+        };
+        let cursor = if horizontal {
+            CursorIcon::ResizeHorizontal
+        } else {
+            CursorIcon::ResizeVertical
+        };
+        let response = ui
+            .interact(
+                hit_rect(divider_at(ratio)),
+                id.with("divider"),
+                Sense::drag(),
+            )
+            .on_hover_cursor(cursor);
+        let mut changed = false;
+        if response.dragged() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                let along = if horizontal { pos.x } else { pos.y };
+                let dragged_ratio = if available > 0.0 {
+                    (along - start - DIVIDER_THICKNESS / 2.0) / available
+                } else {
+                    ratio
+                };
+                let new_ratio = self.clamp_ratio(dragged_ratio, available);
+                changed = new_ratio != ratio;
+                ratio = new_ratio;
+            }
+        }
+        ui.ctx().data_mut(|d| d.insert_temp(id, ratio));
+
+        // Panels
+        let at = divider_at(ratio);
+        let (first_rect, divider_rect, second_rect) = if horizontal {
+            (
+                Rect::from_min_max(rect.min, pos2(at, rect.max.y)),
+                Rect::from_min_max(
+                    pos2(at, rect.min.y),
+                    pos2(at + DIVIDER_THICKNESS, rect.max.y),
+                ),
+                Rect::from_min_max(pos2(at + DIVIDER_THICKNESS, rect.min.y), rect.max),
+            )
+        } else {
+            (
+                Rect::from_min_max(rect.min, pos2(rect.max.x, at)),
+                Rect::from_min_max(
+                    pos2(rect.min.x, at),
+                    pos2(rect.max.x, at + DIVIDER_THICKNESS),
+                ),
+                Rect::from_min_max(pos2(rect.min.x, at + DIVIDER_THICKNESS), rect.max),
+            )
+        };
+        Self::panel(ui, first_rect, "first", first);
+        Self::panel(ui, second_rect, "second", second);
+
+        // Paint the divider with a grip handle
+        if ui.is_rect_visible(divider_rect) {
+            let active = response.hovered() || response.dragged();
+            let color = if active { theme.ring() } else { theme.border() };
+            let painter = ui.painter();
+            painter.rect_filled(divider_rect, 0.0, color);
+            let handle = Rect::from_center_size(
+                divider_rect.center(),
+                if horizontal {
+                    egui::vec2(HANDLE_THICKNESS, HANDLE_LENGTH)
+                } else {
+                    egui::vec2(HANDLE_LENGTH, HANDLE_THICKNESS)
+                },
+            );
+            painter.rect(
+                handle,
+                HANDLE_THICKNESS / 2.0,
+                theme.border(),
+                Stroke::new(1.0, color),
+                egui::StrokeKind::Outside,
+            );
+        }
+
+        ui.allocate_rect(rect, Sense::hover());
+
+        SplitPaneResponse {
+            response,
+            ratio,
+            changed,
+        }
+    }
+
+    fn panel(ui: &mut Ui, rect: Rect, salt: &str, content: impl FnOnce(&mut Ui)) {
+        let mut panel_ui = ui.new_child(UiBuilder::new().max_rect(rect).id_salt(salt));
+        panel_ui.set_clip_rect(rect.intersect(ui.clip_rect()));
+        content(&mut panel_ui);
+    }
+
+    /// Keep both panels at or above their minimum size
+    fn clamp_ratio(&self, ratio: f32, available: f32) -> f32 {
+        if available <= 0.0 {
+            return ratio.clamp(0.0, 1.0);
+        }
+        let min = (self.min_first / available).min(1.0);
+        let max = (1.0 - self.min_second / available).max(min);
+        ratio.clamp(min, max)
+    }
+}
@@ -0,0 +1,257 @@
+//! Virtual List Layout
+//!
+//! Scrollable list that only lays out the rows inside the viewport, for
+//! datasets too large to render with a plain `ui.vertical()`. Row offsets
+//! are computed from the scroll position, so the cost of a frame depends on
+//! the viewport height rather than the number of items.
+
+use egui::{vec2, Id, Rect, Response, ScrollArea, Ui, UiBuilder};
+use std::ops::Range;
+
+// Defaults
+const DEFAULT_OVERSCAN: usize = 3;
+
+/// Response from a virtual list
+#[derive(Debug, Clone)]
+pub struct VirtualListResponse {
+    /// Response of the viewport
+    pub response: Response,
+    /// Indices of the rows laid out this frame (visible rows plus overscan)
+    pub visible: Range<usize>,
+    /// Current vertical scroll offset in points
+    pub scroll_offset: f32,
+}
+
+/// Where each row starts
+enum RowOffsets {
+    /// Every row has the same height
+    Uniform { row_height: f32, count: usize },
+    /// Prefix sums of the row heights (`count + 1` entries, starting at 0.0)
+    Variable(Vec<f32>),
+}
+
+impl RowOffsets {
+    fn variable(count: usize, height: impl Fn(usize) -> f32) -> Self {
+        let mut offsets = Vec::with_capacity(count + 1);
+        let mut y = 0.0;
+        offsets.push(y);
+        for index in 0..count {
+            y += height(index).max(0.0);
+            offsets.push(y);
+        }
+        Self::Variable(offsets)
+    }
+
+    const fn count(&self) -> usize {
+        match self {
+            Self::Uniform { count, .. } => *count,
+            Self::Variable(offsets) => offsets.len() - 1,
+        }
+    }
+
+    /// Top of the row at `index` (`index == count` gives the total height)
+    fn start(&self, index: usize) -> f32 {
+        match self {
+            Self::Uniform { row_height, .. } => *row_height * index as f32,
+            Self::Variable(offsets) => offsets[index],
+        }
+    }
+
+    fn total_height(&self) -> f32 {
+        self.start(self.count())
+    }
+
+    /// Index of the row containing `y`, clamped to `0..=count`
+    fn index_at(&self, y: f32) -> usize {
+        let count = self.count();
+        match self {
+            Self::Uniform { row_height, .. } => {
+                if *row_height <= 0.0 || y <= 0.0 {
+                    0
+                } else {
+                    ((y / row_height) as usize).min(count)
+                }
+            }
+            // First row whose end lies below `y`
+            Self::Variable(offsets) => offsets[1..].partition_point(|&end| end <= y).min(count),
+        }
+    }
+
+    /// Rows overlapping `top..bottom`, widened by `overscan` on each side
+    fn visible_range(&self, top: f32, bottom: f32, overscan: usize) -> Range<usize> {
+        let count = self.count();
+        let first = self.index_at(top);
+        let mut last = first;
+        while last < count && self.start(last) < bottom {
+            last += 1;
+        }
+        first.saturating_sub(overscan)..(last + overscan).min(count)
+    }
+}
+
+/// Vertically scrolling list that only renders visible rows
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use egui::Ui;
+/// # fn example(ui: &mut Ui) {
+/// use armas_basic::layout::VirtualList;
+///
+/// VirtualList::new(10_000, 24.0)
+///     .height(300.0)
+///     .show(ui, |ui, index| {
+///         ui.label(format!("Row {index}"));
+///     });
+///
+/// // Rows of different heights
+/// VirtualList::new(500, 24.0)
+///     .row_heights(|index| if index % 10 == 0 { 48.0 } else { 24.0 })
+///     .show(ui, |ui, index| {
+///         ui.label(format!("Row {index}"));
+///     });
+/// # }
+/// ```
+pub struct VirtualList<'a> {
+    id: Option<Id>,
+    count: usize,
+    row_height: f32,
+    row_heights: Option<Box<dyn Fn(usize) -> f32 + 'a>>,
+    height: Option<f32>,
+    overscan: usize,
+    scroll_offset: Option<f32>,
+}
+
+impl<'a> VirtualList<'a> {
+    /// Create a list of `count` rows that are `row_height` points tall
+    #[must_use]
+    pub fn new(count: usize, row_height: f32) -> Self {
+        Self {
+            id: None,
+            count,
+            row_height,
+            row_heights: None,
+            height: None,
+            overscan: DEFAULT_OVERSCAN,
+            scroll_offset: None,
+        }
+    }
+
+    /// Set custom ID (important when using multiple lists)
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Give each row its own height, overriding the fixed row height
+    ///
+    /// Called once per row every frame to build the offset index.
+    #[must_use]
+    pub fn row_heights(mut self, height: impl Fn(usize) -> f32 + 'a) -> Self {
+        self.row_heights = Some(Box::new(height));
+        self
+    }
+
+    /// Set the viewport height (default: available height)
+    #[must_use]
+    pub const fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set how many rows to render above and below the viewport
+    #[must_use]
+    pub const fn overscan(mut self, rows: usize) -> Self {
+        self.overscan = rows;
+        self
+    }
+
+    /// Scroll to this offset in points
+    #[must_use]
+    pub const fn scroll_offset(mut self, offset: f32) -> Self {
+        self.scroll_offset = Some(offset);
+        self
+    }
+
+    /// Show the list, calling `add_row` for each rendered row index
+    pub fn show(self, ui: &mut Ui, mut add_row: impl FnMut(&mut Ui, usize)) -> VirtualListResponse {
+        let offsets = match &self.row_heights {
+            Some(height) => RowOffsets::variable(self.count, height),
+            None => RowOffsets::Uniform {
+                row_height: self.row_height.max(0.0),
+                count: self.count,
+            },
+        };
+        let id = self.id.unwrap_or_else(|| ui.id().with("virtual_list"));
+        let height = self.height.unwrap_or_else(|| ui.available_height());
+
+        let mut scroll_area = ScrollArea::vertical()
+            .id_salt(id)
+            .max_height(height)
+            .min_scrolled_height(height)
+            .auto_shrink([false, false]);
+        if let Some(offset) = self.scroll_offset {
+            scroll_area = scroll_area.vertical_scroll_offset(offset);
+        }
+
+        let mut visible = 0..0;
+        let output = scroll_area.show_viewport(ui, |ui, viewport| {
+            let width = ui.available_width();
+            ui.set_height(offsets.total_height());
+            let top = ui.max_rect().min;
+
+            visible = offsets.visible_range(viewport.min.y, viewport.max.y, self.overscan);
+            for index in visible.clone() {
+                let y = offsets.start(index);
+                let row_rect = Rect::from_min_size(
+                    top + vec2(0.0, y),
+                    vec2(width, offsets.start(index + 1) - y),
+                );
+                let mut row_ui = ui.new_child(
+                    UiBuilder::new()
+                        .id_salt(("virtual_list_row", index))
+                        .max_rect(row_rect),
+                );
+                add_row(&mut row_ui, index);
+            }
+        });
+
+        VirtualListResponse {
+            response: ui.interact(output.inner_rect, id.with("viewport"), egui::Sense::hover()),
+            visible,
+            scroll_offset: output.state.offset.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uniform_visible_range() {
+        let offsets = RowOffsets::Uniform {
+            row_height: 20.0,
+            count: 1000,
+        };
+        assert_eq!(offsets.visible_range(0.0, 100.0, 0), 0..5);
+        assert_eq!(offsets.visible_range(110.0, 210.0, 2), 3..13);
+        // Clamped at the end of the list
+        assert_eq!(offsets.visible_range(19_950.0, 20_050.0, 3), 994..1000);
+    }
+
+    #[test]
+    fn test_variable_offsets_use_prefix_sums() {
+        // Every fourth row is twice as tall
+        let offsets = RowOffsets::variable(100, |i| if i % 4 == 0 { 40.0 } else { 20.0 });
+        assert_eq!(offsets.start(4), 100.0);
+        assert_eq!(offsets.total_height(), 25.0 * 100.0);
+
+        assert_eq!(offsets.index_at(0.0), 0);
+        assert_eq!(offsets.index_at(39.0), 0);
+        assert_eq!(offsets.index_at(40.0), 1);
+        assert_eq!(offsets.index_at(110.0), 4);
+        assert_eq!(offsets.visible_range(110.0, 170.0, 0), 4..7);
+    }
+}
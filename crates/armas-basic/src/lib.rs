@@ -42,22 +42,28 @@ pub mod icon;
 pub mod layout;
 /// Theme system
 pub mod theme;
+/// Undo/redo history
+pub mod undo;
 
 // Prelude module for convenient imports
 pub mod prelude;
 
 // Re-exports for convenience
 pub use animation::{
-    Animation, AnimationSequence, AnimationState, EasingFunction, LoopMode, LoopingAnimation,
-    SpringAnimation, StaggeredAnimation,
+    crossfade, Animation, AnimationSequence, AnimationState, Crossfade, CrossfadeResponse,
+    EasingFunction, LoopMode, LoopingAnimation, SpringAnimation, SpringAnimation2D,
+    StaggerDirection, StaggeredAnimation,
 };
 pub use color::{
-    blend, lerp_color, saturate, with_alpha, BlendMode, ColorStop, Gradient, NeonPalette,
+    blend, lerp_color, lerp_color_hsl, saturate, with_alpha, BlendMode, ColorStop, Gradient,
+    GradientKind, NeonPalette,
 };
 pub use components::*;
 pub use ext::{
-    ArmasContextExt, {neon_circle, neon_line, PainterExt},
+    begin_drag, drag_payload, drop_target, drop_target_any, focus_rings_enabled, set_focus_rings,
+    ArmasContextExt, DragPayload, {draw_focus_ring, neon_circle, neon_line, PainterExt},
 };
 pub use fonts::{FontFamilyBuilder, FontWeight};
 pub use layout::*;
 pub use theme::Theme;
+pub use undo::UndoStack;
@@ -19,7 +19,7 @@ pub use crate::ext::{ArmasContextExt, PainterExt};
 pub use crate::Theme;
 
 // Color utilities
-pub use crate::color::{BlendMode, ColorStop, Gradient};
+pub use crate::color::{BlendMode, ColorStop, Gradient, GradientKind};
 
 // Layout helpers
 pub use crate::layout::{cell, cell_ui, header_row, row, table, AspectRatio, ContentMode};
@@ -33,23 +33,33 @@ pub use crate::components::{
 
 // Essential interactive components
 pub use crate::components::{
-    Badge, Button, Input, RangeSlider, Select, Slider, Textarea, ThreeValueSlider, Toggle,
+    Badge, Button, ColorPicker, EasingPreview, GradientEditor, Input, NumberInput, RangeSlider,
+    Rating, SegmentedControl, Select, Slider, SplitButton, TagInput, Textarea, ThreeValueSlider,
+    Toggle,
 };
 
 // Display components
-pub use crate::components::{Alert, Avatar, AvatarShape, Kbd, Separator, Skeleton, Spinner};
+pub use crate::components::{
+    Alert, AnimatedNumber, Avatar, AvatarShape, CodeChip, HeatmapCalendar, Kbd, Separator,
+    Skeleton, Spinner,
+};
 
 // Navigation components
-pub use crate::components::{Breadcrumbs, Menu, Pagination, Tabs};
+pub use crate::components::{
+    Breadcrumbs, Carousel, Menu, MenuItem, Pagination, RadialMenu, Tabs, Wizard,
+};
 
 // Card components
 pub use crate::components::Card;
 
 // Overlay components
-pub use crate::components::{Dialog, DialogResponse, Drawer, Popover, Sheet, Tooltip};
+pub use crate::components::{
+    Coachmark, Dialog, DialogResponse, Drawer, Notification, NotificationCenter, Popover, Sheet,
+    Tooltip,
+};
 
 // Grouping components
-pub use crate::components::Accordion;
+pub use crate::components::{Accordion, Collapsible};
 
 // Animation system
 pub use crate::animation::{
@@ -3,6 +3,7 @@
 //! Serializable theme system for egui applications.
 //! Uses shadcn/ui naming conventions for simplicity and maintainability.
 
+use crate::color::{from_hsl, to_hsl, NeonPalette};
 use egui::Color32;
 use serde::{Deserialize, Serialize};
 
@@ -276,6 +277,80 @@ impl Theme {
         }
     }
 
+    /// Cyberpunk theme derived from [`NeonPalette::cyberpunk`]
+    ///
+    /// Neon cyan and magenta accents on deep violet surfaces. Pairs well with
+    /// `GlowingBorder`, `RetroGrid` and the neon painter helpers.
+    #[must_use]
+    pub fn cyberpunk() -> Self {
+        let neon = NeonPalette::cyberpunk();
+        let rgb = |color: Color32| [color.r(), color.g(), color.b()];
+        let (cyan, magenta, violet, pink, sky) = (neon[0], neon[1], neon[2], neon[3], neon[4]);
+
+        // Surfaces take the violet hue, dark enough for neon text to stay readable
+        let (violet_hue, _, _) = to_hsl(violet);
+        let surface = |saturation, lightness| rgb(from_hsl(violet_hue, saturation, lightness));
+        let background = surface(0.6, 0.05);
+        let card = surface(0.55, 0.08);
+        let raised = surface(0.45, 0.15);
+        let border = surface(0.45, 0.24);
+        let muted_foreground = surface(0.4, 0.75);
+        let foreground = [230, 250, 255]; // cyan-tinted white
+        let (pink_hue, _, _) = to_hsl(pink);
+        let destructive = rgb(from_hsl(pink_hue, 0.8, 0.35));
+
+        Self {
+            colors: ColorPalette {
+                background,
+                foreground,
+
+                card,
+                card_foreground: foreground,
+
+                popover: card,
+                popover_foreground: foreground,
+
+                primary: rgb(cyan),
+                primary_foreground: background,
+
+                secondary: raised,
+                secondary_foreground: foreground,
+
+                muted: raised,
+                muted_foreground,
+
+                accent: raised,
+                accent_foreground: foreground,
+
+                destructive,
+                destructive_foreground: foreground,
+
+                border,
+                input: border,
+                ring: rgb(magenta),
+
+                chart_1: rgb(cyan),
+                chart_2: rgb(magenta),
+                chart_3: rgb(violet),
+                chart_4: rgb(pink),
+                chart_5: rgb(sky),
+
+                hover: raised,
+                focus: rgb(cyan),
+
+                sidebar: card,
+                sidebar_foreground: foreground,
+                sidebar_primary: rgb(magenta),
+                sidebar_primary_foreground: background,
+                sidebar_accent: raised,
+                sidebar_accent_foreground: foreground,
+                sidebar_border: border,
+                sidebar_ring: rgb(magenta),
+            },
+            spacing: Self::dark().spacing,
+        }
+    }
+
     // =========================================================================
     // Color accessor methods (shadcn naming)
     // =========================================================================
@@ -522,3 +597,60 @@ impl Theme {
         Color32::from_rgb(r, g, b)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::contrast_ratio;
+
+    /// WCAG AA contrast for normal text
+    const MIN_TEXT_CONTRAST: f32 = 4.5;
+
+    #[test]
+    fn test_cyberpunk_round_trips_through_json() {
+        let theme = Theme::cyberpunk();
+        let json = serde_json::to_string(&theme).expect("serialize theme");
+        let parsed: Theme = serde_json::from_str(&json).expect("deserialize theme");
+
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        assert_eq!(parsed.colors.primary, theme.colors.primary);
+    }
+
+    #[test]
+    fn test_cyberpunk_text_contrast() {
+        let theme = Theme::cyberpunk();
+        let pairs = [
+            ("foreground", theme.foreground(), theme.background()),
+            ("card", theme.card_foreground(), theme.card()),
+            ("popover", theme.popover_foreground(), theme.popover()),
+            ("primary", theme.primary_foreground(), theme.primary()),
+            ("secondary", theme.secondary_foreground(), theme.secondary()),
+            ("muted", theme.muted_foreground(), theme.muted()),
+            (
+                "muted on background",
+                theme.muted_foreground(),
+                theme.background(),
+            ),
+            ("accent", theme.accent_foreground(), theme.accent()),
+            (
+                "destructive",
+                theme.destructive_foreground(),
+                theme.destructive(),
+            ),
+            ("sidebar", theme.sidebar_foreground(), theme.sidebar()),
+            (
+                "sidebar primary",
+                theme.sidebar_primary_foreground(),
+                theme.sidebar_primary(),
+            ),
+        ];
+
+        for (name, text, background) in pairs {
+            let ratio = contrast_ratio(text, background);
+            assert!(
+                ratio >= MIN_TEXT_CONTRAST,
+                "{name} text contrast is {ratio:.2}"
+            );
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Undo/redo history for editing components
+//!
+//! [`UndoStack`] keeps snapshots of an editor's state. Push the initial state,
+//! then push again at each edit boundary (mouse release, committed text, ...);
+//! `undo` and `redo` walk the history and return the snapshot to restore.
+//!
+//! ```rust
+//! use armas_basic::undo::UndoStack;
+//!
+//! let mut history = UndoStack::new();
+//! history.push(vec![1]);
+//! history.push(vec![1, 2]);
+//!
+//! assert_eq!(history.undo(), Some(vec![1]));
+//! assert_eq!(history.redo(), Some(vec![1, 2]));
+//! ```
+
+use std::collections::VecDeque;
+
+const DEFAULT_MAX_DEPTH: usize = 100;
+
+/// Bounded history of snapshots with undo/redo
+///
+/// Pushes made with [`UndoStack::push_at`] less than the coalesce window
+/// apart replace the latest snapshot instead of adding a step, so a burst of
+/// edits (e.g. every frame of a drag) undoes as one.
+#[derive(Debug, Clone)]
+pub struct UndoStack<T: Clone> {
+    entries: VecDeque<T>,
+    /// Index of the current snapshot in `entries`
+    cursor: usize,
+    max_depth: usize,
+    coalesce_window: f64,
+    last_push_time: Option<f64>,
+}
+
+impl<T: Clone> Default for UndoStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> UndoStack<T> {
+    /// Create an empty history keeping up to 100 snapshots
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            cursor: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+            coalesce_window: 0.0,
+            last_push_time: None,
+        }
+    }
+
+    /// Set how many snapshots are kept; the oldest are dropped first
+    #[must_use]
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth.max(1);
+        self.evict();
+        self
+    }
+
+    /// Merge [`UndoStack::push_at`] calls closer together than `seconds`
+    #[must_use]
+    pub const fn coalesce_window(mut self, seconds: f64) -> Self {
+        self.coalesce_window = seconds;
+        self
+    }
+
+    /// Record a new snapshot, discarding anything that could be redone
+    pub fn push(&mut self, state: T) {
+        self.last_push_time = None;
+        self.append(state);
+    }
+
+    /// Record a snapshot taken at `time` (in seconds, e.g. `ui.input(|i| i.time)`)
+    ///
+    /// Within the coalesce window of the previous `push_at`, the latest
+    /// snapshot is replaced instead of adding an undo step.
+    pub fn push_at(&mut self, state: T, time: f64) {
+        let coalesce = self
+            .last_push_time
+            .is_some_and(|last| time - last < self.coalesce_window)
+            && self.cursor + 1 == self.entries.len();
+        self.last_push_time = Some(time);
+
+        if coalesce {
+            self.entries[self.cursor] = state;
+        } else {
+            self.append(state);
+        }
+    }
+
+    /// Step back, returning the snapshot to restore
+    pub fn undo(&mut self) -> Option<T> {
+        if !self.can_undo() {
+            return None;
+        }
+        self.cursor -= 1;
+        self.last_push_time = None;
+        self.entries.get(self.cursor).cloned()
+    }
+
+    /// Step forward again after an undo, returning the snapshot to restore
+    pub fn redo(&mut self) -> Option<T> {
+        if !self.can_redo() {
+            return None;
+        }
+        self.cursor += 1;
+        self.last_push_time = None;
+        self.entries.get(self.cursor).cloned()
+    }
+
+    /// Whether there is an earlier snapshot to go back to
+    #[must_use]
+    pub const fn can_undo(&self) -> bool {
+        self.cursor > 0
+    }
+
+    /// Whether an undone snapshot can be restored
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        self.cursor + 1 < self.entries.len()
+    }
+
+    /// The current snapshot
+    #[must_use]
+    pub fn current(&self) -> Option<&T> {
+        self.entries.get(self.cursor)
+    }
+
+    /// Forget all snapshots
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.cursor = 0;
+        self.last_push_time = None;
+    }
+
+    fn append(&mut self, state: T) {
+        if !self.entries.is_empty() {
+            self.entries.truncate(self.cursor + 1);
+        }
+        self.entries.push_back(state);
+        self.cursor = self.entries.len() - 1;
+        self.evict();
+    }
+
+    /// Drop the oldest snapshots beyond the maximum depth
+    fn evict(&mut self) {
+        while self.entries.len() > self.max_depth {
+            self.entries.pop_front();
+            self.cursor = self.cursor.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_redo_walks_history() {
+        let mut history = UndoStack::new();
+        history.push("a");
+        history.push("b");
+        history.push("c");
+        assert!(history.can_undo());
+        assert!(!history.can_redo());
+
+        assert_eq!(history.undo(), Some("b"));
+        assert_eq!(history.undo(), Some("a"));
+        assert_eq!(history.undo(), None);
+        assert!(!history.can_undo());
+
+        assert_eq!(history.redo(), Some("b"));
+        assert_eq!(history.redo(), Some("c"));
+        assert_eq!(history.redo(), None);
+    }
+
+    #[test]
+    fn test_push_after_undo_discards_redo() {
+        let mut history = UndoStack::new();
+        history.push(1);
+        history.push(2);
+        history.undo();
+        history.push(3);
+
+        assert!(!history.can_redo());
+        assert_eq!(history.current(), Some(&3));
+        assert_eq!(history.undo(), Some(1));
+    }
+
+    #[test]
+    fn test_max_depth_evicts_oldest() {
+        let mut history = UndoStack::new().max_depth(3);
+        for state in 0..5 {
+            history.push(state);
+        }
+
+        assert_eq!(history.current(), Some(&4));
+        assert_eq!(history.undo(), Some(3));
+        assert_eq!(history.undo(), Some(2));
+        assert_eq!(history.undo(), None);
+    }
+
+    #[test]
+    fn test_rapid_pushes_coalesce() {
+        let mut history = UndoStack::new().coalesce_window(0.5);
+        history.push(0);
+        history.push_at(1, 10.0);
+        history.push_at(2, 10.2);
+        history.push_at(3, 10.4);
+        // Outside the window: a new step
+        history.push_at(4, 11.0);
+
+        assert_eq!(history.undo(), Some(3));
+        assert_eq!(history.undo(), Some(0));
+    }
+}
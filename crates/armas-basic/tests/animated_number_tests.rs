@@ -0,0 +1,35 @@
+//! Tests for `AnimatedNumber` using `egui_kittest`
+
+use armas_basic::components::basic::AnimatedNumber;
+use armas_basic::ArmasContextExt;
+use egui_kittest::Harness;
+
+/// Test that a new value is counted toward over several frames
+#[test]
+fn test_animated_number_counts_to_new_value() {
+    let mut harness = Harness::builder().with_step_dt(1.0 / 60.0).build_ui_state(
+        |ui, (value, displayed): &mut (f64, f64)| {
+            let theme = ui.ctx().armas_theme();
+            *displayed = AnimatedNumber::new(*value)
+                .duration(0.5)
+                .show(ui, &theme)
+                .displayed;
+        },
+        (100.0, 0.0),
+    );
+    harness.run();
+    assert!((harness.state().1 - 100.0).abs() < f64::EPSILON);
+
+    harness.state_mut().0 = 200.0;
+    harness.step();
+    let first = harness.state().1;
+    assert!(first > 100.0 && first < 200.0);
+
+    harness.step();
+    harness.step();
+    assert!(harness.state().1 > first);
+
+    // Enough frames for the count and the last digit roll to finish
+    harness.run_steps(60);
+    assert!((harness.state().1 - 200.0).abs() < f64::EPSILON);
+}
@@ -1,5 +1,6 @@
 //! Tests for Button component using `egui_kittest`
 
+use armas_basic::ext::set_focus_rings;
 use armas_basic::prelude::*;
 use egui_kittest::Harness;
 
@@ -124,3 +125,78 @@ fn test_multiple_buttons() {
 //     harness.fit_contents();
 //     harness.snapshot("button_variants");
 // }
+
+/// Test that Button exposes its aria label to screen readers
+#[test]
+fn test_button_accessibility_info() {
+    use egui::accesskit::Role;
+    use egui_kittest::kittest::Queryable;
+
+    let mut harness = Harness::new_ui(|ui| {
+        let theme = ui.ctx().armas_theme();
+        Button::new("×").aria_label("Close dialog").show(ui, &theme);
+    });
+
+    harness.run();
+
+    harness.get_by_role_and_label(Role::Button, "Close dialog");
+}
+
+/// Test that a shortcut hint shows the key caps in a tooltip on hover
+#[test]
+fn test_button_shortcut_hint_tooltip() {
+    use egui_kittest::kittest::Queryable;
+
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(300.0, 200.0))
+        .build_ui(|ui| {
+            let theme = ui.ctx().armas_theme();
+            ui.add_space(80.0);
+            Button::new("Save").shortcut_hint("Ctrl+S").show(ui, &theme);
+        });
+    harness.run();
+    assert!(harness.query_by_label("Ctrl").is_none());
+
+    let button = harness.get_by_label("Save").rect().center();
+    harness.hover_at(button);
+    harness.run();
+
+    harness.get_by_label("Ctrl");
+    harness.get_by_label("S");
+}
+
+/// Count rectangle outlines stroked in the theme's primary color
+fn primary_outlines(harness: &Harness<'_>) -> usize {
+    let primary = Theme::dark().primary();
+    harness
+        .output()
+        .shapes
+        .iter()
+        .filter(|clipped| {
+            matches!(&clipped.shape, egui::Shape::Rect(rect) if rect.stroke.color == primary && rect.stroke.width > 0.0)
+        })
+        .count()
+}
+
+/// Test that a focused button draws a focus ring, unless rings are turned off
+#[test]
+fn test_button_focus_ring() {
+    use egui_kittest::kittest::Queryable;
+
+    let mut harness = Harness::new_ui(|ui| {
+        let theme = ui.ctx().armas_theme();
+        Button::new("Focus me")
+            .variant(ButtonVariant::Outline)
+            .show(ui, &theme);
+    });
+    harness.run();
+    let unfocused = primary_outlines(&harness);
+
+    harness.get_by_label("Focus me").focus();
+    harness.run();
+    assert_eq!(primary_outlines(&harness), unfocused + 1);
+
+    set_focus_rings(&harness.ctx, false);
+    harness.run();
+    assert_eq!(primary_outlines(&harness), unfocused);
+}
@@ -0,0 +1,39 @@
+//! Tests for `Carousel` component using `egui_kittest`
+
+use armas_basic::components::navigation::Carousel;
+use armas_basic::ArmasContextExt;
+use egui_kittest::Harness;
+use std::time::Duration;
+
+/// Test that autoplay advances after the interval and pauses while hovered
+#[test]
+fn test_carousel_autoplay_advances_and_pauses_on_hover() {
+    let mut harness = Harness::builder().with_step_dt(1.0 / 60.0).build_ui_state(
+        |ui, (index, viewport): &mut (usize, egui::Rect)| {
+            let theme = ui.ctx().armas_theme();
+            let response = Carousel::new(3, |ui, index| {
+                ui.label(format!("Slide {}", index + 1));
+            })
+            .width(360.0)
+            .height(200.0)
+            .autoplay(Duration::from_millis(500))
+            .show(ui, &theme);
+            *index = response.index;
+            *viewport = response.response.rect;
+        },
+        (0, egui::Rect::NOTHING),
+    );
+    harness.step();
+
+    // Just under the interval: still on the first slide
+    harness.run_steps(25);
+    assert_eq!(harness.state().0, 0);
+
+    harness.run_steps(10);
+    assert_eq!(harness.state().0, 1);
+
+    // Hovering freezes the autoplay timer
+    harness.hover_at(harness.state().1.center());
+    harness.run_steps(90);
+    assert_eq!(harness.state().0, 1);
+}
@@ -0,0 +1,33 @@
+//! Tests for `Coachmark` component using `egui_kittest`
+
+use armas_basic::{ArmasContextExt, Coachmark, CoachmarkResponse};
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that Next and Back report step navigation
+#[test]
+fn test_coachmark_navigation() {
+    let mut harness = Harness::new_ui_state(
+        |ui, response: &mut CoachmarkResponse| {
+            let theme = ui.ctx().armas_theme();
+            let target =
+                egui::Rect::from_min_size(egui::pos2(100.0, 80.0), egui::vec2(120.0, 32.0));
+            *response = Coachmark::new("tour", target)
+                .step(1, 3)
+                .title("Transport")
+                .description("Start and stop playback here.")
+                .show(ui.ctx(), &theme, |_| {});
+        },
+        CoachmarkResponse::default(),
+    );
+    harness.run();
+
+    harness.get_by_label("Next").click();
+    harness.step();
+    assert!(harness.state().next);
+
+    harness.run();
+    harness.get_by_label("Back").click();
+    harness.step();
+    assert!(harness.state().prev);
+}
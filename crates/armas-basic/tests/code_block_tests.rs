@@ -0,0 +1,35 @@
+//! Tests for `CodeBlock` component using `egui_kittest`
+
+use armas_basic::components::basic::CodeBlock;
+use armas_basic::ArmasContextExt;
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that clicking "Copy" places the code on the clipboard
+#[test]
+fn test_code_block_copy_places_code_on_clipboard() {
+    let code = "fn main() {\n    println!(\"Hello, world!\");\n}";
+    let mut harness = Harness::new_ui_state(
+        move |ui, copied: &mut bool| {
+            let theme = ui.ctx().armas_theme();
+            *copied |= CodeBlock::new(code, "rust").show(ui, &theme).copied;
+        },
+        false,
+    );
+    harness.run();
+
+    harness.get_by_label("Copy").click();
+    harness.step();
+
+    assert!(*harness.state());
+    let copied_text = harness
+        .output()
+        .platform_output
+        .commands
+        .iter()
+        .find_map(|command| match command {
+            egui::OutputCommand::CopyText(text) => Some(text.clone()),
+            _ => None,
+        });
+    assert_eq!(copied_text.as_deref(), Some(code));
+}
@@ -0,0 +1,36 @@
+//! Tests for `CodeChip` component using `egui_kittest`
+
+use armas_basic::components::basic::CodeChip;
+use armas_basic::ArmasContextExt;
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that clicking the chip places its text on the clipboard
+#[test]
+fn test_code_chip_click_copies_text() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (copied, confirming): &mut (bool, bool)| {
+            let theme = ui.ctx().armas_theme();
+            let response = CodeChip::new("cargo add armas").show(ui, &theme);
+            *copied |= response.copied;
+            *confirming = response.confirming;
+        },
+        (false, false),
+    );
+    harness.run();
+
+    harness.get_by_label("cargo add armas").click();
+    harness.step();
+
+    assert_eq!(*harness.state(), (true, true));
+    let copied_text = harness
+        .output()
+        .platform_output
+        .commands
+        .iter()
+        .find_map(|command| match command {
+            egui::OutputCommand::CopyText(text) => Some(text.clone()),
+            _ => None,
+        });
+    assert_eq!(copied_text.as_deref(), Some("cargo add armas"));
+}
@@ -0,0 +1,56 @@
+//! Tests for `Collapsible` using `egui_kittest`
+
+use armas_basic::Collapsible;
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that clicking the header toggles the body, animating its height
+#[test]
+fn test_collapsible_toggles_and_animates() {
+    // Frames for the spring reveal to settle
+    const SETTLE_STEPS: usize = 90;
+
+    let mut harness = Harness::builder().with_step_dt(1.0 / 60.0).build_ui_state(
+        |ui, (open, body_height): &mut (bool, f32)| {
+            let response = Collapsible::new("details").show(
+                ui,
+                |ui, _| ui.button("Details"),
+                |ui| {
+                    ui.label("Body");
+                    ui.add_space(80.0);
+                },
+            );
+            *open = response.open;
+            *body_height = response.body_height;
+        },
+        (false, 0.0),
+    );
+    harness.run();
+    assert!(!harness.state().0);
+    assert!(harness.state().1.abs() < f32::EPSILON);
+
+    harness.get_by_label("Details").click();
+    harness.step();
+    harness.step();
+    harness.step();
+    assert!(harness.state().0);
+    let partial = harness.state().1;
+
+    harness.run_steps(SETTLE_STEPS);
+    let full = harness.state().1;
+    assert!(
+        partial < full,
+        "height should grow from {partial} to {full}"
+    );
+    assert!(full > 80.0);
+
+    // State persists across frames without further input
+    harness.run_steps(10);
+    assert!(harness.state().0);
+    assert!((harness.state().1 - full).abs() < 0.5);
+
+    harness.get_by_label("Details").click();
+    harness.run_steps(SETTLE_STEPS);
+    assert!(!harness.state().0);
+    assert!(harness.state().1.abs() < f32::EPSILON);
+}
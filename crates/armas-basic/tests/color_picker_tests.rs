@@ -0,0 +1,75 @@
+//! Tests for `ColorPicker` component using `egui_kittest`
+
+use armas_basic::prelude::*;
+use armas_basic::ColorPickerState;
+use egui::Color32;
+use egui_kittest::Harness;
+
+/// Test that `ColorPicker` renders without panicking
+#[test]
+fn test_color_picker_renders() {
+    let mut color = Color32::from_rgb(59, 130, 246);
+
+    let mut harness = Harness::new_ui(|ui| {
+        let theme = ui.ctx().armas_theme();
+        ColorPicker::new("picker").show(ui, &mut color, &theme);
+    });
+
+    harness.run();
+}
+
+/// Test `ColorPicker` with alpha slider
+#[test]
+fn test_color_picker_with_alpha() {
+    let mut color = Color32::from_rgba_unmultiplied(236, 72, 153, 128);
+
+    let mut harness = Harness::new_ui(|ui| {
+        let theme = ui.ctx().armas_theme();
+        ColorPicker::new("picker")
+            .alpha(true)
+            .show(ui, &mut color, &theme);
+    });
+
+    harness.run();
+}
+
+/// Test compact swatch mode
+#[test]
+fn test_color_picker_compact() {
+    let mut color = Color32::from_rgb(34, 197, 94);
+
+    let mut harness = Harness::new_ui(|ui| {
+        let theme = ui.ctx().armas_theme();
+        ColorPicker::new("picker")
+            .compact(true)
+            .show(ui, &mut color, &theme);
+    });
+
+    harness.run();
+}
+
+/// Test that entering a hex updates the HSV fields and the returned color
+#[test]
+fn test_color_picker_hex_round_trip() {
+    let mut state = ColorPickerState::from_color(Color32::WHITE);
+
+    assert!(state.set_hex("#00FF00"));
+    assert!((state.hue - 120.0).abs() < 0.01);
+    assert!((state.saturation - 1.0).abs() < 0.01);
+    assert!((state.value - 1.0).abs() < 0.01);
+    assert_eq!(state.color(), Color32::from_rgb(0, 255, 0));
+
+    // Invalid hex keeps the previous color
+    assert!(!state.set_hex("#GG"));
+    assert_eq!(state.color(), Color32::from_rgb(0, 255, 0));
+}
+
+/// Test that the picker reports the color set from its state
+#[test]
+fn test_color_picker_state_from_color() {
+    let color = Color32::from_rgb(51, 102, 204);
+    let state = ColorPickerState::from_color(color);
+
+    assert_eq!(state.hex, "#3366CC");
+    assert_eq!(state.color(), color);
+}
@@ -0,0 +1,37 @@
+//! Tests for `crossfade` using `egui_kittest`
+
+use armas_basic::crossfade;
+use egui_kittest::Harness;
+
+struct CrossfadeState {
+    key: usize,
+    opacities: Vec<f32>,
+}
+
+/// Test that changing the key fades the new content in over several frames
+#[test]
+fn test_crossfade_key_change_drives_opacity() {
+    let mut harness = Harness::builder().with_step_dt(1.0 / 60.0).build_ui_state(
+        |ui, state: &mut CrossfadeState| {
+            let response = crossfade(ui, state.key, |ui| {
+                ui.label(format!("Panel {}", state.key));
+            });
+            state.opacities.push(response.opacity);
+        },
+        CrossfadeState {
+            key: 0,
+            opacities: Vec::new(),
+        },
+    );
+    harness.run();
+    assert!(harness.state().opacities.iter().all(|&o| o == 1.0));
+
+    harness.state_mut().key = 1;
+    harness.state_mut().opacities.clear();
+    harness.run_steps(30);
+
+    let opacities = &harness.state().opacities;
+    assert!(opacities[0] < 0.2, "first frame at {}", opacities[0]);
+    assert!(opacities.windows(2).all(|w| w[1] >= w[0]));
+    assert_eq!(*opacities.last().unwrap(), 1.0);
+}
@@ -0,0 +1,47 @@
+//! Tests for `DockArea` layout using `egui_kittest`
+
+use armas_basic::layout::{DockArea, DockLayout, DockRegion};
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that dropping a tab onto the right edge docks it into the right region
+#[test]
+fn test_dock_area_drop_tab_on_right_region() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (layout, area): &mut (DockLayout, egui::Rect)| {
+            *area = DockArea::new(layout)
+                .show(ui, |ui, panel| {
+                    ui.label(format!("{panel} content"));
+                })
+                .response
+                .rect;
+        },
+        (
+            DockLayout::new()
+                .panel(DockRegion::Left, "Explorer")
+                .panel(DockRegion::Left, "Outline")
+                .panel(DockRegion::Center, "main.rs")
+                .panel(DockRegion::Bottom, "Terminal"),
+            egui::Rect::NOTHING,
+        ),
+    );
+    harness.run();
+
+    let tab = harness.get_by_label("Outline").rect().center();
+    let area = harness.state().1;
+    let target = egui::pos2(area.max.x - 20.0, area.center().y);
+
+    harness.drag_at(tab);
+    harness.run();
+    harness.hover_at(tab + egui::vec2(40.0, 0.0));
+    harness.run();
+    harness.hover_at(target);
+    harness.run();
+    harness.drop_at(target);
+    harness.run();
+
+    let layout = &harness.state().0;
+    assert_eq!(layout.region_of("Outline"), Some(DockRegion::Right));
+    assert_eq!(layout.panels(DockRegion::Left), ["Explorer"]);
+    harness.get_by_label("Outline content");
+}
@@ -0,0 +1,57 @@
+//! Tests for `EasingPreview` component using `egui_kittest`
+
+use armas_basic::animation::EasingFunction;
+use armas_basic::{ArmasContextExt, EasingPreview};
+use egui_kittest::Harness;
+
+/// Test that dragging a control point updates the bezier parameters
+#[test]
+fn test_easing_preview_drag_control_point() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (easing, rect, bezier): &mut (EasingFunction, egui::Rect, Option<[f32; 4]>)| {
+            let theme = ui.ctx().armas_theme();
+            let response = EasingPreview::new("easing")
+                .selector(false)
+                .animate(false)
+                .show(ui, easing, &theme);
+            *rect = response.response.rect;
+            *bezier = response.bezier;
+        },
+        (
+            EasingFunction::Cubic {
+                x1: 0.25,
+                y1: 0.1,
+                x2: 0.25,
+                y2: 1.0,
+            },
+            egui::Rect::NOTHING,
+            None,
+        ),
+    );
+    harness.run();
+
+    // Matches the plot padding (12pt) and overshoot (0.25) of `EasingPreview`
+    let plot = harness.state().1.shrink(12.0);
+    let plot_point = |x: f32, y: f32| {
+        egui::pos2(
+            plot.left() + x * plot.width(),
+            plot.bottom() - (y + 0.25) / 1.5 * plot.height(),
+        )
+    };
+
+    let start = plot_point(0.25, 0.1);
+    let target = plot_point(0.6, 0.4);
+    harness.drag_at(start);
+    harness.run();
+    harness.hover_at(start + egui::vec2(10.0, 0.0));
+    harness.run();
+    harness.hover_at(target);
+    harness.run();
+    harness.drop_at(target);
+    harness.run();
+
+    let [x1, y1, x2, y2] = harness.state().2.expect("cubic easing");
+    assert!((x1 - 0.6).abs() < 0.01, "x1 = {x1}");
+    assert!((y1 - 0.4).abs() < 0.01, "y1 = {y1}");
+    assert_eq!((x2, y2), (0.25, 1.0));
+}
@@ -0,0 +1,114 @@
+//! Tests for `GradientEditor` component using `egui_kittest`
+
+use armas_basic::color::ColorStop;
+use armas_basic::prelude::*;
+use armas_basic::GradientEditor;
+use egui::{pos2, Color32, Rect};
+use egui_kittest::Harness;
+
+struct EditorState {
+    stops: Vec<ColorStop>,
+    bar_rect: Rect,
+}
+
+fn two_stop_state() -> EditorState {
+    EditorState {
+        stops: vec![
+            ColorStop::new(0.0, Color32::BLACK),
+            ColorStop::new(1.0, Color32::WHITE),
+        ],
+        bar_rect: Rect::NOTHING,
+    }
+}
+
+fn editor_harness<'a>(state: EditorState) -> Harness<'a, EditorState> {
+    // Short steps so two clicks land inside the double-click window
+    Harness::builder().with_step_dt(1.0 / 60.0).build_ui_state(
+        |ui, state: &mut EditorState| {
+            let theme = ui.ctx().armas_theme();
+            let response = GradientEditor::new("gradient").show_picker(false).show(
+                ui,
+                &mut state.stops,
+                &theme,
+            );
+            state.bar_rect = response.response.rect;
+        },
+        state,
+    )
+}
+
+/// Test that `GradientEditor` renders without panicking
+#[test]
+fn test_gradient_editor_renders() {
+    let mut stops = vec![
+        ColorStop::new(0.0, Color32::from_rgb(59, 130, 246)),
+        ColorStop::new(1.0, Color32::from_rgb(236, 72, 153)),
+    ];
+
+    let mut harness = Harness::new_ui(|ui| {
+        let theme = ui.ctx().armas_theme();
+        GradientEditor::new("gradient").show(ui, &mut stops, &theme);
+    });
+
+    harness.run();
+}
+
+/// Test that clicking at 50% adds a stop at 0.5 and dragging it moves it
+#[test]
+fn test_gradient_editor_add_and_drag_stop() {
+    let mut harness = editor_harness(two_stop_state());
+    harness.run();
+
+    let rect = harness.state().bar_rect;
+    let y = rect.min.y + 4.0;
+    let middle = pos2(rect.center().x, y);
+
+    // Click in the middle of the bar
+    harness.hover_at(middle);
+    harness.drag_at(middle);
+    harness.step();
+    harness.drop_at(middle);
+    harness.run();
+
+    let stops = &harness.state().stops;
+    assert_eq!(stops.len(), 3);
+    assert!((stops[1].position - 0.5).abs() < 0.01);
+
+    // Drag the new stop to 75%
+    let target = pos2(rect.min.x + rect.width() * 0.75, y);
+    harness.hover_at(middle);
+    harness.drag_at(middle);
+    harness.step();
+    harness.hover_at(pos2(middle.x + 10.0, y));
+    harness.step();
+    harness.hover_at(target);
+    harness.step();
+    harness.drop_at(target);
+    harness.run();
+
+    let stops = &harness.state().stops;
+    assert_eq!(stops.len(), 3);
+    assert!((stops[1].position - 0.75).abs() < 0.01);
+}
+
+/// Test that double-clicking a handle removes the stop but keeps at least two
+#[test]
+fn test_gradient_editor_double_click_removes_stop() {
+    let mut state = two_stop_state();
+    state.stops.insert(1, ColorStop::new(0.5, Color32::GRAY));
+    let mut harness = editor_harness(state);
+    harness.run();
+
+    let rect = harness.state().bar_rect;
+    let middle = pos2(rect.center().x, rect.min.y + 4.0);
+    for _ in 0..2 {
+        harness.hover_at(middle);
+        harness.drag_at(middle);
+        harness.step();
+        harness.drop_at(middle);
+        harness.step();
+    }
+    harness.run();
+
+    assert_eq!(harness.state().stops.len(), 2);
+}
@@ -0,0 +1,50 @@
+//! Tests for `HeatmapCalendar` component using `egui_kittest`
+
+use armas_basic::components::basic::{Date, HeatmapCalendar};
+use armas_basic::prelude::*;
+use egui_kittest::Harness;
+
+struct CalendarState {
+    rect: egui::Rect,
+    hovered: Option<Date>,
+    clicked: Option<Date>,
+}
+
+/// Test that hovering and clicking a cell reports its date
+#[test]
+fn test_heatmap_calendar_reports_clicked_day() {
+    let values: Vec<f32> = (0..90).map(|i| (i % 5) as f32).collect();
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(400.0, 200.0))
+        .build_ui_state(
+            move |ui, state: &mut CalendarState| {
+                let theme = ui.ctx().armas_theme();
+                let start = Date::new(2024, 1, 1).unwrap();
+                let response = HeatmapCalendar::new(start, &values).show(ui, &theme);
+                state.rect = response.response.rect;
+                state.hovered = response.hovered;
+                state.clicked = state.clicked.or(response.clicked);
+            },
+            CalendarState {
+                rect: egui::Rect::NOTHING,
+                hovered: None,
+                clicked: None,
+            },
+        );
+    harness.run();
+    assert_eq!(harness.state().hovered, None);
+
+    // 2024-01-03 is a Wednesday: first week column, fourth row.
+    // Cells start after the 28pt weekday labels and 16pt month labels, 14pt apart.
+    let origin = harness.state().rect.min;
+    let cell = origin + egui::vec2(28.0 + 5.0, 16.0 + 3.0 * 14.0 + 5.0);
+    harness.hover_at(cell);
+    harness.run();
+    assert_eq!(harness.state().hovered, Date::new(2024, 1, 3));
+
+    harness.drag_at(cell);
+    harness.step();
+    harness.drop_at(cell);
+    harness.run();
+    assert_eq!(harness.state().clicked, Date::new(2024, 1, 3));
+}
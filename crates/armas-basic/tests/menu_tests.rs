@@ -288,3 +288,75 @@ fn test_context_menu_comprehensive() {
 
     harness.step();
 }
+
+/// Test that a context menu near the bottom-right corner opens up and to the left
+#[test]
+fn test_menu_show_at_flips_near_screen_edge() {
+    let pos = egui::pos2(780.0, 580.0);
+
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(800.0, 600.0))
+        .build(|ctx| {
+            Menu::new("edge_menu").show_at(ctx, pos, |menu| {
+                menu.item("Cut");
+                menu.item("Copy");
+                menu.item("Paste");
+            });
+        });
+    harness.run();
+
+    let screen = harness.ctx.content_rect();
+    let rect = harness
+        .ctx
+        .memory(|m| m.area_rect(egui::Id::new("edge_menu").with("popover")))
+        .expect("menu should be shown");
+    assert!(screen.contains_rect(rect), "{rect:?} not within {screen:?}");
+    assert!(rect.max.x <= pos.x + 0.5, "menu should open to the left");
+    assert!(rect.max.y <= pos.y + 0.5, "menu should open upwards");
+}
+
+/// Test that a context menu opens down and to the right when there is room
+#[test]
+fn test_menu_show_at_opens_down_right() {
+    let pos = egui::pos2(100.0, 100.0);
+
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(800.0, 600.0))
+        .build(|ctx| {
+            Menu::new("corner_menu").show_at(ctx, pos, |menu| {
+                menu.item("Cut");
+                menu.item("Copy");
+            });
+        });
+    harness.run();
+
+    let rect = harness
+        .ctx
+        .memory(|m| m.area_rect(egui::Id::new("corner_menu").with("popover")))
+        .expect("menu should be shown");
+    assert_eq!(rect.min, pos);
+}
+
+/// Test that Escape dismisses a context menu
+#[test]
+fn test_menu_show_at_escape_dismisses() {
+    let mut harness = Harness::new_state(
+        |ctx, pos: &mut Option<egui::Pos2>| {
+            if let Some(at) = *pos {
+                let response = Menu::new("escape_menu").show_at(ctx, at, |menu| {
+                    menu.item("Rename");
+                });
+                if !response.is_open {
+                    *pos = None;
+                }
+            }
+        },
+        Some(egui::pos2(50.0, 50.0)),
+    );
+    harness.run();
+    assert!(harness.state().is_some());
+
+    harness.key_press(egui::Key::Escape);
+    harness.run();
+    assert!(harness.state().is_none());
+}
@@ -0,0 +1,32 @@
+//! Tests for `NotificationCenter` component using `egui_kittest`
+
+use armas_basic::components::overlays::{Notification, NotificationCenter};
+use armas_basic::ArmasContextExt;
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that clicking a notification marks it read and lowers the unread count
+#[test]
+fn test_notification_center_click_marks_read() {
+    let mut center = NotificationCenter::new("inbox");
+    center.push(Notification::new("Export finished").description("mixdown.wav"));
+    center.push(Notification::new("Plugin updated"));
+
+    let mut harness = Harness::new_ui_state(
+        |ui, center: &mut NotificationCenter| {
+            let theme = ui.ctx().armas_theme();
+            center.show(ui, &theme);
+        },
+        center,
+    );
+    harness.run();
+    assert_eq!(harness.state().unread_count(), 2);
+
+    harness.get_by_label("Notifications").click();
+    harness.run();
+    harness.get_by_label("Export finished").click();
+    harness.run();
+
+    assert_eq!(harness.state().unread_count(), 1);
+    assert!(harness.state().items()[0].read);
+}
@@ -0,0 +1,35 @@
+//! Tests for `NumberInput` component using `egui_kittest`
+
+use armas_basic::components::basic::NumberInput;
+use armas_basic::ArmasContextExt;
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that clicking increment adds one step and clamps at max
+#[test]
+fn test_number_input_increment_steps_and_clamps() {
+    let mut harness = Harness::new_ui_state(
+        |ui, value: &mut i32| {
+            let theme = ui.ctx().armas_theme();
+            NumberInput::new()
+                .step(5)
+                .min(0)
+                .max(12)
+                .show(ui, value, &theme);
+        },
+        3,
+    );
+    harness.run();
+
+    harness.get_by_label("Increment").click();
+    harness.run();
+    assert_eq!(*harness.state(), 8);
+
+    harness.get_by_label("Increment").click();
+    harness.run();
+    assert_eq!(*harness.state(), 12);
+
+    harness.get_by_label("Increment").click();
+    harness.run();
+    assert_eq!(*harness.state(), 12);
+}
@@ -0,0 +1,53 @@
+//! Tests for `RadialMenu` component using `egui_kittest`
+
+use armas_basic::components::navigation::{RadialMenu, RadialMenuResponse};
+use armas_basic::ArmasContextExt;
+use egui_kittest::Harness;
+
+/// Test that a pointer at 90° (straight down) over a 4-item menu selects the third wedge
+#[test]
+fn test_radial_menu_selects_wedge_by_angle() {
+    struct Menu {
+        open: bool,
+        response: RadialMenuResponse,
+        selected: Option<usize>,
+    }
+
+    let center = egui::pos2(200.0, 200.0);
+    let mut harness = Harness::new_ui_state(
+        move |ui, menu: &mut Menu| {
+            let theme = ui.ctx().armas_theme();
+            menu.response = RadialMenu::new("radial")
+                .open(menu.open)
+                .center(center)
+                .item("Up")
+                .item("Right")
+                .item("Down")
+                .item("Left")
+                .show(ui.ctx(), &theme);
+            menu.open = menu.response.is_open;
+            if menu.response.selected.is_some() {
+                menu.selected = menu.response.selected;
+            }
+        },
+        Menu {
+            open: true,
+            response: RadialMenuResponse::default(),
+            selected: None,
+        },
+    );
+    harness.step();
+
+    let below = center + egui::vec2(0.0, 60.0);
+    harness.hover_at(below);
+    harness.step();
+    assert_eq!(harness.state().response.hovered, Some(2));
+
+    harness.drag_at(below);
+    harness.step();
+    harness.drop_at(below);
+    harness.step();
+
+    assert_eq!(harness.state().selected, Some(2));
+    assert!(!harness.state().open);
+}
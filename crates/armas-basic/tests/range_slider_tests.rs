@@ -0,0 +1,122 @@
+//! Tests for `RangeSlider` and `ThreeValueSlider` components using `egui_kittest`
+
+use armas_basic::prelude::*;
+use egui::{pos2, Pos2};
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+struct SliderState {
+    values: [f32; 3],
+    origin: Pos2,
+}
+
+impl SliderState {
+    const fn new(values: [f32; 3]) -> Self {
+        Self {
+            values,
+            origin: Pos2::ZERO,
+        }
+    }
+}
+
+fn hz(value: f32) -> String {
+    format!("{value:.0} Hz")
+}
+
+/// Press at `from_x` and drag to `to_x` along the track, without releasing
+fn drag_thumb(harness: &mut Harness<'_, SliderState>, from_x: f32, to_x: f32) {
+    let origin = harness.state().origin;
+    let y = origin.y + 10.0;
+    harness.hover_at(pos2(origin.x + from_x, y));
+    harness.drag_at(pos2(origin.x + from_x, y));
+    harness.step();
+    harness.hover_at(pos2(origin.x + to_x, y));
+    harness.step();
+}
+
+/// Test that `RangeSlider` renders without panicking
+#[test]
+fn test_range_slider_renders() {
+    let mut min = 20.0;
+    let mut max = 80.0;
+
+    let mut harness = Harness::new_ui(|ui| {
+        RangeSlider::new(0.0, 100.0)
+            .label("Range")
+            .suffix("%")
+            .show(ui, &mut min, &mut max);
+    });
+
+    harness.run();
+}
+
+/// Test that dragging a `RangeSlider` thumb shows its formatted value
+#[test]
+fn test_range_slider_drag_tooltip() {
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut SliderState| {
+            state.origin = ui.cursor().min;
+            let [min, _, max] = &mut state.values;
+            RangeSlider::new(0.0, 1000.0)
+                .show_value(false)
+                .step(10.0)
+                .value_tooltip(true)
+                .format(hz)
+                .show(ui, min, max);
+        },
+        SliderState::new([100.0, 0.0, 800.0]),
+    );
+    harness.run();
+    assert!(harness.query_by_label_contains("Hz").is_none());
+
+    // Drag the max thumb (at 80%) to 44% of the 200px track
+    drag_thumb(&mut harness, 160.0, 88.0);
+    assert_eq!(harness.state().values[2], 440.0);
+    harness.get_by_label("440 Hz");
+
+    // Tooltip hides on release
+    let origin = harness.state().origin;
+    harness.drop_at(pos2(origin.x + 88.0, origin.y + 10.0));
+    harness.run();
+    assert!(harness.query_by_label("440 Hz").is_none());
+}
+
+/// Test that `ThreeValueSlider` renders without panicking
+#[test]
+fn test_three_value_slider_renders() {
+    let mut min = 20.0;
+    let mut value = 50.0;
+    let mut max = 80.0;
+
+    let mut harness = Harness::new_ui(|ui| {
+        ThreeValueSlider::new(0.0, 100.0)
+            .label("Range")
+            .show(ui, &mut min, &mut value, &mut max);
+    });
+
+    harness.run();
+}
+
+/// Test that dragging the `ThreeValueSlider` value thumb shows its formatted value
+#[test]
+fn test_three_value_slider_drag_tooltip() {
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut SliderState| {
+            state.origin = ui.cursor().min;
+            let [min, value, max] = &mut state.values;
+            ThreeValueSlider::new(0.0, 1000.0)
+                .show_value(false)
+                .step(10.0)
+                .value_tooltip(true)
+                .format(hz)
+                .show(ui, min, value, max);
+        },
+        SliderState::new([100.0, 500.0, 900.0]),
+    );
+    harness.run();
+
+    // Drag the value thumb (at 50%) to 44% of the 200px track
+    drag_thumb(&mut harness, 100.0, 88.0);
+    assert_eq!(harness.state().values[1], 440.0);
+    harness.get_by_label("440 Hz");
+}
@@ -0,0 +1,33 @@
+//! Tests for `Rating` component using `egui_kittest`
+
+use armas_basic::components::basic::Rating;
+use armas_basic::ArmasContextExt;
+use egui_kittest::Harness;
+
+/// Test that clicking the left half of the third star yields 2.5
+#[test]
+fn test_rating_half_step_click() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (value, rect): &mut (f32, egui::Rect)| {
+            let theme = ui.ctx().armas_theme();
+            *rect = Rating::new()
+                .size(20.0)
+                .allow_half(true)
+                .show(ui, value, &theme)
+                .response
+                .rect;
+        },
+        (0.0, egui::Rect::NOTHING),
+    );
+    harness.run();
+
+    // Stars are 20px with a 4px gap, so this is a quarter of the way into the third
+    let rect = harness.state().1;
+    let pos = egui::pos2(rect.min.x + 2.0 * 24.0 + 5.0, rect.center().y);
+    harness.drag_at(pos);
+    harness.step();
+    harness.drop_at(pos);
+    harness.run();
+
+    assert_eq!(harness.state().0, 2.5);
+}
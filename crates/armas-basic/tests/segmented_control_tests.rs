@@ -0,0 +1,83 @@
+//! Tests for `SegmentedControl` component using `egui_kittest`
+
+use armas_basic::components::basic::SegmentedControl;
+use armas_basic::ArmasContextExt;
+use egui_kittest::Harness;
+
+/// Test that clicking segment 2 selects it and slides the highlight over it
+#[test]
+fn test_segmented_control_click_moves_highlight() {
+    struct Control {
+        selected: usize,
+        rect: egui::Rect,
+        highlight: egui::Rect,
+    }
+
+    let mut harness = Harness::new_ui_state(
+        |ui, control: &mut Control| {
+            let theme = ui.ctx().armas_theme();
+            let response = SegmentedControl::new(["Day", "Week", "Month"])
+                .width(300.0)
+                .show(ui, &mut control.selected, &theme);
+            control.rect = response.response.rect;
+            control.highlight = response.highlight_rect;
+        },
+        Control {
+            selected: 0,
+            rect: egui::Rect::NOTHING,
+            highlight: egui::Rect::NOTHING,
+        },
+    );
+    harness.run();
+
+    let rect = harness.state().rect;
+    let segment_width = (rect.width() - 4.0) / 3.0;
+    let segment_2_center = rect.min.x + 2.0 + segment_width * 2.5;
+
+    let target = egui::pos2(segment_2_center, rect.center().y);
+    harness.drag_at(target);
+    harness.step();
+    harness.drop_at(target);
+    harness.step();
+    assert_eq!(harness.state().selected, 2);
+
+    // The highlight animates toward the new segment
+    harness.run_steps(60);
+    let highlight = harness.state().highlight;
+    assert!((highlight.center().x - segment_2_center).abs() < 0.5);
+}
+
+/// Test that arrow keys move the selection when focused
+#[test]
+fn test_segmented_control_keyboard() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (selected, rect): &mut (usize, egui::Rect)| {
+            let theme = ui.ctx().armas_theme();
+            *rect = SegmentedControl::new(["Day", "Week", "Month"])
+                .width(300.0)
+                .show(ui, selected, &theme)
+                .response
+                .rect;
+        },
+        (1, egui::Rect::NOTHING),
+    );
+    harness.run();
+
+    let center = harness.state().1.center();
+    harness.drag_at(center);
+    harness.step();
+    harness.drop_at(center);
+    harness.run_steps(2);
+
+    harness.key_press(egui::Key::ArrowRight);
+    harness.run_steps(2);
+    assert_eq!(harness.state().0, 2);
+
+    harness.key_press(egui::Key::ArrowRight);
+    harness.run_steps(2);
+    assert_eq!(harness.state().0, 2);
+
+    harness.key_press(egui::Key::ArrowLeft);
+    harness.run_steps(2);
+    assert_eq!(harness.state().0, 1);
+}
@@ -0,0 +1,51 @@
+//! Tests for `SplitButton` using `egui_kittest`
+
+use armas_basic::components::button::SplitButton;
+use armas_basic::components::navigation::MenuItem;
+use armas_basic::ArmasContextExt;
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that the left half reports the primary action without opening the menu
+#[test]
+fn test_split_button_primary_click() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (primary_clicked, is_open): &mut (bool, bool)| {
+            let theme = ui.ctx().armas_theme();
+            let response = SplitButton::new("Save", "save_actions")
+                .menu([MenuItem::new("Save as"), MenuItem::new("Save all")])
+                .show(ui, &theme);
+            *primary_clicked |= response.primary_clicked;
+            *is_open = response.is_open;
+        },
+        (false, false),
+    );
+    harness.run();
+
+    harness.get_by_label("Save").click();
+    harness.run();
+
+    assert_eq!(*harness.state(), (true, false));
+}
+
+/// Test that the chevron opens the menu without triggering the primary action
+#[test]
+fn test_split_button_chevron_opens_menu() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (primary_clicked, is_open): &mut (bool, bool)| {
+            let theme = ui.ctx().armas_theme();
+            let response = SplitButton::new("Save", "save_actions")
+                .menu([MenuItem::new("Save as"), MenuItem::new("Save all")])
+                .show(ui, &theme);
+            *primary_clicked |= response.primary_clicked;
+            *is_open = response.is_open;
+        },
+        (false, false),
+    );
+    harness.run();
+
+    harness.get_by_label("Save options").click();
+    harness.run();
+
+    assert_eq!(*harness.state(), (false, true));
+}
@@ -0,0 +1,56 @@
+//! Tests for `SplitPane` layout using `egui_kittest`
+
+use armas_basic::layout::SplitPane;
+use egui_kittest::Harness;
+
+/// Test that dragging the divider updates the ratio and clamps to the min sizes
+#[test]
+fn test_split_pane_drag_updates_and_clamps_ratio() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(400.0, 200.0))
+        .build_ui_state(
+            |ui, ratio: &mut f32| {
+                *ratio = SplitPane::horizontal()
+                    .ratio(0.5)
+                    .min_sizes(100.0, 50.0)
+                    .show(
+                        ui,
+                        |left| {
+                            left.label("Left");
+                        },
+                        |right| {
+                            right.label("Right");
+                        },
+                    )
+                    .ratio;
+            },
+            0.0,
+        );
+    harness.run();
+    assert!((*harness.state() - 0.5).abs() < f32::EPSILON);
+
+    // Drag the divider from wherever it sits to each target in turn
+    let available = harness.ctx.content_rect().width() - 16.0 - 1.0;
+    let mut ratios = Vec::new();
+    for to_x in [150.0, 10.0, 395.0] {
+        let from = egui::pos2(8.0 + available * *harness.state(), 100.0);
+        let to = egui::pos2(to_x, 100.0);
+        harness.drag_at(from);
+        harness.step();
+        harness.hover_at(to);
+        harness.step();
+        harness.drop_at(to);
+        harness.run();
+        ratios.push(*harness.state());
+    }
+
+    assert!(
+        ratios[0] < 0.45 && ratios[0] > 0.3,
+        "ratio after drag: {}",
+        ratios[0]
+    );
+    // Far left: the first panel keeps its 100pt minimum
+    assert!((ratios[1] - 100.0 / available).abs() < 1e-4);
+    // Far right: the second panel keeps its 50pt minimum
+    assert!((ratios[2] - (1.0 - 50.0 / available)).abs() < 1e-4);
+}
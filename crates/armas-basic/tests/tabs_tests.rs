@@ -127,3 +127,66 @@ fn test_tabs_active_clamping() {
 
     harness.step();
 }
+
+/// Test that selecting an off-screen tab scrolls the overflowing strip to it
+#[test]
+fn test_tabs_overflow_scrolls_active_into_view() {
+    // (active tab, overflowing, scroll offset)
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(300.0, 100.0))
+        .build_ui_state(
+            |ui, state: &mut (usize, bool, f32)| {
+                let theme = ui.ctx().armas_theme();
+                let labels: Vec<String> = (1..=12).map(|i| format!("Section {i}")).collect();
+                let response = Tabs::new(labels).active(state.0).show(ui, &theme);
+                state.1 = response.overflowing;
+                state.2 = response.scroll_offset;
+            },
+            (0, false, 0.0),
+        );
+
+    harness.run();
+    assert!(harness.state().1);
+    assert_eq!(harness.state().2, 0.0);
+
+    harness.state_mut().0 = 10;
+    harness.run();
+    assert!(harness.state().2 > 0.0);
+}
+
+/// Test that arrow keys, Home and End move the selection while the tab list is focused
+#[test]
+fn test_tabs_keyboard_selection() {
+    let mut harness = Harness::new_ui_state(
+        |ui, active: &mut usize| {
+            let theme = ui.ctx().armas_theme();
+            let response = Tabs::new(vec!["A", "B", "C"])
+                .active(*active)
+                .show(ui, &theme);
+            if let Some(index) = response.selected {
+                *active = index;
+            }
+        },
+        0,
+    );
+    harness.run();
+
+    // Unfocused: arrows are ignored
+    harness.key_press(egui::Key::ArrowRight);
+    harness.run();
+    assert_eq!(*harness.state(), 0);
+
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+    harness.key_press(egui::Key::ArrowRight);
+    harness.run();
+    assert_eq!(*harness.state(), 1);
+
+    harness.key_press(egui::Key::End);
+    harness.run();
+    assert_eq!(*harness.state(), 2);
+
+    harness.key_press(egui::Key::Home);
+    harness.run();
+    assert_eq!(*harness.state(), 0);
+}
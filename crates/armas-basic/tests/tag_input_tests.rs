@@ -0,0 +1,49 @@
+//! Tests for `TagInput` component using `egui_kittest`
+
+use armas_basic::components::basic::TagInput;
+use armas_basic::ArmasContextExt;
+use egui::accesskit::Role;
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that Enter adds the trimmed text as a tag
+#[test]
+fn test_tag_input_enter_adds_trimmed_tag() {
+    let mut harness = Harness::new_ui_state(
+        |ui, tags: &mut Vec<String>| {
+            let theme = ui.ctx().armas_theme();
+            TagInput::new(tags).show(ui, &theme);
+        },
+        Vec::new(),
+    );
+    harness.run();
+
+    harness.get_by_role(Role::TextInput).click();
+    harness.run();
+    harness.get_by_role(Role::TextInput).type_text("  design  ");
+    harness.run();
+    harness.key_press(egui::Key::Enter);
+    harness.run();
+
+    assert_eq!(harness.state(), &["design"]);
+}
+
+/// Test that Backspace on an empty field removes the last tag
+#[test]
+fn test_tag_input_backspace_removes_last_tag() {
+    let mut harness = Harness::new_ui_state(
+        |ui, tags: &mut Vec<String>| {
+            let theme = ui.ctx().armas_theme();
+            TagInput::new(tags).show(ui, &theme);
+        },
+        vec!["alpha".to_string(), "beta".to_string()],
+    );
+    harness.run();
+
+    harness.get_by_role(Role::TextInput).click();
+    harness.run();
+    harness.key_press(egui::Key::Backspace);
+    harness.run();
+
+    assert_eq!(harness.state(), &["alpha"]);
+}
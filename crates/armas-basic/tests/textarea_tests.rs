@@ -0,0 +1,49 @@
+//! Tests for `Textarea` component using `egui_kittest`
+
+use armas_basic::components::basic::{PreviewMode, Textarea};
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that dragging the resize grip increases the persisted height
+#[test]
+fn test_textarea_grip_drag_increases_height() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (text, height): &mut (String, f32)| {
+            *height = Textarea::new("Notes").resizable(true).show(ui, text).height;
+        },
+        (String::new(), 0.0),
+    );
+    harness.run();
+    let initial = harness.state().1;
+
+    let grip = harness.get_by_label("Resize").rect().center();
+    harness.drag_at(grip);
+    harness.run();
+    harness.hover_at(grip + egui::vec2(0.0, 30.0));
+    harness.run();
+    harness.hover_at(grip + egui::vec2(0.0, 60.0));
+    harness.run();
+    harness.drop_at(grip + egui::vec2(0.0, 60.0));
+    harness.run();
+
+    let resized = harness.state().1;
+    assert!(resized > initial + 30.0, "{initial} -> {resized}");
+
+    // The height is kept in memory for later frames
+    harness.run();
+    assert_eq!(harness.state().1, resized);
+}
+
+/// Test that the preview renders markdown without its markers
+#[test]
+fn test_textarea_markdown_preview_renders() {
+    let mut harness = Harness::new_ui(|ui| {
+        let mut text = String::from("# Notes\nSome **bold** text");
+        Textarea::new("Notes")
+            .markdown_preview(PreviewMode::Beside)
+            .show(ui, &mut text);
+    });
+    harness.run();
+
+    harness.get_by_label("Notes\nSome bold text");
+}
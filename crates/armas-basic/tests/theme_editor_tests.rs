@@ -0,0 +1,53 @@
+//! Tests for `ThemeEditor` component using `egui_kittest`
+
+use armas_basic::components::basic::ThemeEditor;
+use armas_basic::Theme;
+use egui::accesskit::Role;
+use egui::{Color32, Key, Modifiers};
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that picking a primary color through the editor updates `Theme::primary`
+#[test]
+fn test_theme_editor_edits_primary() {
+    let mut harness = Harness::new_ui_state(
+        |ui, theme: &mut Theme| {
+            ThemeEditor::new("editor").show(ui, theme);
+        },
+        Theme::dark(),
+    );
+    harness.run();
+
+    harness.get_by_role(Role::TextInput).click();
+    harness.run();
+    harness.key_press_modifiers(Modifiers::COMMAND, Key::A);
+    harness.get_by_role(Role::TextInput).type_text("#FF0000");
+    harness.run();
+
+    assert_eq!(harness.state().primary(), Color32::from_rgb(255, 0, 0));
+}
+
+/// Test that "Export JSON" round-trips through serde
+#[test]
+fn test_theme_editor_export_json() {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(400.0, 1600.0))
+        .build_ui_state(
+            |ui, (theme, exported): &mut (Theme, Option<String>)| {
+                let response = ThemeEditor::new("editor").show(ui, theme);
+                if response.exported.is_some() {
+                    *exported = response.exported;
+                }
+            },
+            (Theme::dark(), None),
+        );
+    harness.run();
+
+    harness.get_by_label("Export JSON").click();
+    harness.run();
+
+    let (theme, exported) = harness.state();
+    let json = exported.as_deref().expect("theme was exported");
+    let parsed: Theme = serde_json::from_str(json).expect("valid theme JSON");
+    assert_eq!(parsed.primary(), theme.primary());
+}
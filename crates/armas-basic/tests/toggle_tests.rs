@@ -156,3 +156,25 @@ fn test_toggle_with_description() {
 //     harness.fit_contents();
 //     harness.snapshot("toggle_checked");
 // }
+
+/// Test that a labeled Toggle is exposed to screen readers as a checked checkbox
+#[test]
+fn test_toggle_accessibility_info() {
+    use egui::accesskit::{Role, Toggled};
+    use egui_kittest::kittest::{NodeT, Queryable};
+
+    let mut harness = Harness::new_ui(|ui| {
+        let theme = ui.ctx().armas_theme();
+        let mut checked = true;
+        Toggle::new()
+            .id("dark_mode")
+            .aria_label("Dark mode toggle")
+            .show(ui, &mut checked, &theme);
+    });
+
+    harness.run();
+
+    let node = harness.get_by_label("Dark mode toggle").accesskit_node();
+    assert_eq!(node.role(), Role::CheckBox);
+    assert_eq!(node.toggled(), Some(Toggled::True));
+}
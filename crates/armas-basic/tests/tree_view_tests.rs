@@ -1,7 +1,9 @@
 //! Tests for `TreeView` component using `egui_kittest`
 
 use armas_basic::components::navigation::{TreeItem, TreeView};
+use armas_basic::ext::drag_payload;
 use armas_basic::ArmasContextExt;
+use egui_kittest::kittest::Queryable;
 use egui_kittest::Harness;
 use std::path::PathBuf;
 
@@ -154,3 +156,74 @@ fn test_tree_view_response() {
 
     harness.run();
 }
+
+/// Test that dragging an item of a draggable `TreeView` carries it as a payload
+#[test]
+fn test_tree_view_item_drag_payload() {
+    let mut harness = Harness::new_ui(|ui| {
+        let theme = ui.ctx().armas_theme();
+        let items = vec![
+            TreeItem::file("kick.wav", "/kick.wav"),
+            TreeItem::file("snare.wav", "/snare.wav"),
+        ];
+
+        let mut tree = TreeView::new()
+            .items(items)
+            .draggable(true)
+            .width(300.0)
+            .height(200.0);
+        tree.show(ui, &theme);
+    });
+    harness.run();
+
+    let item = harness.get_by_label("snare.wav").rect().center();
+    harness.drag_at(item);
+    harness.run();
+    harness.hover_at(item + egui::vec2(60.0, 40.0));
+    harness.run();
+
+    let payload = drag_payload::<TreeItem>(&harness.ctx);
+    assert_eq!(
+        payload.map(|item| item.path.clone()),
+        Some(PathBuf::from("/snare.wav"))
+    );
+}
+
+/// Test that a virtualized `TreeView` only renders the rows in the viewport
+#[test]
+fn test_tree_view_virtualized_renders_visible_rows() {
+    let mut items = vec![TreeItem::folder("samples", "/samples")];
+    items.extend((0..10_000).map(|i| {
+        TreeItem::file(
+            format!("take_{i:05}.wav"),
+            format!("/samples/take_{i:05}.wav"),
+        )
+    }));
+    let mut tree = TreeView::new()
+        .items(items)
+        .virtualized(true)
+        .width(300.0)
+        .height(240.0);
+
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(300.0, 240.0))
+        .build_ui_state(
+            |ui, visible: &mut Option<std::ops::Range<usize>>| {
+                let theme = ui.ctx().armas_theme();
+                *visible = tree.show(ui, &theme).visible;
+            },
+            None,
+        );
+    harness.run();
+    assert_eq!(harness.state().clone(), Some(0..1));
+
+    // Expanding the folder flattens its children into the list
+    harness.get_by_label("samples").click();
+    harness.run();
+
+    let visible = harness.state().clone().expect("virtualized");
+    assert_eq!(visible.start, 0);
+    assert!(visible.end < 20, "rendered {visible:?}");
+    assert!(harness.query_by_label("take_00000.wav").is_some());
+    assert!(harness.query_by_label("take_05000.wav").is_none());
+}
@@ -0,0 +1,27 @@
+//! Tests for `VirtualList` layout using `egui_kittest`
+
+use armas_basic::layout::VirtualList;
+use egui_kittest::Harness;
+
+/// Test that only the rows in view (plus overscan) are rendered
+#[test]
+fn test_virtual_list_renders_visible_rows_only() {
+    let mut harness = Harness::new_ui_state(
+        |ui, rendered: &mut Vec<usize>| {
+            rendered.clear();
+            VirtualList::new(10_000, 20.0)
+                .height(200.0)
+                .overscan(2)
+                .scroll_offset(1000.0)
+                .show(ui, |ui, index| {
+                    rendered.push(index);
+                    ui.label(format!("Row {index}"));
+                });
+        },
+        Vec::new(),
+    );
+    harness.run();
+
+    // Rows 50-59 fill the 200pt viewport at offset 1000, plus 2 rows of overscan
+    assert_eq!(harness.state(), &(48..62).collect::<Vec<_>>());
+}
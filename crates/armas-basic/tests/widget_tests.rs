@@ -0,0 +1,44 @@
+//! Tests for the `egui::Widget` impls of simple display components using `egui_kittest`
+
+use armas_basic::prelude::*;
+use egui_kittest::Harness;
+
+/// Test that `ui.add(Badge::new(..))` returns the badge's response
+#[test]
+fn test_badge_widget_returns_response() {
+    let mut harness = Harness::new_ui(|ui| {
+        let response = ui.add(Badge::new("x"));
+        assert!(response.rect.width() > 0.0);
+    });
+
+    harness.run();
+}
+
+/// Test that the display components compose with `ui.add` inside layouts
+#[test]
+fn test_display_widgets_in_horizontal() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.horizontal(|ui| {
+            ui.add(Badge::new("New"));
+            ui.add(Kbd::new("⌘K"));
+            ui.add(Avatar::new("JD"));
+            ui.add(Spinner::new().size(16.0));
+        });
+        ui.add(Separator::new());
+    });
+
+    // The spinner animates continuously, so step a single frame
+    harness.step();
+}
+
+/// Test that widgets pick up the theme stored in the context
+#[test]
+fn test_widget_uses_context_theme() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.ctx().set_armas_theme(Theme::light());
+        let response = ui.add(Kbd::new("Esc"));
+        assert!(response.rect.height() > 0.0);
+    });
+
+    harness.run();
+}
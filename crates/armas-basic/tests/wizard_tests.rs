@@ -0,0 +1,56 @@
+//! Tests for `Wizard` component using `egui_kittest`
+
+use armas_basic::components::navigation::Wizard;
+use armas_basic::ArmasContextExt;
+use egui_kittest::kittest::Queryable;
+use egui_kittest::Harness;
+
+/// Test that Next does nothing while the step's validator fails
+#[test]
+fn test_wizard_next_disabled_when_invalid() {
+    let mut harness = Harness::new_ui_state(
+        |ui, step: &mut usize| {
+            let theme = ui.ctx().armas_theme();
+            *step = Wizard::new("wizard")
+                .steps(["Account", "Confirm"])
+                .show(ui, &theme, |ui, step| {
+                    ui.label(format!("Panel {step}"));
+                    step > 0
+                })
+                .step;
+        },
+        0,
+    );
+    harness.run();
+
+    harness.get_by_label("Next").click();
+    harness.run();
+
+    assert_eq!(*harness.state(), 0);
+    assert!(harness.query_by_label("Panel 0").is_some());
+}
+
+/// Test that Next advances once the validator passes
+#[test]
+fn test_wizard_advances_when_valid() {
+    let mut harness = Harness::new_ui_state(
+        |ui, step: &mut usize| {
+            let theme = ui.ctx().armas_theme();
+            *step = Wizard::new("wizard")
+                .steps(["Account", "Confirm"])
+                .show(ui, &theme, |ui, step| {
+                    ui.label(format!("Panel {step}"));
+                    true
+                })
+                .step;
+        },
+        0,
+    );
+    harness.run();
+
+    harness.get_by_label("Next").click();
+    harness.run();
+
+    assert_eq!(*harness.state(), 1);
+    assert!(harness.query_by_label("Panel 1").is_some());
+}
@@ -10,7 +10,11 @@
 //! - [`IconData`] - Pre-tessellated icon geometry (static references)
 //! - [`OwnedIconData`] - Runtime-parsed icon geometry (owned buffers)
 //! - [`Icon`] - Generic icon widget (works with both)
-//! - [`render_icon`] / [`render_icon_data`] - Low-level rendering
+//! - [`IconStroke`] - Stroked path kept as a polyline (runtime-parsed icons)
+//! - [`IconSubpath`] - Filled path with its own color (multicolor icons)
+//! - [`IconFit`] - How the viewbox is fitted into a non-square rect
+//! - [`render_icon`] / [`render_icon_data`] / [`render_icon_strokes`] - Low-level rendering
+//!   (with `_fit` variants taking an [`IconFit`])
 //!
 //! # Example
 //!
@@ -35,12 +39,14 @@
 //! ```
 
 #[cfg(feature = "runtime")]
-mod tessellate;
+pub mod tessellate;
 
 #[cfg(feature = "runtime")]
 pub mod runtime;
 
-use egui::{epaint::Vertex, Color32, Mesh, Painter, Pos2, Rect, Response, Sense, Ui, Vec2};
+use egui::{
+    epaint::Vertex, Color32, Mesh, Painter, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2,
+};
 
 /// Pre-tessellated icon data
 ///
@@ -60,11 +66,46 @@ pub struct IconData {
     pub viewbox_height: f32,
 }
 
+/// A stroked SVG path, kept as a polyline.
+///
+/// Drawn as a line of the given width rather than filled triangles, so the
+/// width scales with the icon and can be overridden at render time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IconStroke {
+    /// Points along the path, in viewbox units
+    pub points: Vec<(f32, f32)>,
+    /// Stroke width, in viewbox units
+    pub width: f32,
+    /// Whether the last point connects back to the first
+    pub closed: bool,
+    /// Whether open ends are drawn rounded
+    pub round_caps: bool,
+}
+
+/// An SVG path that keeps its own fill color.
+///
+/// Produced by [`runtime::parse_svg_multicolor`] so multicolor icons (e.g. a
+/// two-tone logo) aren't flattened to a single color. Subpaths are stored in
+/// document order and each one paints its fill, then its strokes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IconSubpath {
+    /// Vertex positions as (x, y) tuples
+    pub vertices: Vec<(f32, f32)>,
+    /// Triangle indices
+    pub indices: Vec<u32>,
+    /// Fill color; `None` (e.g. gradient fills) uses the icon color
+    pub color: Option<Color32>,
+    /// This path's strokes, drawn over its fill in the icon color
+    pub strokes: Vec<IconStroke>,
+}
+
 /// Icon data that owns its buffers.
 ///
 /// Runtime counterpart to [`IconData`]. Use this when icon geometry is
-/// produced at runtime (e.g. from [`runtime::parse_svg`]).
+/// produced at runtime (e.g. from [`runtime::parse_svg`]), or build one from
+/// raw geometry with [`OwnedIconData::new`].
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct OwnedIconData {
     /// Icon name
     pub name: String,
@@ -72,16 +113,68 @@ pub struct OwnedIconData {
     pub vertices: Vec<(f32, f32)>,
     /// Triangle indices
     pub indices: Vec<u32>,
+    /// Stroked paths
+    pub strokes: Vec<IconStroke>,
+    /// Paths with their own colors, drawn in order over `vertices`
+    ///
+    /// Empty unless parsed with [`runtime::parse_svg_multicolor`].
+    pub subpaths: Vec<IconSubpath>,
     /// Original viewbox width
     pub viewbox_width: f32,
     /// Original viewbox height
     pub viewbox_height: f32,
 }
 
+/// How far a pressed interactive icon darkens from its hover color
+const PRESS_DARKEN: f32 = 0.25;
+
+/// How an icon's viewbox is fitted into the rect it is drawn in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IconFit {
+    /// Keep the aspect ratio and fit inside the rect, centered
+    #[default]
+    Contain,
+    /// Keep the aspect ratio and cover the rect, centered and clipped to it
+    Fill,
+    /// Scale each axis independently to fill the rect exactly
+    Stretch,
+}
+
+impl IconFit {
+    /// Per-axis scale and top-left offset that place a viewbox in `rect`
+    #[must_use]
+    pub fn place(self, rect: Rect, viewbox_width: f32, viewbox_height: f32) -> (Vec2, Pos2) {
+        let scale_x = rect.width() / viewbox_width;
+        let scale_y = rect.height() / viewbox_height;
+        let scale = match self {
+            Self::Contain => Vec2::splat(scale_x.min(scale_y)),
+            Self::Fill => Vec2::splat(scale_x.max(scale_y)),
+            Self::Stretch => Vec2::new(scale_x, scale_y),
+        };
+
+        // Center whatever is left over (negative when Fill overflows)
+        let offset_x = rect.left() + viewbox_width.mul_add(-scale.x, rect.width()) / 2.0;
+        let offset_y = rect.top() + viewbox_height.mul_add(-scale.y, rect.height()) / 2.0;
+
+        (scale, Pos2::new(offset_x, offset_y))
+    }
+
+    /// Painter to draw with; `Fill` overflows the rect, so it is clipped
+    fn painter(self, painter: &Painter, rect: Rect) -> Painter {
+        if self == Self::Fill {
+            painter.with_clip_rect(painter.clip_rect().intersect(rect))
+        } else {
+            painter.clone()
+        }
+    }
+}
+
 /// Render icon geometry to an egui painter.
 ///
 /// Low-level function that takes raw vertex/index slices. Both
 /// [`render_icon`] and [`OwnedIconData::render`] delegate to this.
+/// The viewbox is fitted with [`IconFit::Contain`]; see
+/// [`render_icon_data_fit`] for the other fits.
 pub fn render_icon_data(
     painter: &Painter,
     rect: Rect,
@@ -91,17 +184,35 @@ pub fn render_icon_data(
     viewbox_height: f32,
     color: Color32,
 ) {
-    let scale_x = rect.width() / viewbox_width;
-    let scale_y = rect.height() / viewbox_height;
-    let scale = scale_x.min(scale_y);
+    render_icon_data_fit(
+        painter,
+        rect,
+        vertices,
+        indices,
+        Vec2::new(viewbox_width, viewbox_height),
+        color,
+        IconFit::Contain,
+    );
+}
 
-    let offset_x = rect.left() + viewbox_width.mul_add(-scale, rect.width()) / 2.0;
-    let offset_y = rect.top() + viewbox_height.mul_add(-scale, rect.height()) / 2.0;
+/// Render icon geometry with an explicit [`IconFit`].
+///
+/// `viewbox` is the original viewbox width and height.
+pub fn render_icon_data_fit(
+    painter: &Painter,
+    rect: Rect,
+    vertices: &[(f32, f32)],
+    indices: &[u32],
+    viewbox: Vec2,
+    color: Color32,
+    fit: IconFit,
+) {
+    let (scale, offset) = fit.place(rect, viewbox.x, viewbox.y);
 
     let mut mesh = Mesh::default();
 
     for &(x, y) in vertices {
-        let pos = Pos2::new(offset_x + x * scale, offset_y + y * scale);
+        let pos = Pos2::new(x.mul_add(scale.x, offset.x), y.mul_add(scale.y, offset.y));
         mesh.vertices.push(Vertex {
             pos,
             uv: Pos2::ZERO,
@@ -111,7 +222,108 @@ pub fn render_icon_data(
 
     mesh.indices.extend_from_slice(indices);
 
-    painter.add(mesh);
+    fit.painter(painter, rect).add(mesh);
+}
+
+/// Render stroked icon paths to an egui painter.
+///
+/// Given the same rect as [`render_icon_data`], both layers of an icon line
+/// up. Stroke widths scale with the icon; `width_override` replaces every
+/// path's width (in viewbox units).
+pub fn render_icon_strokes(
+    painter: &Painter,
+    rect: Rect,
+    strokes: &[IconStroke],
+    viewbox_width: f32,
+    viewbox_height: f32,
+    color: Color32,
+    width_override: Option<f32>,
+) {
+    render_icon_strokes_fit(
+        painter,
+        rect,
+        strokes,
+        Vec2::new(viewbox_width, viewbox_height),
+        color,
+        width_override,
+        IconFit::Contain,
+    );
+}
+
+/// Render stroked icon paths with an explicit [`IconFit`].
+///
+/// Given the same fit as [`render_icon_data_fit`], both layers of an icon
+/// line up. Widths scale by the smaller axis when stretched.
+pub fn render_icon_strokes_fit(
+    painter: &Painter,
+    rect: Rect,
+    strokes: &[IconStroke],
+    viewbox: Vec2,
+    color: Color32,
+    width_override: Option<f32>,
+    fit: IconFit,
+) {
+    let (scale, offset) = fit.place(rect, viewbox.x, viewbox.y);
+    let painter = fit.painter(painter, rect);
+
+    for icon_stroke in strokes {
+        let points: Vec<Pos2> = icon_stroke
+            .points
+            .iter()
+            .map(|&(x, y)| Pos2::new(x.mul_add(scale.x, offset.x), y.mul_add(scale.y, offset.y)))
+            .collect();
+        let width = width_override.unwrap_or(icon_stroke.width) * scale.min_elem();
+        let stroke = Stroke::new(width, color);
+
+        let ends = match points.as_slice() {
+            [] => continue,
+            [only] => [*only, *only],
+            [first, .., last] => [*first, *last],
+        };
+
+        if icon_stroke.closed {
+            painter.add(Shape::closed_line(points, stroke));
+        } else {
+            painter.add(Shape::line(points, stroke));
+            if icon_stroke.round_caps {
+                for end in ends {
+                    painter.circle_filled(end, width / 2.0, color);
+                }
+            }
+        }
+    }
+}
+
+/// Render multicolor paths in order, each fill in its own color or else `color`
+fn render_icon_subpaths(
+    painter: &Painter,
+    rect: Rect,
+    subpaths: &[IconSubpath],
+    viewbox: Vec2,
+    color: Color32,
+    width_override: Option<f32>,
+    fit: IconFit,
+) {
+    for subpath in subpaths {
+        render_icon_data_fit(
+            painter,
+            rect,
+            &subpath.vertices,
+            &subpath.indices,
+            viewbox,
+            subpath.color.unwrap_or(color),
+            fit,
+        );
+        render_icon_strokes_fit(
+            painter,
+            rect,
+            &subpath.strokes,
+            viewbox,
+            color,
+            width_override,
+            fit,
+        );
+    }
 }
 
 /// Render icon data to an egui painter.
@@ -131,7 +343,29 @@ pub fn render_icon(painter: &Painter, rect: Rect, icon_data: &IconData, color: C
 }
 
 impl OwnedIconData {
-    /// Render this icon to an egui painter.
+    /// Create icon data from filled geometry, with no strokes or subpaths.
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        vertices: Vec<(f32, f32)>,
+        indices: Vec<u32>,
+        viewbox_width: f32,
+        viewbox_height: f32,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            vertices,
+            indices,
+            strokes: Vec::new(),
+            subpaths: Vec::new(),
+            viewbox_width,
+            viewbox_height,
+        }
+    }
+
+    /// Render this icon's fills and strokes to an egui painter.
+    ///
+    /// Subpaths with their own fill color keep it; everything else uses `color`.
     pub fn render(&self, painter: &Painter, rect: Rect, color: Color32) {
         render_icon_data(
             painter,
@@ -142,6 +376,24 @@ impl OwnedIconData {
             self.viewbox_height,
             color,
         );
+        render_icon_subpaths(
+            painter,
+            rect,
+            &self.subpaths,
+            Vec2::new(self.viewbox_width, self.viewbox_height),
+            color,
+            None,
+            IconFit::Contain,
+        );
+        render_icon_strokes(
+            painter,
+            rect,
+            &self.strokes,
+            self.viewbox_width,
+            self.viewbox_height,
+            color,
+            None,
+        );
     }
 }
 
@@ -168,10 +420,16 @@ impl OwnedIconData {
 pub struct Icon<'a> {
     vertices: &'a [(f32, f32)],
     indices: &'a [u32],
+    strokes: &'a [IconStroke],
+    subpaths: &'a [IconSubpath],
+    stroke_width: Option<f32>,
     viewbox_width: f32,
     viewbox_height: f32,
-    size: f32,
+    size: Vec2,
+    fit: IconFit,
     color: Color32,
+    interactive: bool,
+    hover_color: Option<Color32>,
 }
 
 impl<'a> Icon<'a> {
@@ -181,10 +439,16 @@ impl<'a> Icon<'a> {
         Self {
             vertices: icon_data.vertices,
             indices: icon_data.indices,
+            strokes: &[],
+            subpaths: &[],
+            stroke_width: None,
             viewbox_width: icon_data.viewbox_width,
             viewbox_height: icon_data.viewbox_height,
-            size: 24.0,
+            size: Vec2::splat(24.0),
+            fit: IconFit::Contain,
             color: Color32::WHITE,
+            interactive: false,
+            hover_color: None,
         }
     }
 
@@ -194,20 +458,40 @@ impl<'a> Icon<'a> {
         Self {
             vertices: &data.vertices,
             indices: &data.indices,
+            strokes: &data.strokes,
+            subpaths: &data.subpaths,
+            stroke_width: None,
             viewbox_width: data.viewbox_width,
             viewbox_height: data.viewbox_height,
-            size: 24.0,
+            size: Vec2::splat(24.0),
+            fit: IconFit::Contain,
             color: Color32::WHITE,
+            interactive: false,
+            hover_color: None,
         }
     }
 
     /// Set the icon size (width and height will be equal)
     #[must_use]
     pub const fn size(mut self, size: f32) -> Self {
+        self.size = Vec2::splat(size);
+        self
+    }
+
+    /// Set a non-square icon size; see [`Self::fit`] for how the icon fills it
+    #[must_use]
+    pub const fn size2(mut self, size: Vec2) -> Self {
         self.size = size;
         self
     }
 
+    /// Set how the icon is fitted into its size (default: [`IconFit::Contain`])
+    #[must_use]
+    pub const fn fit(mut self, fit: IconFit) -> Self {
+        self.fit = fit;
+        self
+    }
+
     /// Set the icon color
     #[must_use]
     pub const fn color(mut self, color: Color32) -> Self {
@@ -215,22 +499,88 @@ impl<'a> Icon<'a> {
         self
     }
 
+    /// Give hover and press feedback, like an icon button (default: false)
+    ///
+    /// The icon tints toward the hover color while hovered and darkens while
+    /// pressed. Without it the icon is drawn in its color regardless of state.
+    #[must_use]
+    pub const fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Set the tint of an interactive icon on hover
+    /// (default: egui's hovered widget foreground)
+    #[must_use]
+    pub const fn hover_color(mut self, color: Color32) -> Self {
+        self.hover_color = Some(color);
+        self
+    }
+
+    /// Override the width of stroked paths, in viewbox units
+    ///
+    /// Only affects icons with [`IconStroke`] paths, i.e. runtime-parsed ones.
+    #[must_use]
+    pub const fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = Some(width);
+        self
+    }
+
     /// Show the icon
     pub fn show(self, ui: &mut Ui) -> Response {
-        let (rect, response) = ui.allocate_exact_size(Vec2::splat(self.size), Sense::click());
+        let (rect, response) = ui.allocate_exact_size(self.size, Sense::click());
+
+        let color = if self.interactive {
+            let hover_color = self
+                .hover_color
+                .unwrap_or_else(|| ui.visuals().widgets.hovered.fg_stroke.color);
+            let hover_t = ui.ctx().animate_bool(response.id, response.hovered());
+            interactive_color(
+                self.color,
+                hover_color,
+                hover_t,
+                response.is_pointer_button_down_on(),
+            )
+        } else {
+            self.color
+        };
+        let response = if self.interactive {
+            response.on_hover_cursor(egui::CursorIcon::PointingHand)
+        } else {
+            response
+        };
 
         if ui.is_rect_visible(rect) {
-            if self.vertices.is_empty() {
+            if self.vertices.is_empty() && self.strokes.is_empty() && self.subpaths.is_empty() {
                 ui.painter().rect_filled(rect, 2.0, Color32::from_gray(100));
             } else {
-                render_icon_data(
+                let viewbox = Vec2::new(self.viewbox_width, self.viewbox_height);
+                render_icon_data_fit(
                     ui.painter(),
                     rect,
                     self.vertices,
                     self.indices,
-                    self.viewbox_width,
-                    self.viewbox_height,
-                    self.color,
+                    viewbox,
+                    color,
+                    self.fit,
+                );
+                render_icon_subpaths(
+                    ui.painter(),
+                    rect,
+                    self.subpaths,
+                    viewbox,
+                    color,
+                    self.stroke_width,
+                    self.fit,
+                );
+                render_icon_strokes_fit(
+                    ui.painter(),
+                    rect,
+                    self.strokes,
+                    viewbox,
+                    color,
+                    self.stroke_width,
+                    self.fit,
                 );
             }
         }
@@ -238,3 +588,47 @@ impl<'a> Icon<'a> {
         response
     }
 }
+
+/// Color of an interactive icon, `hover_t` of the way to hovered
+fn interactive_color(color: Color32, hover_color: Color32, hover_t: f32, pressed: bool) -> Color32 {
+    let tinted = color.lerp_to_gamma(hover_color, hover_t);
+    if pressed {
+        tinted.lerp_to_gamma(Color32::BLACK, PRESS_DARKEN)
+    } else {
+        tinted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_fit_place() {
+        // A 24x24 viewbox in a wide 96x24 rect
+        let rect = Rect::from_min_size(Pos2::new(10.0, 0.0), Vec2::new(96.0, 24.0));
+
+        let (scale, offset) = IconFit::Contain.place(rect, 24.0, 24.0);
+        assert_eq!(scale, Vec2::splat(1.0));
+        assert_eq!(offset, Pos2::new(46.0, 0.0));
+
+        let (scale, offset) = IconFit::Fill.place(rect, 24.0, 24.0);
+        assert_eq!(scale, Vec2::splat(4.0));
+        assert_eq!(offset, Pos2::new(10.0, -36.0));
+
+        let (scale, offset) = IconFit::Stretch.place(rect, 24.0, 24.0);
+        assert_eq!(scale, Vec2::new(4.0, 1.0));
+        assert_eq!(offset, rect.min);
+    }
+
+    #[test]
+    fn test_interactive_color() {
+        let (color, hover) = (Color32::from_gray(100), Color32::WHITE);
+        assert_eq!(interactive_color(color, hover, 0.0, false), color);
+        assert_eq!(interactive_color(color, hover, 1.0, false), hover);
+
+        let pressed = interactive_color(color, hover, 1.0, true);
+        assert!(pressed.r() < hover.r());
+        assert!(pressed.r() > color.r());
+    }
+}
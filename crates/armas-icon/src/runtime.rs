@@ -71,6 +71,32 @@ pub fn parse_svg_named(svg_str: &str, name: impl Into<String>) -> Result<OwnedIc
         name: name.into(),
         vertices: icon.vertices,
         indices: icon.indices,
+        strokes: icon.strokes,
+        subpaths: icon.subpaths,
+        viewbox_width: icon.viewbox_width,
+        viewbox_height: icon.viewbox_height,
+    })
+}
+
+/// Parse an SVG string into [`OwnedIconData`], keeping per-path fill colors.
+///
+/// Each filled path becomes an [`IconSubpath`](crate::IconSubpath) with its
+/// own color and strokes, in document order, so two-tone logos and brand
+/// icons render as designed. Paths without a solid fill (e.g. gradients)
+/// and strokes still use the color passed at render time. The icon name defaults to `"unnamed"`.
+///
+/// # Errors
+///
+/// Returns an error if the SVG cannot be parsed or tessellated.
+pub fn parse_svg_multicolor(svg_str: &str) -> Result<OwnedIconData, IconError> {
+    let icon = tessellate::tessellate_svg_data_multicolor(svg_str)?;
+
+    Ok(OwnedIconData {
+        name: "unnamed".to_owned(),
+        vertices: icon.vertices,
+        indices: icon.indices,
+        strokes: icon.strokes,
+        subpaths: icon.subpaths,
         viewbox_width: icon.viewbox_width,
         viewbox_height: icon.viewbox_height,
     })
@@ -128,10 +154,66 @@ mod tests {
     #[test]
     fn parse_stroke_svg() {
         let svg = r#"<svg viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg">
-            <line x1="2" y1="2" x2="22" y2="22" stroke="black" stroke-width="2"/>
+            <line x1="2" y1="2" x2="22" y2="22" stroke="black" stroke-width="2" stroke-linecap="round"/>
         </svg>"#;
         let icon = parse_svg(svg).unwrap();
-        assert!(!icon.vertices.is_empty());
+        // Stroked paths are kept as polylines, not filled triangles
+        assert!(icon.indices.is_empty());
+        assert_eq!(icon.strokes.len(), 1);
+
+        let stroke = &icon.strokes[0];
+        assert_eq!(stroke.points, vec![(2.0, 2.0), (22.0, 22.0)]);
+        assert_eq!(stroke.width, 2.0);
+        assert!(!stroke.closed);
+        assert!(stroke.round_caps);
+    }
+
+    #[test]
+    fn parse_filled_and_stroked_svg() {
+        let svg = r#"<svg viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg">
+            <rect x="4" y="4" width="16" height="16" fill="black" stroke="black" stroke-width="1"/>
+        </svg>"#;
+        let icon = parse_svg(svg).unwrap();
+        assert!(!icon.indices.is_empty());
+        assert_eq!(icon.strokes.len(), 1);
+        assert!(icon.strokes[0].closed);
+    }
+
+    #[test]
+    fn parse_multicolor_keeps_fill_colors() {
+        let svg = r##"<svg viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg">
+            <rect x="0" y="0" width="12" height="24" fill="#ff0000"/>
+            <rect x="12" y="0" width="12" height="24" fill="#0000ff" fill-opacity="0.5"/>
+        </svg>"##;
+        let icon = parse_svg_multicolor(svg).unwrap();
+        assert!(icon.vertices.is_empty());
+        assert_eq!(icon.subpaths.len(), 2);
+        assert!(!icon.subpaths[0].indices.is_empty());
+        assert_eq!(icon.subpaths[0].color, Some(egui::Color32::RED));
+        assert_eq!(
+            icon.subpaths[1].color,
+            Some(egui::Color32::from_rgba_unmultiplied(0, 0, 255, 128))
+        );
+
+        // The single-color parser still merges everything
+        let flat = parse_svg(svg).unwrap();
+        assert!(flat.subpaths.is_empty());
+        assert!(!flat.vertices.is_empty());
+    }
+
+    #[test]
+    fn parse_multicolor_keeps_document_order() {
+        let svg = r##"<svg viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg">
+            <rect x="2" y="2" width="12" height="12" fill="#ff0000" stroke="black"/>
+            <rect x="10" y="10" width="12" height="12" fill="#0000ff"/>
+        </svg>"##;
+        let icon = parse_svg_multicolor(svg).unwrap();
+        // The first path's stroke stays with it, under the second fill
+        assert!(icon.strokes.is_empty());
+        assert_eq!(icon.subpaths.len(), 2);
+        assert_eq!(icon.subpaths[0].strokes.len(), 1);
+        assert!(icon.subpaths[1].strokes.is_empty());
+        assert_eq!(icon.subpaths[1].color, Some(egui::Color32::BLUE));
     }
 
     #[test]
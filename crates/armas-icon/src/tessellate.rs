@@ -1,22 +1,38 @@
 //! Shared SVG tessellation logic.
 //!
 //! Used by both the `build` module (compile-time codegen) and the `runtime` module
-//! (runtime SVG parsing).
+//! (runtime SVG parsing). [`tessellate_path`] tessellates a bare SVG path string,
+//! e.g. for shapes generated on the fly.
 
+use crate::{IconStroke, IconSubpath};
+use egui::Color32;
 use lyon_tessellation::{
-    path::Path as TessPath, BuffersBuilder, FillOptions, FillTessellator, FillVertex,
-    StrokeOptions, StrokeTessellator, StrokeVertex, VertexBuffers,
+    path::{iterator::PathIterator, Path as TessPath, PathEvent},
+    BuffersBuilder, FillOptions, FillTessellator, FillVertex, StrokeOptions, VertexBuffers,
 };
+use svgtypes::SimplePathSegment;
 use usvg::tiny_skia_path::PathSegment;
+use usvg::Paint;
 
 /// Tessellated icon geometry.
 pub struct TessellatedIcon {
+    /// Vertex positions of the merged fills
     pub vertices: Vec<(f32, f32)>,
+    /// Triangle indices of the merged fills
     pub indices: Vec<u32>,
+    /// Stroked paths
+    pub strokes: Vec<IconStroke>,
+    /// Paths kept apart with their colors, in document order (multicolor only)
+    pub subpaths: Vec<IconSubpath>,
+    /// Viewbox width
     pub viewbox_width: f32,
+    /// Viewbox height
     pub viewbox_height: f32,
 }
 
+/// Vertex positions and triangle indices of a tessellated path.
+pub type PathGeometry = (Vec<(f32, f32)>, Vec<u32>);
+
 /// Error type for tessellation operations.
 #[derive(Debug)]
 pub enum TessError {
@@ -43,6 +59,11 @@ impl From<lyon_tessellation::TessellationError> for TessError {
     }
 }
 
+impl From<svgtypes::Error> for TessError {
+    fn from(e: svgtypes::Error) -> Self {
+        Self::SvgParse(e.to_string())
+    }
+}
 
 impl From<usvg::Error> for TessError {
     fn from(e: usvg::Error) -> Self {
@@ -51,7 +72,27 @@ impl From<usvg::Error> for TessError {
 }
 
 /// Tessellate an SVG string into vertex/index geometry.
+///
+/// # Errors
+///
+/// Returns an error if the SVG cannot be parsed or tessellated.
 pub fn tessellate_svg_data(svg_str: &str) -> Result<TessellatedIcon, TessError> {
+    tessellate_svg(svg_str, false)
+}
+
+/// Tessellate an SVG string, keeping each filled path and its color apart.
+///
+/// Each path goes to [`TessellatedIcon::subpaths`], fill and strokes
+/// together, instead of the shared buffers.
+///
+/// # Errors
+///
+/// Returns an error if the SVG cannot be parsed or tessellated.
+pub fn tessellate_svg_data_multicolor(svg_str: &str) -> Result<TessellatedIcon, TessError> {
+    tessellate_svg(svg_str, true)
+}
+
+fn tessellate_svg(svg_str: &str, multicolor: bool) -> Result<TessellatedIcon, TessError> {
     let (viewbox_width, viewbox_height) = extract_viewbox(svg_str).unwrap_or((24.0, 24.0));
 
     let options = usvg::Options::default();
@@ -59,13 +100,15 @@ pub fn tessellate_svg_data(svg_str: &str) -> Result<TessellatedIcon, TessError>
 
     let mut geometry: VertexBuffers<[f32; 2], u32> = VertexBuffers::new();
     let mut fill_tessellator = FillTessellator::new();
-    let mut stroke_tessellator = StrokeTessellator::new();
+    let mut strokes = Vec::new();
+    let mut subpaths = Vec::new();
 
     tessellate_group(
         tree.root(),
         &mut fill_tessellator,
-        &mut stroke_tessellator,
         &mut geometry,
+        &mut strokes,
+        multicolor.then_some(&mut subpaths),
     )?;
 
     let vertices = geometry.vertices.iter().map(|v| (v[0], v[1])).collect();
@@ -73,11 +116,73 @@ pub fn tessellate_svg_data(svg_str: &str) -> Result<TessellatedIcon, TessError>
     Ok(TessellatedIcon {
         vertices,
         indices: geometry.indices,
+        strokes,
+        subpaths,
         viewbox_width,
         viewbox_height,
     })
 }
 
+/// Tessellate a single SVG path string (the contents of a `d` attribute).
+///
+/// Curves are flattened the same way as in [`tessellate_svg_data`], to within
+/// `tolerance` units of the true shape: smaller values follow curves more
+/// closely at the cost of more triangles
+/// ([`FillOptions::DEFAULT_TOLERANCE`] is 0.1).
+///
+/// # Errors
+///
+/// Returns an error if the path data cannot be parsed or tessellated.
+///
+/// # Example
+///
+/// ```rust
+/// use armas_icon::tessellate::tessellate_path;
+///
+/// let (vertices, indices) = tessellate_path("M0 0 L24 12 L0 24 Z", 0.1).unwrap();
+/// assert_eq!(vertices.len(), 3);
+/// assert_eq!(indices.len(), 3);
+/// ```
+pub fn tessellate_path(path_d: &str, tolerance: f32) -> Result<PathGeometry, TessError> {
+    let mut builder = usvg::tiny_skia_path::PathBuilder::new();
+    for segment in svgtypes::SimplifyingPathParser::from(path_d) {
+        match segment? {
+            SimplePathSegment::MoveTo { x, y } => builder.move_to(x as f32, y as f32),
+            SimplePathSegment::LineTo { x, y } => builder.line_to(x as f32, y as f32),
+            SimplePathSegment::Quadratic { x1, y1, x, y } => {
+                builder.quad_to(x1 as f32, y1 as f32, x as f32, y as f32);
+            }
+            SimplePathSegment::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => builder.cubic_to(
+                x1 as f32, y1 as f32, x2 as f32, y2 as f32, x as f32, y as f32,
+            ),
+            SimplePathSegment::ClosePath => builder.close(),
+        }
+    }
+
+    // An empty or degenerate path has nothing to fill
+    let Some(data) = builder.finish() else {
+        return Ok((Vec::new(), Vec::new()));
+    };
+
+    let mut geometry: VertexBuffers<[f32; 2], u32> = VertexBuffers::new();
+    fill_path(
+        &mut FillTessellator::new(),
+        &lyon_path(&data),
+        tolerance,
+        &mut geometry,
+    )?;
+
+    let vertices = geometry.vertices.iter().map(|v| (v[0], v[1])).collect();
+    Ok((vertices, geometry.indices))
+}
+
 fn extract_viewbox(svg_data: &str) -> Option<(f32, f32)> {
     if let Some(viewbox_start) = svg_data.find("viewBox=\"") {
         let viewbox_str = &svg_data[viewbox_start + 9..];
@@ -123,11 +228,18 @@ fn extract_dimension(svg_data: &str, attr: &str) -> Option<f32> {
 fn tessellate_group(
     group: &usvg::Group,
     fill_tessellator: &mut FillTessellator,
-    stroke_tessellator: &mut StrokeTessellator,
     geometry: &mut VertexBuffers<[f32; 2], u32>,
+    strokes: &mut Vec<IconStroke>,
+    mut subpaths: Option<&mut Vec<IconSubpath>>,
 ) -> Result<(), TessError> {
     for node in group.children() {
-        tessellate_node(node, fill_tessellator, stroke_tessellator, geometry)?;
+        tessellate_node(
+            node,
+            fill_tessellator,
+            geometry,
+            strokes,
+            subpaths.as_deref_mut(),
+        )?;
     }
     Ok(())
 }
@@ -135,82 +247,195 @@ fn tessellate_group(
 fn tessellate_node(
     node: &usvg::Node,
     fill_tessellator: &mut FillTessellator,
-    stroke_tessellator: &mut StrokeTessellator,
     geometry: &mut VertexBuffers<[f32; 2], u32>,
+    strokes: &mut Vec<IconStroke>,
+    subpaths: Option<&mut Vec<IconSubpath>>,
 ) -> Result<(), TessError> {
     match node {
         usvg::Node::Path(path) => {
-            let mut builder = TessPath::builder();
-            let mut has_begun = false;
-
-            for segment in path.data().segments() {
-                match segment {
-                    PathSegment::MoveTo(p) => {
-                        if has_begun {
-                            builder.end(false);
-                        }
-                        builder.begin(lyon_tessellation::math::Point::new(p.x, p.y));
-                        has_begun = true;
-                    }
-                    PathSegment::LineTo(p) => {
-                        builder.line_to(lyon_tessellation::math::Point::new(p.x, p.y));
-                    }
-                    PathSegment::QuadTo(p1, p2) => {
-                        builder.quadratic_bezier_to(
-                            lyon_tessellation::math::Point::new(p1.x, p1.y),
-                            lyon_tessellation::math::Point::new(p2.x, p2.y),
-                        );
-                    }
-                    PathSegment::CubicTo(p1, p2, p3) => {
-                        builder.cubic_bezier_to(
-                            lyon_tessellation::math::Point::new(p1.x, p1.y),
-                            lyon_tessellation::math::Point::new(p2.x, p2.y),
-                            lyon_tessellation::math::Point::new(p3.x, p3.y),
-                        );
-                    }
-                    PathSegment::Close => {
-                        builder.end(true);
-                        has_begun = false;
-                    }
-                }
-            }
+            let lyon_path = lyon_path(path.data());
 
-            if has_begun {
-                builder.end(false);
+            if let Some(subpaths) = subpaths {
+                // One subpath per SVG path, fill before stroke, so painting
+                // them in order keeps the document's z-order
+                let mut own: VertexBuffers<[f32; 2], u32> = VertexBuffers::new();
+                if path.fill().is_some() {
+                    fill_path(
+                        fill_tessellator,
+                        &lyon_path,
+                        FillOptions::DEFAULT_TOLERANCE,
+                        &mut own,
+                    )?;
+                }
+                let mut own_strokes = Vec::new();
+                if let Some(stroke) = path.stroke() {
+                    flatten_stroke(
+                        &lyon_path,
+                        stroke.width().get(),
+                        stroke.linecap() == usvg::LineCap::Round,
+                        &mut own_strokes,
+                    );
+                }
+                subpaths.push(IconSubpath {
+                    vertices: own.vertices.iter().map(|v| (v[0], v[1])).collect(),
+                    indices: own.indices,
+                    color: path.fill().and_then(fill_color),
+                    strokes: own_strokes,
+                });
+                return Ok(());
             }
 
-            let lyon_path = builder.build();
-
             if path.fill().is_some() {
-                fill_tessellator.tessellate_path(
+                fill_path(
+                    fill_tessellator,
                     &lyon_path,
-                    &FillOptions::default(),
-                    &mut BuffersBuilder::new(geometry, |vertex: FillVertex| {
-                        [vertex.position().x, vertex.position().y]
-                    }),
+                    FillOptions::DEFAULT_TOLERANCE,
+                    geometry,
                 )?;
             }
 
             if let Some(stroke) = path.stroke() {
-                let stroke_width = stroke.width().get();
-                let stroke_options = StrokeOptions::default()
-                    .with_line_width(stroke_width)
-                    .with_line_cap(lyon_tessellation::LineCap::Round)
-                    .with_line_join(lyon_tessellation::LineJoin::Round);
-
-                stroke_tessellator.tessellate_path(
+                flatten_stroke(
                     &lyon_path,
-                    &stroke_options,
-                    &mut BuffersBuilder::new(geometry, |vertex: StrokeVertex| {
-                        [vertex.position().x, vertex.position().y]
-                    }),
-                )?;
+                    stroke.width().get(),
+                    stroke.linecap() == usvg::LineCap::Round,
+                    strokes,
+                );
             }
         }
         usvg::Node::Group(group) => {
-            tessellate_group(group, fill_tessellator, stroke_tessellator, geometry)?;
+            tessellate_group(group, fill_tessellator, geometry, strokes, subpaths)?;
         }
         _ => {}
     }
     Ok(())
 }
+
+fn fill_path(
+    fill_tessellator: &mut FillTessellator,
+    path: &TessPath,
+    tolerance: f32,
+    geometry: &mut VertexBuffers<[f32; 2], u32>,
+) -> Result<(), TessError> {
+    fill_tessellator.tessellate_path(
+        path,
+        &FillOptions::tolerance(tolerance),
+        &mut BuffersBuilder::new(geometry, |vertex: FillVertex| {
+            [vertex.position().x, vertex.position().y]
+        }),
+    )?;
+    Ok(())
+}
+
+/// Solid fill color of a path; gradients and patterns have none.
+fn fill_color(fill: &usvg::Fill) -> Option<Color32> {
+    match fill.paint() {
+        Paint::Color(c) => Some(Color32::from_rgba_unmultiplied(
+            c.red,
+            c.green,
+            c.blue,
+            (fill.opacity().get() * 255.0).round() as u8,
+        )),
+        Paint::LinearGradient(_) | Paint::RadialGradient(_) | Paint::Pattern(_) => None,
+    }
+}
+
+/// Convert usvg path data into a lyon path.
+fn lyon_path(data: &usvg::tiny_skia_path::Path) -> TessPath {
+    let mut builder = TessPath::builder();
+    let mut has_begun = false;
+
+    for segment in data.segments() {
+        match segment {
+            PathSegment::MoveTo(p) => {
+                if has_begun {
+                    builder.end(false);
+                }
+                builder.begin(lyon_tessellation::math::Point::new(p.x, p.y));
+                has_begun = true;
+            }
+            PathSegment::LineTo(p) => {
+                builder.line_to(lyon_tessellation::math::Point::new(p.x, p.y));
+            }
+            PathSegment::QuadTo(p1, p2) => {
+                builder.quadratic_bezier_to(
+                    lyon_tessellation::math::Point::new(p1.x, p1.y),
+                    lyon_tessellation::math::Point::new(p2.x, p2.y),
+                );
+            }
+            PathSegment::CubicTo(p1, p2, p3) => {
+                builder.cubic_bezier_to(
+                    lyon_tessellation::math::Point::new(p1.x, p1.y),
+                    lyon_tessellation::math::Point::new(p2.x, p2.y),
+                    lyon_tessellation::math::Point::new(p3.x, p3.y),
+                );
+            }
+            PathSegment::Close => {
+                builder.end(true);
+                has_begun = false;
+            }
+        }
+    }
+
+    if has_begun {
+        builder.end(false);
+    }
+
+    builder.build()
+}
+
+/// Flatten each subpath of a stroked path into a polyline.
+fn flatten_stroke(path: &TessPath, width: f32, round_caps: bool, strokes: &mut Vec<IconStroke>) {
+    let mut points = Vec::new();
+    for event in path.iter().flattened(StrokeOptions::DEFAULT_TOLERANCE) {
+        match event {
+            PathEvent::Begin { at } => {
+                points = vec![(at.x, at.y)];
+            }
+            PathEvent::Line { to, .. } => points.push((to.x, to.y)),
+            PathEvent::End { close, .. } => {
+                strokes.push(IconStroke {
+                    points: std::mem::take(&mut points),
+                    width,
+                    closed: close,
+                    round_caps,
+                });
+            }
+            PathEvent::Quadratic { .. } | PathEvent::Cubic { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CIRCLE_D: &str = "M12 2 A10 10 0 1 1 12 22 A10 10 0 1 1 12 2 Z";
+
+    #[test]
+    fn tessellate_path_triangle() {
+        let (vertices, indices) = tessellate_path("M0 0 L24 12 L0 24 Z", 0.1).unwrap();
+        assert_eq!(vertices.len(), 3);
+        assert_eq!(indices.len(), 3);
+    }
+
+    #[test]
+    fn tessellate_path_tolerance_trades_detail() {
+        let (coarse, _) = tessellate_path(CIRCLE_D, 1.0).unwrap();
+        let (fine, _) = tessellate_path(CIRCLE_D, 0.01).unwrap();
+        assert!(fine.len() > coarse.len());
+    }
+
+    #[test]
+    fn tessellate_path_relative_commands() {
+        let (vertices, _) = tessellate_path("m2 2 h20 v20 h-20 z", 0.1).unwrap();
+        assert!(vertices.contains(&(22.0, 22.0)));
+    }
+
+    #[test]
+    fn tessellate_path_empty_and_invalid() {
+        let (vertices, indices) = tessellate_path("", 0.1).unwrap();
+        assert!(vertices.is_empty() && indices.is_empty());
+        assert!(tessellate_path("M0 0 L", 0.1).is_err());
+    }
+}
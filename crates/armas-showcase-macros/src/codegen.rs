@@ -202,33 +202,8 @@ fn generate_tab_button() -> proc_macro2::TokenStream {
 
 fn generate_copy_button(code: &str) -> proc_macro2::TokenStream {
     quote! {
-        {
-            let copy_id = demo_id.with("copy_state");
-            let copied_at: Option<f64> = ui.ctx().data(|d| d.get_temp(copy_id));
-            let current_time = ui.input(|i| i.time);
-            let show_check = copied_at.map(|t| current_time - t < 2.0).unwrap_or(false);
-
-            if show_check {
-                ui.ctx().request_repaint_after(std::time::Duration::from_millis(100));
-            }
-
-            let copy_text = if show_check { "Copied!" } else { "Copy" };
-            let copy_response = ui.add(
-                egui::Label::new(
-                    egui::RichText::new(copy_text)
-                        .size(12.0)
-                        .color(theme.muted_foreground()),
-                )
-                .sense(egui::Sense::click()),
-            );
-
-            if copy_response.clicked() {
-                ui.ctx().copy_text(#code.to_string());
-                ui.ctx().data_mut(|d| d.insert_temp(copy_id, current_time));
-            }
-            if copy_response.hovered() {
-                ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
-            }
-        }
+        armas::CopyButton::new(#code)
+            .id(demo_id.with("copy_state"))
+            .show(ui, &theme);
     }
 }
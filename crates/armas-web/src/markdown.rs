@@ -105,23 +105,19 @@ pub fn render_markdown(ui: &mut egui::Ui, markdown: &str, theme: &Theme) {
                     code_block_lang.clear();
                     in_code_block = false;
                 }
-                TagEnd::Paragraph => {
-                    if !current_text.is_empty() && !in_list && !in_table {
-                        render_paragraph(ui, &current_text, theme, base_id, element_counter);
-                        element_counter += 1;
-                        current_text.clear();
-                    }
+                TagEnd::Paragraph if !current_text.is_empty() && !in_list && !in_table => {
+                    render_paragraph(ui, &current_text, theme, base_id, element_counter);
+                    element_counter += 1;
+                    current_text.clear();
                 }
                 TagEnd::List(_) => {
                     in_list = false;
                     ui.add_space(8.0);
                 }
-                TagEnd::Item => {
-                    if !list_item_text.is_empty() {
-                        render_list_item(ui, &list_item_text, theme, base_id, element_counter);
-                        element_counter += 1;
-                        list_item_text.clear();
-                    }
+                TagEnd::Item if !list_item_text.is_empty() => {
+                    render_list_item(ui, &list_item_text, theme, base_id, element_counter);
+                    element_counter += 1;
+                    list_item_text.clear();
                 }
                 TagEnd::Emphasis => {
                     // Emphasis tracking not currently used
@@ -132,32 +128,26 @@ pub fn render_markdown(ui: &mut egui::Ui, markdown: &str, theme: &Theme) {
                 TagEnd::Strikethrough => {
                     // Strikethrough tracking not currently used
                 }
-                TagEnd::Table => {
-                    if in_table {
-                        render_table(
-                            ui,
-                            &table_headers,
-                            &table_rows,
-                            theme,
-                            base_id,
-                            element_counter,
-                        );
-                        element_counter += 1;
-                        in_table = false;
-                    }
+                TagEnd::Table if in_table => {
+                    render_table(
+                        ui,
+                        &table_headers,
+                        &table_rows,
+                        theme,
+                        base_id,
+                        element_counter,
+                    );
+                    element_counter += 1;
+                    in_table = false;
                 }
-                TagEnd::TableHead => {
-                    if in_table_head {
-                        table_headers = current_row.clone();
-                        current_row.clear();
-                        in_table_head = false;
-                    }
+                TagEnd::TableHead if in_table_head => {
+                    table_headers = current_row.clone();
+                    current_row.clear();
+                    in_table_head = false;
                 }
-                TagEnd::TableRow => {
-                    if in_table && !in_table_head {
-                        table_rows.push(current_row.clone());
-                        current_row.clear();
-                    }
+                TagEnd::TableRow if in_table && !in_table_head => {
+                    table_rows.push(current_row.clone());
+                    current_row.clear();
                 }
                 TagEnd::TableCell => {
                     current_row.push(current_cell.clone());
@@ -372,21 +362,7 @@ fn render_code_block(
         } else {
             // Copy button for code blocks
             let copy_id = ui.id().with((base_id, id, "copy_state"));
-            let copied_at: Option<f64> = ui.ctx().data(|d| d.get_temp(copy_id));
-            let current_time = ui.input(|i| i.time);
-
-            // Show check mark for 2 seconds after copying
-            let show_check = if let Some(copied_time) = copied_at {
-                current_time - copied_time < 2.0
-            } else {
-                false
-            };
-
-            // Request repaint if we're showing the check mark
-            if show_check {
-                ui.ctx()
-                    .request_repaint_after(std::time::Duration::from_millis(100));
-            }
+            let show_check = armas::copy_confirming(ui.ctx(), copy_id);
 
             let button_text = if show_check {
                 "✓ Copied"
@@ -414,19 +390,7 @@ fn render_code_block(
                     .on_hover_text(button_tooltip)
                     .clicked()
                 {
-                    ui.ctx().copy_text(code.to_string());
-                    ui.ctx().data_mut(|d| d.insert_temp(copy_id, current_time));
-
-                    // For WASM, also use web Clipboard API
-                    #[cfg(target_arch = "wasm32")]
-                    {
-                        if let Some(window) = web_sys::window() {
-                            let navigator = window.navigator().clipboard();
-                            let code_copy = code.to_string();
-                            let promise = navigator.write_text(&code_copy);
-                            let _ = wasm_bindgen_futures::JsFuture::from(promise);
-                        }
-                    }
+                    armas::copy_to_clipboard(ui.ctx(), copy_id, code);
                 }
             });
         }